@@ -3,6 +3,8 @@
 pub type OutgoingRequest = wasi::http::types::OutgoingRequest;
 pub type IncomingRequest = wasi::http::types::IncomingRequest;
 pub type IncomingBody = wasi::http::types::IncomingBody;
+pub type ResponseOutparam = wasi::http::types::ResponseOutparam;
+pub type FutureIncomingResponse = wasi::http::types::FutureIncomingResponse;
 #[allow(unused_unsafe, clippy::all)]
 pub fn get_manifest() -> _rt::String {
     unsafe {
@@ -51,6 +53,204 @@ pub fn set_component_id(component_id: &str) {
     }
 }
 #[allow(unused_unsafe, clippy::all)]
+/// Set the arguments, environment and stdin for the next run of a WAGI component
+pub fn prepare_wagi(
+    arguments: &[_rt::String],
+    environment: &[(_rt::String, _rt::String)],
+    stdin: &[u8],
+) {
+    unsafe {
+        let vec1 = arguments;
+        let len1 = vec1.len();
+        let layout1 = _rt::alloc::Layout::from_size_align_unchecked(vec1.len() * 8, 4);
+        let result1 = if layout1.size() != 0 {
+            let ptr = _rt::alloc::alloc(layout1).cast::<u8>();
+            if ptr.is_null() {
+                _rt::alloc::handle_alloc_error(layout1);
+            }
+            ptr
+        } else {
+            {
+                ::core::ptr::null_mut()
+            }
+        };
+        for (i, e) in vec1.into_iter().enumerate() {
+            let base = result1.add(i * 8);
+            {
+                let vec0 = e;
+                let ptr0 = vec0.as_ptr().cast::<u8>();
+                let len0 = vec0.len();
+                *base.add(4).cast::<usize>() = len0;
+                *base.add(0).cast::<*mut u8>() = ptr0.cast_mut();
+            }
+        }
+        let vec5 = environment;
+        let len5 = vec5.len();
+        let layout5 = _rt::alloc::Layout::from_size_align_unchecked(vec5.len() * 16, 4);
+        let result5 = if layout5.size() != 0 {
+            let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
+            if ptr.is_null() {
+                _rt::alloc::handle_alloc_error(layout5);
+            }
+            ptr
+        } else {
+            {
+                ::core::ptr::null_mut()
+            }
+        };
+        for (i, e) in vec5.into_iter().enumerate() {
+            let base = result5.add(i * 16);
+            {
+                let (t2_0, t2_1) = e;
+                let vec3 = t2_0;
+                let ptr3 = vec3.as_ptr().cast::<u8>();
+                let len3 = vec3.len();
+                *base.add(4).cast::<usize>() = len3;
+                *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                let vec4 = t2_1;
+                let ptr4 = vec4.as_ptr().cast::<u8>();
+                let len4 = vec4.len();
+                *base.add(12).cast::<usize>() = len4;
+                *base.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+            }
+        }
+        let vec6 = stdin;
+        let ptr6 = vec6.as_ptr().cast::<u8>();
+        let len6 = vec6.len();
+
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "$root")]
+        extern "C" {
+            #[link_name = "prepare-wagi"]
+            fn wit_import(_: *mut u8, _: usize, _: *mut u8, _: usize, _: *mut u8, _: usize);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        fn wit_import(_: *mut u8, _: usize, _: *mut u8, _: usize, _: *mut u8, _: usize) {
+            unreachable!()
+        }
+        wit_import(result1, len1, result5, len5, ptr6.cast_mut(), len6);
+        if layout1.size() != 0 {
+            _rt::alloc::dealloc(result1.cast(), layout1);
+        }
+        if layout5.size() != 0 {
+            _rt::alloc::dealloc(result5.cast(), layout5);
+        }
+    }
+}
+#[allow(unused_unsafe, clippy::all)]
+/// Take everything the WAGI component wrote to stdout since `prepare-wagi` was called
+pub fn finish_wagi() -> _rt::Vec<u8> {
+    unsafe {
+        #[repr(align(4))]
+        struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
+        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "$root")]
+        extern "C" {
+            #[link_name = "finish-wagi"]
+            fn wit_import(_: *mut u8);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        fn wit_import(_: *mut u8) {
+            unreachable!()
+        }
+        wit_import(ptr0);
+        let l1 = *ptr0.add(0).cast::<*mut u8>();
+        let l2 = *ptr0.add(4).cast::<usize>();
+        let len3 = l2;
+        _rt::Vec::from_raw_parts(l1.cast(), len3, len3)
+    }
+}
+#[allow(unused_unsafe, clippy::all)]
+/// Take the reason the last stand-in for an entrypoint the app doesn't export failed with
+pub fn take_stand_in_error() -> Option<_rt::String> {
+    unsafe {
+        #[repr(align(4))]
+        struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
+        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
+        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "$root")]
+        extern "C" {
+            #[link_name = "take-stand-in-error"]
+            fn wit_import(_: *mut u8);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        fn wit_import(_: *mut u8) {
+            unreachable!()
+        }
+        wit_import(ptr0);
+        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
+        match l1 {
+            0 => None,
+            1 => {
+                let e = {
+                    let l2 = *ptr0.add(4).cast::<*mut u8>();
+                    let l3 = *ptr0.add(8).cast::<usize>();
+                    let len4 = l3;
+                    let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
+
+                    _rt::string_lift(bytes4)
+                };
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        }
+    }
+}
+#[allow(unused_unsafe, clippy::all)]
+/// Whether the current component is allowed to make requests to the url
+pub fn allows_outbound_url(url: &str) -> bool {
+    unsafe {
+        let vec0 = url;
+        let ptr0 = vec0.as_ptr().cast::<u8>();
+        let len0 = vec0.len();
+
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "$root")]
+        extern "C" {
+            #[link_name = "allows-outbound-url"]
+            fn wit_import(_: *mut u8, _: usize) -> i32;
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        fn wit_import(_: *mut u8, _: usize) -> i32 {
+            unreachable!()
+        }
+        let ret = wit_import(ptr0.cast_mut(), len0);
+        _rt::bool_lift(ret as u8)
+    }
+}
+#[allow(unused_unsafe, clippy::all)]
+/// Record a request the app made to itself through local service chaining
+pub fn record_internal_request(method: &str, url: &str) {
+    unsafe {
+        let vec0 = method;
+        let ptr0 = vec0.as_ptr().cast::<u8>();
+        let len0 = vec0.len();
+        let vec1 = url;
+        let ptr1 = vec1.as_ptr().cast::<u8>();
+        let len1 = vec1.len();
+
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "$root")]
+        extern "C" {
+            #[link_name = "record-internal-request"]
+            fn wit_import(_: *mut u8, _: usize, _: *mut u8, _: usize);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        fn wit_import(_: *mut u8, _: usize, _: *mut u8, _: usize) {
+            unreachable!()
+        }
+        wit_import(ptr0.cast_mut(), len0, ptr1.cast_mut(), len1);
+    }
+}
+#[allow(unused_unsafe, clippy::all)]
 /// See `fermyon:spin-test/http-helper/new-request` for documentation on this function
 pub fn new_request(
     request: OutgoingRequest,
@@ -76,6 +276,257 @@ pub fn new_request(
         wasi::http::types::IncomingRequest::from_handle(ret as u32)
     }
 }
+#[allow(unused_unsafe, clippy::all)]
+/// See `fermyon:spin-wasi-virt/http-helper/new-response-future` for documentation on this function
+pub fn new_response_future() -> (ResponseOutparam, FutureIncomingResponse) {
+    unsafe {
+        #[repr(align(4))]
+        struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
+        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "$root")]
+        extern "C" {
+            #[link_name = "new-response-future"]
+            fn wit_import(_: *mut u8);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        fn wit_import(_: *mut u8) {
+            unreachable!()
+        }
+        wit_import(ptr0);
+        let l1 = *ptr0.add(0).cast::<i32>();
+        let l2 = *ptr0.add(4).cast::<i32>();
+        (
+            wasi::http::types::ResponseOutparam::from_handle(l1 as u32),
+            wasi::http::types::FutureIncomingResponse::from_handle(l2 as u32),
+        )
+    }
+}
+#[allow(unused_unsafe, clippy::all)]
+/// See `fermyon:spin-wasi-virt/http-helper/client-addr` for documentation on this function
+pub fn client_addr(request: &IncomingRequest) -> Option<_rt::String> {
+    unsafe {
+        #[repr(align(4))]
+        struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
+        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
+        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "$root")]
+        extern "C" {
+            #[link_name = "client-addr"]
+            fn wit_import(_: i32, _: *mut u8);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        fn wit_import(_: i32, _: *mut u8) {
+            unreachable!()
+        }
+        wit_import((request).handle() as i32, ptr0);
+        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
+        match l1 {
+            0 => None,
+            1 => {
+                let e = {
+                    let l2 = *ptr0.add(4).cast::<*mut u8>();
+                    let l3 = *ptr0.add(8).cast::<usize>();
+                    let len4 = l3;
+                    let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
+
+                    _rt::string_lift(bytes4)
+                };
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        }
+    }
+}
+#[allow(unused_unsafe, clippy::all)]
+/// See `fermyon:spin-wasi-virt/http-helper/tls` for documentation on this function
+pub fn tls(request: &IncomingRequest) -> Option<bool> {
+    unsafe {
+        #[repr(align(1))]
+        struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
+        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
+        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "$root")]
+        extern "C" {
+            #[link_name = "tls"]
+            fn wit_import(_: i32, _: *mut u8);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        fn wit_import(_: i32, _: *mut u8) {
+            unreachable!()
+        }
+        wit_import((request).handle() as i32, ptr0);
+        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
+        match l1 {
+            0 => None,
+            1 => {
+                let e = {
+                    let l2 = i32::from(*ptr0.add(1).cast::<u8>());
+
+                    _rt::bool_lift(l2 as u8)
+                };
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        }
+    }
+}
+#[allow(unused_unsafe, clippy::all)]
+/// See `fermyon:spin-wasi-virt/http-helper/listen-addr` for documentation on this function
+pub fn listen_addr(request: &IncomingRequest) -> Option<_rt::String> {
+    unsafe {
+        #[repr(align(4))]
+        struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
+        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
+        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+        #[cfg(target_arch = "wasm32")]
+        #[link(wasm_import_module = "$root")]
+        extern "C" {
+            #[link_name = "listen-addr"]
+            fn wit_import(_: i32, _: *mut u8);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        fn wit_import(_: i32, _: *mut u8) {
+            unreachable!()
+        }
+        wit_import((request).handle() as i32, ptr0);
+        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
+        match l1 {
+            0 => None,
+            1 => {
+                let e = {
+                    let l2 = *ptr0.add(4).cast::<*mut u8>();
+                    let l3 = *ptr0.add(8).cast::<usize>();
+                    let len4 = l3;
+                    let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
+
+                    _rt::string_lift(bytes4)
+                };
+                Some(e)
+            }
+            _ => _rt::invalid_enum_discriminant(),
+        }
+    }
+}
+#[allow(dead_code)]
+pub mod fermyon {
+    #[allow(dead_code)]
+    pub mod router {
+        #[allow(dead_code, clippy::all)]
+        pub mod inbound_redis {
+            #[used]
+            #[doc(hidden)]
+            #[cfg(target_arch = "wasm32")]
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+            use super::super::super::_rt;
+            /// General purpose error.
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, PartialEq)]
+            pub enum Error {
+                Success,
+                Error,
+            }
+            impl Error {
+                pub fn name(&self) -> &'static str {
+                    match self {
+                        Error::Success => "success",
+                        Error::Error => "error",
+                    }
+                }
+                pub fn message(&self) -> &'static str {
+                    match self {
+                        Error::Success => "",
+                        Error::Error => "",
+                    }
+                }
+            }
+            impl ::core::fmt::Debug for Error {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("Error")
+                        .field("code", &(*self as i32))
+                        .field("name", &self.name())
+                        .field("message", &self.message())
+                        .finish()
+                }
+            }
+            impl ::core::fmt::Display for Error {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "{} (error {})", self.name(), *self as i32)
+                }
+            }
+
+            impl std::error::Error for Error {}
+
+            impl Error {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> Error {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+
+                    match val {
+                        0 => Error::Success,
+                        1 => Error::Error,
+
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+
+            /// The message payload.
+            pub type Payload = _rt::Vec<u8>;
+            #[allow(unused_unsafe, clippy::all)]
+            /// The entrypoint for a Redis handler.
+            pub fn handle_message(message: &Payload) -> Result<(), Error> {
+                unsafe {
+                    #[repr(align(1))]
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
+                    let vec0 = message;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "fermyon:router/inbound-redis")]
+                    extern "C" {
+                        #[link_name = "handle-message"]
+                        fn wit_import(_: *mut u8, _: usize, _: *mut u8);
+                    }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: *mut u8, _: usize, _: *mut u8) {
+                        unreachable!()
+                    }
+                    wit_import(ptr0.cast_mut(), len0, ptr1);
+                    let l2 = i32::from(*ptr1.add(0).cast::<u8>());
+                    match l2 {
+                        0 => {
+                            let e = ();
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l3 = i32::from(*ptr1.add(1).cast::<u8>());
+
+                                Error::_lift(l3 as u8)
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    }
+                }
+            }
+        }
+    }
+}
 #[allow(dead_code)]
 pub mod wasi {
     #[allow(dead_code)]
@@ -6033,27 +6484,555 @@ pub mod wasi {
                 }
             }
         }
-    }
-    #[allow(dead_code)]
-    pub mod io {
+
         #[allow(dead_code, clippy::all)]
-        pub mod poll {
+        pub mod outgoing_handler {
             #[used]
             #[doc(hidden)]
             #[cfg(target_arch = "wasm32")]
             static __FORCE_SECTION_REF: fn() =
                 super::super::super::__link_custom_section_describing_imports;
             use super::super::super::_rt;
-            /// `pollable` represents a single I/O event which may be ready, or not.
-
-            #[derive(Debug)]
-            #[repr(transparent)]
-            pub struct Pollable {
-                handle: _rt::Resource<Pollable>,
-            }
+            pub type OutgoingRequest = super::super::super::wasi::http::types::OutgoingRequest;
+            pub type RequestOptions = super::super::super::wasi::http::types::RequestOptions;
+            pub type FutureIncomingResponse =
+                super::super::super::wasi::http::types::FutureIncomingResponse;
+            pub type ErrorCode = super::super::super::wasi::http::types::ErrorCode;
+            #[allow(unused_unsafe, clippy::all)]
+            /// This function is invoked with an outgoing HTTP Request, and it returns
+            /// a resource `future-incoming-response` which represents an HTTP Response
+            /// which may arrive in the future.
+            ///
+            /// The `options` argument accepts optional parameters for the HTTP
+            /// protocol's transport layer.
+            ///
+            /// This function may return an error if the `outgoing-request` is invalid
+            /// or not allowed to be made. Otherwise, protocol errors are reported
+            /// through the `future-incoming-response`.
+            pub fn handle(
+                request: OutgoingRequest,
+                options: Option<RequestOptions>,
+            ) -> Result<FutureIncomingResponse, ErrorCode> {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 40]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 40]);
+                    let (result0_0, result0_1) = match &options {
+                        Some(e) => (1i32, (e).take_handle() as i32),
+                        None => (0i32, 0i32),
+                    };
+                    let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "wasi:http/outgoing-handler@0.2.0")]
+                    extern "C" {
+                        #[link_name = "handle"]
+                        fn wit_import(_: i32, _: i32, _: i32, _: *mut u8);
+                    }
 
-            impl Pollable {
-                #[doc(hidden)]
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: i32, _: i32, _: i32, _: *mut u8) {
+                        unreachable!()
+                    }
+                    wit_import((&request).take_handle() as i32, result0_0, result0_1, ptr1);
+                    let l2 = i32::from(*ptr1.add(0).cast::<u8>());
+                    match l2 {
+                        0 => {
+                            let e = {
+                                let l3 = *ptr1.add(8).cast::<i32>();
+
+                                super::super::super::wasi::http::types::FutureIncomingResponse::from_handle(l3 as u32)
+                            };
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l4 = i32::from(*ptr1.add(8).cast::<u8>());
+                                use super::super::super::wasi::http::types::ErrorCode as V66;
+                                let v66 = match l4 {
+                                    0 => V66::DnsTimeout,
+                                    1 => {
+                                        let e66 = {
+                                            let l5 = i32::from(*ptr1.add(16).cast::<u8>());
+                                            let l9 = i32::from(*ptr1.add(28).cast::<u8>());
+
+                                            super::super::super::wasi::http::types::DnsErrorPayload{
+                        rcode: match l5 {
+                          0 => None,
+                          1 => {
+                            let e = {
+                              let l6 = *ptr1.add(20).cast::<*mut u8>();
+                              let l7 = *ptr1.add(24).cast::<usize>();
+                              let len8 = l7;
+                              let bytes8 = _rt::Vec::from_raw_parts(l6.cast(), len8, len8);
+
+                              _rt::string_lift(bytes8)
+                            };
+                            Some(e)
+                          }
+                          _ => _rt::invalid_enum_discriminant(),
+                        },
+                        info_code: match l9 {
+                          0 => None,
+                          1 => {
+                            let e = {
+                              let l10 = i32::from(*ptr1.add(30).cast::<u16>());
+
+                              l10 as u16
+                            };
+                            Some(e)
+                          }
+                          _ => _rt::invalid_enum_discriminant(),
+                        },
+                      }
+                                        };
+                                        V66::DnsError(e66)
+                                    }
+                                    2 => V66::DestinationNotFound,
+                                    3 => V66::DestinationUnavailable,
+                                    4 => V66::DestinationIpProhibited,
+                                    5 => V66::DestinationIpUnroutable,
+                                    6 => V66::ConnectionRefused,
+                                    7 => V66::ConnectionTerminated,
+                                    8 => V66::ConnectionTimeout,
+                                    9 => V66::ConnectionReadTimeout,
+                                    10 => V66::ConnectionWriteTimeout,
+                                    11 => V66::ConnectionLimitReached,
+                                    12 => V66::TlsProtocolError,
+                                    13 => V66::TlsCertificateError,
+                                    14 => {
+                                        let e66 = {
+                                            let l11 = i32::from(*ptr1.add(16).cast::<u8>());
+                                            let l13 = i32::from(*ptr1.add(20).cast::<u8>());
+
+                                            super::super::super::wasi::http::types::TlsAlertReceivedPayload{
+                        alert_id: match l11 {
+                          0 => None,
+                          1 => {
+                            let e = {
+                              let l12 = i32::from(*ptr1.add(17).cast::<u8>());
+
+                              l12 as u8
+                            };
+                            Some(e)
+                          }
+                          _ => _rt::invalid_enum_discriminant(),
+                        },
+                        alert_message: match l13 {
+                          0 => None,
+                          1 => {
+                            let e = {
+                              let l14 = *ptr1.add(24).cast::<*mut u8>();
+                              let l15 = *ptr1.add(28).cast::<usize>();
+                              let len16 = l15;
+                              let bytes16 = _rt::Vec::from_raw_parts(l14.cast(), len16, len16);
+
+                              _rt::string_lift(bytes16)
+                            };
+                            Some(e)
+                          }
+                          _ => _rt::invalid_enum_discriminant(),
+                        },
+                      }
+                                        };
+                                        V66::TlsAlertReceived(e66)
+                                    }
+                                    15 => V66::HttpRequestDenied,
+                                    16 => V66::HttpRequestLengthRequired,
+                                    17 => {
+                                        let e66 = {
+                                            let l17 = i32::from(*ptr1.add(16).cast::<u8>());
+
+                                            match l17 {
+                                                0 => None,
+                                                1 => {
+                                                    let e = {
+                                                        let l18 = *ptr1.add(24).cast::<i64>();
+
+                                                        l18 as u64
+                                                    };
+                                                    Some(e)
+                                                }
+                                                _ => _rt::invalid_enum_discriminant(),
+                                            }
+                                        };
+                                        V66::HttpRequestBodySize(e66)
+                                    }
+                                    18 => V66::HttpRequestMethodInvalid,
+                                    19 => V66::HttpRequestUriInvalid,
+                                    20 => V66::HttpRequestUriTooLong,
+                                    21 => {
+                                        let e66 = {
+                                            let l19 = i32::from(*ptr1.add(16).cast::<u8>());
+
+                                            match l19 {
+                                                0 => None,
+                                                1 => {
+                                                    let e = {
+                                                        let l20 = *ptr1.add(20).cast::<i32>();
+
+                                                        l20 as u32
+                                                    };
+                                                    Some(e)
+                                                }
+                                                _ => _rt::invalid_enum_discriminant(),
+                                            }
+                                        };
+                                        V66::HttpRequestHeaderSectionSize(e66)
+                                    }
+                                    22 => {
+                                        let e66 = {
+                                            let l21 = i32::from(*ptr1.add(16).cast::<u8>());
+
+                                            match l21 {
+                                                0 => None,
+                                                1 => {
+                                                    let e = {
+                                                        let l22 =
+                                                            i32::from(*ptr1.add(20).cast::<u8>());
+                                                        let l26 =
+                                                            i32::from(*ptr1.add(32).cast::<u8>());
+
+                                                        super::super::super::wasi::http::types::FieldSizePayload{
+                              field_name: match l22 {
+                                0 => None,
+                                1 => {
+                                  let e = {
+                                    let l23 = *ptr1.add(24).cast::<*mut u8>();
+                                    let l24 = *ptr1.add(28).cast::<usize>();
+                                    let len25 = l24;
+                                    let bytes25 = _rt::Vec::from_raw_parts(l23.cast(), len25, len25);
+
+                                    _rt::string_lift(bytes25)
+                                  };
+                                  Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                              },
+                              field_size: match l26 {
+                                0 => None,
+                                1 => {
+                                  let e = {
+                                    let l27 = *ptr1.add(36).cast::<i32>();
+
+                                    l27 as u32
+                                  };
+                                  Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                              },
+                            }
+                                                    };
+                                                    Some(e)
+                                                }
+                                                _ => _rt::invalid_enum_discriminant(),
+                                            }
+                                        };
+                                        V66::HttpRequestHeaderSize(e66)
+                                    }
+                                    23 => {
+                                        let e66 = {
+                                            let l28 = i32::from(*ptr1.add(16).cast::<u8>());
+
+                                            match l28 {
+                                                0 => None,
+                                                1 => {
+                                                    let e = {
+                                                        let l29 = *ptr1.add(20).cast::<i32>();
+
+                                                        l29 as u32
+                                                    };
+                                                    Some(e)
+                                                }
+                                                _ => _rt::invalid_enum_discriminant(),
+                                            }
+                                        };
+                                        V66::HttpRequestTrailerSectionSize(e66)
+                                    }
+                                    24 => {
+                                        let e66 = {
+                                            let l30 = i32::from(*ptr1.add(16).cast::<u8>());
+                                            let l34 = i32::from(*ptr1.add(28).cast::<u8>());
+
+                                            super::super::super::wasi::http::types::FieldSizePayload{
+                        field_name: match l30 {
+                          0 => None,
+                          1 => {
+                            let e = {
+                              let l31 = *ptr1.add(20).cast::<*mut u8>();
+                              let l32 = *ptr1.add(24).cast::<usize>();
+                              let len33 = l32;
+                              let bytes33 = _rt::Vec::from_raw_parts(l31.cast(), len33, len33);
+
+                              _rt::string_lift(bytes33)
+                            };
+                            Some(e)
+                          }
+                          _ => _rt::invalid_enum_discriminant(),
+                        },
+                        field_size: match l34 {
+                          0 => None,
+                          1 => {
+                            let e = {
+                              let l35 = *ptr1.add(32).cast::<i32>();
+
+                              l35 as u32
+                            };
+                            Some(e)
+                          }
+                          _ => _rt::invalid_enum_discriminant(),
+                        },
+                      }
+                                        };
+                                        V66::HttpRequestTrailerSize(e66)
+                                    }
+                                    25 => V66::HttpResponseIncomplete,
+                                    26 => {
+                                        let e66 = {
+                                            let l36 = i32::from(*ptr1.add(16).cast::<u8>());
+
+                                            match l36 {
+                                                0 => None,
+                                                1 => {
+                                                    let e = {
+                                                        let l37 = *ptr1.add(20).cast::<i32>();
+
+                                                        l37 as u32
+                                                    };
+                                                    Some(e)
+                                                }
+                                                _ => _rt::invalid_enum_discriminant(),
+                                            }
+                                        };
+                                        V66::HttpResponseHeaderSectionSize(e66)
+                                    }
+                                    27 => {
+                                        let e66 = {
+                                            let l38 = i32::from(*ptr1.add(16).cast::<u8>());
+                                            let l42 = i32::from(*ptr1.add(28).cast::<u8>());
+
+                                            super::super::super::wasi::http::types::FieldSizePayload{
+                        field_name: match l38 {
+                          0 => None,
+                          1 => {
+                            let e = {
+                              let l39 = *ptr1.add(20).cast::<*mut u8>();
+                              let l40 = *ptr1.add(24).cast::<usize>();
+                              let len41 = l40;
+                              let bytes41 = _rt::Vec::from_raw_parts(l39.cast(), len41, len41);
+
+                              _rt::string_lift(bytes41)
+                            };
+                            Some(e)
+                          }
+                          _ => _rt::invalid_enum_discriminant(),
+                        },
+                        field_size: match l42 {
+                          0 => None,
+                          1 => {
+                            let e = {
+                              let l43 = *ptr1.add(32).cast::<i32>();
+
+                              l43 as u32
+                            };
+                            Some(e)
+                          }
+                          _ => _rt::invalid_enum_discriminant(),
+                        },
+                      }
+                                        };
+                                        V66::HttpResponseHeaderSize(e66)
+                                    }
+                                    28 => {
+                                        let e66 = {
+                                            let l44 = i32::from(*ptr1.add(16).cast::<u8>());
+
+                                            match l44 {
+                                                0 => None,
+                                                1 => {
+                                                    let e = {
+                                                        let l45 = *ptr1.add(24).cast::<i64>();
+
+                                                        l45 as u64
+                                                    };
+                                                    Some(e)
+                                                }
+                                                _ => _rt::invalid_enum_discriminant(),
+                                            }
+                                        };
+                                        V66::HttpResponseBodySize(e66)
+                                    }
+                                    29 => {
+                                        let e66 = {
+                                            let l46 = i32::from(*ptr1.add(16).cast::<u8>());
+
+                                            match l46 {
+                                                0 => None,
+                                                1 => {
+                                                    let e = {
+                                                        let l47 = *ptr1.add(20).cast::<i32>();
+
+                                                        l47 as u32
+                                                    };
+                                                    Some(e)
+                                                }
+                                                _ => _rt::invalid_enum_discriminant(),
+                                            }
+                                        };
+                                        V66::HttpResponseTrailerSectionSize(e66)
+                                    }
+                                    30 => {
+                                        let e66 = {
+                                            let l48 = i32::from(*ptr1.add(16).cast::<u8>());
+                                            let l52 = i32::from(*ptr1.add(28).cast::<u8>());
+
+                                            super::super::super::wasi::http::types::FieldSizePayload{
+                        field_name: match l48 {
+                          0 => None,
+                          1 => {
+                            let e = {
+                              let l49 = *ptr1.add(20).cast::<*mut u8>();
+                              let l50 = *ptr1.add(24).cast::<usize>();
+                              let len51 = l50;
+                              let bytes51 = _rt::Vec::from_raw_parts(l49.cast(), len51, len51);
+
+                              _rt::string_lift(bytes51)
+                            };
+                            Some(e)
+                          }
+                          _ => _rt::invalid_enum_discriminant(),
+                        },
+                        field_size: match l52 {
+                          0 => None,
+                          1 => {
+                            let e = {
+                              let l53 = *ptr1.add(32).cast::<i32>();
+
+                              l53 as u32
+                            };
+                            Some(e)
+                          }
+                          _ => _rt::invalid_enum_discriminant(),
+                        },
+                      }
+                                        };
+                                        V66::HttpResponseTrailerSize(e66)
+                                    }
+                                    31 => {
+                                        let e66 = {
+                                            let l54 = i32::from(*ptr1.add(16).cast::<u8>());
+
+                                            match l54 {
+                                                0 => None,
+                                                1 => {
+                                                    let e = {
+                                                        let l55 = *ptr1.add(20).cast::<*mut u8>();
+                                                        let l56 = *ptr1.add(24).cast::<usize>();
+                                                        let len57 = l56;
+                                                        let bytes57 = _rt::Vec::from_raw_parts(
+                                                            l55.cast(),
+                                                            len57,
+                                                            len57,
+                                                        );
+
+                                                        _rt::string_lift(bytes57)
+                                                    };
+                                                    Some(e)
+                                                }
+                                                _ => _rt::invalid_enum_discriminant(),
+                                            }
+                                        };
+                                        V66::HttpResponseTransferCoding(e66)
+                                    }
+                                    32 => {
+                                        let e66 = {
+                                            let l58 = i32::from(*ptr1.add(16).cast::<u8>());
+
+                                            match l58 {
+                                                0 => None,
+                                                1 => {
+                                                    let e = {
+                                                        let l59 = *ptr1.add(20).cast::<*mut u8>();
+                                                        let l60 = *ptr1.add(24).cast::<usize>();
+                                                        let len61 = l60;
+                                                        let bytes61 = _rt::Vec::from_raw_parts(
+                                                            l59.cast(),
+                                                            len61,
+                                                            len61,
+                                                        );
+
+                                                        _rt::string_lift(bytes61)
+                                                    };
+                                                    Some(e)
+                                                }
+                                                _ => _rt::invalid_enum_discriminant(),
+                                            }
+                                        };
+                                        V66::HttpResponseContentCoding(e66)
+                                    }
+                                    33 => V66::HttpResponseTimeout,
+                                    34 => V66::HttpUpgradeFailed,
+                                    35 => V66::HttpProtocolError,
+                                    36 => V66::LoopDetected,
+                                    37 => V66::ConfigurationError,
+                                    n => {
+                                        debug_assert_eq!(n, 38, "invalid enum discriminant");
+                                        let e66 = {
+                                            let l62 = i32::from(*ptr1.add(16).cast::<u8>());
+
+                                            match l62 {
+                                                0 => None,
+                                                1 => {
+                                                    let e = {
+                                                        let l63 = *ptr1.add(20).cast::<*mut u8>();
+                                                        let l64 = *ptr1.add(24).cast::<usize>();
+                                                        let len65 = l64;
+                                                        let bytes65 = _rt::Vec::from_raw_parts(
+                                                            l63.cast(),
+                                                            len65,
+                                                            len65,
+                                                        );
+
+                                                        _rt::string_lift(bytes65)
+                                                    };
+                                                    Some(e)
+                                                }
+                                                _ => _rt::invalid_enum_discriminant(),
+                                            }
+                                        };
+                                        V66::InternalError(e66)
+                                    }
+                                };
+
+                                v66
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    }
+                }
+            }
+        }
+    }
+    #[allow(dead_code)]
+    pub mod io {
+        #[allow(dead_code, clippy::all)]
+        pub mod poll {
+            #[used]
+            #[doc(hidden)]
+            #[cfg(target_arch = "wasm32")]
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+            use super::super::super::_rt;
+            /// `pollable` represents a single I/O event which may be ready, or not.
+
+            #[derive(Debug)]
+            #[repr(transparent)]
+            pub struct Pollable {
+                handle: _rt::Resource<Pollable>,
+            }
+
+            impl Pollable {
+                #[doc(hidden)]
                 pub unsafe fn from_handle(handle: u32) -> Self {
                     Self {
                         handle: _rt::Resource::from_handle(handle),
@@ -7399,14 +8378,415 @@ pub mod wasi {
         }
     }
 }
-#[allow(dead_code)]
-pub mod exports {
-    #[allow(dead_code)]
-    pub mod wasi {
-        #[allow(dead_code)]
-        pub mod http {
-            #[allow(dead_code, clippy::all)]
-            pub mod incoming_handler {
+#[allow(dead_code, clippy::all)]
+pub mod wagi_instance0 {
+    #[used]
+    #[doc(hidden)]
+    #[cfg(target_arch = "wasm32")]
+    static __FORCE_SECTION_REF: fn() = super::__link_custom_section_describing_imports;
+    use super::_rt;
+    #[allow(unused_unsafe, clippy::all)]
+    pub fn run() -> Result<(), ()> {
+        unsafe {
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "wagi-instance0")]
+            extern "C" {
+                #[link_name = "run"]
+                fn wit_import() -> i32;
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            fn wit_import() -> i32 {
+                unreachable!()
+            }
+            let ret = wit_import();
+            match ret {
+                0 => {
+                    let e = ();
+                    Ok(e)
+                }
+                1 => {
+                    let e = ();
+                    Err(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            }
+        }
+    }
+}
+
+#[allow(dead_code, clippy::all)]
+pub mod wagi_instance1 {
+    #[used]
+    #[doc(hidden)]
+    #[cfg(target_arch = "wasm32")]
+    static __FORCE_SECTION_REF: fn() = super::__link_custom_section_describing_imports;
+    use super::_rt;
+    #[allow(unused_unsafe, clippy::all)]
+    pub fn run() -> Result<(), ()> {
+        unsafe {
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "wagi-instance1")]
+            extern "C" {
+                #[link_name = "run"]
+                fn wit_import() -> i32;
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            fn wit_import() -> i32 {
+                unreachable!()
+            }
+            let ret = wit_import();
+            match ret {
+                0 => {
+                    let e = ();
+                    Ok(e)
+                }
+                1 => {
+                    let e = ();
+                    Err(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            }
+        }
+    }
+}
+
+#[allow(dead_code, clippy::all)]
+pub mod wagi_instance2 {
+    #[used]
+    #[doc(hidden)]
+    #[cfg(target_arch = "wasm32")]
+    static __FORCE_SECTION_REF: fn() = super::__link_custom_section_describing_imports;
+    use super::_rt;
+    #[allow(unused_unsafe, clippy::all)]
+    pub fn run() -> Result<(), ()> {
+        unsafe {
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "wagi-instance2")]
+            extern "C" {
+                #[link_name = "run"]
+                fn wit_import() -> i32;
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            fn wit_import() -> i32 {
+                unreachable!()
+            }
+            let ret = wit_import();
+            match ret {
+                0 => {
+                    let e = ();
+                    Ok(e)
+                }
+                1 => {
+                    let e = ();
+                    Err(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            }
+        }
+    }
+}
+
+#[allow(dead_code, clippy::all)]
+pub mod wagi_instance3 {
+    #[used]
+    #[doc(hidden)]
+    #[cfg(target_arch = "wasm32")]
+    static __FORCE_SECTION_REF: fn() = super::__link_custom_section_describing_imports;
+    use super::_rt;
+    #[allow(unused_unsafe, clippy::all)]
+    pub fn run() -> Result<(), ()> {
+        unsafe {
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "wagi-instance3")]
+            extern "C" {
+                #[link_name = "run"]
+                fn wit_import() -> i32;
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            fn wit_import() -> i32 {
+                unreachable!()
+            }
+            let ret = wit_import();
+            match ret {
+                0 => {
+                    let e = ();
+                    Ok(e)
+                }
+                1 => {
+                    let e = ();
+                    Err(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            }
+        }
+    }
+}
+
+#[allow(dead_code, clippy::all)]
+pub mod wagi_instance4 {
+    #[used]
+    #[doc(hidden)]
+    #[cfg(target_arch = "wasm32")]
+    static __FORCE_SECTION_REF: fn() = super::__link_custom_section_describing_imports;
+    use super::_rt;
+    #[allow(unused_unsafe, clippy::all)]
+    pub fn run() -> Result<(), ()> {
+        unsafe {
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "wagi-instance4")]
+            extern "C" {
+                #[link_name = "run"]
+                fn wit_import() -> i32;
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            fn wit_import() -> i32 {
+                unreachable!()
+            }
+            let ret = wit_import();
+            match ret {
+                0 => {
+                    let e = ();
+                    Ok(e)
+                }
+                1 => {
+                    let e = ();
+                    Err(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            }
+        }
+    }
+}
+
+#[allow(dead_code, clippy::all)]
+pub mod wagi_instance5 {
+    #[used]
+    #[doc(hidden)]
+    #[cfg(target_arch = "wasm32")]
+    static __FORCE_SECTION_REF: fn() = super::__link_custom_section_describing_imports;
+    use super::_rt;
+    #[allow(unused_unsafe, clippy::all)]
+    pub fn run() -> Result<(), ()> {
+        unsafe {
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "wagi-instance5")]
+            extern "C" {
+                #[link_name = "run"]
+                fn wit_import() -> i32;
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            fn wit_import() -> i32 {
+                unreachable!()
+            }
+            let ret = wit_import();
+            match ret {
+                0 => {
+                    let e = ();
+                    Ok(e)
+                }
+                1 => {
+                    let e = ();
+                    Err(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            }
+        }
+    }
+}
+
+#[allow(dead_code, clippy::all)]
+pub mod wagi_instance6 {
+    #[used]
+    #[doc(hidden)]
+    #[cfg(target_arch = "wasm32")]
+    static __FORCE_SECTION_REF: fn() = super::__link_custom_section_describing_imports;
+    use super::_rt;
+    #[allow(unused_unsafe, clippy::all)]
+    pub fn run() -> Result<(), ()> {
+        unsafe {
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "wagi-instance6")]
+            extern "C" {
+                #[link_name = "run"]
+                fn wit_import() -> i32;
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            fn wit_import() -> i32 {
+                unreachable!()
+            }
+            let ret = wit_import();
+            match ret {
+                0 => {
+                    let e = ();
+                    Ok(e)
+                }
+                1 => {
+                    let e = ();
+                    Err(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            }
+        }
+    }
+}
+
+#[allow(dead_code, clippy::all)]
+pub mod wagi_instance7 {
+    #[used]
+    #[doc(hidden)]
+    #[cfg(target_arch = "wasm32")]
+    static __FORCE_SECTION_REF: fn() = super::__link_custom_section_describing_imports;
+    use super::_rt;
+    #[allow(unused_unsafe, clippy::all)]
+    pub fn run() -> Result<(), ()> {
+        unsafe {
+            #[cfg(target_arch = "wasm32")]
+            #[link(wasm_import_module = "wagi-instance7")]
+            extern "C" {
+                #[link_name = "run"]
+                fn wit_import() -> i32;
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            fn wit_import() -> i32 {
+                unreachable!()
+            }
+            let ret = wit_import();
+            match ret {
+                0 => {
+                    let e = ();
+                    Ok(e)
+                }
+                1 => {
+                    let e = ();
+                    Err(e)
+                }
+                _ => _rt::invalid_enum_discriminant(),
+            }
+        }
+    }
+}
+
+#[allow(dead_code)]
+pub mod exports {
+    #[allow(dead_code)]
+    pub mod fermyon {
+        #[allow(dead_code)]
+        pub mod router {
+            #[allow(dead_code, clippy::all)]
+            pub mod redis_trigger {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_publish_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: *mut u8,
+                    arg6: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len1 = arg4;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                    let len2 = arg6;
+                    let result3 = T::publish(
+                        match arg0 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len0 = arg2;
+                                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+
+                                    _rt::string_lift(bytes0)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        _rt::string_lift(bytes1),
+                        _rt::Vec::from_raw_parts(arg5.cast(), len2, len2),
+                    );
+                    let ptr4 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result3 {
+                        Ok(_) => {
+                            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec5 = (e.into_bytes()).into_boxed_slice();
+                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                            let len5 = vec5.len();
+                            ::core::mem::forget(vec5);
+                            *ptr4.add(8).cast::<usize>() = len5;
+                            *ptr4.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+                        }
+                    };
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_publish<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = *arg0.add(4).cast::<*mut u8>();
+                            let l2 = *arg0.add(8).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                    }
+                }
+                pub trait Guest {
+                    fn publish(
+                        address: Option<_rt::String>,
+                        channel: _rt::String,
+                        payload: _rt::Vec<u8>,
+                    ) -> Result<(), _rt::String>;
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_fermyon_router_redis_trigger_cabi{
+        ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+          #[export_name = "fermyon:router/redis-trigger#publish"]
+          unsafe extern "C" fn export_publish(arg0: i32,arg1: *mut u8,arg2: usize,arg3: *mut u8,arg4: usize,arg5: *mut u8,arg6: usize,) -> *mut u8 {
+            $($path_to_types)*::_export_publish_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5, arg6)
+          }
+          #[export_name = "cabi_post_fermyon:router/redis-trigger#publish"]
+          unsafe extern "C" fn _post_return_publish(arg0: *mut u8,) {
+            $($path_to_types)*::__post_return_publish::<$ty>(arg0)
+          }
+        };);
+      }
+                #[doc(hidden)]
+                pub(crate) use __export_fermyon_router_redis_trigger_cabi;
+                #[repr(align(4))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 12]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 12]);
+            }
+        }
+    }
+    #[allow(dead_code)]
+    pub mod wasi {
+        #[allow(dead_code)]
+        pub mod http {
+            #[allow(dead_code, clippy::all)]
+            pub mod incoming_handler {
                 #[used]
                 #[doc(hidden)]
                 #[cfg(target_arch = "wasm32")]
@@ -7440,17 +8820,621 @@ pub mod exports {
                 #[doc(hidden)]
 
                 macro_rules! __export_wasi_http_incoming_handler_0_2_0_cabi{
-        ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+      ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
-          #[export_name = "wasi:http/incoming-handler@0.2.0#handle"]
-          unsafe extern "C" fn export_handle(arg0: i32,arg1: i32,) {
-            $($path_to_types)*::_export_handle_cabi::<$ty>(arg0, arg1)
-          }
-        };);
-      }
+        #[export_name = "wasi:http/incoming-handler@0.2.0#handle"]
+        unsafe extern "C" fn export_handle(arg0: i32,arg1: i32,) {
+          $($path_to_types)*::_export_handle_cabi::<$ty>(arg0, arg1)
+        }
+      };);
+    }
                 #[doc(hidden)]
                 pub(crate) use __export_wasi_http_incoming_handler_0_2_0_cabi;
             }
+
+            #[allow(dead_code, clippy::all)]
+            pub mod outgoing_handler {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type OutgoingRequest =
+                    super::super::super::super::wasi::http::types::OutgoingRequest;
+                pub type RequestOptions =
+                    super::super::super::super::wasi::http::types::RequestOptions;
+                pub type FutureIncomingResponse =
+                    super::super::super::super::wasi::http::types::FutureIncomingResponse;
+                pub type ErrorCode = super::super::super::super::wasi::http::types::ErrorCode;
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_handle_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::handle(
+                        super::super::super::super::wasi::http::types::OutgoingRequest::from_handle(
+                            arg0 as u32,
+                        ),
+                        match arg1 {
+                            0 => None,
+                            1 => {
+                                let e = super::super::super::super::wasi::http::types::RequestOptions::from_handle(arg2 as u32);
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            *ptr1.add(8).cast::<i32>() = (e).take_handle() as i32;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::wasi::http::types::ErrorCode as V17;
+                            match e {
+                                V17::DnsTimeout => {
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                                V17::DnsError(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                                    let super::super::super::super::wasi::http::types::DnsErrorPayload{ rcode:rcode2, info_code:info_code2, } = e;
+                                    match rcode2 {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec3 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                                            let len3 = vec3.len();
+                                            ::core::mem::forget(vec3);
+                                            *ptr1.add(24).cast::<usize>() = len3;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr3.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match info_code2 {
+                                        Some(e) => {
+                                            *ptr1.add(28).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(30).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                        }
+                                        None => {
+                                            *ptr1.add(28).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V17::DestinationNotFound => {
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+                                }
+                                V17::DestinationUnavailable => {
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+                                }
+                                V17::DestinationIpProhibited => {
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                V17::DestinationIpUnroutable => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                }
+                                V17::ConnectionRefused => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                V17::ConnectionTerminated => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                }
+                                V17::ConnectionTimeout => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                V17::ConnectionReadTimeout => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                V17::ConnectionWriteTimeout => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                }
+                                V17::ConnectionLimitReached => {
+                                    *ptr1.add(8).cast::<u8>() = (11i32) as u8;
+                                }
+                                V17::TlsProtocolError => {
+                                    *ptr1.add(8).cast::<u8>() = (12i32) as u8;
+                                }
+                                V17::TlsCertificateError => {
+                                    *ptr1.add(8).cast::<u8>() = (13i32) as u8;
+                                }
+                                V17::TlsAlertReceived(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (14i32) as u8;
+                                    let super::super::super::super::wasi::http::types::TlsAlertReceivedPayload{ alert_id:alert_id4, alert_message:alert_message4, } = e;
+                                    match alert_id4 {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(17).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match alert_message4 {
+                                        Some(e) => {
+                                            *ptr1.add(20).cast::<u8>() = (1i32) as u8;
+                                            let vec5 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                                            let len5 = vec5.len();
+                                            ::core::mem::forget(vec5);
+                                            *ptr1.add(28).cast::<usize>() = len5;
+                                            *ptr1.add(24).cast::<*mut u8>() = ptr5.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(20).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V17::HttpRequestDenied => {
+                                    *ptr1.add(8).cast::<u8>() = (15i32) as u8;
+                                }
+                                V17::HttpRequestLengthRequired => {
+                                    *ptr1.add(8).cast::<u8>() = (16i32) as u8;
+                                }
+                                V17::HttpRequestBodySize(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (17i32) as u8;
+                                    match e {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(24).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V17::HttpRequestMethodInvalid => {
+                                    *ptr1.add(8).cast::<u8>() = (18i32) as u8;
+                                }
+                                V17::HttpRequestUriInvalid => {
+                                    *ptr1.add(8).cast::<u8>() = (19i32) as u8;
+                                }
+                                V17::HttpRequestUriTooLong => {
+                                    *ptr1.add(8).cast::<u8>() = (20i32) as u8;
+                                }
+                                V17::HttpRequestHeaderSectionSize(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (21i32) as u8;
+                                    match e {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(20).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V17::HttpRequestHeaderSize(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (22i32) as u8;
+                                    match e {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let super::super::super::super::wasi::http::types::FieldSizePayload{ field_name:field_name6, field_size:field_size6, } = e;
+                                            match field_name6 {
+                                                Some(e) => {
+                                                    *ptr1.add(20).cast::<u8>() = (1i32) as u8;
+                                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                                    let len7 = vec7.len();
+                                                    ::core::mem::forget(vec7);
+                                                    *ptr1.add(28).cast::<usize>() = len7;
+                                                    *ptr1.add(24).cast::<*mut u8>() =
+                                                        ptr7.cast_mut();
+                                                }
+                                                None => {
+                                                    *ptr1.add(20).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match field_size6 {
+                                                Some(e) => {
+                                                    *ptr1.add(32).cast::<u8>() = (1i32) as u8;
+                                                    *ptr1.add(36).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *ptr1.add(32).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V17::HttpRequestTrailerSectionSize(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (23i32) as u8;
+                                    match e {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(20).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V17::HttpRequestTrailerSize(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (24i32) as u8;
+                                    let super::super::super::super::wasi::http::types::FieldSizePayload{ field_name:field_name8, field_size:field_size8, } = e;
+                                    match field_name8 {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec9 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                                            let len9 = vec9.len();
+                                            ::core::mem::forget(vec9);
+                                            *ptr1.add(24).cast::<usize>() = len9;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr9.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match field_size8 {
+                                        Some(e) => {
+                                            *ptr1.add(28).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(28).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V17::HttpResponseIncomplete => {
+                                    *ptr1.add(8).cast::<u8>() = (25i32) as u8;
+                                }
+                                V17::HttpResponseHeaderSectionSize(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (26i32) as u8;
+                                    match e {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(20).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V17::HttpResponseHeaderSize(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (27i32) as u8;
+                                    let super::super::super::super::wasi::http::types::FieldSizePayload{ field_name:field_name10, field_size:field_size10, } = e;
+                                    match field_name10 {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec11 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr11 = vec11.as_ptr().cast::<u8>();
+                                            let len11 = vec11.len();
+                                            ::core::mem::forget(vec11);
+                                            *ptr1.add(24).cast::<usize>() = len11;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr11.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match field_size10 {
+                                        Some(e) => {
+                                            *ptr1.add(28).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(28).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V17::HttpResponseBodySize(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (28i32) as u8;
+                                    match e {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(24).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V17::HttpResponseTrailerSectionSize(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (29i32) as u8;
+                                    match e {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(20).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V17::HttpResponseTrailerSize(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (30i32) as u8;
+                                    let super::super::super::super::wasi::http::types::FieldSizePayload{ field_name:field_name12, field_size:field_size12, } = e;
+                                    match field_name12 {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec13 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr13 = vec13.as_ptr().cast::<u8>();
+                                            let len13 = vec13.len();
+                                            ::core::mem::forget(vec13);
+                                            *ptr1.add(24).cast::<usize>() = len13;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr13.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match field_size12 {
+                                        Some(e) => {
+                                            *ptr1.add(28).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(28).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V17::HttpResponseTransferCoding(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (31i32) as u8;
+                                    match e {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec14 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                                            let len14 = vec14.len();
+                                            ::core::mem::forget(vec14);
+                                            *ptr1.add(24).cast::<usize>() = len14;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr14.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V17::HttpResponseContentCoding(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (32i32) as u8;
+                                    match e {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec15 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr15 = vec15.as_ptr().cast::<u8>();
+                                            let len15 = vec15.len();
+                                            ::core::mem::forget(vec15);
+                                            *ptr1.add(24).cast::<usize>() = len15;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr15.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V17::HttpResponseTimeout => {
+                                    *ptr1.add(8).cast::<u8>() = (33i32) as u8;
+                                }
+                                V17::HttpUpgradeFailed => {
+                                    *ptr1.add(8).cast::<u8>() = (34i32) as u8;
+                                }
+                                V17::HttpProtocolError => {
+                                    *ptr1.add(8).cast::<u8>() = (35i32) as u8;
+                                }
+                                V17::LoopDetected => {
+                                    *ptr1.add(8).cast::<u8>() = (36i32) as u8;
+                                }
+                                V17::ConfigurationError => {
+                                    *ptr1.add(8).cast::<u8>() = (37i32) as u8;
+                                }
+                                V17::InternalError(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (38i32) as u8;
+                                    match e {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec16 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr16 = vec16.as_ptr().cast::<u8>();
+                                            let len16 = vec16.len();
+                                            ::core::mem::forget(vec16);
+                                            *ptr1.add(24).cast::<usize>() = len16;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr16.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_handle<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                1 => {
+                                    let l2 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l2 {
+                                        0 => (),
+                                        _ => {
+                                            let l3 = *arg0.add(20).cast::<*mut u8>();
+                                            let l4 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l3, l4, 1);
+                                        }
+                                    }
+                                }
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => (),
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => (),
+                                12 => (),
+                                13 => (),
+                                14 => {
+                                    let l5 = i32::from(*arg0.add(20).cast::<u8>());
+                                    match l5 {
+                                        0 => (),
+                                        _ => {
+                                            let l6 = *arg0.add(24).cast::<*mut u8>();
+                                            let l7 = *arg0.add(28).cast::<usize>();
+                                            _rt::cabi_dealloc(l6, l7, 1);
+                                        }
+                                    }
+                                }
+                                15 => (),
+                                16 => (),
+                                17 => (),
+                                18 => (),
+                                19 => (),
+                                20 => (),
+                                21 => (),
+                                22 => {
+                                    let l8 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l8 {
+                                        0 => (),
+                                        _ => {
+                                            let l9 = i32::from(*arg0.add(20).cast::<u8>());
+                                            match l9 {
+                                                0 => (),
+                                                _ => {
+                                                    let l10 = *arg0.add(24).cast::<*mut u8>();
+                                                    let l11 = *arg0.add(28).cast::<usize>();
+                                                    _rt::cabi_dealloc(l10, l11, 1);
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                                23 => (),
+                                24 => {
+                                    let l12 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l12 {
+                                        0 => (),
+                                        _ => {
+                                            let l13 = *arg0.add(20).cast::<*mut u8>();
+                                            let l14 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l13, l14, 1);
+                                        }
+                                    }
+                                }
+                                25 => (),
+                                26 => (),
+                                27 => {
+                                    let l15 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l15 {
+                                        0 => (),
+                                        _ => {
+                                            let l16 = *arg0.add(20).cast::<*mut u8>();
+                                            let l17 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l16, l17, 1);
+                                        }
+                                    }
+                                }
+                                28 => (),
+                                29 => (),
+                                30 => {
+                                    let l18 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l18 {
+                                        0 => (),
+                                        _ => {
+                                            let l19 = *arg0.add(20).cast::<*mut u8>();
+                                            let l20 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l19, l20, 1);
+                                        }
+                                    }
+                                }
+                                31 => {
+                                    let l21 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l21 {
+                                        0 => (),
+                                        _ => {
+                                            let l22 = *arg0.add(20).cast::<*mut u8>();
+                                            let l23 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l22, l23, 1);
+                                        }
+                                    }
+                                }
+                                32 => {
+                                    let l24 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l24 {
+                                        0 => (),
+                                        _ => {
+                                            let l25 = *arg0.add(20).cast::<*mut u8>();
+                                            let l26 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l25, l26, 1);
+                                        }
+                                    }
+                                }
+                                33 => (),
+                                34 => (),
+                                35 => (),
+                                36 => (),
+                                37 => (),
+                                _ => {
+                                    let l27 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l27 {
+                                        0 => (),
+                                        _ => {
+                                            let l28 = *arg0.add(20).cast::<*mut u8>();
+                                            let l29 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l28, l29, 1);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                pub trait Guest {
+                    /// This function is invoked with an outgoing HTTP Request, and it returns
+                    /// a resource `future-incoming-response` which represents an HTTP Response
+                    /// which may arrive in the future.
+                    ///
+                    /// The `options` argument accepts optional parameters for the HTTP
+                    /// protocol's transport layer.
+                    ///
+                    /// This function may return an error if the `outgoing-request` is invalid
+                    /// or not allowed to be made. Otherwise, protocol errors are reported
+                    /// through the `future-incoming-response`.
+                    fn handle(
+                        request: OutgoingRequest,
+                        options: Option<RequestOptions>,
+                    ) -> Result<FutureIncomingResponse, ErrorCode>;
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_wasi_http_outgoing_handler_0_2_0_cabi{
+                                    ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+                                      #[export_name = "wasi:http/outgoing-handler@0.2.0#handle"]
+                                      unsafe extern "C" fn export_handle(arg0: i32,arg1: i32,arg2: i32,) -> *mut u8 {
+                                        $($path_to_types)*::_export_handle_cabi::<$ty>(arg0, arg1, arg2)
+                                      }
+                                      #[export_name = "cabi_post_wasi:http/outgoing-handler@0.2.0#handle"]
+                                      unsafe extern "C" fn _post_return_handle(arg0: *mut u8,) {
+                                        $($path_to_types)*::__post_return_handle::<$ty>(arg0)
+                                      }
+                                    };);
+                                  }
+                #[doc(hidden)]
+                pub(crate) use __export_wasi_http_outgoing_handler_0_2_0_cabi;
+                #[repr(align(8))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 40]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 40]);
+            }
         }
     }
 }
@@ -7711,19 +9695,21 @@ mod _rt {
 #[doc(hidden)]
 
 macro_rules! __export_router_impl {
-  ($ty:ident) => (self::export!($ty with_types_in self););
-  ($ty:ident with_types_in $($path_to_types_root:tt)*) => (
-  $($path_to_types_root)*::exports::wasi::http::incoming_handler::__export_wasi_http_incoming_handler_0_2_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::wasi::http::incoming_handler);
-  )
-}
+                            ($ty:ident) => (self::export!($ty with_types_in self););
+                            ($ty:ident with_types_in $($path_to_types_root:tt)*) => (
+                            $($path_to_types_root)*::exports::wasi::http::incoming_handler::__export_wasi_http_incoming_handler_0_2_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::wasi::http::incoming_handler);
+                            $($path_to_types_root)*::exports::wasi::http::outgoing_handler::__export_wasi_http_outgoing_handler_0_2_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::wasi::http::outgoing_handler);
+                            $($path_to_types_root)*::exports::fermyon::router::redis_trigger::__export_fermyon_router_redis_trigger_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::router::redis_trigger);
+                            )
+                          }
 #[doc(inline)]
 pub(crate) use __export_router_impl as export;
 
 #[cfg(target_arch = "wasm32")]
 #[link_section = "component-type:wit-bindgen:0.25.0:router:encoded world"]
 #[doc(hidden)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 6758] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xe93\x01A\x02\x01A&\x01\
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 8112] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xb3>\x01A\x02\x01A^\x01\
 B\x0a\x04\0\x08pollable\x03\x01\x01h\0\x01@\x01\x04self\x01\0\x7f\x04\0\x16[meth\
 od]pollable.ready\x01\x02\x01@\x01\x04self\x01\x01\0\x04\0\x16[method]pollable.b\
 lock\x01\x03\x01p\x01\x01py\x01@\x01\x02in\x04\0\x05\x04\0\x04poll\x01\x06\x03\x01\
@@ -7845,19 +9831,51 @@ e\x01\x8c\x01\x03\x01\x15wasi:http/types@0.2.0\x05\x09\x02\x03\0\x04\x10incoming
 -request\x02\x03\0\x04\x11response-outparam\x01B\x08\x02\x03\x02\x01\x0a\x04\0\x10\
 incoming-request\x03\0\0\x02\x03\x02\x01\x0b\x04\0\x11response-outparam\x03\0\x02\
 \x01i\x01\x01i\x03\x01@\x02\x07request\x04\x0cresponse-out\x05\x01\0\x04\0\x06ha\
-ndle\x01\x06\x03\x01\x20wasi:http/incoming-handler@0.2.0\x05\x0c\x01B\x05\x02\x03\
-\x02\x01\x08\x04\0\x0doutput-stream\x03\0\0\x01i\x01\x01@\0\0\x02\x04\0\x0aget-s\
-tdout\x01\x03\x03\x01\x15wasi:cli/stdout@0.2.0\x05\x0d\x02\x03\0\x04\x10outgoing\
--request\x03\0\x10outgoing-request\x03\0\x0e\x03\0\x10incoming-request\x03\0\x0a\
-\x02\x03\0\x04\x0dincoming-body\x03\0\x0dincoming-body\x03\0\x11\x01@\0\0s\x03\0\
-\x0cget-manifest\x01\x13\x01@\x01\x0ccomponent-ids\x01\0\x03\0\x10set-component-\
-id\x01\x14\x01i\x0f\x01i\x12\x01k\x16\x01i\x10\x01@\x02\x07request\x15\x0dincomi\
-ng-body\x17\0\x18\x03\0\x0bnew-request\x01\x19\x01B\x08\x02\x03\x02\x01\x0a\x04\0\
-\x10incoming-request\x03\0\0\x02\x03\x02\x01\x0b\x04\0\x11response-outparam\x03\0\
-\x02\x01i\x01\x01i\x03\x01@\x02\x07request\x04\x0cresponse-out\x05\x01\0\x04\0\x06\
-handle\x01\x06\x04\x01\x20wasi:http/incoming-handler@0.2.0\x05\x1a\x04\x01\x15fe\
-rmyon:router/router\x04\0\x0b\x0c\x01\0\x06router\x03\0\0\0G\x09producers\x01\x0c\
-processed-by\x02\x0dwit-component\x070.208.1\x10wit-bindgen-rust\x060.25.0";
+ndle\x01\x06\x03\x01\x20wasi:http/incoming-handler@0.2.0\x05\x0c\x02\x03\0\x04\x10\
+outgoing-request\x02\x03\0\x04\x0frequest-options\x02\x03\0\x04\x18future-incomi\
+ng-response\x02\x03\0\x04\x0aerror-code\x01B\x0f\x02\x03\x02\x01\x0d\x04\0\x10ou\
+tgoing-request\x03\0\0\x02\x03\x02\x01\x0e\x04\0\x0frequest-options\x03\0\x02\x02\
+\x03\x02\x01\x0f\x04\0\x18future-incoming-response\x03\0\x04\x02\x03\x02\x01\x10\
+\x04\0\x0aerror-code\x03\0\x06\x01i\x01\x01i\x03\x01k\x09\x01i\x05\x01j\x01\x0b\x01\
+\x07\x01@\x02\x07request\x08\x07options\x0a\0\x0c\x04\0\x06handle\x01\x0d\x03\x01\
+\x20wasi:http/outgoing-handler@0.2.0\x05\x11\x01B\x05\x02\x03\x02\x01\x08\x04\0\x0d\
+output-stream\x03\0\0\x01i\x01\x01@\0\0\x02\x04\0\x0aget-stdout\x01\x03\x03\x01\x15\
+wasi:cli/stdout@0.2.0\x05\x12\x01B\x07\x01m\x02\x07success\x05error\x04\0\x05err\
+or\x03\0\0\x01p}\x04\0\x07payload\x03\0\x02\x01j\0\x01\x01\x01@\x01\x07message\x03\
+\0\x04\x04\0\x0ehandle-message\x01\x05\x03\x01\x1cfermyon:router/inbound-redis\x05\
+\x13\x01B\x03\x01j\0\0\x01@\0\0\0\x04\0\x03run\x01\x01\x03\0\x0ewagi-instance0\x05\
+\x14\x01B\x03\x01j\0\0\x01@\0\0\0\x04\0\x03run\x01\x01\x03\0\x0ewagi-instance1\x05\
+\x15\x01B\x03\x01j\0\0\x01@\0\0\0\x04\0\x03run\x01\x01\x03\0\x0ewagi-instance2\x05\
+\x16\x01B\x03\x01j\0\0\x01@\0\0\0\x04\0\x03run\x01\x01\x03\0\x0ewagi-instance3\x05\
+\x17\x01B\x03\x01j\0\0\x01@\0\0\0\x04\0\x03run\x01\x01\x03\0\x0ewagi-instance4\x05\
+\x18\x01B\x03\x01j\0\0\x01@\0\0\0\x04\0\x03run\x01\x01\x03\0\x0ewagi-instance5\x05\
+\x19\x01B\x03\x01j\0\0\x01@\0\0\0\x04\0\x03run\x01\x01\x03\0\x0ewagi-instance6\x05\
+\x1a\x01B\x03\x01j\0\0\x01@\0\0\0\x04\0\x03run\x01\x01\x03\0\x0ewagi-instance7\x05\
+\x1b\x03\0\x10outgoing-request\x03\0\x0d\x03\0\x10incoming-request\x03\0\x0a\x02\
+\x03\0\x04\x0dincoming-body\x03\0\x0dincoming-body\x03\0\x1e\x03\0\x11response-o\
+utparam\x03\0\x0b\x03\0\x18future-incoming-response\x03\0\x0f\x01@\0\0s\x03\0\x0c\
+get-manifest\x01\"\x01@\x01\x0ccomponent-ids\x01\0\x03\0\x10set-component-id\x01\
+#\x01ps\x01o\x02ss\x01p%\x01p}\x01@\x03\x09arguments$\x0benvironment&\x05stdin'\x01\
+\0\x03\0\x0cprepare-wagi\x01(\x01@\0\0'\x03\0\x0bfinish-wagi\x01)\x01ks\x01@\0\0\
+*\x03\0\x13take-stand-in-error\x01+\x01@\x01\x03urls\0\x7f\x03\0\x13allows-outbo\
+und-url\x01,\x01@\x02\x06methods\x03urls\x01\0\x03\0\x17record-internal-request\x01\
+-\x01i\x1c\x01i\x1f\x01k/\x01i\x1d\x01@\x02\x07request.\x0dincoming-body0\01\x03\
+\0\x0bnew-request\x012\x01i\x20\x01i!\x01o\x0234\x01@\0\05\x03\0\x13new-response\
+-future\x016\x01h\x1d\x01@\x01\x07request7\0*\x03\0\x0bclient-addr\x018\x01k\x7f\
+\x01@\x01\x07request7\09\x03\0\x03tls\x01:\x03\0\x0blisten-addr\x018\x01B\x08\x02\
+\x03\x02\x01\x0a\x04\0\x10incoming-request\x03\0\0\x02\x03\x02\x01\x0b\x04\0\x11\
+response-outparam\x03\0\x02\x01i\x01\x01i\x03\x01@\x02\x07request\x04\x0crespons\
+e-out\x05\x01\0\x04\0\x06handle\x01\x06\x04\x01\x20wasi:http/incoming-handler@0.\
+2.0\x05;\x01B\x0f\x02\x03\x02\x01\x0d\x04\0\x10outgoing-request\x03\0\0\x02\x03\x02\
+\x01\x0e\x04\0\x0frequest-options\x03\0\x02\x02\x03\x02\x01\x0f\x04\0\x18future-\
+incoming-response\x03\0\x04\x02\x03\x02\x01\x10\x04\0\x0aerror-code\x03\0\x06\x01\
+i\x01\x01i\x03\x01k\x09\x01i\x05\x01j\x01\x0b\x01\x07\x01@\x02\x07request\x08\x07\
+options\x0a\0\x0c\x04\0\x06handle\x01\x0d\x04\x01\x20wasi:http/outgoing-handler@\
+0.2.0\x05<\x01B\x05\x01ks\x01p}\x01j\0\x01s\x01@\x03\x07address\0\x07channels\x07\
+payload\x01\0\x02\x04\0\x07publish\x01\x03\x04\x01\x1cfermyon:router/redis-trigg\
+er\x05=\x04\x01\x15fermyon:router/router\x04\0\x0b\x0c\x01\0\x06router\x03\0\0\0\
+G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.208.1\x10wit-bindge\
+n-rust\x060.25.0";
 
 #[inline(never)]
 #[doc(hidden)]
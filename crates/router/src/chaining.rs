@@ -0,0 +1,64 @@
+//! Local service chaining.
+//!
+//! Requests the app makes to `self` or to `<component>.spin.internal` are routed back into the
+//! app instead of leaving it. All other requests are passed on to the virtualized
+//! `wasi:http/outgoing-handler`.
+//!
+//! The requests are routed to a separate instance of the app whose own outgoing requests go
+//! straight to the virtualized `wasi:http/outgoing-handler`, so chaining is only one level deep.
+
+use crate::bindings::{
+    self,
+    exports::wasi::http::outgoing_handler::Guest,
+    wasi::http::outgoing_handler::handle as upstream,
+    wasi::http::types::{
+        ErrorCode, FutureIncomingResponse, OutgoingRequest, RequestOptions, Scheme,
+    },
+};
+use crate::Component;
+
+/// The domain under which every component can be reached by its id
+const INTERNAL_DOMAIN: &str = ".spin.internal";
+
+impl Guest for Component {
+    fn handle(
+        request: OutgoingRequest,
+        options: Option<RequestOptions>,
+    ) -> Result<FutureIncomingResponse, ErrorCode> {
+        let authority = request.authority();
+        let host = authority
+            .as_deref()
+            .map(|a| a.split_once(':').map_or(a, |(host, _)| host));
+        let component = match host {
+            // Requests without an authority are sent to the app itself
+            None | Some("self") => None,
+            Some(host) => match host.strip_suffix(INTERNAL_DOMAIN) {
+                Some(component) => Some(component.to_owned()),
+                None => return upstream(request, options),
+            },
+        };
+
+        let scheme = match request.scheme() {
+            Some(Scheme::Https) => "https".to_owned(),
+            Some(Scheme::Other(s)) => s,
+            Some(Scheme::Http) | None => "http".to_owned(),
+        };
+        let url = format!(
+            "{scheme}://{authority}{path_with_query}",
+            authority = authority.as_deref().unwrap_or("self"),
+            path_with_query = request.path_with_query().unwrap_or_default()
+        );
+        if !bindings::allows_outbound_url(&url) {
+            return Err(ErrorCode::HttpRequestDenied);
+        }
+        bindings::record_internal_request(&crate::wagi::method_name(&request.method()), &url);
+
+        let (response_out, response) = bindings::new_response_future();
+        crate::route(
+            bindings::new_request(request, None),
+            response_out,
+            component.as_deref(),
+        );
+        Ok(response)
+    }
+}
@@ -1,5 +1,8 @@
 #[allow(warnings)]
 mod bindings;
+mod chaining;
+mod redis;
+mod wagi;
 
 use std::fmt::Display;
 
@@ -8,10 +11,11 @@ use bindings::{
     exports::wasi::http::incoming_handler::Guest,
     wasi::http::incoming_handler::handle as downstream,
     wasi::http::types::{
-        ErrorCode, Headers, IncomingRequest, OutgoingRequest, ResponseOutparam, Scheme,
+        Headers, IncomingRequest, OutgoingBody, OutgoingRequest, OutgoingResponse,
+        ResponseOutparam, Scheme,
     },
 };
-use spin_http::routes::RouteMatch;
+use spin_http::{config::HttpTriggerRouteConfig, routes::RouteMatch};
 
 /// Print to the standard output.
 ///
@@ -30,43 +34,167 @@ struct Component;
 
 impl Guest for Component {
     fn handle(request: IncomingRequest, response_out: ResponseOutparam) {
-        let mut manifest: spin_manifest::schema::v2::AppManifest =
-            toml::from_str(&bindings::get_manifest()).unwrap();
-        spin_manifest::normalize::normalize_manifest(&mut manifest);
-        let base = manifest
-            .application
-            .trigger_global_configs
-            .get("http")
-            .and_then(|c| c.get("base").and_then(|v| v.as_str()))
-            .unwrap_or("/");
-        let route_match = match find_matching_route(&manifest, &request, base) {
-            Err(e) => {
-                set_error_response(response_out, e);
-                return;
-            }
-            Ok(RoutingResult::RouteFound(route_match)) => route_match,
-            Ok(RoutingResult::RouteNotFound) => {
-                ResponseOutparam::set(response_out, Err(ErrorCode::DestinationNotFound));
-                return;
-            }
-        };
-        let request = match apply_request_transformations(request, base, &route_match) {
-            Ok(request) => request,
-            Err(e) => {
-                set_error_response(response_out, e);
-                return;
-            }
-        };
-        bindings::set_component_id(route_match.component_id());
-        downstream(request, response_out)
+        route(request, response_out, None)
+    }
+}
+
+/// Route the request to the app
+///
+/// When `component` is given the request is sent straight to that component, even if it is
+/// private, as Spin does for requests to `<component>.spin.internal`.
+fn route(request: IncomingRequest, response_out: ResponseOutparam, component: Option<&str>) {
+    let mut manifest: spin_manifest::schema::v2::AppManifest =
+        toml::from_str(&bindings::get_manifest()).unwrap();
+    spin_manifest::normalize::normalize_manifest(&mut manifest);
+    let base = manifest
+        .application
+        .trigger_global_configs
+        .get("http")
+        .and_then(|c| c.get("base").and_then(|v| v.as_str()))
+        .unwrap_or("/");
+    if let (None, Some(endpoint)) = (component, well_known_endpoint(&request)) {
+        handle_well_known(&manifest, endpoint, response_out);
+        return;
+    }
+    let triggers = match http_triggers(&manifest) {
+        Ok(triggers) => triggers,
+        Err(e) => {
+            set_error_response(response_out, e);
+            return;
+        }
+    };
+    let route_match = match component {
+        Some(component) => Ok(find_component(&triggers, &request, component)),
+        None => find_matching_route(&triggers, &request, base),
+    };
+    let route_match = match route_match {
+        Err(e) => {
+            set_error_response(response_out, e);
+            return;
+        }
+        Ok(RoutingResult::RouteFound(route_match)) => route_match,
+        Ok(RoutingResult::RouteNotFound) => {
+            set_empty_response(response_out, 404);
+            return;
+        }
+    };
+    let executor = triggers
+        .into_iter()
+        .find(|t| t.component == route_match.component_id())
+        .map(|t| t.executor)
+        .unwrap_or(Executor::Spin);
+    bindings::set_component_id(route_match.component_id());
+    match executor {
+        Executor::Spin => {
+            let request = match apply_request_transformations(request, base, &route_match) {
+                Ok(request) => request,
+                Err(e) => {
+                    set_error_response(response_out, e);
+                    return;
+                }
+            };
+            downstream(request, response_out)
+        }
+        Executor::Wagi(config) => match wagi::handle(request, base, &route_match, &config) {
+            Ok(response) => set_response(
+                response_out,
+                response.status,
+                &response
+                    .headers
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), v.as_str()))
+                    .collect::<Vec<_>>(),
+                &response.body,
+            ),
+            Err(e) => set_error_response(response_out, e),
+        },
     }
 }
 
+/// Respond with a 500 the way Spin's HTTP trigger does: the error is logged and the body is empty.
 fn set_error_response(response_out: ResponseOutparam, message: impl Display) {
-    ResponseOutparam::set(
-        response_out,
-        Err(ErrorCode::InternalError(Some(message.to_string()))),
-    );
+    println!("Error processing request: {message}");
+    set_empty_response(response_out, 500);
+}
+
+/// Respond with the given status code and an empty body.
+fn set_empty_response(response_out: ResponseOutparam, status: u16) {
+    set_response(response_out, status, &[], b"");
+}
+
+/// Respond with the given status code, headers and body.
+fn set_response(
+    response_out: ResponseOutparam,
+    status: u16,
+    headers: &[(&str, &str)],
+    body: &[u8],
+) {
+    let fields = Headers::new();
+    for (key, value) in headers {
+        fields
+            .append(&key.to_string(), &value.as_bytes().to_vec())
+            .unwrap();
+    }
+    fields
+        .append(
+            &"content-length".to_owned(),
+            &body.len().to_string().into_bytes(),
+        )
+        .unwrap();
+    let response = OutgoingResponse::new(fields);
+    response.set_status_code(status).unwrap();
+    let outgoing_body = response.body().unwrap();
+    ResponseOutparam::set(response_out, Ok(response));
+    {
+        let stream = outgoing_body.write().unwrap();
+        // Output streams only accept writes of up to 4096 bytes at a time
+        for chunk in body.chunks(4096) {
+            stream.blocking_write_and_flush(chunk).unwrap();
+        }
+    }
+    OutgoingBody::finish(outgoing_body, None).unwrap();
+}
+
+/// The path prefix of the endpoints Spin serves itself.
+const WELL_KNOWN_PREFIX: &str = "/.well-known/spin/";
+
+/// The Spin endpoint the request is for if it targets the well-known prefix.
+///
+/// Spin checks for these before consulting the app's routes so no app route can shadow them.
+fn well_known_endpoint(request: &IncomingRequest) -> Option<String> {
+    let path_with_query = request.path_with_query()?;
+    let path = path_with_query
+        .split_once('?')
+        .map(|(path, _)| path)
+        .unwrap_or(&path_with_query);
+    path.strip_prefix(WELL_KNOWN_PREFIX).map(ToOwned::to_owned)
+}
+
+/// Respond to a request for one of Spin's well-known endpoints.
+fn handle_well_known(
+    manifest: &spin_manifest::schema::v2::AppManifest,
+    endpoint: String,
+    response_out: ResponseOutparam,
+) {
+    match endpoint.as_str() {
+        "health" => set_response(response_out, 200, &[], b"OK"),
+        "info" => {
+            let mut info = serde_json::Map::new();
+            info.insert("name".into(), manifest.application.name.to_string().into());
+            let version = &manifest.application.version;
+            if !version.is_empty() {
+                info.insert("version".into(), version.clone().into());
+            }
+            let body = serde_json::to_vec_pretty(&info).unwrap();
+            set_response(
+                response_out,
+                200,
+                &[("content-type", "application/json")],
+                &body,
+            )
+        }
+        _ => set_empty_response(response_out, 404),
+    }
 }
 
 enum RoutingResult {
@@ -74,33 +202,97 @@ enum RoutingResult {
     RouteNotFound,
 }
 
-fn find_matching_route(
+/// An `[[trigger.http]]` entry of the manifest
+struct HttpTrigger {
+    /// The id of the component the trigger invokes
+    component: String,
+    route: HttpTriggerRouteConfig,
+    executor: Executor,
+}
+
+/// How a component handles HTTP requests
+enum Executor {
+    /// The component exports `wasi:http/incoming-handler`
+    Spin,
+    /// The component is a WAGI command
+    Wagi(wagi::WagiConfig),
+}
+
+/// Read the HTTP triggers from the manifest
+fn http_triggers(
     manifest: &spin_manifest::schema::v2::AppManifest,
-    request: &IncomingRequest,
-    base: &str,
-) -> anyhow::Result<RoutingResult> {
-    let routes = manifest
+) -> anyhow::Result<Vec<HttpTrigger>> {
+    let triggers = manifest
         .triggers
         .get("http")
-        .unwrap()
+        .filter(|triggers| !triggers.is_empty())
+        .context("the app has no HTTP triggers so it can't handle HTTP requests")?;
+    triggers
         .iter()
         .map(|trigger| {
-            let spin_manifest::schema::v2::ComponentSpec::Reference(comp) =
-                trigger.component.as_ref().unwrap()
-            else {
-                todo!()
+            // Inline components are given an id and moved into `[component]` by normalization
+            let comp = match &trigger.component {
+                Some(spin_manifest::schema::v2::ComponentSpec::Reference(comp)) => comp,
+                Some(spin_manifest::schema::v2::ComponentSpec::Inline(_)) => {
+                    anyhow::bail!("HTTP trigger '{}' has an inline component", trigger.id)
+                }
+                None => anyhow::bail!("HTTP trigger '{}' has no component", trigger.id),
             };
-            (
-                comp.to_string(),
-                trigger
-                    .config
-                    .get("route")
-                    .and_then(|route| route.as_str())
-                    .unwrap()
-                    .into(),
-            )
+            let route = trigger
+                .config
+                .get("route")
+                .with_context(|| format!("HTTP trigger for '{comp}' has no `route`"))?
+                .clone()
+                .try_into::<HttpTriggerRouteConfig>()
+                .with_context(|| format!("HTTP trigger for '{comp}' has an invalid `route`"))?;
+            let executor = match trigger.config.get("executor") {
+                None => Executor::Spin,
+                Some(executor) => parse_executor(executor).with_context(|| {
+                    format!("HTTP trigger for '{comp}' has an invalid `executor`")
+                })?,
+            };
+            Ok(HttpTrigger {
+                component: comp.to_string(),
+                route,
+                executor,
+            })
         })
-        .collect::<Vec<_>>();
+        .collect()
+}
+
+/// Parse the `executor` of an HTTP trigger
+fn parse_executor(executor: &toml::Value) -> anyhow::Result<Executor> {
+    let executor = executor.as_table().context("`executor` must be a table")?;
+    match executor.get("type").and_then(|t| t.as_str()) {
+        Some("spin") => Ok(Executor::Spin),
+        Some("wagi") => {
+            let field = |name: &str, default: &str| -> anyhow::Result<String> {
+                match executor.get(name) {
+                    None => Ok(default.to_owned()),
+                    Some(value) => value
+                        .as_str()
+                        .map(ToOwned::to_owned)
+                        .with_context(|| format!("`executor.{name}` must be a string")),
+                }
+            };
+            Ok(Executor::Wagi(wagi::WagiConfig {
+                entrypoint: field("entrypoint", wagi::DEFAULT_ENTRYPOINT)?,
+                argv: field("argv", wagi::DEFAULT_ARGV)?,
+            }))
+        }
+        Some(other) => anyhow::bail!("unknown executor type '{other}'"),
+        None => anyhow::bail!("`executor.type` must be either \"spin\" or \"wagi\""),
+    }
+}
+
+/// Find the route matching the request
+///
+/// Private routes are never matched since they can't be reached from outside the app.
+fn find_matching_route(
+    triggers: &[HttpTrigger],
+    request: &IncomingRequest,
+    base: &str,
+) -> anyhow::Result<RoutingResult> {
     let path_with_query = request
         .path_with_query()
         .unwrap_or_else(|| String::from("/"));
@@ -108,14 +300,35 @@ fn find_matching_route(
         .split_once('?')
         .map(|(path, _)| path)
         .unwrap_or(&path_with_query);
-    let (router, _) =
-        spin_http::routes::Router::build(base, routes.iter().map(|(c, t)| (c.as_str(), t)))?;
+    let (router, _) = spin_http::routes::Router::build(
+        base,
+        triggers.iter().map(|t| (t.component.as_str(), &t.route)),
+    )?;
     router
         .route(path)
         .map(RoutingResult::RouteFound)
         .or(Ok(RoutingResult::RouteNotFound))
 }
 
+/// Route the request to the component with the given id regardless of its route
+fn find_component(
+    triggers: &[HttpTrigger],
+    request: &IncomingRequest,
+    component: &str,
+) -> RoutingResult {
+    if !triggers.iter().any(|t| t.component == component) {
+        return RoutingResult::RouteNotFound;
+    }
+    let path_with_query = request
+        .path_with_query()
+        .unwrap_or_else(|| String::from("/"));
+    let path = path_with_query
+        .split_once('?')
+        .map(|(path, _)| path)
+        .unwrap_or(&path_with_query);
+    RoutingResult::RouteFound(RouteMatch::synthetic(component, path))
+}
+
 /// Apply any request transformations needed for the given route.
 fn apply_request_transformations(
     request: IncomingRequest,
@@ -133,13 +346,38 @@ fn apply_request_transformations(
     }
     let new = OutgoingRequest::new(headers);
     // Make sure that the scheme and authority are set as the Spin runtime does this
-    let _ = new.set_scheme(request.scheme().as_ref().or(Some(&Scheme::Http)));
-    let _ = new.set_authority(request.authority().as_deref().or(Some("127.0.0.1:3000")));
+    let _ = new.set_scheme(Some(&scheme(&request)));
+    let _ = new.set_authority(listen_addr(&request).as_deref().or(Some("127.0.0.1:3000")));
     let _ = new.set_method(&request.method());
     let _ = new.set_path_with_query(request.path_with_query().as_deref().or(Some("/")));
     Ok(bindings::new_request(new, Some(request.consume().unwrap())))
 }
 
+/// The address of the client the request comes from
+///
+/// Tests can set it per request, otherwise the request comes from the local machine.
+fn client_addr(req: &IncomingRequest) -> String {
+    bindings::client_addr(req).unwrap_or_else(|| "127.0.0.1:0".to_owned())
+}
+
+/// The scheme the request was received with
+///
+/// Tests can set whether the request came over TLS, otherwise the scheme of the request is used.
+fn scheme(req: &IncomingRequest) -> Scheme {
+    match bindings::tls(req) {
+        Some(true) => Scheme::Https,
+        Some(false) => Scheme::Http,
+        None => req.scheme().unwrap_or(Scheme::Http),
+    }
+}
+
+/// The address the app listens on
+///
+/// Tests can set it per request, otherwise the authority of the request is used.
+fn listen_addr(req: &IncomingRequest) -> Option<String> {
+    bindings::listen_addr(req).or_else(|| req.authority())
+}
+
 const FULL_URL: [&str; 2] = ["SPIN_FULL_URL", "X_FULL_URL"];
 const PATH_INFO: [&str; 2] = ["SPIN_PATH_INFO", "PATH_INFO"];
 const MATCHED_ROUTE: [&str; 2] = ["SPIN_MATCHED_ROUTE", "X_MATCHED_ROUTE"];
@@ -173,8 +411,8 @@ fn calculate_default_headers(
     let abs_path = req.path_with_query().unwrap_or_else(|| String::from("/"));
     let path_info = route_match.trailing_wildcard();
 
-    let scheme = req.scheme();
-    let scheme = match scheme.as_ref().unwrap_or(&Scheme::Http) {
+    let scheme = scheme(req);
+    let scheme = match &scheme {
         Scheme::Http => "http",
         Scheme::Https => "https",
         Scheme::Other(s) => s,
@@ -187,6 +425,7 @@ fn calculate_default_headers(
         .map(String::from_utf8)
         .transpose()
         .context("expected 'Host' header to be UTF-8 encoded but it was not")?
+        .or_else(|| listen_addr(req))
         .unwrap_or_else(|| "localhost:3000".into());
 
     let full_url = format!("{}://{}{}", scheme, host, abs_path);
@@ -201,7 +440,7 @@ fn calculate_default_headers(
         route_match.raw_route().to_string(),
     ));
     res.push((owned_component_route, route_match.raw_route_or_prefix()));
-    res.push((owned_client_addr, "127.0.0.1:0".to_owned()));
+    res.push((owned_client_addr, client_addr(req)));
 
     for (wild_name, wild_value) in route_match.named_wildcards() {
         let wild_header = convert(&format!(
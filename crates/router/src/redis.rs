@@ -0,0 +1,105 @@
+//! Delivering messages to the app's Redis triggers.
+
+use anyhow::Context as _;
+
+use crate::bindings::{
+    self,
+    exports::fermyon::router::redis_trigger::Guest,
+    fermyon::router::inbound_redis::{handle_message, Error},
+};
+use crate::Component;
+
+impl Guest for Component {
+    fn publish(address: Option<String>, channel: String, payload: Vec<u8>) -> Result<(), String> {
+        publish(address, &channel, payload).map_err(|e| format!("{e:#}"))
+    }
+}
+
+/// A `[[trigger.redis]]` entry of the manifest
+struct RedisTrigger {
+    /// The id of the component the trigger invokes
+    component: String,
+    /// The address of the Redis instance the trigger subscribes to
+    address: Option<String>,
+    channel: String,
+}
+
+/// Deliver the payload to every component subscribed to the channel
+fn publish(address: Option<String>, channel: &str, payload: Vec<u8>) -> anyhow::Result<()> {
+    let mut manifest: spin_manifest::schema::v2::AppManifest =
+        toml::from_str(&bindings::get_manifest()).context("failed to parse the manifest")?;
+    spin_manifest::normalize::normalize_manifest(&mut manifest);
+    let default_address = manifest
+        .application
+        .trigger_global_configs
+        .get("redis")
+        .and_then(|c| c.get("address").and_then(|v| v.as_str()))
+        .map(ToOwned::to_owned);
+    let address = address.or_else(|| default_address.clone());
+
+    let subscribers = redis_triggers(&manifest, default_address)?
+        .into_iter()
+        .filter(|t| t.channel == channel && t.address == address)
+        .collect::<Vec<_>>();
+    if subscribers.is_empty() {
+        anyhow::bail!(
+            "no Redis trigger subscribes to channel '{channel}' at address '{}'",
+            address.as_deref().unwrap_or("<none>")
+        );
+    }
+    for subscriber in subscribers {
+        bindings::set_component_id(&subscriber.component);
+        if let Err(Error::Error) = handle_message(&payload) {
+            let error = match bindings::take_stand_in_error() {
+                Some(reason) => anyhow::anyhow!(reason),
+                None => anyhow::anyhow!("the handler returned an error"),
+            };
+            return Err(error.context(format!(
+                "component '{}' failed to handle the message on channel '{channel}'",
+                subscriber.component
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Read the Redis triggers from the manifest
+fn redis_triggers(
+    manifest: &spin_manifest::schema::v2::AppManifest,
+    default_address: Option<String>,
+) -> anyhow::Result<Vec<RedisTrigger>> {
+    let triggers = manifest
+        .triggers
+        .get("redis")
+        .filter(|triggers| !triggers.is_empty())
+        .context("the app has no Redis triggers so it can't receive Redis messages")?;
+    triggers
+        .iter()
+        .map(|trigger| {
+            let component = match &trigger.component {
+                Some(spin_manifest::schema::v2::ComponentSpec::Reference(comp)) => comp,
+                Some(spin_manifest::schema::v2::ComponentSpec::Inline(_)) => {
+                    anyhow::bail!("Redis trigger '{}' has an inline component", trigger.id)
+                }
+                None => anyhow::bail!("Redis trigger '{}' has no component", trigger.id),
+            };
+            let channel = trigger
+                .config
+                .get("channel")
+                .and_then(|c| c.as_str())
+                .with_context(|| format!("Redis trigger for '{component}' has no `channel`"))?;
+            // Triggers without their own address use the app's default address
+            let address = trigger
+                .config
+                .get("address")
+                .and_then(|a| a.as_str())
+                .map(ToOwned::to_owned)
+                .or_else(|| default_address.clone());
+            Ok(RedisTrigger {
+                component: component.to_string(),
+                address,
+                channel: channel.to_owned(),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,275 @@
+//! Running WAGI components the way Spin's WAGI executor does.
+//!
+//! The request is passed to the component through CGI environment variables, arguments and
+//! stdin and the response is read from what the component writes to stdout.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anyhow::Context as _;
+use spin_http::routes::RouteMatch;
+
+use crate::bindings::{
+    self,
+    wasi::http::types::{IncomingRequest, Method, Scheme},
+    wasi::io::streams::StreamError,
+};
+
+/// The entrypoint Spin calls when none is configured
+pub const DEFAULT_ENTRYPOINT: &str = "_start";
+/// The arguments Spin passes when none are configured
+pub const DEFAULT_ARGV: &str = "${SCRIPT_NAME} ${ARGS}";
+
+/// The `executor` configuration of a WAGI trigger
+pub struct WagiConfig {
+    /// The function to call
+    pub entrypoint: String,
+    /// The template for the arguments passed to the component
+    pub argv: String,
+}
+
+/// The entrypoints of the fresh instances of the WAGI component behind this router
+///
+/// wasi-libc's `_start` traps when it is called a second time on the same instance, so each
+/// request is run in an instance of its own.
+const INSTANCES: [fn() -> Result<(), ()>; 8] = [
+    bindings::wagi_instance0::run,
+    bindings::wagi_instance1::run,
+    bindings::wagi_instance2::run,
+    bindings::wagi_instance3::run,
+    bindings::wagi_instance4::run,
+    bindings::wagi_instance5::run,
+    bindings::wagi_instance6::run,
+    bindings::wagi_instance7::run,
+];
+
+/// The number of requests the WAGI component behind this router has handled
+static HANDLED: AtomicUsize = AtomicUsize::new(0);
+
+/// The response a WAGI component wrote to stdout
+pub struct WagiResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// Run the current component as a WAGI command with the given request
+///
+/// Fails if all the instances of the component already handled a request.
+pub fn handle(
+    request: IncomingRequest,
+    base: &str,
+    route_match: &RouteMatch,
+    config: &WagiConfig,
+) -> anyhow::Result<WagiResponse> {
+    if config.entrypoint != DEFAULT_ENTRYPOINT {
+        anyhow::bail!(
+            "WAGI entrypoint '{}' is not supported by `spin-test`; only '{DEFAULT_ENTRYPOINT}' is",
+            config.entrypoint
+        );
+    }
+    let path_with_query = request
+        .path_with_query()
+        .unwrap_or_else(|| String::from("/"));
+    let query = path_with_query
+        .split_once('?')
+        .map(|(_, query)| query)
+        .unwrap_or_default();
+    let script_name = route_match
+        .based_route()
+        .trim_end_matches("/...")
+        .to_owned();
+
+    let environment = environment(&request, base, route_match, &script_name, query)?;
+    let arguments = arguments(&config.argv, &script_name, query);
+    let body = read_body(request)?;
+    let environment = environment
+        .into_iter()
+        .chain([("CONTENT_LENGTH".to_owned(), body.len().to_string())])
+        .collect::<Vec<_>>();
+
+    let Some(run) = INSTANCES.get(HANDLED.fetch_add(1, Ordering::Relaxed)) else {
+        anyhow::bail!(
+            "the WAGI component already handled {} requests in this test and `spin-test` runs \
+            each request in one of that many instances of the component",
+            INSTANCES.len()
+        );
+    };
+    bindings::prepare_wagi(&arguments, &environment, &body);
+    let result = run();
+    let stdout = bindings::finish_wagi();
+    result.map_err(|()| match bindings::take_stand_in_error() {
+        Some(reason) => anyhow::anyhow!(reason),
+        None => anyhow::anyhow!("WAGI component exited with an error"),
+    })?;
+    parse_response(&stdout)
+}
+
+/// The CGI environment variables for the request
+fn environment(
+    request: &IncomingRequest,
+    base: &str,
+    route_match: &RouteMatch,
+    script_name: &str,
+    query: &str,
+) -> anyhow::Result<Vec<(String, String)>> {
+    let headers = request.headers().entries();
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| String::from_utf8_lossy(v).into_owned())
+    };
+    let host = header("host")
+        .or_else(|| crate::listen_addr(request))
+        .unwrap_or_else(|| "localhost:3000".into());
+    let client_addr = crate::client_addr(request);
+    let client_ip = client_addr
+        .rsplit_once(':')
+        .map_or(client_addr.as_str(), |(ip, _)| ip)
+        .trim_start_matches('[')
+        .trim_end_matches(']');
+    let default_port = match crate::scheme(request) {
+        Scheme::Https => "443",
+        _ => "80",
+    };
+    let (server_name, server_port) = host.split_once(':').unwrap_or((&host, default_port));
+    let path_info = route_match.trailing_wildcard();
+
+    let mut environment = vec![
+        ("AUTH_TYPE".to_owned(), String::new()),
+        (
+            "CONTENT_TYPE".to_owned(),
+            header("content-type").unwrap_or_default(),
+        ),
+        ("GATEWAY_INTERFACE".to_owned(), "CGI/1.1".to_owned()),
+        ("PATH_INFO".to_owned(), path_info.clone()),
+        ("PATH_TRANSLATED".to_owned(), path_info.clone()),
+        ("QUERY_STRING".to_owned(), query.to_owned()),
+        ("REMOTE_ADDR".to_owned(), client_ip.to_owned()),
+        ("REMOTE_HOST".to_owned(), client_ip.to_owned()),
+        ("REMOTE_USER".to_owned(), String::new()),
+        ("REQUEST_METHOD".to_owned(), method_name(&request.method())),
+        ("SCRIPT_NAME".to_owned(), script_name.to_owned()),
+        ("SERVER_NAME".to_owned(), server_name.to_owned()),
+        ("SERVER_PORT".to_owned(), server_port.to_owned()),
+        ("SERVER_PROTOCOL".to_owned(), "HTTP/1.1".to_owned()),
+        ("SERVER_SOFTWARE".to_owned(), "WAGI/1".to_owned()),
+        ("X_RAW_PATH_INFO".to_owned(), path_info),
+    ];
+    // The default Spin headers are passed using their WAGI names (e.g., `X_FULL_URL`)
+    for ([_, wagi_name], value) in crate::calculate_default_headers(request, base, route_match)? {
+        environment.push((wagi_name.replace('-', "_").to_ascii_uppercase(), value));
+    }
+    for (name, value) in &headers {
+        // Credentials and hop-by-hop headers are not passed to CGI scripts
+        if name.eq_ignore_ascii_case("authorization") || name.eq_ignore_ascii_case("connection") {
+            continue;
+        }
+        environment.push((
+            format!("HTTP_{}", name.replace('-', "_").to_ascii_uppercase()),
+            String::from_utf8_lossy(value).into_owned(),
+        ));
+    }
+    Ok(environment)
+}
+
+/// Expand the `argv` template into the arguments passed to the component
+fn arguments(argv: &str, script_name: &str, query: &str) -> Vec<String> {
+    let args = query
+        .split('&')
+        .filter(|a| !a.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    argv.replace("${SCRIPT_NAME}", script_name)
+        .replace("${ARGS}", &args)
+        .split_whitespace()
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+/// Read the whole body of the request
+fn read_body(request: IncomingRequest) -> anyhow::Result<Vec<u8>> {
+    let body = request
+        .consume()
+        .map_err(|()| anyhow::anyhow!("request body was already consumed"))?;
+    let stream = body
+        .stream()
+        .map_err(|()| anyhow::anyhow!("request body stream was already taken"))?;
+    let mut bytes = Vec::new();
+    loop {
+        match stream.blocking_read(4096) {
+            Ok(chunk) => bytes.extend(chunk),
+            Err(StreamError::Closed) => break,
+            Err(StreamError::LastOperationFailed(e)) => {
+                anyhow::bail!("failed to read request body: {}", e.to_debug_string())
+            }
+        }
+    }
+    Ok(bytes)
+}
+
+/// Parse the CGI response a WAGI component wrote to stdout
+fn parse_response(stdout: &[u8]) -> anyhow::Result<WagiResponse> {
+    let (head, body) = split_head(stdout)
+        .context("WAGI component did not write an empty line after the response headers")?;
+    let head = std::str::from_utf8(head).context("WAGI response headers were not UTF-8")?;
+    let mut status = None;
+    let mut headers = Vec::new();
+    for line in head.lines().filter(|l| !l.trim().is_empty()) {
+        let (name, value) = line
+            .split_once(':')
+            .with_context(|| format!("WAGI response header '{line}' has no value"))?;
+        let (name, value) = (name.trim(), value.trim());
+        if name.eq_ignore_ascii_case("status") {
+            let code = value.split_whitespace().next().unwrap_or_default();
+            status = Some(
+                code.parse::<u16>()
+                    .with_context(|| format!("WAGI response status '{value}' is invalid"))?,
+            );
+        } else {
+            headers.push((name.to_owned(), value.to_owned()));
+        }
+    }
+    let has_header = |n: &str| headers.iter().any(|(k, _)| k.eq_ignore_ascii_case(n));
+    let has_location = has_header("location");
+    if !has_location && !has_header("content-type") {
+        anyhow::bail!("WAGI response must have either a Location or a Content-Type header");
+    }
+    let status = status.unwrap_or(if has_location { 302 } else { 200 });
+    Ok(WagiResponse {
+        status,
+        headers,
+        body: body.to_vec(),
+    })
+}
+
+/// Split the output at the first empty line into the headers and the body
+fn split_head(stdout: &[u8]) -> Option<(&[u8], &[u8])> {
+    (0..stdout.len()).find_map(|i| {
+        let rest = &stdout[i..];
+        if rest.starts_with(b"\r\n\r\n") {
+            Some((&stdout[..i], &stdout[i + 4..]))
+        } else if rest.starts_with(b"\n\n") {
+            Some((&stdout[..i], &stdout[i + 2..]))
+        } else {
+            None
+        }
+    })
+}
+
+/// The name of an HTTP method
+pub fn method_name(method: &Method) -> String {
+    match method {
+        Method::Get => "GET",
+        Method::Head => "HEAD",
+        Method::Post => "POST",
+        Method::Put => "PUT",
+        Method::Delete => "DELETE",
+        Method::Connect => "CONNECT",
+        Method::Options => "OPTIONS",
+        Method::Trace => "TRACE",
+        Method::Patch => "PATCH",
+        Method::Other(other) => other,
+    }
+    .to_owned()
+}
@@ -15,8 +15,62 @@ use bindings::fermyon::spin_test;
 use bindings::wasi::http;
 
 /// Make a request to the Spin app and return the response.
+///
+/// The scheme and authority of the request are used as the scheme and the address the app
+/// listens on.
 pub fn perform_request(request: http::types::OutgoingRequest) -> http::types::IncomingResponse {
+    send(spin_test::http_helper::new_request(request, None))
+}
+
+/// Make a request to the Spin app as if it came from the client at `client_addr`.
+///
+/// The address includes the port (e.g., `203.0.113.7:51234`).
+pub fn perform_request_from(
+    request: http::types::OutgoingRequest,
+    client_addr: &str,
+) -> http::types::IncomingResponse {
+    perform_request_with(
+        request,
+        RequestOptions {
+            client_addr: Some(client_addr),
+            ..Default::default()
+        },
+    )
+}
+
+/// How a request reaches the Spin app.
+///
+/// Options which are not set are taken from the request itself or Spin's defaults.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RequestOptions<'a> {
+    /// The address of the client the request comes from (e.g., `203.0.113.7:51234`)
+    pub client_addr: Option<&'a str>,
+    /// Whether the request was received over TLS
+    pub tls: Option<bool>,
+    /// The address the app listens on (e.g., `0.0.0.0:8080`)
+    pub listen_addr: Option<&'a str>,
+}
+
+/// Make a request to the Spin app with the given options and return the response.
+pub fn perform_request_with(
+    request: http::types::OutgoingRequest,
+    options: RequestOptions<'_>,
+) -> http::types::IncomingResponse {
     let request = spin_test::http_helper::new_request(request, None);
+    if let Some(client_addr) = options.client_addr {
+        spin_test::http_helper::set_client_addr(&request, client_addr);
+    }
+    if let Some(tls) = options.tls {
+        spin_test::http_helper::set_tls(&request, tls);
+    }
+    if let Some(listen_addr) = options.listen_addr {
+        spin_test::http_helper::set_listen_addr(&request, listen_addr);
+    }
+    send(request)
+}
+
+/// Send the request to the Spin app and return the response.
+fn send(request: http::types::IncomingRequest) -> http::types::IncomingResponse {
     let (response_out, response_receiver) = spin_test::http_helper::new_response();
     http::incoming_handler::handle(request, response_out);
     response_receiver.get().unwrap()
@@ -1,5 +1,8 @@
 use crate::bindings::{
-    fermyon::spin_test_virt,
+    fermyon::{
+        spin::{llm, rdbms_types, redis, sqlite, variables},
+        spin_test_virt, spin_wasi_virt,
+    },
     wasi::{http, io::streams},
 };
 
@@ -17,6 +20,190 @@ impl PartialEq for spin_test_virt::key_value::Call {
     }
 }
 
+impl PartialEq for spin_test_virt::variables::Call {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.result == other.result
+    }
+}
+
+impl PartialEq for variables::Error {
+    fn eq(&self, other: &Self) -> bool {
+        use variables::Error::*;
+        match (self, other) {
+            (InvalidName(a), InvalidName(b)) => a == b,
+            (Undefined(a), Undefined(b)) => a == b,
+            (Provider(a), Provider(b)) => a == b,
+            (Other(a), Other(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for spin_test_virt::llm::Call {
+    fn eq(&self, other: &Self) -> bool {
+        use spin_test_virt::llm::Call::*;
+        match (self, other) {
+            (Infer(a), Infer(b)) => {
+                a.model == b.model && a.prompt == b.prompt && a.params == b.params
+            }
+            (GenerateEmbeddings(a), GenerateEmbeddings(b)) => {
+                a.model == b.model && a.text == b.text
+            }
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for llm::InferencingParams {
+    fn eq(&self, other: &Self) -> bool {
+        self.max_tokens == other.max_tokens
+            && self.repeat_penalty == other.repeat_penalty
+            && self.repeat_penalty_last_n_token_count == other.repeat_penalty_last_n_token_count
+            && self.temperature == other.temperature
+            && self.top_k == other.top_k
+            && self.top_p == other.top_p
+    }
+}
+
+impl PartialEq for spin_test_virt::sqlite::Call {
+    fn eq(&self, other: &Self) -> bool {
+        self.statement == other.statement
+            && self.parameters == other.parameters
+            && self.result == other.result
+    }
+}
+
+impl PartialEq for sqlite::Value {
+    fn eq(&self, other: &Self) -> bool {
+        use sqlite::Value::*;
+        match (self, other) {
+            (Integer(a), Integer(b)) => a == b,
+            (Real(a), Real(b)) => a == b,
+            (Text(a), Text(b)) => a == b,
+            (Blob(a), Blob(b)) => a == b,
+            (Null, Null) => true,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for sqlite::Error {
+    fn eq(&self, other: &Self) -> bool {
+        use sqlite::Error::*;
+        match (self, other) {
+            (NoSuchDatabase, NoSuchDatabase) => true,
+            (AccessDenied, AccessDenied) => true,
+            (InvalidConnection, InvalidConnection) => true,
+            (DatabaseFull, DatabaseFull) => true,
+            (Io(a), Io(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for spin_test_virt::rdbms::Call {
+    fn eq(&self, other: &Self) -> bool {
+        self.statement == other.statement
+            && self.parameters == other.parameters
+            && self.result == other.result
+    }
+}
+
+impl PartialEq for rdbms_types::ParameterValue {
+    fn eq(&self, other: &Self) -> bool {
+        use rdbms_types::ParameterValue::*;
+        match (self, other) {
+            (Boolean(a), Boolean(b)) => a == b,
+            (Int8(a), Int8(b)) => a == b,
+            (Int16(a), Int16(b)) => a == b,
+            (Int32(a), Int32(b)) => a == b,
+            (Int64(a), Int64(b)) => a == b,
+            (Uint8(a), Uint8(b)) => a == b,
+            (Uint16(a), Uint16(b)) => a == b,
+            (Uint32(a), Uint32(b)) => a == b,
+            (Uint64(a), Uint64(b)) => a == b,
+            (Floating32(a), Floating32(b)) => a == b,
+            (Floating64(a), Floating64(b)) => a == b,
+            (Str(a), Str(b)) => a == b,
+            (Binary(a), Binary(b)) => a == b,
+            (DbNull, DbNull) => true,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for rdbms_types::Error {
+    fn eq(&self, other: &Self) -> bool {
+        use rdbms_types::Error::*;
+        match (self, other) {
+            (ConnectionFailed(a), ConnectionFailed(b)) => a == b,
+            (BadParameter(a), BadParameter(b)) => a == b,
+            (QueryFailed(a), QueryFailed(b)) => a == b,
+            (ValueConversionFailed(a), ValueConversionFailed(b)) => a == b,
+            (Other(a), Other(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for spin_test_virt::redis::Call {
+    fn eq(&self, other: &Self) -> bool {
+        use spin_test_virt::redis::Call::*;
+        match (self, other) {
+            (Publish(a), Publish(b)) => a == b,
+            (Get(a), Get(b)) => a == b,
+            (Set(a), Set(b)) => a == b,
+            (Incr(a), Incr(b)) => a == b,
+            (Del(a), Del(b)) => a == b,
+            (Sadd(a), Sadd(b)) => a == b,
+            (Smembers(a), Smembers(b)) => a == b,
+            (Srem(a), Srem(b)) => a == b,
+            (Execute(a), Execute(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for redis::RedisParameter {
+    fn eq(&self, other: &Self) -> bool {
+        use redis::RedisParameter::*;
+        match (self, other) {
+            (Int64(a), Int64(b)) => a == b,
+            (Binary(a), Binary(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for spin_test_virt::mqtt::Publish {
+    fn eq(&self, other: &Self) -> bool {
+        self.topic == other.topic
+            && self.payload == other.payload
+            && self.qos == other.qos
+            && self.username == other.username
+            && self.keep_alive_interval_in_secs == other.keep_alive_interval_in_secs
+    }
+}
+
+impl PartialEq for spin_wasi_virt::http_handler::InternalRequest {
+    fn eq(&self, other: &Self) -> bool {
+        self.method == other.method && self.url == other.url
+    }
+}
+
+impl PartialEq for redis::RedisResult {
+    fn eq(&self, other: &Self) -> bool {
+        use redis::RedisResult::*;
+        match (self, other) {
+            (Nil, Nil) => true,
+            (Status(a), Status(b)) => a == b,
+            (Int64(a), Int64(b)) => a == b,
+            (Binary(a), Binary(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
 impl http::types::IncomingResponse {
     /// Read the body of the incoming response calling the callback on each chunk.
     pub fn read_body(self, callback: impl FnMut(Vec<u8>)) -> Result<(), streams::Error> {
@@ -135,3 +322,13 @@ impl spin_test_virt::key_value::Store {
             .unwrap_or_default()
     }
 }
+
+impl spin_test_virt::redis::Connection {
+    pub fn calls(&self) -> Vec<spin_test_virt::redis::Call> {
+        spin_test_virt::redis::calls()
+            .iter()
+            .find(|(address, _)| address == &self.address())
+            .map(|(_, calls)| calls.clone())
+            .unwrap_or_default()
+    }
+}
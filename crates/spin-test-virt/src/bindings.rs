@@ -35,9 +35,188 @@ pub unsafe fn _export_set_component_id_cabi<T: Guest>(arg0: *mut u8, arg1: usize
     let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
     T::set_component_id(_rt::string_lift(bytes0));
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_provide_variable_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    T::provide_variable(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_prepare_wagi_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: *mut u8,
+    arg5: usize,
+) {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let base3 = arg0;
+    let len3 = arg1;
+    let mut result3 = _rt::Vec::with_capacity(len3);
+    for i in 0..len3 {
+        let base = base3.add(i * 8);
+        let e3 = {
+            let l0 = *base.add(0).cast::<*mut u8>();
+            let l1 = *base.add(4).cast::<usize>();
+            let len2 = l1;
+            let bytes2 = _rt::Vec::from_raw_parts(l0.cast(), len2, len2);
+
+            _rt::string_lift(bytes2)
+        };
+        result3.push(e3);
+    }
+    _rt::cabi_dealloc(base3, len3 * 8, 4);
+    let base10 = arg2;
+    let len10 = arg3;
+    let mut result10 = _rt::Vec::with_capacity(len10);
+    for i in 0..len10 {
+        let base = base10.add(i * 16);
+        let e10 = {
+            let l4 = *base.add(0).cast::<*mut u8>();
+            let l5 = *base.add(4).cast::<usize>();
+            let len6 = l5;
+            let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+            let l7 = *base.add(8).cast::<*mut u8>();
+            let l8 = *base.add(12).cast::<usize>();
+            let len9 = l8;
+            let bytes9 = _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+
+            (_rt::string_lift(bytes6), _rt::string_lift(bytes9))
+        };
+        result10.push(e10);
+    }
+    _rt::cabi_dealloc(base10, len10 * 16, 4);
+    let len11 = arg5;
+    T::prepare_wagi(
+        result3,
+        result10,
+        _rt::Vec::from_raw_parts(arg4.cast(), len11, len11),
+    );
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_finish_wagi_cabi<T: Guest>() -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let result0 = T::finish_wagi();
+    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    let vec2 = (result0).into_boxed_slice();
+    let ptr2 = vec2.as_ptr().cast::<u8>();
+    let len2 = vec2.len();
+    ::core::mem::forget(vec2);
+    *ptr1.add(4).cast::<usize>() = len2;
+    *ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+    ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_finish_wagi<T: Guest>(arg0: *mut u8) {
+    let l0 = *arg0.add(0).cast::<*mut u8>();
+    let l1 = *arg0.add(4).cast::<usize>();
+    let base2 = l0;
+    let len2 = l1;
+    _rt::cabi_dealloc(base2, len2 * 1, 1);
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_take_stand_in_error_cabi<T: Guest>() -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let result0 = T::take_stand_in_error();
+    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result0 {
+        Some(e) => {
+            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+            let vec2 = (e.into_bytes()).into_boxed_slice();
+            let ptr2 = vec2.as_ptr().cast::<u8>();
+            let len2 = vec2.len();
+            ::core::mem::forget(vec2);
+            *ptr1.add(8).cast::<usize>() = len2;
+            *ptr1.add(4).cast::<*mut u8>() = ptr2.cast_mut();
+        }
+        None => {
+            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+        }
+    };
+    ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_take_stand_in_error<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => (),
+        _ => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_allows_outbound_url_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> i32 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::allows_outbound_url(_rt::string_lift(bytes0));
+    match result1 {
+        true => 1,
+        false => 0,
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_record_internal_request_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    T::record_internal_request(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+}
 pub trait Guest {
     /// Helper functions
     fn set_component_id(component_id: _rt::String);
+    /// Provide the value of an application variable from the variable sources of the host
+    ///
+    /// Values set through the `variables` interface take precedence and `variables.unset` and
+    /// `variables.clear` leave these values in place.
+    fn provide_variable(key: _rt::String, value: _rt::String);
+    /// Set the arguments, environment and stdin for the next run of a WAGI component
+    fn prepare_wagi(
+        arguments: _rt::Vec<_rt::String>,
+        environment: _rt::Vec<(_rt::String, _rt::String)>,
+        stdin: _rt::Vec<u8>,
+    );
+    /// Take everything the WAGI component wrote to stdout since `prepare-wagi` was called
+    fn finish_wagi() -> _rt::Vec<u8>;
+    /// Take the reason the last stand-in for an entrypoint the app doesn't export failed with
+    fn take_stand_in_error() -> Option<_rt::String>;
+    /// Whether the current component is allowed to make requests to the url
+    fn allows_outbound_url(url: _rt::String) -> bool;
+    /// Record a request the app made to itself through local service chaining
+    fn record_internal_request(method: _rt::String, url: _rt::String);
 }
 #[doc(hidden)]
 
@@ -48,10 +227,45 @@ macro_rules! __export_world_env_cabi{
     unsafe extern "C" fn export_set_component_id(arg0: *mut u8,arg1: usize,) {
       $($path_to_types)*::_export_set_component_id_cabi::<$ty>(arg0, arg1)
     }
+    #[export_name = "provide-variable"]
+    unsafe extern "C" fn export_provide_variable(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) {
+      $($path_to_types)*::_export_provide_variable_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[export_name = "prepare-wagi"]
+    unsafe extern "C" fn export_prepare_wagi(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,arg4: *mut u8,arg5: usize,) {
+      $($path_to_types)*::_export_prepare_wagi_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5)
+    }
+    #[export_name = "finish-wagi"]
+    unsafe extern "C" fn export_finish_wagi() -> *mut u8 {
+      $($path_to_types)*::_export_finish_wagi_cabi::<$ty>()
+    }
+    #[export_name = "cabi_post_finish-wagi"]
+    unsafe extern "C" fn _post_return_finish_wagi(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_finish_wagi::<$ty>(arg0)
+    }
+    #[export_name = "take-stand-in-error"]
+    unsafe extern "C" fn export_take_stand_in_error() -> *mut u8 {
+      $($path_to_types)*::_export_take_stand_in_error_cabi::<$ty>()
+    }
+    #[export_name = "cabi_post_take-stand-in-error"]
+    unsafe extern "C" fn _post_return_take_stand_in_error(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_take_stand_in_error::<$ty>(arg0)
+    }
+    #[export_name = "allows-outbound-url"]
+    unsafe extern "C" fn export_allows_outbound_url(arg0: *mut u8,arg1: usize,) -> i32 {
+      $($path_to_types)*::_export_allows_outbound_url_cabi::<$ty>(arg0, arg1)
+    }
+    #[export_name = "record-internal-request"]
+    unsafe extern "C" fn export_record_internal_request(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) {
+      $($path_to_types)*::_export_record_internal_request_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
   };);
 }
 #[doc(hidden)]
 pub(crate) use __export_world_env_cabi;
+#[repr(align(4))]
+struct _RetArea([::core::mem::MaybeUninit<u8>; 12]);
+static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 12]);
 #[allow(dead_code)]
 pub mod wasi {
     #[allow(dead_code)]
@@ -14532,6 +14746,46 @@ pub mod exports {
         }
         #[allow(dead_code)]
         pub mod spin_test_virt {
+            #[allow(dead_code, clippy::all)]
+            pub mod types {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                /// How a rule matches a piece of text (e.g., a sqlite statement)
+                #[derive(Clone)]
+                pub enum Matcher {
+                    /// The text must be exactly this
+                    Exact(_rt::String),
+                    /// The text must start with this
+                    Prefix(_rt::String),
+                    /// The text must match this regular expression
+                    Regex(_rt::String),
+                }
+                impl ::core::fmt::Debug for Matcher {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            Matcher::Exact(e) => f.debug_tuple("Matcher::Exact").field(e).finish(),
+                            Matcher::Prefix(e) => {
+                                f.debug_tuple("Matcher::Prefix").field(e).finish()
+                            }
+                            Matcher::Regex(e) => f.debug_tuple("Matcher::Regex").field(e).finish(),
+                        }
+                    }
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_fermyon_spin_test_virt_types_cabi {
+                    ($ty:ident with_types_in $($path_to_types:tt)*) => {
+                        const _: () = {};
+                    };
+                }
+                #[doc(hidden)]
+                pub(crate) use __export_fermyon_spin_test_virt_types_cabi;
+            }
+
             #[allow(dead_code, clippy::all)]
             pub mod key_value {
                 #[used]
@@ -15124,6 +15378,614 @@ pub mod exports {
                 static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 12]);
             }
 
+            #[allow(dead_code, clippy::all)]
+            pub mod llm {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type InferencingParams =
+                    super::super::super::super::exports::fermyon::spin::llm::InferencingParams;
+                pub type InferencingResult =
+                    super::super::super::super::exports::fermyon::spin::llm::InferencingResult;
+                pub type EmbeddingsResult =
+                    super::super::super::super::exports::fermyon::spin::llm::EmbeddingsResult;
+                pub type Error = super::super::super::super::exports::fermyon::spin::llm::Error;
+                pub type Matcher =
+                    super::super::super::super::exports::fermyon::spin_test_virt::types::Matcher;
+                /// What an inferencing request returns
+                #[derive(Clone)]
+                pub enum InferResponse {
+                    /// Fail the request with the given error
+                    Error(Error),
+                    /// Return the given text and usage
+                    Result(InferencingResult),
+                }
+                impl ::core::fmt::Debug for InferResponse {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            InferResponse::Error(e) => {
+                                f.debug_tuple("InferResponse::Error").field(e).finish()
+                            }
+                            InferResponse::Result(e) => {
+                                f.debug_tuple("InferResponse::Result").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                /// What an embeddings request returns
+                #[derive(Clone)]
+                pub enum EmbeddingsResponse {
+                    /// Fail the request with the given error
+                    Error(Error),
+                    /// Return the given embeddings and usage
+                    Result(EmbeddingsResult),
+                }
+                impl ::core::fmt::Debug for EmbeddingsResponse {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            EmbeddingsResponse::Error(e) => {
+                                f.debug_tuple("EmbeddingsResponse::Error").field(e).finish()
+                            }
+                            EmbeddingsResponse::Result(e) => f
+                                .debug_tuple("EmbeddingsResponse::Result")
+                                .field(e)
+                                .finish(),
+                        }
+                    }
+                }
+                /// An inferencing request
+                #[derive(Clone)]
+                pub struct InferCall {
+                    pub model: _rt::String,
+                    pub prompt: _rt::String,
+                    pub params: Option<InferencingParams>,
+                }
+                impl ::core::fmt::Debug for InferCall {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("InferCall")
+                            .field("model", &self.model)
+                            .field("prompt", &self.prompt)
+                            .field("params", &self.params)
+                            .finish()
+                    }
+                }
+                /// An embeddings request
+                #[derive(Clone)]
+                pub struct EmbeddingsCall {
+                    pub model: _rt::String,
+                    pub text: _rt::Vec<_rt::String>,
+                }
+                impl ::core::fmt::Debug for EmbeddingsCall {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("EmbeddingsCall")
+                            .field("model", &self.model)
+                            .field("text", &self.text)
+                            .finish()
+                    }
+                }
+                /// A call to the llm interface
+                #[derive(Clone)]
+                pub enum Call {
+                    Infer(InferCall),
+                    GenerateEmbeddings(EmbeddingsCall),
+                }
+                impl ::core::fmt::Debug for Call {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            Call::Infer(e) => f.debug_tuple("Call::Infer").field(e).finish(),
+                            Call::GenerateEmbeddings(e) => {
+                                f.debug_tuple("Call::GenerateEmbeddings").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_add_infer_rule_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: *mut u8,
+                    arg6: usize,
+                    arg7: i32,
+                    arg8: *mut u8,
+                    arg9: *mut u8,
+                    arg10: usize,
+                    arg11: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let v9 = match arg7 {
+                        0 => {
+                            let e9 = {
+                                use super::super::super::super::exports::fermyon::spin::llm::Error as V7;
+                                let v7 = match arg8 as i32 {
+                                    0 => V7::ModelNotSupported,
+                                    1 => {
+                                        let e7 = {
+                                            let len5 = arg10;
+                                            let bytes5 =
+                                                _rt::Vec::from_raw_parts(arg9.cast(), len5, len5);
+
+                                            _rt::string_lift(bytes5)
+                                        };
+                                        V7::RuntimeError(e7)
+                                    }
+                                    n => {
+                                        debug_assert_eq!(n, 2, "invalid enum discriminant");
+                                        let e7 = {
+                                            let len6 = arg10;
+                                            let bytes6 =
+                                                _rt::Vec::from_raw_parts(arg9.cast(), len6, len6);
+
+                                            _rt::string_lift(bytes6)
+                                        };
+                                        V7::InvalidInput(e7)
+                                    }
+                                };
+
+                                v7
+                            };
+                            InferResponse::Error(e9)
+                        }
+                        n => {
+                            debug_assert_eq!(n, 1, "invalid enum discriminant");
+                            let e9 = {
+                                let len8 = arg9 as usize;
+                                let bytes8 = _rt::Vec::from_raw_parts(arg8.cast(), len8, len8);
+
+                                super::super::super::super::exports::fermyon::spin::llm::InferencingResult{
+          text: _rt::string_lift(bytes8),
+          usage: super::super::super::super::exports::fermyon::spin::llm::InferencingUsage{
+            prompt_token_count: arg10 as i32 as u32,
+            generated_token_count: arg11 as u32,
+          },
+        }
+                            };
+                            InferResponse::Result(e9)
+                        }
+                    };
+                    let result10 = T::add_infer_rule(
+                        match arg0 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len0 = arg2;
+                                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+
+                                    _rt::string_lift(bytes0)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        match arg3 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    use super::super::super::super::exports::fermyon::spin_test_virt::types::Matcher as V4;
+                                    let v4 = match arg4 {
+                                        0 => {
+                                            let e4 = {
+                                                let len1 = arg6;
+                                                let bytes1 = _rt::Vec::from_raw_parts(
+                                                    arg5.cast(),
+                                                    len1,
+                                                    len1,
+                                                );
+
+                                                _rt::string_lift(bytes1)
+                                            };
+                                            V4::Exact(e4)
+                                        }
+                                        1 => {
+                                            let e4 = {
+                                                let len2 = arg6;
+                                                let bytes2 = _rt::Vec::from_raw_parts(
+                                                    arg5.cast(),
+                                                    len2,
+                                                    len2,
+                                                );
+
+                                                _rt::string_lift(bytes2)
+                                            };
+                                            V4::Prefix(e4)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 2, "invalid enum discriminant");
+                                            let e4 = {
+                                                let len3 = arg6;
+                                                let bytes3 = _rt::Vec::from_raw_parts(
+                                                    arg5.cast(),
+                                                    len3,
+                                                    len3,
+                                                );
+
+                                                _rt::string_lift(bytes3)
+                                            };
+                                            V4::Regex(e4)
+                                        }
+                                    };
+
+                                    v4
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        v9,
+                    );
+                    let ptr11 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result10 {
+                        Ok(_) => {
+                            *ptr11.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr11.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec12 = (e.into_bytes()).into_boxed_slice();
+                            let ptr12 = vec12.as_ptr().cast::<u8>();
+                            let len12 = vec12.len();
+                            ::core::mem::forget(vec12);
+                            *ptr11.add(8).cast::<usize>() = len12;
+                            *ptr11.add(4).cast::<*mut u8>() = ptr12.cast_mut();
+                        }
+                    };
+                    ptr11
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_add_infer_rule<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = *arg0.add(4).cast::<*mut u8>();
+                            let l2 = *arg0.add(8).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_add_embeddings_rule_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i32,
+                    arg4: *mut u8,
+                    arg5: *mut u8,
+                    arg6: usize,
+                ) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let v8 = match arg3 {
+                        0 => {
+                            let e8 = {
+                                use super::super::super::super::exports::fermyon::spin::llm::Error as V3;
+                                let v3 = match arg4 as i32 {
+                                    0 => V3::ModelNotSupported,
+                                    1 => {
+                                        let e3 = {
+                                            let len1 = arg6;
+                                            let bytes1 =
+                                                _rt::Vec::from_raw_parts(arg5.cast(), len1, len1);
+
+                                            _rt::string_lift(bytes1)
+                                        };
+                                        V3::RuntimeError(e3)
+                                    }
+                                    n => {
+                                        debug_assert_eq!(n, 2, "invalid enum discriminant");
+                                        let e3 = {
+                                            let len2 = arg6;
+                                            let bytes2 =
+                                                _rt::Vec::from_raw_parts(arg5.cast(), len2, len2);
+
+                                            _rt::string_lift(bytes2)
+                                        };
+                                        V3::InvalidInput(e3)
+                                    }
+                                };
+
+                                v3
+                            };
+                            EmbeddingsResponse::Error(e8)
+                        }
+                        n => {
+                            debug_assert_eq!(n, 1, "invalid enum discriminant");
+                            let e8 = {
+                                let base7 = arg4;
+                                let len7 = arg5 as usize;
+                                let mut result7 = _rt::Vec::with_capacity(len7);
+                                for i in 0..len7 {
+                                    let base = base7.add(i * 8);
+                                    let e7 = {
+                                        let l4 = *base.add(0).cast::<*mut u8>();
+                                        let l5 = *base.add(4).cast::<usize>();
+                                        let len6 = l5;
+
+                                        _rt::Vec::from_raw_parts(l4.cast(), len6, len6)
+                                    };
+                                    result7.push(e7);
+                                }
+                                _rt::cabi_dealloc(base7, len7 * 8, 4);
+
+                                super::super::super::super::exports::fermyon::spin::llm::EmbeddingsResult{
+        embeddings: result7,
+        usage: super::super::super::super::exports::fermyon::spin::llm::EmbeddingsUsage{
+          prompt_token_count: arg6 as i32 as u32,
+        },
+      }
+                            };
+                            EmbeddingsResponse::Result(e8)
+                        }
+                    };
+                    T::add_embeddings_rule(
+                        match arg0 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len0 = arg2;
+                                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+
+                                    _rt::string_lift(bytes0)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        v8,
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_clear_rules_cabi<T: Guest>() {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::clear_rules();
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_calls_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::calls();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec10 = result0;
+                    let len10 = vec10.len();
+                    let layout10 =
+                        _rt::alloc::Layout::from_size_align_unchecked(vec10.len() * 48, 4);
+                    let result10 = if layout10.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout10).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout10);
+                        }
+                        ptr
+                    } else {
+                        {
+                            ::core::ptr::null_mut()
+                        }
+                    };
+                    for (i, e) in vec10.into_iter().enumerate() {
+                        let base = result10.add(i * 48);
+                        {
+                            match e {
+                                Call::Infer(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    let InferCall {
+                                        model: model2,
+                                        prompt: prompt2,
+                                        params: params2,
+                                    } = e;
+                                    let vec3 = (model2.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *base.add(8).cast::<usize>() = len3;
+                                    *base.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+                                    let vec4 = (prompt2.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *base.add(16).cast::<usize>() = len4;
+                                    *base.add(12).cast::<*mut u8>() = ptr4.cast_mut();
+                                    match params2 {
+                                        Some(e) => {
+                                            *base.add(20).cast::<u8>() = (1i32) as u8;
+                                            let super::super::super::super::exports::fermyon::spin::llm::InferencingParams{ max_tokens:max_tokens5, repeat_penalty:repeat_penalty5, repeat_penalty_last_n_token_count:repeat_penalty_last_n_token_count5, temperature:temperature5, top_k:top_k5, top_p:top_p5, } = e;
+                                            *base.add(24).cast::<i32>() = _rt::as_i32(max_tokens5);
+                                            *base.add(28).cast::<f32>() =
+                                                _rt::as_f32(repeat_penalty5);
+                                            *base.add(32).cast::<i32>() =
+                                                _rt::as_i32(repeat_penalty_last_n_token_count5);
+                                            *base.add(36).cast::<f32>() = _rt::as_f32(temperature5);
+                                            *base.add(40).cast::<i32>() = _rt::as_i32(top_k5);
+                                            *base.add(44).cast::<f32>() = _rt::as_f32(top_p5);
+                                        }
+                                        None => {
+                                            *base.add(20).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                Call::GenerateEmbeddings(e) => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    let EmbeddingsCall {
+                                        model: model6,
+                                        text: text6,
+                                    } = e;
+                                    let vec7 = (model6.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *base.add(8).cast::<usize>() = len7;
+                                    *base.add(4).cast::<*mut u8>() = ptr7.cast_mut();
+                                    let vec9 = text6;
+                                    let len9 = vec9.len();
+                                    let layout9 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec9.len() * 8,
+                                        4,
+                                    );
+                                    let result9 = if layout9.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout9).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout9);
+                                        }
+                                        ptr
+                                    } else {
+                                        {
+                                            ::core::ptr::null_mut()
+                                        }
+                                    };
+                                    for (i, e) in vec9.into_iter().enumerate() {
+                                        let base = result9.add(i * 8);
+                                        {
+                                            let vec8 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr8 = vec8.as_ptr().cast::<u8>();
+                                            let len8 = vec8.len();
+                                            ::core::mem::forget(vec8);
+                                            *base.add(4).cast::<usize>() = len8;
+                                            *base.add(0).cast::<*mut u8>() = ptr8.cast_mut();
+                                        }
+                                    }
+                                    *base.add(16).cast::<usize>() = len9;
+                                    *base.add(12).cast::<*mut u8>() = result9;
+                                }
+                            }
+                        }
+                    }
+                    *ptr1.add(4).cast::<usize>() = len10;
+                    *ptr1.add(0).cast::<*mut u8>() = result10;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_calls<T: Guest>(arg0: *mut u8) {
+                    let l12 = *arg0.add(0).cast::<*mut u8>();
+                    let l13 = *arg0.add(4).cast::<usize>();
+                    let base14 = l12;
+                    let len14 = l13;
+                    for i in 0..len14 {
+                        let base = base14.add(i * 48);
+                        {
+                            let l0 = i32::from(*base.add(0).cast::<u8>());
+                            match l0 {
+                                0 => {
+                                    let l1 = *base.add(4).cast::<*mut u8>();
+                                    let l2 = *base.add(8).cast::<usize>();
+                                    _rt::cabi_dealloc(l1, l2, 1);
+                                    let l3 = *base.add(12).cast::<*mut u8>();
+                                    let l4 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l3, l4, 1);
+                                }
+                                _ => {
+                                    let l5 = *base.add(4).cast::<*mut u8>();
+                                    let l6 = *base.add(8).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                    let l9 = *base.add(12).cast::<*mut u8>();
+                                    let l10 = *base.add(16).cast::<usize>();
+                                    let base11 = l9;
+                                    let len11 = l10;
+                                    for i in 0..len11 {
+                                        let base = base11.add(i * 8);
+                                        {
+                                            let l7 = *base.add(0).cast::<*mut u8>();
+                                            let l8 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l7, l8, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base11, len11 * 8, 4);
+                                }
+                            }
+                        }
+                    }
+                    _rt::cabi_dealloc(base14, len14 * 48, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_reset_calls_cabi<T: Guest>() {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::reset_calls();
+                }
+                pub trait Guest {
+                    /// Add a rule that determines the result of inferencing requests
+                    ///
+                    /// Rules are consulted in the order they were added and the first matching rule wins.
+                    /// Requests that match no rule return "Hello, world!" without any token usage.
+                    ///
+                    /// If `model` is `none`, the rule applies to all models. If `prompt` is `none`, any prompt
+                    /// matches.
+                    ///
+                    /// Returns an error if the matcher is an invalid regular expression.
+                    fn add_infer_rule(
+                        model: Option<_rt::String>,
+                        prompt: Option<Matcher>,
+                        response: InferResponse,
+                    ) -> Result<(), _rt::String>;
+                    /// Add a rule that determines the result of embeddings requests
+                    ///
+                    /// Rules are consulted in the order they were added and the first matching rule wins.
+                    /// Requests that match no rule return no embeddings.
+                    ///
+                    /// If `model` is `none`, the rule applies to all models.
+                    fn add_embeddings_rule(
+                        model: Option<_rt::String>,
+                        response: EmbeddingsResponse,
+                    );
+                    /// Remove all rules previously added with `add-infer-rule` or `add-embeddings-rule`
+                    fn clear_rules();
+                    /// Get the recorded calls to the llm interface
+                    fn calls() -> _rt::Vec<Call>;
+                    /// Reset the state of the call tracking
+                    fn reset_calls();
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_fermyon_spin_test_virt_llm_cabi{
+  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+    #[export_name = "fermyon:spin-test-virt/llm#add-infer-rule"]
+    unsafe extern "C" fn export_add_infer_rule(arg0: i32,arg1: *mut u8,arg2: usize,arg3: i32,arg4: i32,arg5: *mut u8,arg6: usize,arg7: i32,arg8: *mut u8,arg9: *mut u8,arg10: usize,arg11: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_add_infer_rule_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/llm#add-infer-rule"]
+    unsafe extern "C" fn _post_return_add_infer_rule(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_add_infer_rule::<$ty>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/llm#add-embeddings-rule"]
+    unsafe extern "C" fn export_add_embeddings_rule(arg0: i32,arg1: *mut u8,arg2: usize,arg3: i32,arg4: *mut u8,arg5: *mut u8,arg6: usize,) {
+      $($path_to_types)*::_export_add_embeddings_rule_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5, arg6)
+    }
+    #[export_name = "fermyon:spin-test-virt/llm#clear-rules"]
+    unsafe extern "C" fn export_clear_rules() {
+      $($path_to_types)*::_export_clear_rules_cabi::<$ty>()
+    }
+    #[export_name = "fermyon:spin-test-virt/llm#calls"]
+    unsafe extern "C" fn export_calls() -> *mut u8 {
+      $($path_to_types)*::_export_calls_cabi::<$ty>()
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/llm#calls"]
+    unsafe extern "C" fn _post_return_calls(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_calls::<$ty>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/llm#reset-calls"]
+    unsafe extern "C" fn export_reset_calls() {
+      $($path_to_types)*::_export_reset_calls_cabi::<$ty>()
+    }
+  };);
+}
+                #[doc(hidden)]
+                pub(crate) use __export_fermyon_spin_test_virt_llm_cabi;
+                #[repr(align(4))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 12]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 12]);
+            }
+
             #[allow(dead_code, clippy::all)]
             pub mod sqlite {
                 #[used]
@@ -15136,6 +15998,8 @@ pub mod exports {
                 pub type QueryResult =
                     super::super::super::super::exports::fermyon::spin::sqlite::QueryResult;
                 pub type Error = super::super::super::super::exports::fermyon::spin::sqlite::Error;
+                pub type Matcher =
+                    super::super::super::super::exports::fermyon::spin_test_virt::types::Matcher;
 
                 #[derive(Debug)]
                 #[repr(transparent)]
@@ -15277,6 +16141,47 @@ pub mod exports {
                     }
                 }
 
+                /// A statement executed through the sqlite interface
+                #[derive(Clone)]
+                pub struct Call {
+                    /// The statement that was executed
+                    pub statement: _rt::String,
+                    /// The parameters the statement was executed with
+                    pub parameters: _rt::Vec<Value>,
+                    /// The number of rows returned by the statement or the error it failed with
+                    pub result: Result<u64, Error>,
+                }
+                impl ::core::fmt::Debug for Call {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Call")
+                            .field("statement", &self.statement)
+                            .field("parameters", &self.parameters)
+                            .field("result", &self.result)
+                            .finish()
+                    }
+                }
+                /// A serialized copy of sqlite databases keyed by database name
+                pub type Snapshot = _rt::Vec<(_rt::String, _rt::Vec<u8>)>;
+                /// What a rule returns instead of executing the statement
+                #[derive(Clone)]
+                pub enum RuleResponse {
+                    /// Fail the statement with the given error
+                    Error(Error),
+                    /// Return the given result without touching the database
+                    Result(QueryResult),
+                }
+                impl ::core::fmt::Debug for RuleResponse {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            RuleResponse::Error(e) => {
+                                f.debug_tuple("RuleResponse::Error").field(e).finish()
+                            }
+                            RuleResponse::Result(e) => {
+                                f.debug_tuple("RuleResponse::Result").field(e).finish()
+                            }
+                        }
+                    }
+                }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
                 pub unsafe fn _export_static_connection_open_cabi<T: GuestConnection>(
@@ -15645,299 +16550,7125 @@ pub mod exports {
                         }
                     }
                 }
-                pub trait Guest {
-                    type Connection: GuestConnection;
-                }
-                pub trait GuestConnection: 'static {
-                    #[doc(hidden)]
-                    unsafe fn _resource_new(val: *mut u8) -> u32
-                    where
-                        Self: Sized,
-                    {
-                        #[cfg(not(target_arch = "wasm32"))]
-                        {
-                            let _ = val;
-                            unreachable!();
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_execute_batch_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::execute_batch(
+                        ConnectionBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(_) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
                         }
-
-                        #[cfg(target_arch = "wasm32")]
-                        {
-                            #[link(wasm_import_module = "[export]fermyon:spin-test-virt/sqlite")]
-                            extern "C" {
-                                #[link_name = "[resource-new]connection"]
-                                fn new(_: *mut u8) -> u32;
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::fermyon::spin::sqlite::Error as V4;
+                            match e {
+                                V4::NoSuchDatabase => {
+                                    *ptr2.add(4).cast::<u8>() = (0i32) as u8;
+                                }
+                                V4::AccessDenied => {
+                                    *ptr2.add(4).cast::<u8>() = (1i32) as u8;
+                                }
+                                V4::InvalidConnection => {
+                                    *ptr2.add(4).cast::<u8>() = (2i32) as u8;
+                                }
+                                V4::DatabaseFull => {
+                                    *ptr2.add(4).cast::<u8>() = (3i32) as u8;
+                                }
+                                V4::Io(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (4i32) as u8;
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr2.add(12).cast::<usize>() = len3;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                                }
                             }
-                            new(val)
-                        }
-                    }
-
-                    #[doc(hidden)]
-                    fn _resource_rep(handle: u32) -> *mut u8
-                    where
-                        Self: Sized,
-                    {
-                        #[cfg(not(target_arch = "wasm32"))]
-                        {
-                            let _ = handle;
-                            unreachable!();
                         }
-
-                        #[cfg(target_arch = "wasm32")]
-                        {
-                            #[link(wasm_import_module = "[export]fermyon:spin-test-virt/sqlite")]
-                            extern "C" {
-                                #[link_name = "[resource-rep]connection"]
-                                fn rep(_: u32) -> *mut u8;
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_connection_execute_batch<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(4).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                _ => {
+                                    let l2 = *arg0.add(8).cast::<*mut u8>();
+                                    let l3 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
                             }
-                            unsafe { rep(handle) }
                         }
                     }
-
-                    /// Open a connection to a named database instance.
-                    ///
-                    /// If `database` is "default", the default instance is opened.
-                    ///
-                    /// `error::no-such-database` will be raised if the `name` is not recognized.
-                    fn open(database: _rt::String) -> Result<Connection, Error>;
-                    /// Execute a statement returning back data if there is any
-                    fn execute(
-                        &self,
-                        statement: _rt::String,
-                        parameters: _rt::Vec<Value>,
-                    ) -> Result<QueryResult, Error>;
                 }
                 #[doc(hidden)]
-
-                macro_rules! __export_fermyon_spin_test_virt_sqlite_cabi{
-  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
-
-    #[export_name = "fermyon:spin-test-virt/sqlite#[static]connection.open"]
-    unsafe extern "C" fn export_static_connection_open(arg0: *mut u8,arg1: usize,) -> *mut u8 {
-      $($path_to_types)*::_export_static_connection_open_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1)
-    }
-    #[export_name = "cabi_post_fermyon:spin-test-virt/sqlite#[static]connection.open"]
-    unsafe extern "C" fn _post_return_static_connection_open(arg0: *mut u8,) {
-      $($path_to_types)*::__post_return_static_connection_open::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
-    }
-    #[export_name = "fermyon:spin-test-virt/sqlite#[method]connection.execute"]
-    unsafe extern "C" fn export_method_connection_execute(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: *mut u8,arg4: usize,) -> *mut u8 {
-      $($path_to_types)*::_export_method_connection_execute_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1, arg2, arg3, arg4)
-    }
-    #[export_name = "cabi_post_fermyon:spin-test-virt/sqlite#[method]connection.execute"]
-    unsafe extern "C" fn _post_return_method_connection_execute(arg0: *mut u8,) {
-      $($path_to_types)*::__post_return_method_connection_execute::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
-    }
-
-    const _: () = {
-      #[doc(hidden)]
-      #[export_name = "fermyon:spin-test-virt/sqlite#[dtor]connection"]
-      #[allow(non_snake_case)]
-      unsafe extern "C" fn dtor(rep: *mut u8) {
-        $($path_to_types)*::Connection::dtor::<
-        <$ty as $($path_to_types)*::Guest>::Connection
-        >(rep)
-      }
-    };
-
-  };);
-}
-                #[doc(hidden)]
-                pub(crate) use __export_fermyon_spin_test_virt_sqlite_cabi;
-                #[repr(align(4))]
-                struct _RetArea([::core::mem::MaybeUninit<u8>; 20]);
-                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 20]);
-            }
-
-            #[allow(dead_code, clippy::all)]
-            pub mod variables {
-                #[used]
-                #[doc(hidden)]
-                #[cfg(target_arch = "wasm32")]
-                static __FORCE_SECTION_REF: fn() =
-                    super::super::super::super::__link_custom_section_describing_imports;
-                use super::super::super::super::_rt;
-                #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_set_cabi<T: Guest>(
-                    arg0: *mut u8,
-                    arg1: usize,
-                    arg2: *mut u8,
-                    arg3: usize,
-                ) {
+                pub unsafe fn _export_calls_cabi<T: Guest>() -> *mut u8 {
                     #[cfg(target_arch = "wasm32")]
                     _rt::run_ctors_once();
-                    let len0 = arg1;
-                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-                    let len1 = arg3;
-                    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-                    T::set(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
-                }
-                pub trait Guest {
-                    fn set(key: _rt::String, value: _rt::String);
+                    let result0 = T::calls();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec13 = result0;
+                    let len13 = vec13.len();
+                    let layout13 =
+                        _rt::alloc::Layout::from_size_align_unchecked(vec13.len() * 16, 4);
+                    let result13 = if layout13.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout13).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout13);
+                        }
+                        ptr
+                    } else {
+                        {
+                            ::core::ptr::null_mut()
+                        }
+                    };
+                    for (i, e) in vec13.into_iter().enumerate() {
+                        let base = result13.add(i * 16);
+                        {
+                            let (t2_0, t2_1) = e;
+                            let vec3 = (t2_0.into_bytes()).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *base.add(4).cast::<usize>() = len3;
+                            *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                            let vec12 = t2_1;
+                            let len12 = vec12.len();
+                            let layout12 =
+                                _rt::alloc::Layout::from_size_align_unchecked(vec12.len() * 40, 8);
+                            let result12 = if layout12.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout12).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout12);
+                                }
+                                ptr
+                            } else {
+                                {
+                                    ::core::ptr::null_mut()
+                                }
+                            };
+                            for (i, e) in vec12.into_iter().enumerate() {
+                                let base = result12.add(i * 40);
+                                {
+                                    let Call {
+                                        statement: statement4,
+                                        parameters: parameters4,
+                                        result: result4,
+                                    } = e;
+                                    let vec5 = (statement4.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *base.add(4).cast::<usize>() = len5;
+                                    *base.add(0).cast::<*mut u8>() = ptr5.cast_mut();
+                                    let vec9 = parameters4;
+                                    let len9 = vec9.len();
+                                    let layout9 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec9.len() * 16,
+                                        8,
+                                    );
+                                    let result9 = if layout9.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout9).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout9);
+                                        }
+                                        ptr
+                                    } else {
+                                        {
+                                            ::core::ptr::null_mut()
+                                        }
+                                    };
+                                    for (i, e) in vec9.into_iter().enumerate() {
+                                        let base = result9.add(i * 16);
+                                        {
+                                            use super::super::super::super::exports::fermyon::spin::sqlite::Value as V8;
+                                            match e {
+                                                V8::Integer(e) => {
+                                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                    *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                                }
+                                                V8::Real(e) => {
+                                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(8).cast::<f64>() = _rt::as_f64(e);
+                                                }
+                                                V8::Text(e) => {
+                                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                                    let len6 = vec6.len();
+                                                    ::core::mem::forget(vec6);
+                                                    *base.add(12).cast::<usize>() = len6;
+                                                    *base.add(8).cast::<*mut u8>() =
+                                                        ptr6.cast_mut();
+                                                }
+                                                V8::Blob(e) => {
+                                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                    let vec7 = (e).into_boxed_slice();
+                                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                                    let len7 = vec7.len();
+                                                    ::core::mem::forget(vec7);
+                                                    *base.add(12).cast::<usize>() = len7;
+                                                    *base.add(8).cast::<*mut u8>() =
+                                                        ptr7.cast_mut();
+                                                }
+                                                V8::Null => {
+                                                    *base.add(0).cast::<u8>() = (4i32) as u8;
+                                                }
+                                            }
+                                        }
+                                    }
+                                    *base.add(12).cast::<usize>() = len9;
+                                    *base.add(8).cast::<*mut u8>() = result9;
+                                    match result4 {
+                                        Ok(e) => {
+                                            *base.add(16).cast::<u8>() = (0i32) as u8;
+                                            *base.add(24).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        Err(e) => {
+                                            *base.add(16).cast::<u8>() = (1i32) as u8;
+                                            use super::super::super::super::exports::fermyon::spin::sqlite::Error as V11;
+                                            match e {
+                                                V11::NoSuchDatabase => {
+                                                    *base.add(24).cast::<u8>() = (0i32) as u8;
+                                                }
+                                                V11::AccessDenied => {
+                                                    *base.add(24).cast::<u8>() = (1i32) as u8;
+                                                }
+                                                V11::InvalidConnection => {
+                                                    *base.add(24).cast::<u8>() = (2i32) as u8;
+                                                }
+                                                V11::DatabaseFull => {
+                                                    *base.add(24).cast::<u8>() = (3i32) as u8;
+                                                }
+                                                V11::Io(e) => {
+                                                    *base.add(24).cast::<u8>() = (4i32) as u8;
+                                                    let vec10 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                                    let len10 = vec10.len();
+                                                    ::core::mem::forget(vec10);
+                                                    *base.add(32).cast::<usize>() = len10;
+                                                    *base.add(28).cast::<*mut u8>() =
+                                                        ptr10.cast_mut();
+                                                }
+                                            }
+                                        }
+                                    };
+                                }
+                            }
+                            *base.add(12).cast::<usize>() = len12;
+                            *base.add(8).cast::<*mut u8>() = result12;
+                        }
+                    }
+                    *ptr1.add(4).cast::<usize>() = len13;
+                    *ptr1.add(0).cast::<*mut u8>() = result13;
+                    ptr1
                 }
                 #[doc(hidden)]
-
-                macro_rules! __export_fermyon_spin_test_virt_variables_cabi{
-  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
-
-    #[export_name = "fermyon:spin-test-virt/variables#set"]
-    unsafe extern "C" fn export_set(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) {
-      $($path_to_types)*::_export_set_cabi::<$ty>(arg0, arg1, arg2, arg3)
-    }
-  };);
-}
-                #[doc(hidden)]
-                pub(crate) use __export_fermyon_spin_test_virt_variables_cabi;
-            }
-        }
-        #[allow(dead_code)]
-        pub mod spin_wasi_virt {
-            #[allow(dead_code, clippy::all)]
-            pub mod http_handler {
-                #[used]
-                #[doc(hidden)]
-                #[cfg(target_arch = "wasm32")]
-                static __FORCE_SECTION_REF: fn() =
-                    super::super::super::super::__link_custom_section_describing_imports;
-                use super::super::super::super::_rt;
-                pub type OutgoingResponse =
-                    super::super::super::super::exports::wasi::http::types::OutgoingResponse;
-                pub type OutgoingResponseBorrow<'a> =
-                    super::super::super::super::exports::wasi::http::types::OutgoingResponseBorrow<
-                        'a,
-                    >;
-                pub enum ResponseHandler {
-                    Echo,
-                    Response(OutgoingResponse),
-                }
-                impl ::core::fmt::Debug for ResponseHandler {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                        match self {
-                            ResponseHandler::Echo => {
-                                f.debug_tuple("ResponseHandler::Echo").finish()
-                            }
-                            ResponseHandler::Response(e) => {
-                                f.debug_tuple("ResponseHandler::Response").field(e).finish()
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_calls<T: Guest>(arg0: *mut u8) {
+                    let l20 = *arg0.add(0).cast::<*mut u8>();
+                    let l21 = *arg0.add(4).cast::<usize>();
+                    let base22 = l20;
+                    let len22 = l21;
+                    for i in 0..len22 {
+                        let base = base22.add(i * 16);
+                        {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            _rt::cabi_dealloc(l0, l1, 1);
+                            let l17 = *base.add(8).cast::<*mut u8>();
+                            let l18 = *base.add(12).cast::<usize>();
+                            let base19 = l17;
+                            let len19 = l18;
+                            for i in 0..len19 {
+                                let base = base19.add(i * 40);
+                                {
+                                    let l2 = *base.add(0).cast::<*mut u8>();
+                                    let l3 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                    let l10 = *base.add(8).cast::<*mut u8>();
+                                    let l11 = *base.add(12).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    for i in 0..len12 {
+                                        let base = base12.add(i * 16);
+                                        {
+                                            let l4 = i32::from(*base.add(0).cast::<u8>());
+                                            match l4 {
+                                                0 => (),
+                                                1 => (),
+                                                2 => {
+                                                    let l5 = *base.add(8).cast::<*mut u8>();
+                                                    let l6 = *base.add(12).cast::<usize>();
+                                                    _rt::cabi_dealloc(l5, l6, 1);
+                                                }
+                                                3 => {
+                                                    let l7 = *base.add(8).cast::<*mut u8>();
+                                                    let l8 = *base.add(12).cast::<usize>();
+                                                    let base9 = l7;
+                                                    let len9 = l8;
+                                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                                }
+                                                _ => (),
+                                            }
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base12, len12 * 16, 8);
+                                    let l13 = i32::from(*base.add(16).cast::<u8>());
+                                    match l13 {
+                                        0 => (),
+                                        _ => {
+                                            let l14 = i32::from(*base.add(24).cast::<u8>());
+                                            match l14 {
+                                                0 => (),
+                                                1 => (),
+                                                2 => (),
+                                                3 => (),
+                                                _ => {
+                                                    let l15 = *base.add(28).cast::<*mut u8>();
+                                                    let l16 = *base.add(32).cast::<usize>();
+                                                    _rt::cabi_dealloc(l15, l16, 1);
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
                             }
+                            _rt::cabi_dealloc(base19, len19 * 40, 8);
                         }
                     }
+                    _rt::cabi_dealloc(base22, len22 * 16, 4);
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_set_response_cabi<T: Guest>(
-                    arg0: *mut u8,
-                    arg1: usize,
-                    arg2: i32,
+                pub unsafe fn _export_reset_calls_cabi<T: Guest>() {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::reset_calls();
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_add_rule_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: *mut u8,
+                    arg2: usize,
                     arg3: i32,
-                ) {
+                    arg4: *mut u8,
+                    arg5: usize,
+                    arg6: i32,
+                    arg7: *mut u8,
+                    arg8: *mut u8,
+                    arg9: *mut u8,
+                    arg10: usize,
+                ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")]
                     _rt::run_ctors_once();
-                    let len0 = arg1;
-                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-                    let v1 = match arg2 {
-                        0 => ResponseHandler::Echo,
+                    use super::super::super::super::exports::fermyon::spin_test_virt::types::Matcher as V4;
+                    let v4 = match arg3 {
+                        0 => {
+                            let e4 = {
+                                let len1 = arg5;
+                                let bytes1 = _rt::Vec::from_raw_parts(arg4.cast(), len1, len1);
+
+                                _rt::string_lift(bytes1)
+                            };
+                            V4::Exact(e4)
+                        }
+                        1 => {
+                            let e4 = {
+                                let len2 = arg5;
+                                let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
+
+                                _rt::string_lift(bytes2)
+                            };
+                            V4::Prefix(e4)
+                        }
                         n => {
-                            debug_assert_eq!(n, 1, "invalid enum discriminant");
-                            let e1 = super::super::super::super::exports::wasi::http::types::OutgoingResponse::from_handle(arg3 as u32);
-                            ResponseHandler::Response(e1)
+                            debug_assert_eq!(n, 2, "invalid enum discriminant");
+                            let e4 = {
+                                let len3 = arg5;
+                                let bytes3 = _rt::Vec::from_raw_parts(arg4.cast(), len3, len3);
+
+                                _rt::string_lift(bytes3)
+                            };
+                            V4::Regex(e4)
                         }
                     };
-                    T::set_response(_rt::string_lift(bytes0), v1);
-                }
-                pub trait Guest {
-                    /// Set a response for a given url
-                    fn set_response(url: _rt::String, response: ResponseHandler);
-                }
-                #[doc(hidden)]
+                    let v25 = match arg6 {
+                        0 => {
+                            let e25 = {
+                                use super::super::super::super::exports::fermyon::spin::sqlite::Error as V6;
+                                let v6 = match arg7 as i32 {
+                                    0 => V6::NoSuchDatabase,
+                                    1 => V6::AccessDenied,
+                                    2 => V6::InvalidConnection,
+                                    3 => V6::DatabaseFull,
+                                    n => {
+                                        debug_assert_eq!(n, 4, "invalid enum discriminant");
+                                        let e6 = {
+                                            let len5 = arg9 as usize;
+                                            let bytes5 =
+                                                _rt::Vec::from_raw_parts(arg8.cast(), len5, len5);
 
-                macro_rules! __export_fermyon_spin_wasi_virt_http_handler_cabi{
-    ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+                                            _rt::string_lift(bytes5)
+                                        };
+                                        V6::Io(e6)
+                                    }
+                                };
 
-      #[export_name = "fermyon:spin-wasi-virt/http-handler#set-response"]
-      unsafe extern "C" fn export_set_response(arg0: *mut u8,arg1: usize,arg2: i32,arg3: i32,) {
-        $($path_to_types)*::_export_set_response_cabi::<$ty>(arg0, arg1, arg2, arg3)
-      }
-    };);
-  }
-                #[doc(hidden)]
-                pub(crate) use __export_fermyon_spin_wasi_virt_http_handler_cabi;
-            }
+                                v6
+                            };
+                            RuleResponse::Error(e25)
+                        }
+                        n => {
+                            debug_assert_eq!(n, 1, "invalid enum discriminant");
+                            let e25 = {
+                                let base10 = arg7;
+                                let len10 = arg8 as usize;
+                                let mut result10 = _rt::Vec::with_capacity(len10);
+                                for i in 0..len10 {
+                                    let base = base10.add(i * 8);
+                                    let e10 = {
+                                        let l7 = *base.add(0).cast::<*mut u8>();
+                                        let l8 = *base.add(4).cast::<usize>();
+                                        let len9 = l8;
+                                        let bytes9 =
+                                            _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
 
-            #[allow(dead_code, clippy::all)]
-            pub mod http_helper {
-                #[used]
-                #[doc(hidden)]
-                #[cfg(target_arch = "wasm32")]
-                static __FORCE_SECTION_REF: fn() =
-                    super::super::super::super::__link_custom_section_describing_imports;
-                use super::super::super::super::_rt;
-                pub type IncomingRequest =
-                    super::super::super::super::exports::wasi::http::types::IncomingRequest;
-                pub type IncomingRequestBorrow<'a> =
-                    super::super::super::super::exports::wasi::http::types::IncomingRequestBorrow<
-                        'a,
-                    >;
-                pub type IncomingResponse =
-                    super::super::super::super::exports::wasi::http::types::IncomingResponse;
-                pub type IncomingResponseBorrow<'a> =
-                    super::super::super::super::exports::wasi::http::types::IncomingResponseBorrow<
-                        'a,
-                    >;
-                pub type OutgoingResponseBorrow<'a> =
-                    super::super::super::super::exports::wasi::http::types::OutgoingResponseBorrow<
-                        'a,
-                    >;
-                pub type OutgoingRequest =
-                    super::super::super::super::exports::wasi::http::types::OutgoingRequest;
-                pub type OutgoingRequestBorrow<'a> =
-                    super::super::super::super::exports::wasi::http::types::OutgoingRequestBorrow<
-                        'a,
-                    >;
-                pub type ResponseOutparam =
-                    super::super::super::super::exports::wasi::http::types::ResponseOutparam;
-                pub type ResponseOutparamBorrow<'a> =
-                    super::super::super::super::exports::wasi::http::types::ResponseOutparamBorrow<
-                        'a,
-                    >;
-                pub type FutureIncomingResponseBorrow<'a> = super::super::super::super::exports::wasi::http::types::FutureIncomingResponseBorrow<'a>;
-                pub type IncomingBody =
-                    super::super::super::super::exports::wasi::http::types::IncomingBody;
-                pub type IncomingBodyBorrow<'a> =
-                    super::super::super::super::exports::wasi::http::types::IncomingBodyBorrow<'a>;
-                /// A receiver of an `incoming-response`
+                                        _rt::string_lift(bytes9)
+                                    };
+                                    result10.push(e10);
+                                }
+                                _rt::cabi_dealloc(base10, len10 * 8, 4);
+                                let base24 = arg9;
+                                let len24 = arg10;
+                                let mut result24 = _rt::Vec::with_capacity(len24);
+                                for i in 0..len24 {
+                                    let base = base24.add(i * 8);
+                                    let e24 = {
+                                        let l11 = *base.add(0).cast::<*mut u8>();
+                                        let l12 = *base.add(4).cast::<usize>();
+                                        let base23 = l11;
+                                        let len23 = l12;
+                                        let mut result23 = _rt::Vec::with_capacity(len23);
+                                        for i in 0..len23 {
+                                            let base = base23.add(i * 16);
+                                            let e23 = {
+                                                let l13 = i32::from(*base.add(0).cast::<u8>());
+                                                use super::super::super::super::exports::fermyon::spin::sqlite::Value as V22;
+                                                let v22 = match l13 {
+                                                    0 => {
+                                                        let e22 = {
+                                                            let l14 = *base.add(8).cast::<i64>();
 
-                #[derive(Debug)]
-                #[repr(transparent)]
-                pub struct ResponseReceiver {
-                    handle: _rt::Resource<ResponseReceiver>,
-                }
+                                                            l14
+                                                        };
+                                                        V22::Integer(e22)
+                                                    }
+                                                    1 => {
+                                                        let e22 = {
+                                                            let l15 = *base.add(8).cast::<f64>();
 
-                type _ResponseReceiverRep<T> = Option<T>;
+                                                            l15
+                                                        };
+                                                        V22::Real(e22)
+                                                    }
+                                                    2 => {
+                                                        let e22 = {
+                                                            let l16 =
+                                                                *base.add(8).cast::<*mut u8>();
+                                                            let l17 = *base.add(12).cast::<usize>();
+                                                            let len18 = l17;
+                                                            let bytes18 = _rt::Vec::from_raw_parts(
+                                                                l16.cast(),
+                                                                len18,
+                                                                len18,
+                                                            );
 
-                impl ResponseReceiver {
-                    /// Creates a new resource from the specified representation.
-                    ///
-                    /// This function will create a new resource handle by moving `val` onto
-                    /// the heap and then passing that heap pointer to the component model to
-                    /// create a handle. The owned handle is then returned as `ResponseReceiver`.
-                    pub fn new<T: GuestResponseReceiver>(val: T) -> Self {
-                        Self::type_guard::<T>();
-                        let val: _ResponseReceiverRep<T> = Some(val);
-                        let ptr: *mut _ResponseReceiverRep<T> =
-                            _rt::Box::into_raw(_rt::Box::new(val));
-                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
-                    }
+                                                            _rt::string_lift(bytes18)
+                                                        };
+                                                        V22::Text(e22)
+                                                    }
+                                                    3 => {
+                                                        let e22 = {
+                                                            let l19 =
+                                                                *base.add(8).cast::<*mut u8>();
+                                                            let l20 = *base.add(12).cast::<usize>();
+                                                            let len21 = l20;
+
+                                                            _rt::Vec::from_raw_parts(
+                                                                l19.cast(),
+                                                                len21,
+                                                                len21,
+                                                            )
+                                                        };
+                                                        V22::Blob(e22)
+                                                    }
+                                                    n => {
+                                                        debug_assert_eq!(
+                                                            n, 4,
+                                                            "invalid enum discriminant"
+                                                        );
+                                                        V22::Null
+                                                    }
+                                                };
 
-                    /// Gets access to the underlying `T` which represents this resource.
-                    pub fn get<T: GuestResponseReceiver>(&self) -> &T {
-                        let ptr = unsafe { &*self.as_ptr::<T>() };
-                        ptr.as_ref().unwrap()
-                    }
+                                                v22
+                                            };
+                                            result23.push(e23);
+                                        }
+                                        _rt::cabi_dealloc(base23, len23 * 16, 8);
 
-                    /// Gets mutable access to the underlying `T` which represents this
-                    /// resource.
-                    pub fn get_mut<T: GuestResponseReceiver>(&mut self) -> &mut T {
-                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                                        super::super::super::super::exports::fermyon::spin::sqlite::RowResult{
+            values: result23,
+          }
+                                    };
+                                    result24.push(e24);
+                                }
+                                _rt::cabi_dealloc(base24, len24 * 8, 4);
+
+                                super::super::super::super::exports::fermyon::spin::sqlite::QueryResult{
+        columns: result10,
+        rows: result24,
+      }
+                            };
+                            RuleResponse::Result(e25)
+                        }
+                    };
+                    let result26 = T::add_rule(
+                        match arg0 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len0 = arg2;
+                                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+
+                                    _rt::string_lift(bytes0)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        v4,
+                        v25,
+                    );
+                    let ptr27 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result26 {
+                        Ok(_) => {
+                            *ptr27.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr27.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec28 = (e.into_bytes()).into_boxed_slice();
+                            let ptr28 = vec28.as_ptr().cast::<u8>();
+                            let len28 = vec28.len();
+                            ::core::mem::forget(vec28);
+                            *ptr27.add(8).cast::<usize>() = len28;
+                            *ptr27.add(4).cast::<*mut u8>() = ptr28.cast_mut();
+                        }
+                    };
+                    ptr27
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_add_rule<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = *arg0.add(4).cast::<*mut u8>();
+                            let l2 = *arg0.add(8).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_clear_rules_cabi<T: Guest>() {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::clear_rules();
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_take_snapshot_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::take_snapshot();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec5 = e;
+                            let len5 = vec5.len();
+                            let layout5 =
+                                _rt::alloc::Layout::from_size_align_unchecked(vec5.len() * 16, 4);
+                            let result5 = if layout5.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout5);
+                                }
+                                ptr
+                            } else {
+                                {
+                                    ::core::ptr::null_mut()
+                                }
+                            };
+                            for (i, e) in vec5.into_iter().enumerate() {
+                                let base = result5.add(i * 16);
+                                {
+                                    let (t2_0, t2_1) = e;
+                                    let vec3 = (t2_0.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *base.add(4).cast::<usize>() = len3;
+                                    *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                                    let vec4 = (t2_1).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *base.add(12).cast::<usize>() = len4;
+                                    *base.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                            }
+                            *ptr1.add(8).cast::<usize>() = len5;
+                            *ptr1.add(4).cast::<*mut u8>() = result5;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::fermyon::spin::sqlite::Error as V7;
+                            match e {
+                                V7::NoSuchDatabase => {
+                                    *ptr1.add(4).cast::<u8>() = (0i32) as u8;
+                                }
+                                V7::AccessDenied => {
+                                    *ptr1.add(4).cast::<u8>() = (1i32) as u8;
+                                }
+                                V7::InvalidConnection => {
+                                    *ptr1.add(4).cast::<u8>() = (2i32) as u8;
+                                }
+                                V7::DatabaseFull => {
+                                    *ptr1.add(4).cast::<u8>() = (3i32) as u8;
+                                }
+                                V7::Io(e) => {
+                                    *ptr1.add(4).cast::<u8>() = (4i32) as u8;
+                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr1.add(12).cast::<usize>() = len6;
+                                    *ptr1.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_take_snapshot<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l6 = *arg0.add(4).cast::<*mut u8>();
+                            let l7 = *arg0.add(8).cast::<usize>();
+                            let base8 = l6;
+                            let len8 = l7;
+                            for i in 0..len8 {
+                                let base = base8.add(i * 16);
+                                {
+                                    let l1 = *base.add(0).cast::<*mut u8>();
+                                    let l2 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l1, l2, 1);
+                                    let l3 = *base.add(8).cast::<*mut u8>();
+                                    let l4 = *base.add(12).cast::<usize>();
+                                    let base5 = l3;
+                                    let len5 = l4;
+                                    _rt::cabi_dealloc(base5, len5 * 1, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base8, len8 * 16, 4);
+                        }
+                        _ => {
+                            let l9 = i32::from(*arg0.add(4).cast::<u8>());
+                            match l9 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                _ => {
+                                    let l10 = *arg0.add(8).cast::<*mut u8>();
+                                    let l11 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_restore_snapshot_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let base6 = arg0;
+                    let len6 = arg1;
+                    let mut result6 = _rt::Vec::with_capacity(len6);
+                    for i in 0..len6 {
+                        let base = base6.add(i * 16);
+                        let e6 = {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            let len2 = l1;
+                            let bytes2 = _rt::Vec::from_raw_parts(l0.cast(), len2, len2);
+                            let l3 = *base.add(8).cast::<*mut u8>();
+                            let l4 = *base.add(12).cast::<usize>();
+                            let len5 = l4;
+
+                            (
+                                _rt::string_lift(bytes2),
+                                _rt::Vec::from_raw_parts(l3.cast(), len5, len5),
+                            )
+                        };
+                        result6.push(e6);
+                    }
+                    _rt::cabi_dealloc(base6, len6 * 16, 4);
+                    let result7 = T::restore_snapshot(result6);
+                    let ptr8 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result7 {
+                        Ok(_) => {
+                            *ptr8.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr8.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::fermyon::spin::sqlite::Error as V10;
+                            match e {
+                                V10::NoSuchDatabase => {
+                                    *ptr8.add(4).cast::<u8>() = (0i32) as u8;
+                                }
+                                V10::AccessDenied => {
+                                    *ptr8.add(4).cast::<u8>() = (1i32) as u8;
+                                }
+                                V10::InvalidConnection => {
+                                    *ptr8.add(4).cast::<u8>() = (2i32) as u8;
+                                }
+                                V10::DatabaseFull => {
+                                    *ptr8.add(4).cast::<u8>() = (3i32) as u8;
+                                }
+                                V10::Io(e) => {
+                                    *ptr8.add(4).cast::<u8>() = (4i32) as u8;
+                                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr8.add(12).cast::<usize>() = len9;
+                                    *ptr8.add(8).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr8
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_restore_snapshot<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(4).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                _ => {
+                                    let l2 = *arg0.add(8).cast::<*mut u8>();
+                                    let l3 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_reset_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::reset();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(_) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::fermyon::spin::sqlite::Error as V3;
+                            match e {
+                                V3::NoSuchDatabase => {
+                                    *ptr1.add(4).cast::<u8>() = (0i32) as u8;
+                                }
+                                V3::AccessDenied => {
+                                    *ptr1.add(4).cast::<u8>() = (1i32) as u8;
+                                }
+                                V3::InvalidConnection => {
+                                    *ptr1.add(4).cast::<u8>() = (2i32) as u8;
+                                }
+                                V3::DatabaseFull => {
+                                    *ptr1.add(4).cast::<u8>() = (3i32) as u8;
+                                }
+                                V3::Io(e) => {
+                                    *ptr1.add(4).cast::<u8>() = (4i32) as u8;
+                                    let vec2 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                                    let len2 = vec2.len();
+                                    ::core::mem::forget(vec2);
+                                    *ptr1.add(12).cast::<usize>() = len2;
+                                    *ptr1.add(8).cast::<*mut u8>() = ptr2.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_reset<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(4).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                _ => {
+                                    let l2 = *arg0.add(8).cast::<*mut u8>();
+                                    let l3 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                pub trait Guest {
+                    type Connection: GuestConnection;
+                    /// Get the recorded calls to the sqlite interface
+                    ///
+                    /// The calls are grouped by the name of the database they were executed against.
+                    fn calls() -> _rt::Vec<(_rt::String, _rt::Vec<Call>)>;
+                    /// Reset the state of the call tracking
+                    fn reset_calls();
+                    /// Add a rule that overrides the result of statements executed through the sqlite interface
+                    ///
+                    /// Rules are consulted in the order they were added and the first matching rule wins.
+                    /// Statements that match no rule are executed against the in-memory database.
+                    ///
+                    /// If `database` is `none`, the rule applies to all databases.
+                    ///
+                    /// Returns an error if the matcher is an invalid regular expression.
+                    fn add_rule(
+                        database: Option<_rt::String>,
+                        matcher: Matcher,
+                        response: RuleResponse,
+                    ) -> Result<(), _rt::String>;
+                    /// Remove all rules previously added with `add-rule`
+                    fn clear_rules();
+                    /// Take a snapshot of every database that has been opened
+                    fn take_snapshot() -> Result<Snapshot, Error>;
+                    /// Restore the databases to the state captured in `snapshot`
+                    ///
+                    /// Databases which are not part of the snapshot are reset to an empty state.
+                    fn restore_snapshot(snapshot: Snapshot) -> Result<(), Error>;
+                    /// Reset every database to an empty state
+                    fn reset() -> Result<(), Error>;
+                }
+                pub trait GuestConnection: 'static {
+                    #[doc(hidden)]
+                    unsafe fn _resource_new(val: *mut u8) -> u32
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = val;
+                            unreachable!();
+                        }
+
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]fermyon:spin-test-virt/sqlite")]
+                            extern "C" {
+                                #[link_name = "[resource-new]connection"]
+                                fn new(_: *mut u8) -> u32;
+                            }
+                            new(val)
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    fn _resource_rep(handle: u32) -> *mut u8
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = handle;
+                            unreachable!();
+                        }
+
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]fermyon:spin-test-virt/sqlite")]
+                            extern "C" {
+                                #[link_name = "[resource-rep]connection"]
+                                fn rep(_: u32) -> *mut u8;
+                            }
+                            unsafe { rep(handle) }
+                        }
+                    }
+
+                    /// Open a connection to a named database instance.
+                    ///
+                    /// If `database` is "default", the default instance is opened.
+                    ///
+                    /// `error::no-such-database` will be raised if the `name` is not recognized.
+                    fn open(database: _rt::String) -> Result<Connection, Error>;
+                    /// Execute a statement returning back data if there is any
+                    fn execute(
+                        &self,
+                        statement: _rt::String,
+                        parameters: _rt::Vec<Value>,
+                    ) -> Result<QueryResult, Error>;
+                    /// Execute a batch of semicolon separated statements (e.g., a migration file)
+                    fn execute_batch(&self, statements: _rt::String) -> Result<(), Error>;
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_fermyon_spin_test_virt_sqlite_cabi{
+  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+    #[export_name = "fermyon:spin-test-virt/sqlite#[static]connection.open"]
+    unsafe extern "C" fn export_static_connection_open(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_static_connection_open_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/sqlite#[static]connection.open"]
+    unsafe extern "C" fn _post_return_static_connection_open(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_static_connection_open::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/sqlite#[method]connection.execute"]
+    unsafe extern "C" fn export_method_connection_execute(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: *mut u8,arg4: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_connection_execute_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1, arg2, arg3, arg4)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/sqlite#[method]connection.execute"]
+    unsafe extern "C" fn _post_return_method_connection_execute(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_connection_execute::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/sqlite#[method]connection.execute-batch"]
+    unsafe extern "C" fn export_method_connection_execute_batch(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_connection_execute_batch_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1, arg2)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/sqlite#[method]connection.execute-batch"]
+    unsafe extern "C" fn _post_return_method_connection_execute_batch(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_connection_execute_batch::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/sqlite#calls"]
+    unsafe extern "C" fn export_calls() -> *mut u8 {
+      $($path_to_types)*::_export_calls_cabi::<$ty>()
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/sqlite#calls"]
+    unsafe extern "C" fn _post_return_calls(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_calls::<$ty>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/sqlite#reset-calls"]
+    unsafe extern "C" fn export_reset_calls() {
+      $($path_to_types)*::_export_reset_calls_cabi::<$ty>()
+    }
+    #[export_name = "fermyon:spin-test-virt/sqlite#add-rule"]
+    unsafe extern "C" fn export_add_rule(arg0: i32,arg1: *mut u8,arg2: usize,arg3: i32,arg4: *mut u8,arg5: usize,arg6: i32,arg7: *mut u8,arg8: *mut u8,arg9: *mut u8,arg10: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_add_rule_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/sqlite#add-rule"]
+    unsafe extern "C" fn _post_return_add_rule(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_add_rule::<$ty>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/sqlite#clear-rules"]
+    unsafe extern "C" fn export_clear_rules() {
+      $($path_to_types)*::_export_clear_rules_cabi::<$ty>()
+    }
+    #[export_name = "fermyon:spin-test-virt/sqlite#take-snapshot"]
+    unsafe extern "C" fn export_take_snapshot() -> *mut u8 {
+      $($path_to_types)*::_export_take_snapshot_cabi::<$ty>()
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/sqlite#take-snapshot"]
+    unsafe extern "C" fn _post_return_take_snapshot(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_take_snapshot::<$ty>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/sqlite#restore-snapshot"]
+    unsafe extern "C" fn export_restore_snapshot(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_restore_snapshot_cabi::<$ty>(arg0, arg1)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/sqlite#restore-snapshot"]
+    unsafe extern "C" fn _post_return_restore_snapshot(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_restore_snapshot::<$ty>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/sqlite#reset"]
+    unsafe extern "C" fn export_reset() -> *mut u8 {
+      $($path_to_types)*::_export_reset_cabi::<$ty>()
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/sqlite#reset"]
+    unsafe extern "C" fn _post_return_reset(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_reset::<$ty>(arg0)
+    }
+
+    const _: () = {
+      #[doc(hidden)]
+      #[export_name = "fermyon:spin-test-virt/sqlite#[dtor]connection"]
+      #[allow(non_snake_case)]
+      unsafe extern "C" fn dtor(rep: *mut u8) {
+        $($path_to_types)*::Connection::dtor::<
+        <$ty as $($path_to_types)*::Guest>::Connection
+        >(rep)
+      }
+    };
+
+  };);
+}
+                #[doc(hidden)]
+                pub(crate) use __export_fermyon_spin_test_virt_sqlite_cabi;
+                #[repr(align(4))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 20]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 20]);
+            }
+
+            #[allow(dead_code, clippy::all)]
+            pub mod redis {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type Payload =
+                    super::super::super::super::exports::fermyon::spin::redis::Payload;
+                pub type RedisParameter =
+                    super::super::super::super::exports::fermyon::spin::redis::RedisParameter;
+                pub type RedisResult =
+                    super::super::super::super::exports::fermyon::spin::redis::RedisResult;
+                pub type Error = super::super::super::super::exports::fermyon::spin::redis::Error;
+                pub type Matcher =
+                    super::super::super::super::exports::fermyon::spin_test_virt::types::Matcher;
+                /// A connection to the Redis database at a specific address
+                ///
+                /// The app and the test share the data of each address.
+
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct Connection {
+                    handle: _rt::Resource<Connection>,
+                }
+
+                type _ConnectionRep<T> = Option<T>;
+
+                impl Connection {
+                    /// Creates a new resource from the specified representation.
+                    ///
+                    /// This function will create a new resource handle by moving `val` onto
+                    /// the heap and then passing that heap pointer to the component model to
+                    /// create a handle. The owned handle is then returned as `Connection`.
+                    pub fn new<T: GuestConnection>(val: T) -> Self {
+                        Self::type_guard::<T>();
+                        let val: _ConnectionRep<T> = Some(val);
+                        let ptr: *mut _ConnectionRep<T> = _rt::Box::into_raw(_rt::Box::new(val));
+                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
+                    }
+
+                    /// Gets access to the underlying `T` which represents this resource.
+                    pub fn get<T: GuestConnection>(&self) -> &T {
+                        let ptr = unsafe { &*self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+
+                    /// Gets mutable access to the underlying `T` which represents this
+                    /// resource.
+                    pub fn get_mut<T: GuestConnection>(&mut self) -> &mut T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_mut().unwrap()
+                    }
+
+                    /// Consumes this resource and returns the underlying `T`.
+                    pub fn into_inner<T: GuestConnection>(self) -> T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.take().unwrap()
+                    }
+
+                    #[doc(hidden)]
+                    pub unsafe fn from_handle(handle: u32) -> Self {
+                        Self {
+                            handle: _rt::Resource::from_handle(handle),
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    pub fn take_handle(&self) -> u32 {
+                        _rt::Resource::take_handle(&self.handle)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn handle(&self) -> u32 {
+                        _rt::Resource::handle(&self.handle)
+                    }
+
+                    // It's theoretically possible to implement the `GuestConnection` trait twice
+                    // so guard against using it with two different types here.
+                    #[doc(hidden)]
+                    fn type_guard<T: 'static>() {
+                        use core::any::TypeId;
+                        static mut LAST_TYPE: Option<TypeId> = None;
+                        unsafe {
+                            assert!(!cfg!(target_feature = "threads"));
+                            let id = TypeId::of::<T>();
+                            match LAST_TYPE {
+                                Some(ty) => assert!(
+                                    ty == id,
+                                    "cannot use two types with this resource type"
+                                ),
+                                None => LAST_TYPE = Some(id),
+                            }
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
+                        Self::type_guard::<T>();
+                        let _ = _rt::Box::from_raw(handle as *mut _ConnectionRep<T>);
+                    }
+
+                    fn as_ptr<T: GuestConnection>(&self) -> *mut _ConnectionRep<T> {
+                        Connection::type_guard::<T>();
+                        T::_resource_rep(self.handle()).cast()
+                    }
+                }
+
+                /// A borrowed version of [`Connection`] which represents a borrowed value
+                /// with the lifetime `'a`.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct ConnectionBorrow<'a> {
+                    rep: *mut u8,
+                    _marker: core::marker::PhantomData<&'a Connection>,
+                }
+
+                impl<'a> ConnectionBorrow<'a> {
+                    #[doc(hidden)]
+                    pub unsafe fn lift(rep: usize) -> Self {
+                        Self {
+                            rep: rep as *mut u8,
+                            _marker: core::marker::PhantomData,
+                        }
+                    }
+
+                    /// Gets access to the underlying `T` in this resource.
+                    pub fn get<T: GuestConnection>(&self) -> &T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+
+                    // NB: mutable access is not allowed due to the component model allowing
+                    // multiple borrows of the same resource.
+
+                    fn as_ptr<T: 'static>(&self) -> *mut _ConnectionRep<T> {
+                        Connection::type_guard::<T>();
+                        self.rep.cast()
+                    }
+                }
+
+                unsafe impl _rt::WasmResource for Connection {
+                    #[inline]
+                    unsafe fn drop(_handle: u32) {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unreachable!();
+
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]fermyon:spin-test-virt/redis")]
+                            extern "C" {
+                                #[link_name = "[resource-drop]connection"]
+                                fn drop(_: u32);
+                            }
+
+                            drop(_handle);
+                        }
+                    }
+                }
+
+                /// A call to the redis interface
+                #[derive(Clone)]
+                pub enum Call {
+                    Publish((_rt::String, Payload)),
+                    Get(_rt::String),
+                    Set((_rt::String, Payload)),
+                    Incr(_rt::String),
+                    Del(_rt::Vec<_rt::String>),
+                    Sadd((_rt::String, _rt::Vec<_rt::String>)),
+                    Smembers(_rt::String),
+                    Srem((_rt::String, _rt::Vec<_rt::String>)),
+                    Execute((_rt::String, _rt::Vec<RedisParameter>)),
+                }
+                impl ::core::fmt::Debug for Call {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            Call::Publish(e) => f.debug_tuple("Call::Publish").field(e).finish(),
+                            Call::Get(e) => f.debug_tuple("Call::Get").field(e).finish(),
+                            Call::Set(e) => f.debug_tuple("Call::Set").field(e).finish(),
+                            Call::Incr(e) => f.debug_tuple("Call::Incr").field(e).finish(),
+                            Call::Del(e) => f.debug_tuple("Call::Del").field(e).finish(),
+                            Call::Sadd(e) => f.debug_tuple("Call::Sadd").field(e).finish(),
+                            Call::Smembers(e) => f.debug_tuple("Call::Smembers").field(e).finish(),
+                            Call::Srem(e) => f.debug_tuple("Call::Srem").field(e).finish(),
+                            Call::Execute(e) => f.debug_tuple("Call::Execute").field(e).finish(),
+                        }
+                    }
+                }
+                /// What a rule returns instead of running the command
+                #[derive(Clone)]
+                pub enum ExecuteResponse {
+                    /// Fail the command with the given error
+                    Error(Error),
+                    /// Return the given results
+                    Result(_rt::Vec<RedisResult>),
+                }
+                impl ::core::fmt::Debug for ExecuteResponse {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            ExecuteResponse::Error(e) => {
+                                f.debug_tuple("ExecuteResponse::Error").field(e).finish()
+                            }
+                            ExecuteResponse::Result(e) => {
+                                f.debug_tuple("ExecuteResponse::Result").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_static_connection_open_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let result1 = T::open(_rt::string_lift(bytes0));
+                    (result1).take_handle() as i32
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_address_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::address(ConnectionBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec2 = (result0.into_bytes()).into_boxed_slice();
+                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                    let len2 = vec2.len();
+                    ::core::mem::forget(vec2);
+                    *ptr1.add(4).cast::<usize>() = len2;
+                    *ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_connection_address<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l0, l1, 1);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_get_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::get(
+                        ConnectionBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            match e {
+                                Some(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (1i32) as u8;
+                                    let vec3 = (e).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr2.add(12).cast::<usize>() = len3;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                                }
+                                None => {
+                                    *ptr2.add(4).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::fermyon::spin::redis::Error as V5;
+                            match e {
+                                V5::InvalidAddress => {
+                                    *ptr2.add(4).cast::<u8>() = (0i32) as u8;
+                                }
+                                V5::TooManyConnections => {
+                                    *ptr2.add(4).cast::<u8>() = (1i32) as u8;
+                                }
+                                V5::TypeError => {
+                                    *ptr2.add(4).cast::<u8>() = (2i32) as u8;
+                                }
+                                V5::Other(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (3i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr2.add(12).cast::<usize>() = len4;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_connection_get<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = i32::from(*arg0.add(4).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                _ => {
+                                    let l2 = *arg0.add(8).cast::<*mut u8>();
+                                    let l3 = *arg0.add(12).cast::<usize>();
+                                    let base4 = l2;
+                                    let len4 = l3;
+                                    _rt::cabi_dealloc(base4, len4 * 1, 1);
+                                }
+                            }
+                        }
+                        _ => {
+                            let l5 = i32::from(*arg0.add(4).cast::<u8>());
+                            match l5 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                _ => {
+                                    let l6 = *arg0.add(8).cast::<*mut u8>();
+                                    let l7 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_set_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                ) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let len1 = arg4;
+                    T::set(
+                        ConnectionBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        _rt::Vec::from_raw_parts(arg3.cast(), len1, len1),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_smembers_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::smembers(
+                        ConnectionBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec4 = e;
+                            let len4 = vec4.len();
+                            let layout4 =
+                                _rt::alloc::Layout::from_size_align_unchecked(vec4.len() * 8, 4);
+                            let result4 = if layout4.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout4);
+                                }
+                                ptr
+                            } else {
+                                {
+                                    ::core::ptr::null_mut()
+                                }
+                            };
+                            for (i, e) in vec4.into_iter().enumerate() {
+                                let base = result4.add(i * 8);
+                                {
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *base.add(4).cast::<usize>() = len3;
+                                    *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                                }
+                            }
+                            *ptr2.add(8).cast::<usize>() = len4;
+                            *ptr2.add(4).cast::<*mut u8>() = result4;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::fermyon::spin::redis::Error as V6;
+                            match e {
+                                V6::InvalidAddress => {
+                                    *ptr2.add(4).cast::<u8>() = (0i32) as u8;
+                                }
+                                V6::TooManyConnections => {
+                                    *ptr2.add(4).cast::<u8>() = (1i32) as u8;
+                                }
+                                V6::TypeError => {
+                                    *ptr2.add(4).cast::<u8>() = (2i32) as u8;
+                                }
+                                V6::Other(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (3i32) as u8;
+                                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr2.add(12).cast::<usize>() = len5;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_connection_smembers<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l3 = *arg0.add(4).cast::<*mut u8>();
+                            let l4 = *arg0.add(8).cast::<usize>();
+                            let base5 = l3;
+                            let len5 = l4;
+                            for i in 0..len5 {
+                                let base = base5.add(i * 8);
+                                {
+                                    let l1 = *base.add(0).cast::<*mut u8>();
+                                    let l2 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l1, l2, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base5, len5 * 8, 4);
+                        }
+                        _ => {
+                            let l6 = i32::from(*arg0.add(4).cast::<u8>());
+                            match l6 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                _ => {
+                                    let l7 = *arg0.add(8).cast::<*mut u8>();
+                                    let l8 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l7, l8, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_sadd_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let base4 = arg3;
+                    let len4 = arg4;
+                    let mut result4 = _rt::Vec::with_capacity(len4);
+                    for i in 0..len4 {
+                        let base = base4.add(i * 8);
+                        let e4 = {
+                            let l1 = *base.add(0).cast::<*mut u8>();
+                            let l2 = *base.add(4).cast::<usize>();
+                            let len3 = l2;
+                            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+
+                            _rt::string_lift(bytes3)
+                        };
+                        result4.push(e4);
+                    }
+                    _rt::cabi_dealloc(base4, len4 * 8, 4);
+                    let result5 = T::sadd(
+                        ConnectionBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        result4,
+                    );
+                    let ptr6 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result5 {
+                        Ok(_) => {
+                            *ptr6.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr6.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::fermyon::spin::redis::Error as V8;
+                            match e {
+                                V8::InvalidAddress => {
+                                    *ptr6.add(4).cast::<u8>() = (0i32) as u8;
+                                }
+                                V8::TooManyConnections => {
+                                    *ptr6.add(4).cast::<u8>() = (1i32) as u8;
+                                }
+                                V8::TypeError => {
+                                    *ptr6.add(4).cast::<u8>() = (2i32) as u8;
+                                }
+                                V8::Other(e) => {
+                                    *ptr6.add(4).cast::<u8>() = (3i32) as u8;
+                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr6.add(12).cast::<usize>() = len7;
+                                    *ptr6.add(8).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr6
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_connection_sadd<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(4).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                _ => {
+                                    let l2 = *arg0.add(8).cast::<*mut u8>();
+                                    let l3 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_del_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let base3 = arg1;
+                    let len3 = arg2;
+                    let mut result3 = _rt::Vec::with_capacity(len3);
+                    for i in 0..len3 {
+                        let base = base3.add(i * 8);
+                        let e3 = {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            let len2 = l1;
+                            let bytes2 = _rt::Vec::from_raw_parts(l0.cast(), len2, len2);
+
+                            _rt::string_lift(bytes2)
+                        };
+                        result3.push(e3);
+                    }
+                    _rt::cabi_dealloc(base3, len3 * 8, 4);
+                    T::del(ConnectionBorrow::lift(arg0 as u32 as usize).get(), result3);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_keys_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::keys(ConnectionBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec3 = result0;
+                    let len3 = vec3.len();
+                    let layout3 = _rt::alloc::Layout::from_size_align_unchecked(vec3.len() * 8, 4);
+                    let result3 = if layout3.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout3);
+                        }
+                        ptr
+                    } else {
+                        {
+                            ::core::ptr::null_mut()
+                        }
+                    };
+                    for (i, e) in vec3.into_iter().enumerate() {
+                        let base = result3.add(i * 8);
+                        {
+                            let vec2 = (e.into_bytes()).into_boxed_slice();
+                            let ptr2 = vec2.as_ptr().cast::<u8>();
+                            let len2 = vec2.len();
+                            ::core::mem::forget(vec2);
+                            *base.add(4).cast::<usize>() = len2;
+                            *base.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                        }
+                    }
+                    *ptr1.add(4).cast::<usize>() = len3;
+                    *ptr1.add(0).cast::<*mut u8>() = result3;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_connection_keys<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l2 = *arg0.add(0).cast::<*mut u8>();
+                    let l3 = *arg0.add(4).cast::<usize>();
+                    let base4 = l2;
+                    let len4 = l3;
+                    for i in 0..len4 {
+                        let base = base4.add(i * 8);
+                        {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            _rt::cabi_dealloc(l0, l1, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(base4, len4 * 8, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_calls_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::calls();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec29 = result0;
+                    let len29 = vec29.len();
+                    let layout29 =
+                        _rt::alloc::Layout::from_size_align_unchecked(vec29.len() * 16, 4);
+                    let result29 = if layout29.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout29).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout29);
+                        }
+                        ptr
+                    } else {
+                        {
+                            ::core::ptr::null_mut()
+                        }
+                    };
+                    for (i, e) in vec29.into_iter().enumerate() {
+                        let base = result29.add(i * 16);
+                        {
+                            let (t2_0, t2_1) = e;
+                            let vec3 = (t2_0.into_bytes()).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *base.add(4).cast::<usize>() = len3;
+                            *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                            let vec28 = t2_1;
+                            let len28 = vec28.len();
+                            let layout28 =
+                                _rt::alloc::Layout::from_size_align_unchecked(vec28.len() * 20, 4);
+                            let result28 = if layout28.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout28).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout28);
+                                }
+                                ptr
+                            } else {
+                                {
+                                    ::core::ptr::null_mut()
+                                }
+                            };
+                            for (i, e) in vec28.into_iter().enumerate() {
+                                let base = result28.add(i * 20);
+                                {
+                                    match e {
+                                        Call::Publish(e) => {
+                                            *base.add(0).cast::<u8>() = (0i32) as u8;
+                                            let (t4_0, t4_1) = e;
+                                            let vec5 = (t4_0.into_bytes()).into_boxed_slice();
+                                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                                            let len5 = vec5.len();
+                                            ::core::mem::forget(vec5);
+                                            *base.add(8).cast::<usize>() = len5;
+                                            *base.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+                                            let vec6 = (t4_1).into_boxed_slice();
+                                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                                            let len6 = vec6.len();
+                                            ::core::mem::forget(vec6);
+                                            *base.add(16).cast::<usize>() = len6;
+                                            *base.add(12).cast::<*mut u8>() = ptr6.cast_mut();
+                                        }
+                                        Call::Get(e) => {
+                                            *base.add(0).cast::<u8>() = (1i32) as u8;
+                                            let vec7 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                                            let len7 = vec7.len();
+                                            ::core::mem::forget(vec7);
+                                            *base.add(8).cast::<usize>() = len7;
+                                            *base.add(4).cast::<*mut u8>() = ptr7.cast_mut();
+                                        }
+                                        Call::Set(e) => {
+                                            *base.add(0).cast::<u8>() = (2i32) as u8;
+                                            let (t8_0, t8_1) = e;
+                                            let vec9 = (t8_0.into_bytes()).into_boxed_slice();
+                                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                                            let len9 = vec9.len();
+                                            ::core::mem::forget(vec9);
+                                            *base.add(8).cast::<usize>() = len9;
+                                            *base.add(4).cast::<*mut u8>() = ptr9.cast_mut();
+                                            let vec10 = (t8_1).into_boxed_slice();
+                                            let ptr10 = vec10.as_ptr().cast::<u8>();
+                                            let len10 = vec10.len();
+                                            ::core::mem::forget(vec10);
+                                            *base.add(16).cast::<usize>() = len10;
+                                            *base.add(12).cast::<*mut u8>() = ptr10.cast_mut();
+                                        }
+                                        Call::Incr(e) => {
+                                            *base.add(0).cast::<u8>() = (3i32) as u8;
+                                            let vec11 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr11 = vec11.as_ptr().cast::<u8>();
+                                            let len11 = vec11.len();
+                                            ::core::mem::forget(vec11);
+                                            *base.add(8).cast::<usize>() = len11;
+                                            *base.add(4).cast::<*mut u8>() = ptr11.cast_mut();
+                                        }
+                                        Call::Del(e) => {
+                                            *base.add(0).cast::<u8>() = (4i32) as u8;
+                                            let vec13 = e;
+                                            let len13 = vec13.len();
+                                            let layout13 =
+                                                _rt::alloc::Layout::from_size_align_unchecked(
+                                                    vec13.len() * 8,
+                                                    4,
+                                                );
+                                            let result13 = if layout13.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout13).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout13);
+                                                }
+                                                ptr
+                                            } else {
+                                                {
+                                                    ::core::ptr::null_mut()
+                                                }
+                                            };
+                                            for (i, e) in vec13.into_iter().enumerate() {
+                                                let base = result13.add(i * 8);
+                                                {
+                                                    let vec12 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                                    let len12 = vec12.len();
+                                                    ::core::mem::forget(vec12);
+                                                    *base.add(4).cast::<usize>() = len12;
+                                                    *base.add(0).cast::<*mut u8>() =
+                                                        ptr12.cast_mut();
+                                                }
+                                            }
+                                            *base.add(8).cast::<usize>() = len13;
+                                            *base.add(4).cast::<*mut u8>() = result13;
+                                        }
+                                        Call::Sadd(e) => {
+                                            *base.add(0).cast::<u8>() = (5i32) as u8;
+                                            let (t14_0, t14_1) = e;
+                                            let vec15 = (t14_0.into_bytes()).into_boxed_slice();
+                                            let ptr15 = vec15.as_ptr().cast::<u8>();
+                                            let len15 = vec15.len();
+                                            ::core::mem::forget(vec15);
+                                            *base.add(8).cast::<usize>() = len15;
+                                            *base.add(4).cast::<*mut u8>() = ptr15.cast_mut();
+                                            let vec17 = t14_1;
+                                            let len17 = vec17.len();
+                                            let layout17 =
+                                                _rt::alloc::Layout::from_size_align_unchecked(
+                                                    vec17.len() * 8,
+                                                    4,
+                                                );
+                                            let result17 = if layout17.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout17).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout17);
+                                                }
+                                                ptr
+                                            } else {
+                                                {
+                                                    ::core::ptr::null_mut()
+                                                }
+                                            };
+                                            for (i, e) in vec17.into_iter().enumerate() {
+                                                let base = result17.add(i * 8);
+                                                {
+                                                    let vec16 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                                    let len16 = vec16.len();
+                                                    ::core::mem::forget(vec16);
+                                                    *base.add(4).cast::<usize>() = len16;
+                                                    *base.add(0).cast::<*mut u8>() =
+                                                        ptr16.cast_mut();
+                                                }
+                                            }
+                                            *base.add(16).cast::<usize>() = len17;
+                                            *base.add(12).cast::<*mut u8>() = result17;
+                                        }
+                                        Call::Smembers(e) => {
+                                            *base.add(0).cast::<u8>() = (6i32) as u8;
+                                            let vec18 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr18 = vec18.as_ptr().cast::<u8>();
+                                            let len18 = vec18.len();
+                                            ::core::mem::forget(vec18);
+                                            *base.add(8).cast::<usize>() = len18;
+                                            *base.add(4).cast::<*mut u8>() = ptr18.cast_mut();
+                                        }
+                                        Call::Srem(e) => {
+                                            *base.add(0).cast::<u8>() = (7i32) as u8;
+                                            let (t19_0, t19_1) = e;
+                                            let vec20 = (t19_0.into_bytes()).into_boxed_slice();
+                                            let ptr20 = vec20.as_ptr().cast::<u8>();
+                                            let len20 = vec20.len();
+                                            ::core::mem::forget(vec20);
+                                            *base.add(8).cast::<usize>() = len20;
+                                            *base.add(4).cast::<*mut u8>() = ptr20.cast_mut();
+                                            let vec22 = t19_1;
+                                            let len22 = vec22.len();
+                                            let layout22 =
+                                                _rt::alloc::Layout::from_size_align_unchecked(
+                                                    vec22.len() * 8,
+                                                    4,
+                                                );
+                                            let result22 = if layout22.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout22).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout22);
+                                                }
+                                                ptr
+                                            } else {
+                                                {
+                                                    ::core::ptr::null_mut()
+                                                }
+                                            };
+                                            for (i, e) in vec22.into_iter().enumerate() {
+                                                let base = result22.add(i * 8);
+                                                {
+                                                    let vec21 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                                    let len21 = vec21.len();
+                                                    ::core::mem::forget(vec21);
+                                                    *base.add(4).cast::<usize>() = len21;
+                                                    *base.add(0).cast::<*mut u8>() =
+                                                        ptr21.cast_mut();
+                                                }
+                                            }
+                                            *base.add(16).cast::<usize>() = len22;
+                                            *base.add(12).cast::<*mut u8>() = result22;
+                                        }
+                                        Call::Execute(e) => {
+                                            *base.add(0).cast::<u8>() = (8i32) as u8;
+                                            let (t23_0, t23_1) = e;
+                                            let vec24 = (t23_0.into_bytes()).into_boxed_slice();
+                                            let ptr24 = vec24.as_ptr().cast::<u8>();
+                                            let len24 = vec24.len();
+                                            ::core::mem::forget(vec24);
+                                            *base.add(8).cast::<usize>() = len24;
+                                            *base.add(4).cast::<*mut u8>() = ptr24.cast_mut();
+                                            let vec27 = t23_1;
+                                            let len27 = vec27.len();
+                                            let layout27 =
+                                                _rt::alloc::Layout::from_size_align_unchecked(
+                                                    vec27.len() * 16,
+                                                    8,
+                                                );
+                                            let result27 = if layout27.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout27).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout27);
+                                                }
+                                                ptr
+                                            } else {
+                                                {
+                                                    ::core::ptr::null_mut()
+                                                }
+                                            };
+                                            for (i, e) in vec27.into_iter().enumerate() {
+                                                let base = result27.add(i * 16);
+                                                {
+                                                    use super::super::super::super::exports::fermyon::spin::redis::RedisParameter as V26;
+                                                    match e {
+                                                        V26::Int64(e) => {
+                                                            *base.add(0).cast::<u8>() =
+                                                                (0i32) as u8;
+                                                            *base.add(8).cast::<i64>() =
+                                                                _rt::as_i64(e);
+                                                        }
+                                                        V26::Binary(e) => {
+                                                            *base.add(0).cast::<u8>() =
+                                                                (1i32) as u8;
+                                                            let vec25 = (e).into_boxed_slice();
+                                                            let ptr25 = vec25.as_ptr().cast::<u8>();
+                                                            let len25 = vec25.len();
+                                                            ::core::mem::forget(vec25);
+                                                            *base.add(12).cast::<usize>() = len25;
+                                                            *base.add(8).cast::<*mut u8>() =
+                                                                ptr25.cast_mut();
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                            *base.add(16).cast::<usize>() = len27;
+                                            *base.add(12).cast::<*mut u8>() = result27;
+                                        }
+                                    }
+                                }
+                            }
+                            *base.add(12).cast::<usize>() = len28;
+                            *base.add(8).cast::<*mut u8>() = result28;
+                        }
+                    }
+                    *ptr1.add(4).cast::<usize>() = len29;
+                    *ptr1.add(0).cast::<*mut u8>() = result29;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_calls<T: Guest>(arg0: *mut u8) {
+                    let l50 = *arg0.add(0).cast::<*mut u8>();
+                    let l51 = *arg0.add(4).cast::<usize>();
+                    let base52 = l50;
+                    let len52 = l51;
+                    for i in 0..len52 {
+                        let base = base52.add(i * 16);
+                        {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            _rt::cabi_dealloc(l0, l1, 1);
+                            let l47 = *base.add(8).cast::<*mut u8>();
+                            let l48 = *base.add(12).cast::<usize>();
+                            let base49 = l47;
+                            let len49 = l48;
+                            for i in 0..len49 {
+                                let base = base49.add(i * 20);
+                                {
+                                    let l2 = i32::from(*base.add(0).cast::<u8>());
+                                    match l2 {
+                                        0 => {
+                                            let l3 = *base.add(4).cast::<*mut u8>();
+                                            let l4 = *base.add(8).cast::<usize>();
+                                            _rt::cabi_dealloc(l3, l4, 1);
+                                            let l5 = *base.add(12).cast::<*mut u8>();
+                                            let l6 = *base.add(16).cast::<usize>();
+                                            let base7 = l5;
+                                            let len7 = l6;
+                                            _rt::cabi_dealloc(base7, len7 * 1, 1);
+                                        }
+                                        1 => {
+                                            let l8 = *base.add(4).cast::<*mut u8>();
+                                            let l9 = *base.add(8).cast::<usize>();
+                                            _rt::cabi_dealloc(l8, l9, 1);
+                                        }
+                                        2 => {
+                                            let l10 = *base.add(4).cast::<*mut u8>();
+                                            let l11 = *base.add(8).cast::<usize>();
+                                            _rt::cabi_dealloc(l10, l11, 1);
+                                            let l12 = *base.add(12).cast::<*mut u8>();
+                                            let l13 = *base.add(16).cast::<usize>();
+                                            let base14 = l12;
+                                            let len14 = l13;
+                                            _rt::cabi_dealloc(base14, len14 * 1, 1);
+                                        }
+                                        3 => {
+                                            let l15 = *base.add(4).cast::<*mut u8>();
+                                            let l16 = *base.add(8).cast::<usize>();
+                                            _rt::cabi_dealloc(l15, l16, 1);
+                                        }
+                                        4 => {
+                                            let l19 = *base.add(4).cast::<*mut u8>();
+                                            let l20 = *base.add(8).cast::<usize>();
+                                            let base21 = l19;
+                                            let len21 = l20;
+                                            for i in 0..len21 {
+                                                let base = base21.add(i * 8);
+                                                {
+                                                    let l17 = *base.add(0).cast::<*mut u8>();
+                                                    let l18 = *base.add(4).cast::<usize>();
+                                                    _rt::cabi_dealloc(l17, l18, 1);
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(base21, len21 * 8, 4);
+                                        }
+                                        5 => {
+                                            let l22 = *base.add(4).cast::<*mut u8>();
+                                            let l23 = *base.add(8).cast::<usize>();
+                                            _rt::cabi_dealloc(l22, l23, 1);
+                                            let l26 = *base.add(12).cast::<*mut u8>();
+                                            let l27 = *base.add(16).cast::<usize>();
+                                            let base28 = l26;
+                                            let len28 = l27;
+                                            for i in 0..len28 {
+                                                let base = base28.add(i * 8);
+                                                {
+                                                    let l24 = *base.add(0).cast::<*mut u8>();
+                                                    let l25 = *base.add(4).cast::<usize>();
+                                                    _rt::cabi_dealloc(l24, l25, 1);
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(base28, len28 * 8, 4);
+                                        }
+                                        6 => {
+                                            let l29 = *base.add(4).cast::<*mut u8>();
+                                            let l30 = *base.add(8).cast::<usize>();
+                                            _rt::cabi_dealloc(l29, l30, 1);
+                                        }
+                                        7 => {
+                                            let l31 = *base.add(4).cast::<*mut u8>();
+                                            let l32 = *base.add(8).cast::<usize>();
+                                            _rt::cabi_dealloc(l31, l32, 1);
+                                            let l35 = *base.add(12).cast::<*mut u8>();
+                                            let l36 = *base.add(16).cast::<usize>();
+                                            let base37 = l35;
+                                            let len37 = l36;
+                                            for i in 0..len37 {
+                                                let base = base37.add(i * 8);
+                                                {
+                                                    let l33 = *base.add(0).cast::<*mut u8>();
+                                                    let l34 = *base.add(4).cast::<usize>();
+                                                    _rt::cabi_dealloc(l33, l34, 1);
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(base37, len37 * 8, 4);
+                                        }
+                                        _ => {
+                                            let l38 = *base.add(4).cast::<*mut u8>();
+                                            let l39 = *base.add(8).cast::<usize>();
+                                            _rt::cabi_dealloc(l38, l39, 1);
+                                            let l44 = *base.add(12).cast::<*mut u8>();
+                                            let l45 = *base.add(16).cast::<usize>();
+                                            let base46 = l44;
+                                            let len46 = l45;
+                                            for i in 0..len46 {
+                                                let base = base46.add(i * 16);
+                                                {
+                                                    let l40 = i32::from(*base.add(0).cast::<u8>());
+                                                    match l40 {
+                                                        0 => (),
+                                                        _ => {
+                                                            let l41 =
+                                                                *base.add(8).cast::<*mut u8>();
+                                                            let l42 = *base.add(12).cast::<usize>();
+                                                            let base43 = l41;
+                                                            let len43 = l42;
+                                                            _rt::cabi_dealloc(base43, len43 * 1, 1);
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(base46, len46 * 16, 8);
+                                        }
+                                    }
+                                }
+                            }
+                            _rt::cabi_dealloc(base49, len49 * 20, 4);
+                        }
+                    }
+                    _rt::cabi_dealloc(base52, len52 * 16, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_reset_calls_cabi<T: Guest>() {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::reset_calls();
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_add_execute_rule_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: *mut u8,
+                    arg8: usize,
+                    arg9: i32,
+                    arg10: *mut u8,
+                    arg11: *mut u8,
+                    arg12: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len1 = arg4;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                    let v18 = match arg9 {
+                        0 => {
+                            let e18 = {
+                                use super::super::super::super::exports::fermyon::spin::redis::Error as V7;
+                                let v7 = match arg10 as i32 {
+                                    0 => V7::InvalidAddress,
+                                    1 => V7::TooManyConnections,
+                                    2 => V7::TypeError,
+                                    n => {
+                                        debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                        let e7 = {
+                                            let len6 = arg12;
+                                            let bytes6 =
+                                                _rt::Vec::from_raw_parts(arg11.cast(), len6, len6);
+
+                                            _rt::string_lift(bytes6)
+                                        };
+                                        V7::Other(e7)
+                                    }
+                                };
+
+                                v7
+                            };
+                            ExecuteResponse::Error(e18)
+                        }
+                        n => {
+                            debug_assert_eq!(n, 1, "invalid enum discriminant");
+                            let e18 = {
+                                let base17 = arg10;
+                                let len17 = arg11 as usize;
+                                let mut result17 = _rt::Vec::with_capacity(len17);
+                                for i in 0..len17 {
+                                    let base = base17.add(i * 16);
+                                    let e17 = {
+                                        let l8 = i32::from(*base.add(0).cast::<u8>());
+                                        use super::super::super::super::exports::fermyon::spin::redis::RedisResult as V16;
+                                        let v16 = match l8 {
+                                            0 => V16::Nil,
+                                            1 => {
+                                                let e16 = {
+                                                    let l9 = *base.add(8).cast::<*mut u8>();
+                                                    let l10 = *base.add(12).cast::<usize>();
+                                                    let len11 = l10;
+                                                    let bytes11 = _rt::Vec::from_raw_parts(
+                                                        l9.cast(),
+                                                        len11,
+                                                        len11,
+                                                    );
+
+                                                    _rt::string_lift(bytes11)
+                                                };
+                                                V16::Status(e16)
+                                            }
+                                            2 => {
+                                                let e16 = {
+                                                    let l12 = *base.add(8).cast::<i64>();
+
+                                                    l12
+                                                };
+                                                V16::Int64(e16)
+                                            }
+                                            n => {
+                                                debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                                let e16 = {
+                                                    let l13 = *base.add(8).cast::<*mut u8>();
+                                                    let l14 = *base.add(12).cast::<usize>();
+                                                    let len15 = l14;
+
+                                                    _rt::Vec::from_raw_parts(
+                                                        l13.cast(),
+                                                        len15,
+                                                        len15,
+                                                    )
+                                                };
+                                                V16::Binary(e16)
+                                            }
+                                        };
+
+                                        v16
+                                    };
+                                    result17.push(e17);
+                                }
+                                _rt::cabi_dealloc(base17, len17 * 16, 8);
+
+                                result17
+                            };
+                            ExecuteResponse::Result(e18)
+                        }
+                    };
+                    let result19 = T::add_execute_rule(
+                        match arg0 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len0 = arg2;
+                                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+
+                                    _rt::string_lift(bytes0)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        _rt::string_lift(bytes1),
+                        match arg5 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    use super::super::super::super::exports::fermyon::spin_test_virt::types::Matcher as V5;
+                                    let v5 = match arg6 {
+                                        0 => {
+                                            let e5 = {
+                                                let len2 = arg8;
+                                                let bytes2 = _rt::Vec::from_raw_parts(
+                                                    arg7.cast(),
+                                                    len2,
+                                                    len2,
+                                                );
+
+                                                _rt::string_lift(bytes2)
+                                            };
+                                            V5::Exact(e5)
+                                        }
+                                        1 => {
+                                            let e5 = {
+                                                let len3 = arg8;
+                                                let bytes3 = _rt::Vec::from_raw_parts(
+                                                    arg7.cast(),
+                                                    len3,
+                                                    len3,
+                                                );
+
+                                                _rt::string_lift(bytes3)
+                                            };
+                                            V5::Prefix(e5)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 2, "invalid enum discriminant");
+                                            let e5 = {
+                                                let len4 = arg8;
+                                                let bytes4 = _rt::Vec::from_raw_parts(
+                                                    arg7.cast(),
+                                                    len4,
+                                                    len4,
+                                                );
+
+                                                _rt::string_lift(bytes4)
+                                            };
+                                            V5::Regex(e5)
+                                        }
+                                    };
+
+                                    v5
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        v18,
+                    );
+                    let ptr20 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result19 {
+                        Ok(_) => {
+                            *ptr20.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr20.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec21 = (e.into_bytes()).into_boxed_slice();
+                            let ptr21 = vec21.as_ptr().cast::<u8>();
+                            let len21 = vec21.len();
+                            ::core::mem::forget(vec21);
+                            *ptr20.add(8).cast::<usize>() = len21;
+                            *ptr20.add(4).cast::<*mut u8>() = ptr21.cast_mut();
+                        }
+                    };
+                    ptr20
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_add_execute_rule<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = *arg0.add(4).cast::<*mut u8>();
+                            let l2 = *arg0.add(8).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_clear_execute_rules_cabi<T: Guest>() {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::clear_execute_rules();
+                }
+                pub trait Guest {
+                    type Connection: GuestConnection;
+                    /// Get the recorded calls to the redis interface keyed by address
+                    fn calls() -> _rt::Vec<(_rt::String, _rt::Vec<Call>)>;
+                    /// Reset the state of the call tracking
+                    fn reset_calls();
+                    /// Add a rule that scripts the response of commands run through `connection.execute`
+                    ///
+                    /// Rules are consulted in the order they were added and the first matching rule wins.
+                    /// Commands that match no rule are handled by the built-in command implementations.
+                    ///
+                    /// `command` is compared case-insensitively. The `arguments` matcher is applied to the
+                    /// arguments joined by a single space, with integers written in decimal. If `arguments` is
+                    /// `none`, any arguments match.
+                    ///
+                    /// If `address` is `none`, the rule applies to all databases.
+                    ///
+                    /// Returns an error if the matcher is an invalid regular expression.
+                    fn add_execute_rule(
+                        address: Option<_rt::String>,
+                        command: _rt::String,
+                        arguments: Option<Matcher>,
+                        response: ExecuteResponse,
+                    ) -> Result<(), _rt::String>;
+                    /// Remove all rules previously added with `add-execute-rule`
+                    fn clear_execute_rules();
+                }
+                pub trait GuestConnection: 'static {
+                    #[doc(hidden)]
+                    unsafe fn _resource_new(val: *mut u8) -> u32
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = val;
+                            unreachable!();
+                        }
+
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]fermyon:spin-test-virt/redis")]
+                            extern "C" {
+                                #[link_name = "[resource-new]connection"]
+                                fn new(_: *mut u8) -> u32;
+                            }
+                            new(val)
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    fn _resource_rep(handle: u32) -> *mut u8
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = handle;
+                            unreachable!();
+                        }
+
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]fermyon:spin-test-virt/redis")]
+                            extern "C" {
+                                #[link_name = "[resource-rep]connection"]
+                                fn rep(_: u32) -> *mut u8;
+                            }
+                            unsafe { rep(handle) }
+                        }
+                    }
+
+                    /// Open a connection to the Redis database at `address`.
+                    fn open(address: _rt::String) -> Connection;
+                    /// Get the address of the database
+                    fn address(&self) -> _rt::String;
+                    /// Get the value of a key.
+                    ///
+                    /// Returns `error::type-error` if the key does not hold a payload (e.g., it holds a set).
+                    fn get(&self, key: _rt::String) -> Result<Option<Payload>, Error>;
+                    /// Set key to value overwriting any existing value.
+                    fn set(&self, key: _rt::String, value: Payload);
+                    /// Retrieve the contents of the set named `key`.
+                    fn smembers(&self, key: _rt::String) -> Result<_rt::Vec<_rt::String>, Error>;
+                    /// Add the specified `values` to the set named `key`.
+                    fn sadd(
+                        &self,
+                        key: _rt::String,
+                        values: _rt::Vec<_rt::String>,
+                    ) -> Result<(), Error>;
+                    /// Remove the specified keys.
+                    fn del(&self, keys: _rt::Vec<_rt::String>);
+                    /// Get all keys in the database.
+                    fn keys(&self) -> _rt::Vec<_rt::String>;
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_fermyon_spin_test_virt_redis_cabi{
+  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+    #[export_name = "fermyon:spin-test-virt/redis#[static]connection.open"]
+    unsafe extern "C" fn export_static_connection_open(arg0: *mut u8,arg1: usize,) -> i32 {
+      $($path_to_types)*::_export_static_connection_open_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1)
+    }
+    #[export_name = "fermyon:spin-test-virt/redis#[method]connection.address"]
+    unsafe extern "C" fn export_method_connection_address(arg0: *mut u8,) -> *mut u8 {
+      $($path_to_types)*::_export_method_connection_address_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/redis#[method]connection.address"]
+    unsafe extern "C" fn _post_return_method_connection_address(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_connection_address::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/redis#[method]connection.get"]
+    unsafe extern "C" fn export_method_connection_get(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_connection_get_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1, arg2)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/redis#[method]connection.get"]
+    unsafe extern "C" fn _post_return_method_connection_get(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_connection_get::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/redis#[method]connection.set"]
+    unsafe extern "C" fn export_method_connection_set(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: *mut u8,arg4: usize,) {
+      $($path_to_types)*::_export_method_connection_set_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1, arg2, arg3, arg4)
+    }
+    #[export_name = "fermyon:spin-test-virt/redis#[method]connection.smembers"]
+    unsafe extern "C" fn export_method_connection_smembers(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_connection_smembers_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1, arg2)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/redis#[method]connection.smembers"]
+    unsafe extern "C" fn _post_return_method_connection_smembers(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_connection_smembers::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/redis#[method]connection.sadd"]
+    unsafe extern "C" fn export_method_connection_sadd(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: *mut u8,arg4: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_connection_sadd_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1, arg2, arg3, arg4)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/redis#[method]connection.sadd"]
+    unsafe extern "C" fn _post_return_method_connection_sadd(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_connection_sadd::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/redis#[method]connection.del"]
+    unsafe extern "C" fn export_method_connection_del(arg0: *mut u8,arg1: *mut u8,arg2: usize,) {
+      $($path_to_types)*::_export_method_connection_del_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1, arg2)
+    }
+    #[export_name = "fermyon:spin-test-virt/redis#[method]connection.keys"]
+    unsafe extern "C" fn export_method_connection_keys(arg0: *mut u8,) -> *mut u8 {
+      $($path_to_types)*::_export_method_connection_keys_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/redis#[method]connection.keys"]
+    unsafe extern "C" fn _post_return_method_connection_keys(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_connection_keys::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/redis#calls"]
+    unsafe extern "C" fn export_calls() -> *mut u8 {
+      $($path_to_types)*::_export_calls_cabi::<$ty>()
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/redis#calls"]
+    unsafe extern "C" fn _post_return_calls(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_calls::<$ty>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/redis#reset-calls"]
+    unsafe extern "C" fn export_reset_calls() {
+      $($path_to_types)*::_export_reset_calls_cabi::<$ty>()
+    }
+    #[export_name = "fermyon:spin-test-virt/redis#add-execute-rule"]
+    unsafe extern "C" fn export_add_execute_rule(arg0: i32,arg1: *mut u8,arg2: usize,arg3: *mut u8,arg4: usize,arg5: i32,arg6: i32,arg7: *mut u8,arg8: usize,arg9: i32,arg10: *mut u8,arg11: *mut u8,arg12: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_add_execute_rule_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/redis#add-execute-rule"]
+    unsafe extern "C" fn _post_return_add_execute_rule(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_add_execute_rule::<$ty>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/redis#clear-execute-rules"]
+    unsafe extern "C" fn export_clear_execute_rules() {
+      $($path_to_types)*::_export_clear_execute_rules_cabi::<$ty>()
+    }
+
+    const _: () = {
+      #[doc(hidden)]
+      #[export_name = "fermyon:spin-test-virt/redis#[dtor]connection"]
+      #[allow(non_snake_case)]
+      unsafe extern "C" fn dtor(rep: *mut u8) {
+        $($path_to_types)*::Connection::dtor::<
+        <$ty as $($path_to_types)*::Guest>::Connection
+        >(rep)
+      }
+    };
+
+  };);
+}
+                #[doc(hidden)]
+                pub(crate) use __export_fermyon_spin_test_virt_redis_cabi;
+                #[repr(align(4))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 16]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 16]);
+            }
+
+            #[allow(dead_code, clippy::all)]
+            pub mod mqtt {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type Payload =
+                    super::super::super::super::exports::fermyon::spin::mqtt::Payload;
+                pub type Qos = super::super::super::super::exports::fermyon::spin::mqtt::Qos;
+                pub type Error = super::super::super::super::exports::fermyon::spin::mqtt::Error;
+                /// A message published through the mqtt interface
+                #[derive(Clone)]
+                pub struct Publish {
+                    /// The topic the message was published to
+                    pub topic: _rt::String,
+                    /// The payload of the message
+                    pub payload: Payload,
+                    /// The quality of service the message was published with
+                    pub qos: Qos,
+                    /// The username the connection was opened with
+                    pub username: _rt::String,
+                    /// The keep alive interval the connection was opened with
+                    pub keep_alive_interval_in_secs: u64,
+                }
+                impl ::core::fmt::Debug for Publish {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Publish")
+                            .field("topic", &self.topic)
+                            .field("payload", &self.payload)
+                            .field("qos", &self.qos)
+                            .field("username", &self.username)
+                            .field(
+                                "keep-alive-interval-in-secs",
+                                &self.keep_alive_interval_in_secs,
+                            )
+                            .finish()
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_publishes_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::publishes();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec9 = result0;
+                    let len9 = vec9.len();
+                    let layout9 = _rt::alloc::Layout::from_size_align_unchecked(vec9.len() * 16, 4);
+                    let result9 = if layout9.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout9).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout9);
+                        }
+                        ptr
+                    } else {
+                        {
+                            ::core::ptr::null_mut()
+                        }
+                    };
+                    for (i, e) in vec9.into_iter().enumerate() {
+                        let base = result9.add(i * 16);
+                        {
+                            let (t2_0, t2_1) = e;
+                            let vec3 = (t2_0.into_bytes()).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *base.add(4).cast::<usize>() = len3;
+                            *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                            let vec8 = t2_1;
+                            let len8 = vec8.len();
+                            let layout8 =
+                                _rt::alloc::Layout::from_size_align_unchecked(vec8.len() * 40, 8);
+                            let result8 = if layout8.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout8);
+                                }
+                                ptr
+                            } else {
+                                {
+                                    ::core::ptr::null_mut()
+                                }
+                            };
+                            for (i, e) in vec8.into_iter().enumerate() {
+                                let base = result8.add(i * 40);
+                                {
+                                    let Publish {
+                                        topic: topic4,
+                                        payload: payload4,
+                                        qos: qos4,
+                                        username: username4,
+                                        keep_alive_interval_in_secs: keep_alive_interval_in_secs4,
+                                    } = e;
+                                    let vec5 = (topic4.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *base.add(4).cast::<usize>() = len5;
+                                    *base.add(0).cast::<*mut u8>() = ptr5.cast_mut();
+                                    let vec6 = (payload4).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *base.add(12).cast::<usize>() = len6;
+                                    *base.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+                                    *base.add(16).cast::<u8>() = (qos4.clone() as i32) as u8;
+                                    let vec7 = (username4.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *base.add(24).cast::<usize>() = len7;
+                                    *base.add(20).cast::<*mut u8>() = ptr7.cast_mut();
+                                    *base.add(32).cast::<i64>() =
+                                        _rt::as_i64(keep_alive_interval_in_secs4);
+                                }
+                            }
+                            *base.add(12).cast::<usize>() = len8;
+                            *base.add(8).cast::<*mut u8>() = result8;
+                        }
+                    }
+                    *ptr1.add(4).cast::<usize>() = len9;
+                    *ptr1.add(0).cast::<*mut u8>() = result9;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_publishes<T: Guest>(arg0: *mut u8) {
+                    let l12 = *arg0.add(0).cast::<*mut u8>();
+                    let l13 = *arg0.add(4).cast::<usize>();
+                    let base14 = l12;
+                    let len14 = l13;
+                    for i in 0..len14 {
+                        let base = base14.add(i * 16);
+                        {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            _rt::cabi_dealloc(l0, l1, 1);
+                            let l9 = *base.add(8).cast::<*mut u8>();
+                            let l10 = *base.add(12).cast::<usize>();
+                            let base11 = l9;
+                            let len11 = l10;
+                            for i in 0..len11 {
+                                let base = base11.add(i * 40);
+                                {
+                                    let l2 = *base.add(0).cast::<*mut u8>();
+                                    let l3 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                    let l4 = *base.add(8).cast::<*mut u8>();
+                                    let l5 = *base.add(12).cast::<usize>();
+                                    let base6 = l4;
+                                    let len6 = l5;
+                                    _rt::cabi_dealloc(base6, len6 * 1, 1);
+                                    let l7 = *base.add(20).cast::<*mut u8>();
+                                    let l8 = *base.add(24).cast::<usize>();
+                                    _rt::cabi_dealloc(l7, l8, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base11, len11 * 40, 8);
+                        }
+                    }
+                    _rt::cabi_dealloc(base14, len14 * 16, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_reset_publishes_cabi<T: Guest>() {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::reset_publishes();
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_add_credentials_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                    arg2: *mut u8,
+                    arg3: usize,
+                    arg4: *mut u8,
+                    arg5: usize,
+                ) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let len1 = arg3;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+                    let len2 = arg5;
+                    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
+                    T::add_credentials(
+                        _rt::string_lift(bytes0),
+                        _rt::string_lift(bytes1),
+                        _rt::string_lift(bytes2),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_set_open_error_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: *mut u8,
+                    arg5: usize,
+                ) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    T::set_open_error(
+                        _rt::string_lift(bytes0),
+                        match arg2 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    use super::super::super::super::exports::fermyon::spin::mqtt::Error as V3;
+                                    let v3 = match arg3 {
+                                        0 => V3::InvalidAddress,
+                                        1 => V3::TooManyConnections,
+                                        2 => {
+                                            let e3 = {
+                                                let len1 = arg5;
+                                                let bytes1 = _rt::Vec::from_raw_parts(
+                                                    arg4.cast(),
+                                                    len1,
+                                                    len1,
+                                                );
+
+                                                _rt::string_lift(bytes1)
+                                            };
+                                            V3::ConnectionFailed(e3)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                            let e3 = {
+                                                let len2 = arg5;
+                                                let bytes2 = _rt::Vec::from_raw_parts(
+                                                    arg4.cast(),
+                                                    len2,
+                                                    len2,
+                                                );
+
+                                                _rt::string_lift(bytes2)
+                                            };
+                                            V3::Other(e3)
+                                        }
+                                    };
+
+                                    v3
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_set_publish_error_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: *mut u8,
+                    arg5: usize,
+                ) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    T::set_publish_error(
+                        _rt::string_lift(bytes0),
+                        match arg2 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    use super::super::super::super::exports::fermyon::spin::mqtt::Error as V3;
+                                    let v3 = match arg3 {
+                                        0 => V3::InvalidAddress,
+                                        1 => V3::TooManyConnections,
+                                        2 => {
+                                            let e3 = {
+                                                let len1 = arg5;
+                                                let bytes1 = _rt::Vec::from_raw_parts(
+                                                    arg4.cast(),
+                                                    len1,
+                                                    len1,
+                                                );
+
+                                                _rt::string_lift(bytes1)
+                                            };
+                                            V3::ConnectionFailed(e3)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                            let e3 = {
+                                                let len2 = arg5;
+                                                let bytes2 = _rt::Vec::from_raw_parts(
+                                                    arg4.cast(),
+                                                    len2,
+                                                    len2,
+                                                );
+
+                                                _rt::string_lift(bytes2)
+                                            };
+                                            V3::Other(e3)
+                                        }
+                                    };
+
+                                    v3
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_reset_brokers_cabi<T: Guest>() {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::reset_brokers();
+                }
+                pub trait Guest {
+                    /// Get the messages published through the mqtt interface keyed by broker address
+                    fn publishes() -> _rt::Vec<(_rt::String, _rt::Vec<Publish>)>;
+                    /// Reset the recorded publishes
+                    fn reset_publishes();
+                    /// Allow connections to the broker at `address` with the given credentials
+                    ///
+                    /// A broker without any credentials accepts all connections. Once credentials are added,
+                    /// opening a connection with other credentials fails with `error::connection-failed`.
+                    fn add_credentials(
+                        address: _rt::String,
+                        username: _rt::String,
+                        password: _rt::String,
+                    );
+                    /// Make opening a connection to the broker at `address` fail with `error`
+                    ///
+                    /// Passing `none` removes a previously injected error.
+                    fn set_open_error(address: _rt::String, error: Option<Error>);
+                    /// Make publishing to the broker at `address` fail with `error`
+                    ///
+                    /// Failed publishes are not recorded. Passing `none` removes a previously injected error.
+                    fn set_publish_error(address: _rt::String, error: Option<Error>);
+                    /// Remove all credentials and injected errors
+                    fn reset_brokers();
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_fermyon_spin_test_virt_mqtt_cabi{
+  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+    #[export_name = "fermyon:spin-test-virt/mqtt#publishes"]
+    unsafe extern "C" fn export_publishes() -> *mut u8 {
+      $($path_to_types)*::_export_publishes_cabi::<$ty>()
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/mqtt#publishes"]
+    unsafe extern "C" fn _post_return_publishes(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_publishes::<$ty>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/mqtt#reset-publishes"]
+    unsafe extern "C" fn export_reset_publishes() {
+      $($path_to_types)*::_export_reset_publishes_cabi::<$ty>()
+    }
+    #[export_name = "fermyon:spin-test-virt/mqtt#add-credentials"]
+    unsafe extern "C" fn export_add_credentials(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,arg4: *mut u8,arg5: usize,) {
+      $($path_to_types)*::_export_add_credentials_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5)
+    }
+    #[export_name = "fermyon:spin-test-virt/mqtt#set-open-error"]
+    unsafe extern "C" fn export_set_open_error(arg0: *mut u8,arg1: usize,arg2: i32,arg3: i32,arg4: *mut u8,arg5: usize,) {
+      $($path_to_types)*::_export_set_open_error_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5)
+    }
+    #[export_name = "fermyon:spin-test-virt/mqtt#set-publish-error"]
+    unsafe extern "C" fn export_set_publish_error(arg0: *mut u8,arg1: usize,arg2: i32,arg3: i32,arg4: *mut u8,arg5: usize,) {
+      $($path_to_types)*::_export_set_publish_error_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5)
+    }
+    #[export_name = "fermyon:spin-test-virt/mqtt#reset-brokers"]
+    unsafe extern "C" fn export_reset_brokers() {
+      $($path_to_types)*::_export_reset_brokers_cabi::<$ty>()
+    }
+  };);
+}
+                #[doc(hidden)]
+                pub(crate) use __export_fermyon_spin_test_virt_mqtt_cabi;
+                #[repr(align(4))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+            }
+
+            #[allow(dead_code, clippy::all)]
+            pub mod postgres {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type ParameterValue =
+                    super::super::super::super::exports::fermyon::spin::rdbms_types::ParameterValue;
+                pub type RowSet =
+                    super::super::super::super::exports::fermyon::spin::rdbms_types::RowSet;
+                pub type Error =
+                    super::super::super::super::exports::fermyon::spin::rdbms_types::Error;
+                /// A connection to the Postgres database at a specific address
+                ///
+                /// The app and the test share the data of each address.
+
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct Connection {
+                    handle: _rt::Resource<Connection>,
+                }
+
+                type _ConnectionRep<T> = Option<T>;
+
+                impl Connection {
+                    /// Creates a new resource from the specified representation.
+                    ///
+                    /// This function will create a new resource handle by moving `val` onto
+                    /// the heap and then passing that heap pointer to the component model to
+                    /// create a handle. The owned handle is then returned as `Connection`.
+                    pub fn new<T: GuestConnection>(val: T) -> Self {
+                        Self::type_guard::<T>();
+                        let val: _ConnectionRep<T> = Some(val);
+                        let ptr: *mut _ConnectionRep<T> = _rt::Box::into_raw(_rt::Box::new(val));
+                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
+                    }
+
+                    /// Gets access to the underlying `T` which represents this resource.
+                    pub fn get<T: GuestConnection>(&self) -> &T {
+                        let ptr = unsafe { &*self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+
+                    /// Gets mutable access to the underlying `T` which represents this
+                    /// resource.
+                    pub fn get_mut<T: GuestConnection>(&mut self) -> &mut T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_mut().unwrap()
+                    }
+
+                    /// Consumes this resource and returns the underlying `T`.
+                    pub fn into_inner<T: GuestConnection>(self) -> T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.take().unwrap()
+                    }
+
+                    #[doc(hidden)]
+                    pub unsafe fn from_handle(handle: u32) -> Self {
+                        Self {
+                            handle: _rt::Resource::from_handle(handle),
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    pub fn take_handle(&self) -> u32 {
+                        _rt::Resource::take_handle(&self.handle)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn handle(&self) -> u32 {
+                        _rt::Resource::handle(&self.handle)
+                    }
+
+                    // It's theoretically possible to implement the `GuestConnection` trait twice
+                    // so guard against using it with two different types here.
+                    #[doc(hidden)]
+                    fn type_guard<T: 'static>() {
+                        use core::any::TypeId;
+                        static mut LAST_TYPE: Option<TypeId> = None;
+                        unsafe {
+                            assert!(!cfg!(target_feature = "threads"));
+                            let id = TypeId::of::<T>();
+                            match LAST_TYPE {
+                                Some(ty) => assert!(
+                                    ty == id,
+                                    "cannot use two types with this resource type"
+                                ),
+                                None => LAST_TYPE = Some(id),
+                            }
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
+                        Self::type_guard::<T>();
+                        let _ = _rt::Box::from_raw(handle as *mut _ConnectionRep<T>);
+                    }
+
+                    fn as_ptr<T: GuestConnection>(&self) -> *mut _ConnectionRep<T> {
+                        Connection::type_guard::<T>();
+                        T::_resource_rep(self.handle()).cast()
+                    }
+                }
+
+                /// A borrowed version of [`Connection`] which represents a borrowed value
+                /// with the lifetime `'a`.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct ConnectionBorrow<'a> {
+                    rep: *mut u8,
+                    _marker: core::marker::PhantomData<&'a Connection>,
+                }
+
+                impl<'a> ConnectionBorrow<'a> {
+                    #[doc(hidden)]
+                    pub unsafe fn lift(rep: usize) -> Self {
+                        Self {
+                            rep: rep as *mut u8,
+                            _marker: core::marker::PhantomData,
+                        }
+                    }
+
+                    /// Gets access to the underlying `T` in this resource.
+                    pub fn get<T: GuestConnection>(&self) -> &T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+
+                    // NB: mutable access is not allowed due to the component model allowing
+                    // multiple borrows of the same resource.
+
+                    fn as_ptr<T: 'static>(&self) -> *mut _ConnectionRep<T> {
+                        Connection::type_guard::<T>();
+                        self.rep.cast()
+                    }
+                }
+
+                unsafe impl _rt::WasmResource for Connection {
+                    #[inline]
+                    unsafe fn drop(_handle: u32) {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unreachable!();
+
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]fermyon:spin-test-virt/postgres")]
+                            extern "C" {
+                                #[link_name = "[resource-drop]connection"]
+                                fn drop(_: u32);
+                            }
+
+                            drop(_handle);
+                        }
+                    }
+                }
+
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_static_connection_open_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let result1 = T::open(_rt::string_lift(bytes0));
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            *ptr2.add(4).cast::<i32>() = (e).take_handle() as i32;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::fermyon::spin::rdbms_types::Error as V8;
+                            match e {
+                                V8::ConnectionFailed(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (0i32) as u8;
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr2.add(12).cast::<usize>() = len3;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                                }
+                                V8::BadParameter(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (1i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr2.add(12).cast::<usize>() = len4;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                                V8::QueryFailed(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (2i32) as u8;
+                                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr2.add(12).cast::<usize>() = len5;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                                }
+                                V8::ValueConversionFailed(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (3i32) as u8;
+                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr2.add(12).cast::<usize>() = len6;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                                V8::Other(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (4i32) as u8;
+                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr2.add(12).cast::<usize>() = len7;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_static_connection_open<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(4).cast::<u8>());
+                            match l1 {
+                                0 => {
+                                    let l2 = *arg0.add(8).cast::<*mut u8>();
+                                    let l3 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                1 => {
+                                    let l4 = *arg0.add(8).cast::<*mut u8>();
+                                    let l5 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                2 => {
+                                    let l6 = *arg0.add(8).cast::<*mut u8>();
+                                    let l7 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                3 => {
+                                    let l8 = *arg0.add(8).cast::<*mut u8>();
+                                    let l9 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                _ => {
+                                    let l10 = *arg0.add(8).cast::<*mut u8>();
+                                    let l11 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_address_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::address(ConnectionBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec2 = (result0.into_bytes()).into_boxed_slice();
+                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                    let len2 = vec2.len();
+                    ::core::mem::forget(vec2);
+                    *ptr1.add(4).cast::<usize>() = len2;
+                    *ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_connection_address<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l0, l1, 1);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_query_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let base20 = arg3;
+                    let len20 = arg4;
+                    let mut result20 = _rt::Vec::with_capacity(len20);
+                    for i in 0..len20 {
+                        let base = base20.add(i * 16);
+                        let e20 = {
+                            let l1 = i32::from(*base.add(0).cast::<u8>());
+                            use super::super::super::super::exports::fermyon::spin::rdbms_types::ParameterValue as V19;
+                            let v19 = match l1 {
+                                0 => {
+                                    let e19 = {
+                                        let l2 = i32::from(*base.add(8).cast::<u8>());
+
+                                        _rt::bool_lift(l2 as u8)
+                                    };
+                                    V19::Boolean(e19)
+                                }
+                                1 => {
+                                    let e19 = {
+                                        let l3 = i32::from(*base.add(8).cast::<i8>());
+
+                                        l3 as i8
+                                    };
+                                    V19::Int8(e19)
+                                }
+                                2 => {
+                                    let e19 = {
+                                        let l4 = i32::from(*base.add(8).cast::<i16>());
+
+                                        l4 as i16
+                                    };
+                                    V19::Int16(e19)
+                                }
+                                3 => {
+                                    let e19 = {
+                                        let l5 = *base.add(8).cast::<i32>();
+
+                                        l5
+                                    };
+                                    V19::Int32(e19)
+                                }
+                                4 => {
+                                    let e19 = {
+                                        let l6 = *base.add(8).cast::<i64>();
+
+                                        l6
+                                    };
+                                    V19::Int64(e19)
+                                }
+                                5 => {
+                                    let e19 = {
+                                        let l7 = i32::from(*base.add(8).cast::<u8>());
+
+                                        l7 as u8
+                                    };
+                                    V19::Uint8(e19)
+                                }
+                                6 => {
+                                    let e19 = {
+                                        let l8 = i32::from(*base.add(8).cast::<u16>());
+
+                                        l8 as u16
+                                    };
+                                    V19::Uint16(e19)
+                                }
+                                7 => {
+                                    let e19 = {
+                                        let l9 = *base.add(8).cast::<i32>();
+
+                                        l9 as u32
+                                    };
+                                    V19::Uint32(e19)
+                                }
+                                8 => {
+                                    let e19 = {
+                                        let l10 = *base.add(8).cast::<i64>();
+
+                                        l10 as u64
+                                    };
+                                    V19::Uint64(e19)
+                                }
+                                9 => {
+                                    let e19 = {
+                                        let l11 = *base.add(8).cast::<f32>();
+
+                                        l11
+                                    };
+                                    V19::Floating32(e19)
+                                }
+                                10 => {
+                                    let e19 = {
+                                        let l12 = *base.add(8).cast::<f64>();
+
+                                        l12
+                                    };
+                                    V19::Floating64(e19)
+                                }
+                                11 => {
+                                    let e19 = {
+                                        let l13 = *base.add(8).cast::<*mut u8>();
+                                        let l14 = *base.add(12).cast::<usize>();
+                                        let len15 = l14;
+                                        let bytes15 =
+                                            _rt::Vec::from_raw_parts(l13.cast(), len15, len15);
+
+                                        _rt::string_lift(bytes15)
+                                    };
+                                    V19::Str(e19)
+                                }
+                                12 => {
+                                    let e19 = {
+                                        let l16 = *base.add(8).cast::<*mut u8>();
+                                        let l17 = *base.add(12).cast::<usize>();
+                                        let len18 = l17;
+
+                                        _rt::Vec::from_raw_parts(l16.cast(), len18, len18)
+                                    };
+                                    V19::Binary(e19)
+                                }
+                                n => {
+                                    debug_assert_eq!(n, 13, "invalid enum discriminant");
+                                    V19::DbNull
+                                }
+                            };
+
+                            v19
+                        };
+                        result20.push(e20);
+                    }
+                    _rt::cabi_dealloc(base20, len20 * 16, 8);
+                    let result21 = T::query(
+                        ConnectionBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        result20,
+                    );
+                    let ptr22 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result21 {
+                        Ok(e) => {
+                            *ptr22.add(0).cast::<u8>() = (0i32) as u8;
+                            let super::super::super::super::exports::fermyon::spin::rdbms_types::RowSet{ columns:columns23, rows:rows23, } = e;
+                            let vec26 = columns23;
+                            let len26 = vec26.len();
+                            let layout26 =
+                                _rt::alloc::Layout::from_size_align_unchecked(vec26.len() * 12, 4);
+                            let result26 = if layout26.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout26).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout26);
+                                }
+                                ptr
+                            } else {
+                                {
+                                    ::core::ptr::null_mut()
+                                }
+                            };
+                            for (i, e) in vec26.into_iter().enumerate() {
+                                let base = result26.add(i * 12);
+                                {
+                                    let super::super::super::super::exports::fermyon::spin::rdbms_types::Column{ name:name24, data_type:data_type24, } = e;
+                                    let vec25 = (name24.into_bytes()).into_boxed_slice();
+                                    let ptr25 = vec25.as_ptr().cast::<u8>();
+                                    let len25 = vec25.len();
+                                    ::core::mem::forget(vec25);
+                                    *base.add(4).cast::<usize>() = len25;
+                                    *base.add(0).cast::<*mut u8>() = ptr25.cast_mut();
+                                    *base.add(8).cast::<u8>() = (data_type24.clone() as i32) as u8;
+                                }
+                            }
+                            *ptr22.add(8).cast::<usize>() = len26;
+                            *ptr22.add(4).cast::<*mut u8>() = result26;
+                            let vec31 = rows23;
+                            let len31 = vec31.len();
+                            let layout31 =
+                                _rt::alloc::Layout::from_size_align_unchecked(vec31.len() * 8, 4);
+                            let result31 = if layout31.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout31).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout31);
+                                }
+                                ptr
+                            } else {
+                                {
+                                    ::core::ptr::null_mut()
+                                }
+                            };
+                            for (i, e) in vec31.into_iter().enumerate() {
+                                let base = result31.add(i * 8);
+                                {
+                                    let vec30 = e;
+                                    let len30 = vec30.len();
+                                    let layout30 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec30.len() * 16,
+                                        8,
+                                    );
+                                    let result30 = if layout30.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout30).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout30);
+                                        }
+                                        ptr
+                                    } else {
+                                        {
+                                            ::core::ptr::null_mut()
+                                        }
+                                    };
+                                    for (i, e) in vec30.into_iter().enumerate() {
+                                        let base = result30.add(i * 16);
+                                        {
+                                            use super::super::super::super::exports::fermyon::spin::rdbms_types::DbValue as V29;
+                                            match e {
+                                                V29::Boolean(e) => {
+                                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                    *base.add(8).cast::<u8>() = (match e {
+                                                        true => 1,
+                                                        false => 0,
+                                                    })
+                                                        as u8;
+                                                }
+                                                V29::Int8(e) => {
+                                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(8).cast::<u8>() =
+                                                        (_rt::as_i32(e)) as u8;
+                                                }
+                                                V29::Int16(e) => {
+                                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                                    *base.add(8).cast::<u16>() =
+                                                        (_rt::as_i32(e)) as u16;
+                                                }
+                                                V29::Int32(e) => {
+                                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                    *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                V29::Int64(e) => {
+                                                    *base.add(0).cast::<u8>() = (4i32) as u8;
+                                                    *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                                }
+                                                V29::Uint8(e) => {
+                                                    *base.add(0).cast::<u8>() = (5i32) as u8;
+                                                    *base.add(8).cast::<u8>() =
+                                                        (_rt::as_i32(e)) as u8;
+                                                }
+                                                V29::Uint16(e) => {
+                                                    *base.add(0).cast::<u8>() = (6i32) as u8;
+                                                    *base.add(8).cast::<u16>() =
+                                                        (_rt::as_i32(e)) as u16;
+                                                }
+                                                V29::Uint32(e) => {
+                                                    *base.add(0).cast::<u8>() = (7i32) as u8;
+                                                    *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                V29::Uint64(e) => {
+                                                    *base.add(0).cast::<u8>() = (8i32) as u8;
+                                                    *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                                }
+                                                V29::Floating32(e) => {
+                                                    *base.add(0).cast::<u8>() = (9i32) as u8;
+                                                    *base.add(8).cast::<f32>() = _rt::as_f32(e);
+                                                }
+                                                V29::Floating64(e) => {
+                                                    *base.add(0).cast::<u8>() = (10i32) as u8;
+                                                    *base.add(8).cast::<f64>() = _rt::as_f64(e);
+                                                }
+                                                V29::Str(e) => {
+                                                    *base.add(0).cast::<u8>() = (11i32) as u8;
+                                                    let vec27 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                                                    let len27 = vec27.len();
+                                                    ::core::mem::forget(vec27);
+                                                    *base.add(12).cast::<usize>() = len27;
+                                                    *base.add(8).cast::<*mut u8>() =
+                                                        ptr27.cast_mut();
+                                                }
+                                                V29::Binary(e) => {
+                                                    *base.add(0).cast::<u8>() = (12i32) as u8;
+                                                    let vec28 = (e).into_boxed_slice();
+                                                    let ptr28 = vec28.as_ptr().cast::<u8>();
+                                                    let len28 = vec28.len();
+                                                    ::core::mem::forget(vec28);
+                                                    *base.add(12).cast::<usize>() = len28;
+                                                    *base.add(8).cast::<*mut u8>() =
+                                                        ptr28.cast_mut();
+                                                }
+                                                V29::DbNull => {
+                                                    *base.add(0).cast::<u8>() = (13i32) as u8;
+                                                }
+                                                V29::Unsupported => {
+                                                    *base.add(0).cast::<u8>() = (14i32) as u8;
+                                                }
+                                            }
+                                        }
+                                    }
+                                    *base.add(4).cast::<usize>() = len30;
+                                    *base.add(0).cast::<*mut u8>() = result30;
+                                }
+                            }
+                            *ptr22.add(16).cast::<usize>() = len31;
+                            *ptr22.add(12).cast::<*mut u8>() = result31;
+                        }
+                        Err(e) => {
+                            *ptr22.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::fermyon::spin::rdbms_types::Error as V37;
+                            match e {
+                                V37::ConnectionFailed(e) => {
+                                    *ptr22.add(4).cast::<u8>() = (0i32) as u8;
+                                    let vec32 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr32 = vec32.as_ptr().cast::<u8>();
+                                    let len32 = vec32.len();
+                                    ::core::mem::forget(vec32);
+                                    *ptr22.add(12).cast::<usize>() = len32;
+                                    *ptr22.add(8).cast::<*mut u8>() = ptr32.cast_mut();
+                                }
+                                V37::BadParameter(e) => {
+                                    *ptr22.add(4).cast::<u8>() = (1i32) as u8;
+                                    let vec33 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr33 = vec33.as_ptr().cast::<u8>();
+                                    let len33 = vec33.len();
+                                    ::core::mem::forget(vec33);
+                                    *ptr22.add(12).cast::<usize>() = len33;
+                                    *ptr22.add(8).cast::<*mut u8>() = ptr33.cast_mut();
+                                }
+                                V37::QueryFailed(e) => {
+                                    *ptr22.add(4).cast::<u8>() = (2i32) as u8;
+                                    let vec34 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr34 = vec34.as_ptr().cast::<u8>();
+                                    let len34 = vec34.len();
+                                    ::core::mem::forget(vec34);
+                                    *ptr22.add(12).cast::<usize>() = len34;
+                                    *ptr22.add(8).cast::<*mut u8>() = ptr34.cast_mut();
+                                }
+                                V37::ValueConversionFailed(e) => {
+                                    *ptr22.add(4).cast::<u8>() = (3i32) as u8;
+                                    let vec35 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr35 = vec35.as_ptr().cast::<u8>();
+                                    let len35 = vec35.len();
+                                    ::core::mem::forget(vec35);
+                                    *ptr22.add(12).cast::<usize>() = len35;
+                                    *ptr22.add(8).cast::<*mut u8>() = ptr35.cast_mut();
+                                }
+                                V37::Other(e) => {
+                                    *ptr22.add(4).cast::<u8>() = (4i32) as u8;
+                                    let vec36 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr36 = vec36.as_ptr().cast::<u8>();
+                                    let len36 = vec36.len();
+                                    ::core::mem::forget(vec36);
+                                    *ptr22.add(12).cast::<usize>() = len36;
+                                    *ptr22.add(8).cast::<*mut u8>() = ptr36.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr22
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_connection_query<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l3 = *arg0.add(4).cast::<*mut u8>();
+                            let l4 = *arg0.add(8).cast::<usize>();
+                            let base5 = l3;
+                            let len5 = l4;
+                            for i in 0..len5 {
+                                let base = base5.add(i * 12);
+                                {
+                                    let l1 = *base.add(0).cast::<*mut u8>();
+                                    let l2 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l1, l2, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base5, len5 * 12, 4);
+                            let l15 = *arg0.add(12).cast::<*mut u8>();
+                            let l16 = *arg0.add(16).cast::<usize>();
+                            let base17 = l15;
+                            let len17 = l16;
+                            for i in 0..len17 {
+                                let base = base17.add(i * 8);
+                                {
+                                    let l12 = *base.add(0).cast::<*mut u8>();
+                                    let l13 = *base.add(4).cast::<usize>();
+                                    let base14 = l12;
+                                    let len14 = l13;
+                                    for i in 0..len14 {
+                                        let base = base14.add(i * 16);
+                                        {
+                                            let l6 = i32::from(*base.add(0).cast::<u8>());
+                                            match l6 {
+                                                0 => (),
+                                                1 => (),
+                                                2 => (),
+                                                3 => (),
+                                                4 => (),
+                                                5 => (),
+                                                6 => (),
+                                                7 => (),
+                                                8 => (),
+                                                9 => (),
+                                                10 => (),
+                                                11 => {
+                                                    let l7 = *base.add(8).cast::<*mut u8>();
+                                                    let l8 = *base.add(12).cast::<usize>();
+                                                    _rt::cabi_dealloc(l7, l8, 1);
+                                                }
+                                                12 => {
+                                                    let l9 = *base.add(8).cast::<*mut u8>();
+                                                    let l10 = *base.add(12).cast::<usize>();
+                                                    let base11 = l9;
+                                                    let len11 = l10;
+                                                    _rt::cabi_dealloc(base11, len11 * 1, 1);
+                                                }
+                                                13 => (),
+                                                _ => (),
+                                            }
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base14, len14 * 16, 8);
+                                }
+                            }
+                            _rt::cabi_dealloc(base17, len17 * 8, 4);
+                        }
+                        _ => {
+                            let l18 = i32::from(*arg0.add(4).cast::<u8>());
+                            match l18 {
+                                0 => {
+                                    let l19 = *arg0.add(8).cast::<*mut u8>();
+                                    let l20 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l19, l20, 1);
+                                }
+                                1 => {
+                                    let l21 = *arg0.add(8).cast::<*mut u8>();
+                                    let l22 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l21, l22, 1);
+                                }
+                                2 => {
+                                    let l23 = *arg0.add(8).cast::<*mut u8>();
+                                    let l24 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l23, l24, 1);
+                                }
+                                3 => {
+                                    let l25 = *arg0.add(8).cast::<*mut u8>();
+                                    let l26 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l25, l26, 1);
+                                }
+                                _ => {
+                                    let l27 = *arg0.add(8).cast::<*mut u8>();
+                                    let l28 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l27, l28, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_execute_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let base20 = arg3;
+                    let len20 = arg4;
+                    let mut result20 = _rt::Vec::with_capacity(len20);
+                    for i in 0..len20 {
+                        let base = base20.add(i * 16);
+                        let e20 = {
+                            let l1 = i32::from(*base.add(0).cast::<u8>());
+                            use super::super::super::super::exports::fermyon::spin::rdbms_types::ParameterValue as V19;
+                            let v19 = match l1 {
+                                0 => {
+                                    let e19 = {
+                                        let l2 = i32::from(*base.add(8).cast::<u8>());
+
+                                        _rt::bool_lift(l2 as u8)
+                                    };
+                                    V19::Boolean(e19)
+                                }
+                                1 => {
+                                    let e19 = {
+                                        let l3 = i32::from(*base.add(8).cast::<i8>());
+
+                                        l3 as i8
+                                    };
+                                    V19::Int8(e19)
+                                }
+                                2 => {
+                                    let e19 = {
+                                        let l4 = i32::from(*base.add(8).cast::<i16>());
+
+                                        l4 as i16
+                                    };
+                                    V19::Int16(e19)
+                                }
+                                3 => {
+                                    let e19 = {
+                                        let l5 = *base.add(8).cast::<i32>();
+
+                                        l5
+                                    };
+                                    V19::Int32(e19)
+                                }
+                                4 => {
+                                    let e19 = {
+                                        let l6 = *base.add(8).cast::<i64>();
+
+                                        l6
+                                    };
+                                    V19::Int64(e19)
+                                }
+                                5 => {
+                                    let e19 = {
+                                        let l7 = i32::from(*base.add(8).cast::<u8>());
+
+                                        l7 as u8
+                                    };
+                                    V19::Uint8(e19)
+                                }
+                                6 => {
+                                    let e19 = {
+                                        let l8 = i32::from(*base.add(8).cast::<u16>());
+
+                                        l8 as u16
+                                    };
+                                    V19::Uint16(e19)
+                                }
+                                7 => {
+                                    let e19 = {
+                                        let l9 = *base.add(8).cast::<i32>();
+
+                                        l9 as u32
+                                    };
+                                    V19::Uint32(e19)
+                                }
+                                8 => {
+                                    let e19 = {
+                                        let l10 = *base.add(8).cast::<i64>();
+
+                                        l10 as u64
+                                    };
+                                    V19::Uint64(e19)
+                                }
+                                9 => {
+                                    let e19 = {
+                                        let l11 = *base.add(8).cast::<f32>();
+
+                                        l11
+                                    };
+                                    V19::Floating32(e19)
+                                }
+                                10 => {
+                                    let e19 = {
+                                        let l12 = *base.add(8).cast::<f64>();
+
+                                        l12
+                                    };
+                                    V19::Floating64(e19)
+                                }
+                                11 => {
+                                    let e19 = {
+                                        let l13 = *base.add(8).cast::<*mut u8>();
+                                        let l14 = *base.add(12).cast::<usize>();
+                                        let len15 = l14;
+                                        let bytes15 =
+                                            _rt::Vec::from_raw_parts(l13.cast(), len15, len15);
+
+                                        _rt::string_lift(bytes15)
+                                    };
+                                    V19::Str(e19)
+                                }
+                                12 => {
+                                    let e19 = {
+                                        let l16 = *base.add(8).cast::<*mut u8>();
+                                        let l17 = *base.add(12).cast::<usize>();
+                                        let len18 = l17;
+
+                                        _rt::Vec::from_raw_parts(l16.cast(), len18, len18)
+                                    };
+                                    V19::Binary(e19)
+                                }
+                                n => {
+                                    debug_assert_eq!(n, 13, "invalid enum discriminant");
+                                    V19::DbNull
+                                }
+                            };
+
+                            v19
+                        };
+                        result20.push(e20);
+                    }
+                    _rt::cabi_dealloc(base20, len20 * 16, 8);
+                    let result21 = T::execute(
+                        ConnectionBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        result20,
+                    );
+                    let ptr22 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result21 {
+                        Ok(e) => {
+                            *ptr22.add(0).cast::<u8>() = (0i32) as u8;
+                            *ptr22.add(8).cast::<i64>() = _rt::as_i64(e);
+                        }
+                        Err(e) => {
+                            *ptr22.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::fermyon::spin::rdbms_types::Error as V28;
+                            match e {
+                                V28::ConnectionFailed(e) => {
+                                    *ptr22.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec23 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr23 = vec23.as_ptr().cast::<u8>();
+                                    let len23 = vec23.len();
+                                    ::core::mem::forget(vec23);
+                                    *ptr22.add(16).cast::<usize>() = len23;
+                                    *ptr22.add(12).cast::<*mut u8>() = ptr23.cast_mut();
+                                }
+                                V28::BadParameter(e) => {
+                                    *ptr22.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec24 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr24 = vec24.as_ptr().cast::<u8>();
+                                    let len24 = vec24.len();
+                                    ::core::mem::forget(vec24);
+                                    *ptr22.add(16).cast::<usize>() = len24;
+                                    *ptr22.add(12).cast::<*mut u8>() = ptr24.cast_mut();
+                                }
+                                V28::QueryFailed(e) => {
+                                    *ptr22.add(8).cast::<u8>() = (2i32) as u8;
+                                    let vec25 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr25 = vec25.as_ptr().cast::<u8>();
+                                    let len25 = vec25.len();
+                                    ::core::mem::forget(vec25);
+                                    *ptr22.add(16).cast::<usize>() = len25;
+                                    *ptr22.add(12).cast::<*mut u8>() = ptr25.cast_mut();
+                                }
+                                V28::ValueConversionFailed(e) => {
+                                    *ptr22.add(8).cast::<u8>() = (3i32) as u8;
+                                    let vec26 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr26 = vec26.as_ptr().cast::<u8>();
+                                    let len26 = vec26.len();
+                                    ::core::mem::forget(vec26);
+                                    *ptr22.add(16).cast::<usize>() = len26;
+                                    *ptr22.add(12).cast::<*mut u8>() = ptr26.cast_mut();
+                                }
+                                V28::Other(e) => {
+                                    *ptr22.add(8).cast::<u8>() = (4i32) as u8;
+                                    let vec27 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                                    let len27 = vec27.len();
+                                    ::core::mem::forget(vec27);
+                                    *ptr22.add(16).cast::<usize>() = len27;
+                                    *ptr22.add(12).cast::<*mut u8>() = ptr27.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr22
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_connection_execute<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => {
+                                    let l2 = *arg0.add(12).cast::<*mut u8>();
+                                    let l3 = *arg0.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                1 => {
+                                    let l4 = *arg0.add(12).cast::<*mut u8>();
+                                    let l5 = *arg0.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                2 => {
+                                    let l6 = *arg0.add(12).cast::<*mut u8>();
+                                    let l7 = *arg0.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                3 => {
+                                    let l8 = *arg0.add(12).cast::<*mut u8>();
+                                    let l9 = *arg0.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                _ => {
+                                    let l10 = *arg0.add(12).cast::<*mut u8>();
+                                    let l11 = *arg0.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_execute_batch_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::execute_batch(
+                        ConnectionBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(_) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::fermyon::spin::rdbms_types::Error as V8;
+                            match e {
+                                V8::ConnectionFailed(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (0i32) as u8;
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr2.add(12).cast::<usize>() = len3;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                                }
+                                V8::BadParameter(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (1i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr2.add(12).cast::<usize>() = len4;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                                V8::QueryFailed(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (2i32) as u8;
+                                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr2.add(12).cast::<usize>() = len5;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                                }
+                                V8::ValueConversionFailed(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (3i32) as u8;
+                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr2.add(12).cast::<usize>() = len6;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                                V8::Other(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (4i32) as u8;
+                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr2.add(12).cast::<usize>() = len7;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_connection_execute_batch<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(4).cast::<u8>());
+                            match l1 {
+                                0 => {
+                                    let l2 = *arg0.add(8).cast::<*mut u8>();
+                                    let l3 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                1 => {
+                                    let l4 = *arg0.add(8).cast::<*mut u8>();
+                                    let l5 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                2 => {
+                                    let l6 = *arg0.add(8).cast::<*mut u8>();
+                                    let l7 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                3 => {
+                                    let l8 = *arg0.add(8).cast::<*mut u8>();
+                                    let l9 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                _ => {
+                                    let l10 = *arg0.add(8).cast::<*mut u8>();
+                                    let l11 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                pub trait Guest {
+                    type Connection: GuestConnection;
+                }
+                pub trait GuestConnection: 'static {
+                    #[doc(hidden)]
+                    unsafe fn _resource_new(val: *mut u8) -> u32
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = val;
+                            unreachable!();
+                        }
+
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]fermyon:spin-test-virt/postgres")]
+                            extern "C" {
+                                #[link_name = "[resource-new]connection"]
+                                fn new(_: *mut u8) -> u32;
+                            }
+                            new(val)
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    fn _resource_rep(handle: u32) -> *mut u8
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = handle;
+                            unreachable!();
+                        }
+
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]fermyon:spin-test-virt/postgres")]
+                            extern "C" {
+                                #[link_name = "[resource-rep]connection"]
+                                fn rep(_: u32) -> *mut u8;
+                            }
+                            unsafe { rep(handle) }
+                        }
+                    }
+
+                    /// Open a connection to the Postgres database at `address`.
+                    fn open(address: _rt::String) -> Result<Connection, Error>;
+                    /// Get the address of the database
+                    fn address(&self) -> _rt::String;
+                    /// Query the database.
+                    ///
+                    /// Parameters are referenced with Postgres' `$1` style placeholders.
+                    fn query(
+                        &self,
+                        statement: _rt::String,
+                        params: _rt::Vec<ParameterValue>,
+                    ) -> Result<RowSet, Error>;
+                    /// Execute a statement returning the number of changed rows.
+                    ///
+                    /// Parameters are referenced with Postgres' `$1` style placeholders.
+                    fn execute(
+                        &self,
+                        statement: _rt::String,
+                        params: _rt::Vec<ParameterValue>,
+                    ) -> Result<u64, Error>;
+                    /// Execute a batch of semicolon separated statements (e.g., a schema file)
+                    fn execute_batch(&self, statements: _rt::String) -> Result<(), Error>;
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_fermyon_spin_test_virt_postgres_cabi{
+  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+    #[export_name = "fermyon:spin-test-virt/postgres#[static]connection.open"]
+    unsafe extern "C" fn export_static_connection_open(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_static_connection_open_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/postgres#[static]connection.open"]
+    unsafe extern "C" fn _post_return_static_connection_open(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_static_connection_open::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/postgres#[method]connection.address"]
+    unsafe extern "C" fn export_method_connection_address(arg0: *mut u8,) -> *mut u8 {
+      $($path_to_types)*::_export_method_connection_address_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/postgres#[method]connection.address"]
+    unsafe extern "C" fn _post_return_method_connection_address(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_connection_address::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/postgres#[method]connection.query"]
+    unsafe extern "C" fn export_method_connection_query(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: *mut u8,arg4: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_connection_query_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1, arg2, arg3, arg4)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/postgres#[method]connection.query"]
+    unsafe extern "C" fn _post_return_method_connection_query(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_connection_query::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/postgres#[method]connection.execute"]
+    unsafe extern "C" fn export_method_connection_execute(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: *mut u8,arg4: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_connection_execute_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1, arg2, arg3, arg4)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/postgres#[method]connection.execute"]
+    unsafe extern "C" fn _post_return_method_connection_execute(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_connection_execute::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/postgres#[method]connection.execute-batch"]
+    unsafe extern "C" fn export_method_connection_execute_batch(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_connection_execute_batch_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1, arg2)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/postgres#[method]connection.execute-batch"]
+    unsafe extern "C" fn _post_return_method_connection_execute_batch(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_connection_execute_batch::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+
+    const _: () = {
+      #[doc(hidden)]
+      #[export_name = "fermyon:spin-test-virt/postgres#[dtor]connection"]
+      #[allow(non_snake_case)]
+      unsafe extern "C" fn dtor(rep: *mut u8) {
+        $($path_to_types)*::Connection::dtor::<
+        <$ty as $($path_to_types)*::Guest>::Connection
+        >(rep)
+      }
+    };
+
+  };);
+}
+                #[doc(hidden)]
+                pub(crate) use __export_fermyon_spin_test_virt_postgres_cabi;
+                #[repr(align(8))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 24]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 24]);
+            }
+
+            #[allow(dead_code, clippy::all)]
+            pub mod mysql {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type ParameterValue =
+                    super::super::super::super::exports::fermyon::spin::rdbms_types::ParameterValue;
+                pub type RowSet =
+                    super::super::super::super::exports::fermyon::spin::rdbms_types::RowSet;
+                pub type Error =
+                    super::super::super::super::exports::fermyon::spin::rdbms_types::Error;
+                /// A connection to the MySQL database at a specific address
+                ///
+                /// The app and the test share the data of each address.
+
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct Connection {
+                    handle: _rt::Resource<Connection>,
+                }
+
+                type _ConnectionRep<T> = Option<T>;
+
+                impl Connection {
+                    /// Creates a new resource from the specified representation.
+                    ///
+                    /// This function will create a new resource handle by moving `val` onto
+                    /// the heap and then passing that heap pointer to the component model to
+                    /// create a handle. The owned handle is then returned as `Connection`.
+                    pub fn new<T: GuestConnection>(val: T) -> Self {
+                        Self::type_guard::<T>();
+                        let val: _ConnectionRep<T> = Some(val);
+                        let ptr: *mut _ConnectionRep<T> = _rt::Box::into_raw(_rt::Box::new(val));
+                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
+                    }
+
+                    /// Gets access to the underlying `T` which represents this resource.
+                    pub fn get<T: GuestConnection>(&self) -> &T {
+                        let ptr = unsafe { &*self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+
+                    /// Gets mutable access to the underlying `T` which represents this
+                    /// resource.
+                    pub fn get_mut<T: GuestConnection>(&mut self) -> &mut T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_mut().unwrap()
+                    }
+
+                    /// Consumes this resource and returns the underlying `T`.
+                    pub fn into_inner<T: GuestConnection>(self) -> T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.take().unwrap()
+                    }
+
+                    #[doc(hidden)]
+                    pub unsafe fn from_handle(handle: u32) -> Self {
+                        Self {
+                            handle: _rt::Resource::from_handle(handle),
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    pub fn take_handle(&self) -> u32 {
+                        _rt::Resource::take_handle(&self.handle)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn handle(&self) -> u32 {
+                        _rt::Resource::handle(&self.handle)
+                    }
+
+                    // It's theoretically possible to implement the `GuestConnection` trait twice
+                    // so guard against using it with two different types here.
+                    #[doc(hidden)]
+                    fn type_guard<T: 'static>() {
+                        use core::any::TypeId;
+                        static mut LAST_TYPE: Option<TypeId> = None;
+                        unsafe {
+                            assert!(!cfg!(target_feature = "threads"));
+                            let id = TypeId::of::<T>();
+                            match LAST_TYPE {
+                                Some(ty) => assert!(
+                                    ty == id,
+                                    "cannot use two types with this resource type"
+                                ),
+                                None => LAST_TYPE = Some(id),
+                            }
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
+                        Self::type_guard::<T>();
+                        let _ = _rt::Box::from_raw(handle as *mut _ConnectionRep<T>);
+                    }
+
+                    fn as_ptr<T: GuestConnection>(&self) -> *mut _ConnectionRep<T> {
+                        Connection::type_guard::<T>();
+                        T::_resource_rep(self.handle()).cast()
+                    }
+                }
+
+                /// A borrowed version of [`Connection`] which represents a borrowed value
+                /// with the lifetime `'a`.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct ConnectionBorrow<'a> {
+                    rep: *mut u8,
+                    _marker: core::marker::PhantomData<&'a Connection>,
+                }
+
+                impl<'a> ConnectionBorrow<'a> {
+                    #[doc(hidden)]
+                    pub unsafe fn lift(rep: usize) -> Self {
+                        Self {
+                            rep: rep as *mut u8,
+                            _marker: core::marker::PhantomData,
+                        }
+                    }
+
+                    /// Gets access to the underlying `T` in this resource.
+                    pub fn get<T: GuestConnection>(&self) -> &T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+
+                    // NB: mutable access is not allowed due to the component model allowing
+                    // multiple borrows of the same resource.
+
+                    fn as_ptr<T: 'static>(&self) -> *mut _ConnectionRep<T> {
+                        Connection::type_guard::<T>();
+                        self.rep.cast()
+                    }
+                }
+
+                unsafe impl _rt::WasmResource for Connection {
+                    #[inline]
+                    unsafe fn drop(_handle: u32) {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unreachable!();
+
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]fermyon:spin-test-virt/mysql")]
+                            extern "C" {
+                                #[link_name = "[resource-drop]connection"]
+                                fn drop(_: u32);
+                            }
+
+                            drop(_handle);
+                        }
+                    }
+                }
+
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_static_connection_open_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let result1 = T::open(_rt::string_lift(bytes0));
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            *ptr2.add(4).cast::<i32>() = (e).take_handle() as i32;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::fermyon::spin::rdbms_types::Error as V8;
+                            match e {
+                                V8::ConnectionFailed(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (0i32) as u8;
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr2.add(12).cast::<usize>() = len3;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                                }
+                                V8::BadParameter(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (1i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr2.add(12).cast::<usize>() = len4;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                                V8::QueryFailed(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (2i32) as u8;
+                                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr2.add(12).cast::<usize>() = len5;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                                }
+                                V8::ValueConversionFailed(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (3i32) as u8;
+                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr2.add(12).cast::<usize>() = len6;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                                V8::Other(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (4i32) as u8;
+                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr2.add(12).cast::<usize>() = len7;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_static_connection_open<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(4).cast::<u8>());
+                            match l1 {
+                                0 => {
+                                    let l2 = *arg0.add(8).cast::<*mut u8>();
+                                    let l3 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                1 => {
+                                    let l4 = *arg0.add(8).cast::<*mut u8>();
+                                    let l5 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                2 => {
+                                    let l6 = *arg0.add(8).cast::<*mut u8>();
+                                    let l7 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                3 => {
+                                    let l8 = *arg0.add(8).cast::<*mut u8>();
+                                    let l9 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                _ => {
+                                    let l10 = *arg0.add(8).cast::<*mut u8>();
+                                    let l11 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_address_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::address(ConnectionBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec2 = (result0.into_bytes()).into_boxed_slice();
+                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                    let len2 = vec2.len();
+                    ::core::mem::forget(vec2);
+                    *ptr1.add(4).cast::<usize>() = len2;
+                    *ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_connection_address<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l0, l1, 1);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_query_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let base20 = arg3;
+                    let len20 = arg4;
+                    let mut result20 = _rt::Vec::with_capacity(len20);
+                    for i in 0..len20 {
+                        let base = base20.add(i * 16);
+                        let e20 = {
+                            let l1 = i32::from(*base.add(0).cast::<u8>());
+                            use super::super::super::super::exports::fermyon::spin::rdbms_types::ParameterValue as V19;
+                            let v19 = match l1 {
+                                0 => {
+                                    let e19 = {
+                                        let l2 = i32::from(*base.add(8).cast::<u8>());
+
+                                        _rt::bool_lift(l2 as u8)
+                                    };
+                                    V19::Boolean(e19)
+                                }
+                                1 => {
+                                    let e19 = {
+                                        let l3 = i32::from(*base.add(8).cast::<i8>());
+
+                                        l3 as i8
+                                    };
+                                    V19::Int8(e19)
+                                }
+                                2 => {
+                                    let e19 = {
+                                        let l4 = i32::from(*base.add(8).cast::<i16>());
+
+                                        l4 as i16
+                                    };
+                                    V19::Int16(e19)
+                                }
+                                3 => {
+                                    let e19 = {
+                                        let l5 = *base.add(8).cast::<i32>();
+
+                                        l5
+                                    };
+                                    V19::Int32(e19)
+                                }
+                                4 => {
+                                    let e19 = {
+                                        let l6 = *base.add(8).cast::<i64>();
+
+                                        l6
+                                    };
+                                    V19::Int64(e19)
+                                }
+                                5 => {
+                                    let e19 = {
+                                        let l7 = i32::from(*base.add(8).cast::<u8>());
+
+                                        l7 as u8
+                                    };
+                                    V19::Uint8(e19)
+                                }
+                                6 => {
+                                    let e19 = {
+                                        let l8 = i32::from(*base.add(8).cast::<u16>());
+
+                                        l8 as u16
+                                    };
+                                    V19::Uint16(e19)
+                                }
+                                7 => {
+                                    let e19 = {
+                                        let l9 = *base.add(8).cast::<i32>();
+
+                                        l9 as u32
+                                    };
+                                    V19::Uint32(e19)
+                                }
+                                8 => {
+                                    let e19 = {
+                                        let l10 = *base.add(8).cast::<i64>();
+
+                                        l10 as u64
+                                    };
+                                    V19::Uint64(e19)
+                                }
+                                9 => {
+                                    let e19 = {
+                                        let l11 = *base.add(8).cast::<f32>();
+
+                                        l11
+                                    };
+                                    V19::Floating32(e19)
+                                }
+                                10 => {
+                                    let e19 = {
+                                        let l12 = *base.add(8).cast::<f64>();
+
+                                        l12
+                                    };
+                                    V19::Floating64(e19)
+                                }
+                                11 => {
+                                    let e19 = {
+                                        let l13 = *base.add(8).cast::<*mut u8>();
+                                        let l14 = *base.add(12).cast::<usize>();
+                                        let len15 = l14;
+                                        let bytes15 =
+                                            _rt::Vec::from_raw_parts(l13.cast(), len15, len15);
+
+                                        _rt::string_lift(bytes15)
+                                    };
+                                    V19::Str(e19)
+                                }
+                                12 => {
+                                    let e19 = {
+                                        let l16 = *base.add(8).cast::<*mut u8>();
+                                        let l17 = *base.add(12).cast::<usize>();
+                                        let len18 = l17;
+
+                                        _rt::Vec::from_raw_parts(l16.cast(), len18, len18)
+                                    };
+                                    V19::Binary(e19)
+                                }
+                                n => {
+                                    debug_assert_eq!(n, 13, "invalid enum discriminant");
+                                    V19::DbNull
+                                }
+                            };
+
+                            v19
+                        };
+                        result20.push(e20);
+                    }
+                    _rt::cabi_dealloc(base20, len20 * 16, 8);
+                    let result21 = T::query(
+                        ConnectionBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        result20,
+                    );
+                    let ptr22 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result21 {
+                        Ok(e) => {
+                            *ptr22.add(0).cast::<u8>() = (0i32) as u8;
+                            let super::super::super::super::exports::fermyon::spin::rdbms_types::RowSet{ columns:columns23, rows:rows23, } = e;
+                            let vec26 = columns23;
+                            let len26 = vec26.len();
+                            let layout26 =
+                                _rt::alloc::Layout::from_size_align_unchecked(vec26.len() * 12, 4);
+                            let result26 = if layout26.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout26).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout26);
+                                }
+                                ptr
+                            } else {
+                                {
+                                    ::core::ptr::null_mut()
+                                }
+                            };
+                            for (i, e) in vec26.into_iter().enumerate() {
+                                let base = result26.add(i * 12);
+                                {
+                                    let super::super::super::super::exports::fermyon::spin::rdbms_types::Column{ name:name24, data_type:data_type24, } = e;
+                                    let vec25 = (name24.into_bytes()).into_boxed_slice();
+                                    let ptr25 = vec25.as_ptr().cast::<u8>();
+                                    let len25 = vec25.len();
+                                    ::core::mem::forget(vec25);
+                                    *base.add(4).cast::<usize>() = len25;
+                                    *base.add(0).cast::<*mut u8>() = ptr25.cast_mut();
+                                    *base.add(8).cast::<u8>() = (data_type24.clone() as i32) as u8;
+                                }
+                            }
+                            *ptr22.add(8).cast::<usize>() = len26;
+                            *ptr22.add(4).cast::<*mut u8>() = result26;
+                            let vec31 = rows23;
+                            let len31 = vec31.len();
+                            let layout31 =
+                                _rt::alloc::Layout::from_size_align_unchecked(vec31.len() * 8, 4);
+                            let result31 = if layout31.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout31).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout31);
+                                }
+                                ptr
+                            } else {
+                                {
+                                    ::core::ptr::null_mut()
+                                }
+                            };
+                            for (i, e) in vec31.into_iter().enumerate() {
+                                let base = result31.add(i * 8);
+                                {
+                                    let vec30 = e;
+                                    let len30 = vec30.len();
+                                    let layout30 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec30.len() * 16,
+                                        8,
+                                    );
+                                    let result30 = if layout30.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout30).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout30);
+                                        }
+                                        ptr
+                                    } else {
+                                        {
+                                            ::core::ptr::null_mut()
+                                        }
+                                    };
+                                    for (i, e) in vec30.into_iter().enumerate() {
+                                        let base = result30.add(i * 16);
+                                        {
+                                            use super::super::super::super::exports::fermyon::spin::rdbms_types::DbValue as V29;
+                                            match e {
+                                                V29::Boolean(e) => {
+                                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                    *base.add(8).cast::<u8>() = (match e {
+                                                        true => 1,
+                                                        false => 0,
+                                                    })
+                                                        as u8;
+                                                }
+                                                V29::Int8(e) => {
+                                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(8).cast::<u8>() =
+                                                        (_rt::as_i32(e)) as u8;
+                                                }
+                                                V29::Int16(e) => {
+                                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                                    *base.add(8).cast::<u16>() =
+                                                        (_rt::as_i32(e)) as u16;
+                                                }
+                                                V29::Int32(e) => {
+                                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                    *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                V29::Int64(e) => {
+                                                    *base.add(0).cast::<u8>() = (4i32) as u8;
+                                                    *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                                }
+                                                V29::Uint8(e) => {
+                                                    *base.add(0).cast::<u8>() = (5i32) as u8;
+                                                    *base.add(8).cast::<u8>() =
+                                                        (_rt::as_i32(e)) as u8;
+                                                }
+                                                V29::Uint16(e) => {
+                                                    *base.add(0).cast::<u8>() = (6i32) as u8;
+                                                    *base.add(8).cast::<u16>() =
+                                                        (_rt::as_i32(e)) as u16;
+                                                }
+                                                V29::Uint32(e) => {
+                                                    *base.add(0).cast::<u8>() = (7i32) as u8;
+                                                    *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                V29::Uint64(e) => {
+                                                    *base.add(0).cast::<u8>() = (8i32) as u8;
+                                                    *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                                }
+                                                V29::Floating32(e) => {
+                                                    *base.add(0).cast::<u8>() = (9i32) as u8;
+                                                    *base.add(8).cast::<f32>() = _rt::as_f32(e);
+                                                }
+                                                V29::Floating64(e) => {
+                                                    *base.add(0).cast::<u8>() = (10i32) as u8;
+                                                    *base.add(8).cast::<f64>() = _rt::as_f64(e);
+                                                }
+                                                V29::Str(e) => {
+                                                    *base.add(0).cast::<u8>() = (11i32) as u8;
+                                                    let vec27 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                                                    let len27 = vec27.len();
+                                                    ::core::mem::forget(vec27);
+                                                    *base.add(12).cast::<usize>() = len27;
+                                                    *base.add(8).cast::<*mut u8>() =
+                                                        ptr27.cast_mut();
+                                                }
+                                                V29::Binary(e) => {
+                                                    *base.add(0).cast::<u8>() = (12i32) as u8;
+                                                    let vec28 = (e).into_boxed_slice();
+                                                    let ptr28 = vec28.as_ptr().cast::<u8>();
+                                                    let len28 = vec28.len();
+                                                    ::core::mem::forget(vec28);
+                                                    *base.add(12).cast::<usize>() = len28;
+                                                    *base.add(8).cast::<*mut u8>() =
+                                                        ptr28.cast_mut();
+                                                }
+                                                V29::DbNull => {
+                                                    *base.add(0).cast::<u8>() = (13i32) as u8;
+                                                }
+                                                V29::Unsupported => {
+                                                    *base.add(0).cast::<u8>() = (14i32) as u8;
+                                                }
+                                            }
+                                        }
+                                    }
+                                    *base.add(4).cast::<usize>() = len30;
+                                    *base.add(0).cast::<*mut u8>() = result30;
+                                }
+                            }
+                            *ptr22.add(16).cast::<usize>() = len31;
+                            *ptr22.add(12).cast::<*mut u8>() = result31;
+                        }
+                        Err(e) => {
+                            *ptr22.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::fermyon::spin::rdbms_types::Error as V37;
+                            match e {
+                                V37::ConnectionFailed(e) => {
+                                    *ptr22.add(4).cast::<u8>() = (0i32) as u8;
+                                    let vec32 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr32 = vec32.as_ptr().cast::<u8>();
+                                    let len32 = vec32.len();
+                                    ::core::mem::forget(vec32);
+                                    *ptr22.add(12).cast::<usize>() = len32;
+                                    *ptr22.add(8).cast::<*mut u8>() = ptr32.cast_mut();
+                                }
+                                V37::BadParameter(e) => {
+                                    *ptr22.add(4).cast::<u8>() = (1i32) as u8;
+                                    let vec33 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr33 = vec33.as_ptr().cast::<u8>();
+                                    let len33 = vec33.len();
+                                    ::core::mem::forget(vec33);
+                                    *ptr22.add(12).cast::<usize>() = len33;
+                                    *ptr22.add(8).cast::<*mut u8>() = ptr33.cast_mut();
+                                }
+                                V37::QueryFailed(e) => {
+                                    *ptr22.add(4).cast::<u8>() = (2i32) as u8;
+                                    let vec34 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr34 = vec34.as_ptr().cast::<u8>();
+                                    let len34 = vec34.len();
+                                    ::core::mem::forget(vec34);
+                                    *ptr22.add(12).cast::<usize>() = len34;
+                                    *ptr22.add(8).cast::<*mut u8>() = ptr34.cast_mut();
+                                }
+                                V37::ValueConversionFailed(e) => {
+                                    *ptr22.add(4).cast::<u8>() = (3i32) as u8;
+                                    let vec35 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr35 = vec35.as_ptr().cast::<u8>();
+                                    let len35 = vec35.len();
+                                    ::core::mem::forget(vec35);
+                                    *ptr22.add(12).cast::<usize>() = len35;
+                                    *ptr22.add(8).cast::<*mut u8>() = ptr35.cast_mut();
+                                }
+                                V37::Other(e) => {
+                                    *ptr22.add(4).cast::<u8>() = (4i32) as u8;
+                                    let vec36 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr36 = vec36.as_ptr().cast::<u8>();
+                                    let len36 = vec36.len();
+                                    ::core::mem::forget(vec36);
+                                    *ptr22.add(12).cast::<usize>() = len36;
+                                    *ptr22.add(8).cast::<*mut u8>() = ptr36.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr22
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_connection_query<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l3 = *arg0.add(4).cast::<*mut u8>();
+                            let l4 = *arg0.add(8).cast::<usize>();
+                            let base5 = l3;
+                            let len5 = l4;
+                            for i in 0..len5 {
+                                let base = base5.add(i * 12);
+                                {
+                                    let l1 = *base.add(0).cast::<*mut u8>();
+                                    let l2 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l1, l2, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base5, len5 * 12, 4);
+                            let l15 = *arg0.add(12).cast::<*mut u8>();
+                            let l16 = *arg0.add(16).cast::<usize>();
+                            let base17 = l15;
+                            let len17 = l16;
+                            for i in 0..len17 {
+                                let base = base17.add(i * 8);
+                                {
+                                    let l12 = *base.add(0).cast::<*mut u8>();
+                                    let l13 = *base.add(4).cast::<usize>();
+                                    let base14 = l12;
+                                    let len14 = l13;
+                                    for i in 0..len14 {
+                                        let base = base14.add(i * 16);
+                                        {
+                                            let l6 = i32::from(*base.add(0).cast::<u8>());
+                                            match l6 {
+                                                0 => (),
+                                                1 => (),
+                                                2 => (),
+                                                3 => (),
+                                                4 => (),
+                                                5 => (),
+                                                6 => (),
+                                                7 => (),
+                                                8 => (),
+                                                9 => (),
+                                                10 => (),
+                                                11 => {
+                                                    let l7 = *base.add(8).cast::<*mut u8>();
+                                                    let l8 = *base.add(12).cast::<usize>();
+                                                    _rt::cabi_dealloc(l7, l8, 1);
+                                                }
+                                                12 => {
+                                                    let l9 = *base.add(8).cast::<*mut u8>();
+                                                    let l10 = *base.add(12).cast::<usize>();
+                                                    let base11 = l9;
+                                                    let len11 = l10;
+                                                    _rt::cabi_dealloc(base11, len11 * 1, 1);
+                                                }
+                                                13 => (),
+                                                _ => (),
+                                            }
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base14, len14 * 16, 8);
+                                }
+                            }
+                            _rt::cabi_dealloc(base17, len17 * 8, 4);
+                        }
+                        _ => {
+                            let l18 = i32::from(*arg0.add(4).cast::<u8>());
+                            match l18 {
+                                0 => {
+                                    let l19 = *arg0.add(8).cast::<*mut u8>();
+                                    let l20 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l19, l20, 1);
+                                }
+                                1 => {
+                                    let l21 = *arg0.add(8).cast::<*mut u8>();
+                                    let l22 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l21, l22, 1);
+                                }
+                                2 => {
+                                    let l23 = *arg0.add(8).cast::<*mut u8>();
+                                    let l24 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l23, l24, 1);
+                                }
+                                3 => {
+                                    let l25 = *arg0.add(8).cast::<*mut u8>();
+                                    let l26 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l25, l26, 1);
+                                }
+                                _ => {
+                                    let l27 = *arg0.add(8).cast::<*mut u8>();
+                                    let l28 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l27, l28, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_execute_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let base20 = arg3;
+                    let len20 = arg4;
+                    let mut result20 = _rt::Vec::with_capacity(len20);
+                    for i in 0..len20 {
+                        let base = base20.add(i * 16);
+                        let e20 = {
+                            let l1 = i32::from(*base.add(0).cast::<u8>());
+                            use super::super::super::super::exports::fermyon::spin::rdbms_types::ParameterValue as V19;
+                            let v19 = match l1 {
+                                0 => {
+                                    let e19 = {
+                                        let l2 = i32::from(*base.add(8).cast::<u8>());
+
+                                        _rt::bool_lift(l2 as u8)
+                                    };
+                                    V19::Boolean(e19)
+                                }
+                                1 => {
+                                    let e19 = {
+                                        let l3 = i32::from(*base.add(8).cast::<i8>());
+
+                                        l3 as i8
+                                    };
+                                    V19::Int8(e19)
+                                }
+                                2 => {
+                                    let e19 = {
+                                        let l4 = i32::from(*base.add(8).cast::<i16>());
+
+                                        l4 as i16
+                                    };
+                                    V19::Int16(e19)
+                                }
+                                3 => {
+                                    let e19 = {
+                                        let l5 = *base.add(8).cast::<i32>();
+
+                                        l5
+                                    };
+                                    V19::Int32(e19)
+                                }
+                                4 => {
+                                    let e19 = {
+                                        let l6 = *base.add(8).cast::<i64>();
+
+                                        l6
+                                    };
+                                    V19::Int64(e19)
+                                }
+                                5 => {
+                                    let e19 = {
+                                        let l7 = i32::from(*base.add(8).cast::<u8>());
+
+                                        l7 as u8
+                                    };
+                                    V19::Uint8(e19)
+                                }
+                                6 => {
+                                    let e19 = {
+                                        let l8 = i32::from(*base.add(8).cast::<u16>());
+
+                                        l8 as u16
+                                    };
+                                    V19::Uint16(e19)
+                                }
+                                7 => {
+                                    let e19 = {
+                                        let l9 = *base.add(8).cast::<i32>();
+
+                                        l9 as u32
+                                    };
+                                    V19::Uint32(e19)
+                                }
+                                8 => {
+                                    let e19 = {
+                                        let l10 = *base.add(8).cast::<i64>();
+
+                                        l10 as u64
+                                    };
+                                    V19::Uint64(e19)
+                                }
+                                9 => {
+                                    let e19 = {
+                                        let l11 = *base.add(8).cast::<f32>();
+
+                                        l11
+                                    };
+                                    V19::Floating32(e19)
+                                }
+                                10 => {
+                                    let e19 = {
+                                        let l12 = *base.add(8).cast::<f64>();
+
+                                        l12
+                                    };
+                                    V19::Floating64(e19)
+                                }
+                                11 => {
+                                    let e19 = {
+                                        let l13 = *base.add(8).cast::<*mut u8>();
+                                        let l14 = *base.add(12).cast::<usize>();
+                                        let len15 = l14;
+                                        let bytes15 =
+                                            _rt::Vec::from_raw_parts(l13.cast(), len15, len15);
+
+                                        _rt::string_lift(bytes15)
+                                    };
+                                    V19::Str(e19)
+                                }
+                                12 => {
+                                    let e19 = {
+                                        let l16 = *base.add(8).cast::<*mut u8>();
+                                        let l17 = *base.add(12).cast::<usize>();
+                                        let len18 = l17;
+
+                                        _rt::Vec::from_raw_parts(l16.cast(), len18, len18)
+                                    };
+                                    V19::Binary(e19)
+                                }
+                                n => {
+                                    debug_assert_eq!(n, 13, "invalid enum discriminant");
+                                    V19::DbNull
+                                }
+                            };
+
+                            v19
+                        };
+                        result20.push(e20);
+                    }
+                    _rt::cabi_dealloc(base20, len20 * 16, 8);
+                    let result21 = T::execute(
+                        ConnectionBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        result20,
+                    );
+                    let ptr22 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result21 {
+                        Ok(e) => {
+                            *ptr22.add(0).cast::<u8>() = (0i32) as u8;
+                            *ptr22.add(8).cast::<i64>() = _rt::as_i64(e);
+                        }
+                        Err(e) => {
+                            *ptr22.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::fermyon::spin::rdbms_types::Error as V28;
+                            match e {
+                                V28::ConnectionFailed(e) => {
+                                    *ptr22.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec23 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr23 = vec23.as_ptr().cast::<u8>();
+                                    let len23 = vec23.len();
+                                    ::core::mem::forget(vec23);
+                                    *ptr22.add(16).cast::<usize>() = len23;
+                                    *ptr22.add(12).cast::<*mut u8>() = ptr23.cast_mut();
+                                }
+                                V28::BadParameter(e) => {
+                                    *ptr22.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec24 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr24 = vec24.as_ptr().cast::<u8>();
+                                    let len24 = vec24.len();
+                                    ::core::mem::forget(vec24);
+                                    *ptr22.add(16).cast::<usize>() = len24;
+                                    *ptr22.add(12).cast::<*mut u8>() = ptr24.cast_mut();
+                                }
+                                V28::QueryFailed(e) => {
+                                    *ptr22.add(8).cast::<u8>() = (2i32) as u8;
+                                    let vec25 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr25 = vec25.as_ptr().cast::<u8>();
+                                    let len25 = vec25.len();
+                                    ::core::mem::forget(vec25);
+                                    *ptr22.add(16).cast::<usize>() = len25;
+                                    *ptr22.add(12).cast::<*mut u8>() = ptr25.cast_mut();
+                                }
+                                V28::ValueConversionFailed(e) => {
+                                    *ptr22.add(8).cast::<u8>() = (3i32) as u8;
+                                    let vec26 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr26 = vec26.as_ptr().cast::<u8>();
+                                    let len26 = vec26.len();
+                                    ::core::mem::forget(vec26);
+                                    *ptr22.add(16).cast::<usize>() = len26;
+                                    *ptr22.add(12).cast::<*mut u8>() = ptr26.cast_mut();
+                                }
+                                V28::Other(e) => {
+                                    *ptr22.add(8).cast::<u8>() = (4i32) as u8;
+                                    let vec27 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                                    let len27 = vec27.len();
+                                    ::core::mem::forget(vec27);
+                                    *ptr22.add(16).cast::<usize>() = len27;
+                                    *ptr22.add(12).cast::<*mut u8>() = ptr27.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr22
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_connection_execute<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => {
+                                    let l2 = *arg0.add(12).cast::<*mut u8>();
+                                    let l3 = *arg0.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                1 => {
+                                    let l4 = *arg0.add(12).cast::<*mut u8>();
+                                    let l5 = *arg0.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                2 => {
+                                    let l6 = *arg0.add(12).cast::<*mut u8>();
+                                    let l7 = *arg0.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                3 => {
+                                    let l8 = *arg0.add(12).cast::<*mut u8>();
+                                    let l9 = *arg0.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                _ => {
+                                    let l10 = *arg0.add(12).cast::<*mut u8>();
+                                    let l11 = *arg0.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_connection_execute_batch_cabi<T: GuestConnection>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::execute_batch(
+                        ConnectionBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(_) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::exports::fermyon::spin::rdbms_types::Error as V8;
+                            match e {
+                                V8::ConnectionFailed(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (0i32) as u8;
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr2.add(12).cast::<usize>() = len3;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                                }
+                                V8::BadParameter(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (1i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr2.add(12).cast::<usize>() = len4;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                                V8::QueryFailed(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (2i32) as u8;
+                                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr2.add(12).cast::<usize>() = len5;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                                }
+                                V8::ValueConversionFailed(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (3i32) as u8;
+                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr2.add(12).cast::<usize>() = len6;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                                V8::Other(e) => {
+                                    *ptr2.add(4).cast::<u8>() = (4i32) as u8;
+                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr2.add(12).cast::<usize>() = len7;
+                                    *ptr2.add(8).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_connection_execute_batch<T: GuestConnection>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(4).cast::<u8>());
+                            match l1 {
+                                0 => {
+                                    let l2 = *arg0.add(8).cast::<*mut u8>();
+                                    let l3 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                1 => {
+                                    let l4 = *arg0.add(8).cast::<*mut u8>();
+                                    let l5 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                2 => {
+                                    let l6 = *arg0.add(8).cast::<*mut u8>();
+                                    let l7 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                3 => {
+                                    let l8 = *arg0.add(8).cast::<*mut u8>();
+                                    let l9 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                _ => {
+                                    let l10 = *arg0.add(8).cast::<*mut u8>();
+                                    let l11 = *arg0.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                pub trait Guest {
+                    type Connection: GuestConnection;
+                }
+                pub trait GuestConnection: 'static {
+                    #[doc(hidden)]
+                    unsafe fn _resource_new(val: *mut u8) -> u32
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = val;
+                            unreachable!();
+                        }
+
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]fermyon:spin-test-virt/mysql")]
+                            extern "C" {
+                                #[link_name = "[resource-new]connection"]
+                                fn new(_: *mut u8) -> u32;
+                            }
+                            new(val)
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    fn _resource_rep(handle: u32) -> *mut u8
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = handle;
+                            unreachable!();
+                        }
+
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]fermyon:spin-test-virt/mysql")]
+                            extern "C" {
+                                #[link_name = "[resource-rep]connection"]
+                                fn rep(_: u32) -> *mut u8;
+                            }
+                            unsafe { rep(handle) }
+                        }
+                    }
+
+                    /// Open a connection to the MySQL database at `address`.
+                    fn open(address: _rt::String) -> Result<Connection, Error>;
+                    /// Get the address of the database
+                    fn address(&self) -> _rt::String;
+                    /// Query the database.
+                    fn query(
+                        &self,
+                        statement: _rt::String,
+                        params: _rt::Vec<ParameterValue>,
+                    ) -> Result<RowSet, Error>;
+                    /// Execute a statement returning the number of changed rows.
+                    fn execute(
+                        &self,
+                        statement: _rt::String,
+                        params: _rt::Vec<ParameterValue>,
+                    ) -> Result<u64, Error>;
+                    /// Execute a batch of semicolon separated statements (e.g., a schema file)
+                    fn execute_batch(&self, statements: _rt::String) -> Result<(), Error>;
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_fermyon_spin_test_virt_mysql_cabi{
+  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+    #[export_name = "fermyon:spin-test-virt/mysql#[static]connection.open"]
+    unsafe extern "C" fn export_static_connection_open(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_static_connection_open_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/mysql#[static]connection.open"]
+    unsafe extern "C" fn _post_return_static_connection_open(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_static_connection_open::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/mysql#[method]connection.address"]
+    unsafe extern "C" fn export_method_connection_address(arg0: *mut u8,) -> *mut u8 {
+      $($path_to_types)*::_export_method_connection_address_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/mysql#[method]connection.address"]
+    unsafe extern "C" fn _post_return_method_connection_address(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_connection_address::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/mysql#[method]connection.query"]
+    unsafe extern "C" fn export_method_connection_query(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: *mut u8,arg4: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_connection_query_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1, arg2, arg3, arg4)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/mysql#[method]connection.query"]
+    unsafe extern "C" fn _post_return_method_connection_query(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_connection_query::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/mysql#[method]connection.execute"]
+    unsafe extern "C" fn export_method_connection_execute(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: *mut u8,arg4: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_connection_execute_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1, arg2, arg3, arg4)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/mysql#[method]connection.execute"]
+    unsafe extern "C" fn _post_return_method_connection_execute(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_connection_execute::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/mysql#[method]connection.execute-batch"]
+    unsafe extern "C" fn export_method_connection_execute_batch(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_connection_execute_batch_cabi::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0, arg1, arg2)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/mysql#[method]connection.execute-batch"]
+    unsafe extern "C" fn _post_return_method_connection_execute_batch(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_connection_execute_batch::<<$ty as $($path_to_types)*::Guest>::Connection>(arg0)
+    }
+
+    const _: () = {
+      #[doc(hidden)]
+      #[export_name = "fermyon:spin-test-virt/mysql#[dtor]connection"]
+      #[allow(non_snake_case)]
+      unsafe extern "C" fn dtor(rep: *mut u8) {
+        $($path_to_types)*::Connection::dtor::<
+        <$ty as $($path_to_types)*::Guest>::Connection
+        >(rep)
+      }
+    };
+
+  };);
+}
+                #[doc(hidden)]
+                pub(crate) use __export_fermyon_spin_test_virt_mysql_cabi;
+                #[repr(align(8))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 24]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 24]);
+            }
+
+            #[allow(dead_code, clippy::all)]
+            pub mod rdbms {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type ParameterValue =
+                    super::super::super::super::exports::fermyon::spin::rdbms_types::ParameterValue;
+                pub type RowSet =
+                    super::super::super::super::exports::fermyon::spin::rdbms_types::RowSet;
+                pub type Error =
+                    super::super::super::super::exports::fermyon::spin::rdbms_types::Error;
+                pub type Matcher =
+                    super::super::super::super::exports::fermyon::spin_test_virt::types::Matcher;
+                /// What a rule returns instead of running the statement
+                #[derive(Clone)]
+                pub enum RuleResponse {
+                    /// Fail the statement with the given error
+                    Error(Error),
+                    /// Return the given rows
+                    ///
+                    /// When returned from an execute, the number of rows is the number of changed rows.
+                    Rows(RowSet),
+                    /// Report the given number of changed rows
+                    ///
+                    /// When returned from a query, no rows are returned.
+                    Changed(u64),
+                }
+                impl ::core::fmt::Debug for RuleResponse {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            RuleResponse::Error(e) => {
+                                f.debug_tuple("RuleResponse::Error").field(e).finish()
+                            }
+                            RuleResponse::Rows(e) => {
+                                f.debug_tuple("RuleResponse::Rows").field(e).finish()
+                            }
+                            RuleResponse::Changed(e) => {
+                                f.debug_tuple("RuleResponse::Changed").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                /// A statement run through the postgres or mysql interface
+                #[derive(Clone)]
+                pub struct Call {
+                    /// The statement that was run
+                    pub statement: _rt::String,
+                    /// The parameters the statement was run with
+                    pub parameters: _rt::Vec<ParameterValue>,
+                    /// The number of rows returned by a query, or changed by an execute, or the error it failed with
+                    pub result: Result<u64, Error>,
+                }
+                impl ::core::fmt::Debug for Call {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Call")
+                            .field("statement", &self.statement)
+                            .field("parameters", &self.parameters)
+                            .field("result", &self.result)
+                            .finish()
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_add_rule_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i32,
+                    arg4: *mut u8,
+                    arg5: usize,
+                    arg6: i32,
+                    arg7: *mut u8,
+                    arg8: usize,
+                    arg9: i32,
+                    arg10: ::core::mem::MaybeUninit<u64>,
+                    arg11: *mut u8,
+                    arg12: *mut u8,
+                    arg13: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    use super::super::super::super::exports::fermyon::spin_test_virt::types::Matcher as V4;
+                    let v4 = match arg3 {
+                        0 => {
+                            let e4 = {
+                                let len1 = arg5;
+                                let bytes1 = _rt::Vec::from_raw_parts(arg4.cast(), len1, len1);
+
+                                _rt::string_lift(bytes1)
+                            };
+                            V4::Exact(e4)
+                        }
+                        1 => {
+                            let e4 = {
+                                let len2 = arg5;
+                                let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
+
+                                _rt::string_lift(bytes2)
+                            };
+                            V4::Prefix(e4)
+                        }
+                        n => {
+                            debug_assert_eq!(n, 2, "invalid enum discriminant");
+                            let e4 = {
+                                let len3 = arg5;
+                                let bytes3 = _rt::Vec::from_raw_parts(arg4.cast(), len3, len3);
+
+                                _rt::string_lift(bytes3)
+                            };
+                            V4::Regex(e4)
+                        }
+                    };
+                    let v59 = match arg9 {
+                        0 => {
+                            let e59 = {
+                                use super::super::super::super::exports::fermyon::spin::rdbms_types::Error as V30;
+                                let v30 = match arg10.assume_init() as i64 as i32 {
+                                    0 => {
+                                        let e30 = {
+                                            let len25 = arg12 as usize;
+                                            let bytes25 = _rt::Vec::from_raw_parts(
+                                                arg11.cast(),
+                                                len25,
+                                                len25,
+                                            );
+
+                                            _rt::string_lift(bytes25)
+                                        };
+                                        V30::ConnectionFailed(e30)
+                                    }
+                                    1 => {
+                                        let e30 = {
+                                            let len26 = arg12 as usize;
+                                            let bytes26 = _rt::Vec::from_raw_parts(
+                                                arg11.cast(),
+                                                len26,
+                                                len26,
+                                            );
+
+                                            _rt::string_lift(bytes26)
+                                        };
+                                        V30::BadParameter(e30)
+                                    }
+                                    2 => {
+                                        let e30 = {
+                                            let len27 = arg12 as usize;
+                                            let bytes27 = _rt::Vec::from_raw_parts(
+                                                arg11.cast(),
+                                                len27,
+                                                len27,
+                                            );
+
+                                            _rt::string_lift(bytes27)
+                                        };
+                                        V30::QueryFailed(e30)
+                                    }
+                                    3 => {
+                                        let e30 = {
+                                            let len28 = arg12 as usize;
+                                            let bytes28 = _rt::Vec::from_raw_parts(
+                                                arg11.cast(),
+                                                len28,
+                                                len28,
+                                            );
+
+                                            _rt::string_lift(bytes28)
+                                        };
+                                        V30::ValueConversionFailed(e30)
+                                    }
+                                    n => {
+                                        debug_assert_eq!(n, 4, "invalid enum discriminant");
+                                        let e30 = {
+                                            let len29 = arg12 as usize;
+                                            let bytes29 = _rt::Vec::from_raw_parts(
+                                                arg11.cast(),
+                                                len29,
+                                                len29,
+                                            );
+
+                                            _rt::string_lift(bytes29)
+                                        };
+                                        V30::Other(e30)
+                                    }
+                                };
+
+                                v30
+                            };
+                            RuleResponse::Error(e59)
+                        }
+                        1 => {
+                            let e59 = {
+                                let base35 = arg10.as_ptr().cast::<*mut u8>().read();
+                                let len35 = arg11 as usize;
+                                let mut result35 = _rt::Vec::with_capacity(len35);
+                                for i in 0..len35 {
+                                    let base = base35.add(i * 12);
+                                    let e35 = {
+                                        let l31 = *base.add(0).cast::<*mut u8>();
+                                        let l32 = *base.add(4).cast::<usize>();
+                                        let len33 = l32;
+                                        let bytes33 =
+                                            _rt::Vec::from_raw_parts(l31.cast(), len33, len33);
+                                        let l34 = i32::from(*base.add(8).cast::<u8>());
+
+                                        super::super::super::super::exports::fermyon::spin::rdbms_types::Column{
+              name: _rt::string_lift(bytes33),
+              data_type: super::super::super::super::exports::fermyon::spin::rdbms_types::DbDataType::_lift(l34 as u8),
+            }
+                                    };
+                                    result35.push(e35);
+                                }
+                                _rt::cabi_dealloc(base35, len35 * 12, 4);
+                                let base58 = arg12;
+                                let len58 = arg13;
+                                let mut result58 = _rt::Vec::with_capacity(len58);
+                                for i in 0..len58 {
+                                    let base = base58.add(i * 8);
+                                    let e58 = {
+                                        let l36 = *base.add(0).cast::<*mut u8>();
+                                        let l37 = *base.add(4).cast::<usize>();
+                                        let base57 = l36;
+                                        let len57 = l37;
+                                        let mut result57 = _rt::Vec::with_capacity(len57);
+                                        for i in 0..len57 {
+                                            let base = base57.add(i * 16);
+                                            let e57 = {
+                                                let l38 = i32::from(*base.add(0).cast::<u8>());
+                                                use super::super::super::super::exports::fermyon::spin::rdbms_types::DbValue as V56;
+                                                let v56 = match l38 {
+                                                    0 => {
+                                                        let e56 = {
+                                                            let l39 = i32::from(
+                                                                *base.add(8).cast::<u8>(),
+                                                            );
+
+                                                            _rt::bool_lift(l39 as u8)
+                                                        };
+                                                        V56::Boolean(e56)
+                                                    }
+                                                    1 => {
+                                                        let e56 = {
+                                                            let l40 = i32::from(
+                                                                *base.add(8).cast::<i8>(),
+                                                            );
+
+                                                            l40 as i8
+                                                        };
+                                                        V56::Int8(e56)
+                                                    }
+                                                    2 => {
+                                                        let e56 = {
+                                                            let l41 = i32::from(
+                                                                *base.add(8).cast::<i16>(),
+                                                            );
+
+                                                            l41 as i16
+                                                        };
+                                                        V56::Int16(e56)
+                                                    }
+                                                    3 => {
+                                                        let e56 = {
+                                                            let l42 = *base.add(8).cast::<i32>();
+
+                                                            l42
+                                                        };
+                                                        V56::Int32(e56)
+                                                    }
+                                                    4 => {
+                                                        let e56 = {
+                                                            let l43 = *base.add(8).cast::<i64>();
+
+                                                            l43
+                                                        };
+                                                        V56::Int64(e56)
+                                                    }
+                                                    5 => {
+                                                        let e56 = {
+                                                            let l44 = i32::from(
+                                                                *base.add(8).cast::<u8>(),
+                                                            );
+
+                                                            l44 as u8
+                                                        };
+                                                        V56::Uint8(e56)
+                                                    }
+                                                    6 => {
+                                                        let e56 = {
+                                                            let l45 = i32::from(
+                                                                *base.add(8).cast::<u16>(),
+                                                            );
+
+                                                            l45 as u16
+                                                        };
+                                                        V56::Uint16(e56)
+                                                    }
+                                                    7 => {
+                                                        let e56 = {
+                                                            let l46 = *base.add(8).cast::<i32>();
+
+                                                            l46 as u32
+                                                        };
+                                                        V56::Uint32(e56)
+                                                    }
+                                                    8 => {
+                                                        let e56 = {
+                                                            let l47 = *base.add(8).cast::<i64>();
+
+                                                            l47 as u64
+                                                        };
+                                                        V56::Uint64(e56)
+                                                    }
+                                                    9 => {
+                                                        let e56 = {
+                                                            let l48 = *base.add(8).cast::<f32>();
+
+                                                            l48
+                                                        };
+                                                        V56::Floating32(e56)
+                                                    }
+                                                    10 => {
+                                                        let e56 = {
+                                                            let l49 = *base.add(8).cast::<f64>();
+
+                                                            l49
+                                                        };
+                                                        V56::Floating64(e56)
+                                                    }
+                                                    11 => {
+                                                        let e56 = {
+                                                            let l50 =
+                                                                *base.add(8).cast::<*mut u8>();
+                                                            let l51 = *base.add(12).cast::<usize>();
+                                                            let len52 = l51;
+                                                            let bytes52 = _rt::Vec::from_raw_parts(
+                                                                l50.cast(),
+                                                                len52,
+                                                                len52,
+                                                            );
+
+                                                            _rt::string_lift(bytes52)
+                                                        };
+                                                        V56::Str(e56)
+                                                    }
+                                                    12 => {
+                                                        let e56 = {
+                                                            let l53 =
+                                                                *base.add(8).cast::<*mut u8>();
+                                                            let l54 = *base.add(12).cast::<usize>();
+                                                            let len55 = l54;
+
+                                                            _rt::Vec::from_raw_parts(
+                                                                l53.cast(),
+                                                                len55,
+                                                                len55,
+                                                            )
+                                                        };
+                                                        V56::Binary(e56)
+                                                    }
+                                                    13 => V56::DbNull,
+                                                    n => {
+                                                        debug_assert_eq!(
+                                                            n, 14,
+                                                            "invalid enum discriminant"
+                                                        );
+                                                        V56::Unsupported
+                                                    }
+                                                };
+
+                                                v56
+                                            };
+                                            result57.push(e57);
+                                        }
+                                        _rt::cabi_dealloc(base57, len57 * 16, 8);
+
+                                        result57
+                                    };
+                                    result58.push(e58);
+                                }
+                                _rt::cabi_dealloc(base58, len58 * 8, 4);
+
+                                super::super::super::super::exports::fermyon::spin::rdbms_types::RowSet{
+          columns: result35,
+          rows: result58,
+        }
+                            };
+                            RuleResponse::Rows(e59)
+                        }
+                        n => {
+                            debug_assert_eq!(n, 2, "invalid enum discriminant");
+                            let e59 = arg10.assume_init() as i64 as u64;
+                            RuleResponse::Changed(e59)
+                        }
+                    };
+                    let result60 = T::add_rule(
+                        match arg0 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len0 = arg2;
+                                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+
+                                    _rt::string_lift(bytes0)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        v4,
+                        match arg6 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let base24 = arg7;
+                                    let len24 = arg8;
+                                    let mut result24 = _rt::Vec::with_capacity(len24);
+                                    for i in 0..len24 {
+                                        let base = base24.add(i * 16);
+                                        let e24 = {
+                                            let l5 = i32::from(*base.add(0).cast::<u8>());
+                                            use super::super::super::super::exports::fermyon::spin::rdbms_types::ParameterValue as V23;
+                                            let v23 = match l5 {
+                                                0 => {
+                                                    let e23 = {
+                                                        let l6 =
+                                                            i32::from(*base.add(8).cast::<u8>());
+
+                                                        _rt::bool_lift(l6 as u8)
+                                                    };
+                                                    V23::Boolean(e23)
+                                                }
+                                                1 => {
+                                                    let e23 = {
+                                                        let l7 =
+                                                            i32::from(*base.add(8).cast::<i8>());
+
+                                                        l7 as i8
+                                                    };
+                                                    V23::Int8(e23)
+                                                }
+                                                2 => {
+                                                    let e23 = {
+                                                        let l8 =
+                                                            i32::from(*base.add(8).cast::<i16>());
+
+                                                        l8 as i16
+                                                    };
+                                                    V23::Int16(e23)
+                                                }
+                                                3 => {
+                                                    let e23 = {
+                                                        let l9 = *base.add(8).cast::<i32>();
+
+                                                        l9
+                                                    };
+                                                    V23::Int32(e23)
+                                                }
+                                                4 => {
+                                                    let e23 = {
+                                                        let l10 = *base.add(8).cast::<i64>();
+
+                                                        l10
+                                                    };
+                                                    V23::Int64(e23)
+                                                }
+                                                5 => {
+                                                    let e23 = {
+                                                        let l11 =
+                                                            i32::from(*base.add(8).cast::<u8>());
+
+                                                        l11 as u8
+                                                    };
+                                                    V23::Uint8(e23)
+                                                }
+                                                6 => {
+                                                    let e23 = {
+                                                        let l12 =
+                                                            i32::from(*base.add(8).cast::<u16>());
+
+                                                        l12 as u16
+                                                    };
+                                                    V23::Uint16(e23)
+                                                }
+                                                7 => {
+                                                    let e23 = {
+                                                        let l13 = *base.add(8).cast::<i32>();
+
+                                                        l13 as u32
+                                                    };
+                                                    V23::Uint32(e23)
+                                                }
+                                                8 => {
+                                                    let e23 = {
+                                                        let l14 = *base.add(8).cast::<i64>();
+
+                                                        l14 as u64
+                                                    };
+                                                    V23::Uint64(e23)
+                                                }
+                                                9 => {
+                                                    let e23 = {
+                                                        let l15 = *base.add(8).cast::<f32>();
+
+                                                        l15
+                                                    };
+                                                    V23::Floating32(e23)
+                                                }
+                                                10 => {
+                                                    let e23 = {
+                                                        let l16 = *base.add(8).cast::<f64>();
+
+                                                        l16
+                                                    };
+                                                    V23::Floating64(e23)
+                                                }
+                                                11 => {
+                                                    let e23 = {
+                                                        let l17 = *base.add(8).cast::<*mut u8>();
+                                                        let l18 = *base.add(12).cast::<usize>();
+                                                        let len19 = l18;
+                                                        let bytes19 = _rt::Vec::from_raw_parts(
+                                                            l17.cast(),
+                                                            len19,
+                                                            len19,
+                                                        );
+
+                                                        _rt::string_lift(bytes19)
+                                                    };
+                                                    V23::Str(e23)
+                                                }
+                                                12 => {
+                                                    let e23 = {
+                                                        let l20 = *base.add(8).cast::<*mut u8>();
+                                                        let l21 = *base.add(12).cast::<usize>();
+                                                        let len22 = l21;
+
+                                                        _rt::Vec::from_raw_parts(
+                                                            l20.cast(),
+                                                            len22,
+                                                            len22,
+                                                        )
+                                                    };
+                                                    V23::Binary(e23)
+                                                }
+                                                n => {
+                                                    debug_assert_eq!(
+                                                        n, 13,
+                                                        "invalid enum discriminant"
+                                                    );
+                                                    V23::DbNull
+                                                }
+                                            };
+
+                                            v23
+                                        };
+                                        result24.push(e24);
+                                    }
+                                    _rt::cabi_dealloc(base24, len24 * 16, 8);
+
+                                    result24
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        v59,
+                    );
+                    let ptr61 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result60 {
+                        Ok(_) => {
+                            *ptr61.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr61.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec62 = (e.into_bytes()).into_boxed_slice();
+                            let ptr62 = vec62.as_ptr().cast::<u8>();
+                            let len62 = vec62.len();
+                            ::core::mem::forget(vec62);
+                            *ptr61.add(8).cast::<usize>() = len62;
+                            *ptr61.add(4).cast::<*mut u8>() = ptr62.cast_mut();
+                        }
+                    };
+                    ptr61
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_add_rule<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = *arg0.add(4).cast::<*mut u8>();
+                            let l2 = *arg0.add(8).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_clear_rules_cabi<T: Guest>() {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::clear_rules();
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_calls_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::calls();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec17 = result0;
+                    let len17 = vec17.len();
+                    let layout17 =
+                        _rt::alloc::Layout::from_size_align_unchecked(vec17.len() * 16, 4);
+                    let result17 = if layout17.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout17).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout17);
+                        }
+                        ptr
+                    } else {
+                        {
+                            ::core::ptr::null_mut()
+                        }
+                    };
+                    for (i, e) in vec17.into_iter().enumerate() {
+                        let base = result17.add(i * 16);
+                        {
+                            let (t2_0, t2_1) = e;
+                            let vec3 = (t2_0.into_bytes()).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *base.add(4).cast::<usize>() = len3;
+                            *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                            let vec16 = t2_1;
+                            let len16 = vec16.len();
+                            let layout16 =
+                                _rt::alloc::Layout::from_size_align_unchecked(vec16.len() * 40, 8);
+                            let result16 = if layout16.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout16).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout16);
+                                }
+                                ptr
+                            } else {
+                                {
+                                    ::core::ptr::null_mut()
+                                }
+                            };
+                            for (i, e) in vec16.into_iter().enumerate() {
+                                let base = result16.add(i * 40);
+                                {
+                                    let Call {
+                                        statement: statement4,
+                                        parameters: parameters4,
+                                        result: result4,
+                                    } = e;
+                                    let vec5 = (statement4.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *base.add(4).cast::<usize>() = len5;
+                                    *base.add(0).cast::<*mut u8>() = ptr5.cast_mut();
+                                    let vec9 = parameters4;
+                                    let len9 = vec9.len();
+                                    let layout9 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec9.len() * 16,
+                                        8,
+                                    );
+                                    let result9 = if layout9.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout9).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout9);
+                                        }
+                                        ptr
+                                    } else {
+                                        {
+                                            ::core::ptr::null_mut()
+                                        }
+                                    };
+                                    for (i, e) in vec9.into_iter().enumerate() {
+                                        let base = result9.add(i * 16);
+                                        {
+                                            use super::super::super::super::exports::fermyon::spin::rdbms_types::ParameterValue as V8;
+                                            match e {
+                                                V8::Boolean(e) => {
+                                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                    *base.add(8).cast::<u8>() = (match e {
+                                                        true => 1,
+                                                        false => 0,
+                                                    })
+                                                        as u8;
+                                                }
+                                                V8::Int8(e) => {
+                                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(8).cast::<u8>() =
+                                                        (_rt::as_i32(e)) as u8;
+                                                }
+                                                V8::Int16(e) => {
+                                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                                    *base.add(8).cast::<u16>() =
+                                                        (_rt::as_i32(e)) as u16;
+                                                }
+                                                V8::Int32(e) => {
+                                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                    *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                V8::Int64(e) => {
+                                                    *base.add(0).cast::<u8>() = (4i32) as u8;
+                                                    *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                                }
+                                                V8::Uint8(e) => {
+                                                    *base.add(0).cast::<u8>() = (5i32) as u8;
+                                                    *base.add(8).cast::<u8>() =
+                                                        (_rt::as_i32(e)) as u8;
+                                                }
+                                                V8::Uint16(e) => {
+                                                    *base.add(0).cast::<u8>() = (6i32) as u8;
+                                                    *base.add(8).cast::<u16>() =
+                                                        (_rt::as_i32(e)) as u16;
+                                                }
+                                                V8::Uint32(e) => {
+                                                    *base.add(0).cast::<u8>() = (7i32) as u8;
+                                                    *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                V8::Uint64(e) => {
+                                                    *base.add(0).cast::<u8>() = (8i32) as u8;
+                                                    *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                                }
+                                                V8::Floating32(e) => {
+                                                    *base.add(0).cast::<u8>() = (9i32) as u8;
+                                                    *base.add(8).cast::<f32>() = _rt::as_f32(e);
+                                                }
+                                                V8::Floating64(e) => {
+                                                    *base.add(0).cast::<u8>() = (10i32) as u8;
+                                                    *base.add(8).cast::<f64>() = _rt::as_f64(e);
+                                                }
+                                                V8::Str(e) => {
+                                                    *base.add(0).cast::<u8>() = (11i32) as u8;
+                                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                                    let len6 = vec6.len();
+                                                    ::core::mem::forget(vec6);
+                                                    *base.add(12).cast::<usize>() = len6;
+                                                    *base.add(8).cast::<*mut u8>() =
+                                                        ptr6.cast_mut();
+                                                }
+                                                V8::Binary(e) => {
+                                                    *base.add(0).cast::<u8>() = (12i32) as u8;
+                                                    let vec7 = (e).into_boxed_slice();
+                                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                                    let len7 = vec7.len();
+                                                    ::core::mem::forget(vec7);
+                                                    *base.add(12).cast::<usize>() = len7;
+                                                    *base.add(8).cast::<*mut u8>() =
+                                                        ptr7.cast_mut();
+                                                }
+                                                V8::DbNull => {
+                                                    *base.add(0).cast::<u8>() = (13i32) as u8;
+                                                }
+                                            }
+                                        }
+                                    }
+                                    *base.add(12).cast::<usize>() = len9;
+                                    *base.add(8).cast::<*mut u8>() = result9;
+                                    match result4 {
+                                        Ok(e) => {
+                                            *base.add(16).cast::<u8>() = (0i32) as u8;
+                                            *base.add(24).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        Err(e) => {
+                                            *base.add(16).cast::<u8>() = (1i32) as u8;
+                                            use super::super::super::super::exports::fermyon::spin::rdbms_types::Error as V15;
+                                            match e {
+                                                V15::ConnectionFailed(e) => {
+                                                    *base.add(24).cast::<u8>() = (0i32) as u8;
+                                                    let vec10 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                                    let len10 = vec10.len();
+                                                    ::core::mem::forget(vec10);
+                                                    *base.add(32).cast::<usize>() = len10;
+                                                    *base.add(28).cast::<*mut u8>() =
+                                                        ptr10.cast_mut();
+                                                }
+                                                V15::BadParameter(e) => {
+                                                    *base.add(24).cast::<u8>() = (1i32) as u8;
+                                                    let vec11 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                                    let len11 = vec11.len();
+                                                    ::core::mem::forget(vec11);
+                                                    *base.add(32).cast::<usize>() = len11;
+                                                    *base.add(28).cast::<*mut u8>() =
+                                                        ptr11.cast_mut();
+                                                }
+                                                V15::QueryFailed(e) => {
+                                                    *base.add(24).cast::<u8>() = (2i32) as u8;
+                                                    let vec12 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                                    let len12 = vec12.len();
+                                                    ::core::mem::forget(vec12);
+                                                    *base.add(32).cast::<usize>() = len12;
+                                                    *base.add(28).cast::<*mut u8>() =
+                                                        ptr12.cast_mut();
+                                                }
+                                                V15::ValueConversionFailed(e) => {
+                                                    *base.add(24).cast::<u8>() = (3i32) as u8;
+                                                    let vec13 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                                    let len13 = vec13.len();
+                                                    ::core::mem::forget(vec13);
+                                                    *base.add(32).cast::<usize>() = len13;
+                                                    *base.add(28).cast::<*mut u8>() =
+                                                        ptr13.cast_mut();
+                                                }
+                                                V15::Other(e) => {
+                                                    *base.add(24).cast::<u8>() = (4i32) as u8;
+                                                    let vec14 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                                    let len14 = vec14.len();
+                                                    ::core::mem::forget(vec14);
+                                                    *base.add(32).cast::<usize>() = len14;
+                                                    *base.add(28).cast::<*mut u8>() =
+                                                        ptr14.cast_mut();
+                                                }
+                                            }
+                                        }
+                                    };
+                                }
+                            }
+                            *base.add(12).cast::<usize>() = len16;
+                            *base.add(8).cast::<*mut u8>() = result16;
+                        }
+                    }
+                    *ptr1.add(4).cast::<usize>() = len17;
+                    *ptr1.add(0).cast::<*mut u8>() = result17;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_calls<T: Guest>(arg0: *mut u8) {
+                    let l28 = *arg0.add(0).cast::<*mut u8>();
+                    let l29 = *arg0.add(4).cast::<usize>();
+                    let base30 = l28;
+                    let len30 = l29;
+                    for i in 0..len30 {
+                        let base = base30.add(i * 16);
+                        {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            _rt::cabi_dealloc(l0, l1, 1);
+                            let l25 = *base.add(8).cast::<*mut u8>();
+                            let l26 = *base.add(12).cast::<usize>();
+                            let base27 = l25;
+                            let len27 = l26;
+                            for i in 0..len27 {
+                                let base = base27.add(i * 40);
+                                {
+                                    let l2 = *base.add(0).cast::<*mut u8>();
+                                    let l3 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                    let l10 = *base.add(8).cast::<*mut u8>();
+                                    let l11 = *base.add(12).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    for i in 0..len12 {
+                                        let base = base12.add(i * 16);
+                                        {
+                                            let l4 = i32::from(*base.add(0).cast::<u8>());
+                                            match l4 {
+                                                0 => (),
+                                                1 => (),
+                                                2 => (),
+                                                3 => (),
+                                                4 => (),
+                                                5 => (),
+                                                6 => (),
+                                                7 => (),
+                                                8 => (),
+                                                9 => (),
+                                                10 => (),
+                                                11 => {
+                                                    let l5 = *base.add(8).cast::<*mut u8>();
+                                                    let l6 = *base.add(12).cast::<usize>();
+                                                    _rt::cabi_dealloc(l5, l6, 1);
+                                                }
+                                                12 => {
+                                                    let l7 = *base.add(8).cast::<*mut u8>();
+                                                    let l8 = *base.add(12).cast::<usize>();
+                                                    let base9 = l7;
+                                                    let len9 = l8;
+                                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                                }
+                                                _ => (),
+                                            }
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base12, len12 * 16, 8);
+                                    let l13 = i32::from(*base.add(16).cast::<u8>());
+                                    match l13 {
+                                        0 => (),
+                                        _ => {
+                                            let l14 = i32::from(*base.add(24).cast::<u8>());
+                                            match l14 {
+                                                0 => {
+                                                    let l15 = *base.add(28).cast::<*mut u8>();
+                                                    let l16 = *base.add(32).cast::<usize>();
+                                                    _rt::cabi_dealloc(l15, l16, 1);
+                                                }
+                                                1 => {
+                                                    let l17 = *base.add(28).cast::<*mut u8>();
+                                                    let l18 = *base.add(32).cast::<usize>();
+                                                    _rt::cabi_dealloc(l17, l18, 1);
+                                                }
+                                                2 => {
+                                                    let l19 = *base.add(28).cast::<*mut u8>();
+                                                    let l20 = *base.add(32).cast::<usize>();
+                                                    _rt::cabi_dealloc(l19, l20, 1);
+                                                }
+                                                3 => {
+                                                    let l21 = *base.add(28).cast::<*mut u8>();
+                                                    let l22 = *base.add(32).cast::<usize>();
+                                                    _rt::cabi_dealloc(l21, l22, 1);
+                                                }
+                                                _ => {
+                                                    let l23 = *base.add(28).cast::<*mut u8>();
+                                                    let l24 = *base.add(32).cast::<usize>();
+                                                    _rt::cabi_dealloc(l23, l24, 1);
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            _rt::cabi_dealloc(base27, len27 * 40, 8);
+                        }
+                    }
+                    _rt::cabi_dealloc(base30, len30 * 16, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_reset_calls_cabi<T: Guest>() {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::reset_calls();
+                }
+                pub trait Guest {
+                    /// Add a rule that overrides the result of statements run by the app
+                    ///
+                    /// Rules are consulted in the order they were added and the first matching rule wins.
+                    /// Statements that match no rule are run against the in-memory database.
+                    ///
+                    /// If `address` is `none`, the rule applies to all databases. If `parameters` is `none`,
+                    /// the rule matches statements run with any parameters.
+                    ///
+                    /// Returns an error if the matcher is an invalid regular expression.
+                    fn add_rule(
+                        address: Option<_rt::String>,
+                        matcher: Matcher,
+                        parameters: Option<_rt::Vec<ParameterValue>>,
+                        response: RuleResponse,
+                    ) -> Result<(), _rt::String>;
+                    /// Remove all rules previously added with `add-rule`
+                    fn clear_rules();
+                    /// Get the recorded statements run by the app keyed by address
+                    fn calls() -> _rt::Vec<(_rt::String, _rt::Vec<Call>)>;
+                    /// Reset the state of the call tracking
+                    fn reset_calls();
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_fermyon_spin_test_virt_rdbms_cabi{
+  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+    #[export_name = "fermyon:spin-test-virt/rdbms#add-rule"]
+    unsafe extern "C" fn export_add_rule(arg0: i32,arg1: *mut u8,arg2: usize,arg3: i32,arg4: *mut u8,arg5: usize,arg6: i32,arg7: *mut u8,arg8: usize,arg9: i32,arg10: ::core::mem::MaybeUninit::<u64>,arg11: *mut u8,arg12: *mut u8,arg13: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_add_rule_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13)
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/rdbms#add-rule"]
+    unsafe extern "C" fn _post_return_add_rule(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_add_rule::<$ty>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/rdbms#clear-rules"]
+    unsafe extern "C" fn export_clear_rules() {
+      $($path_to_types)*::_export_clear_rules_cabi::<$ty>()
+    }
+    #[export_name = "fermyon:spin-test-virt/rdbms#calls"]
+    unsafe extern "C" fn export_calls() -> *mut u8 {
+      $($path_to_types)*::_export_calls_cabi::<$ty>()
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/rdbms#calls"]
+    unsafe extern "C" fn _post_return_calls(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_calls::<$ty>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/rdbms#reset-calls"]
+    unsafe extern "C" fn export_reset_calls() {
+      $($path_to_types)*::_export_reset_calls_cabi::<$ty>()
+    }
+  };);
+}
+                #[doc(hidden)]
+                pub(crate) use __export_fermyon_spin_test_virt_rdbms_cabi;
+                #[repr(align(4))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 12]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 12]);
+            }
+
+            #[allow(dead_code, clippy::all)]
+            pub mod variables {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type Error =
+                    super::super::super::super::exports::fermyon::spin::variables::Error;
+                /// A lookup of a variable
+                #[derive(Clone)]
+                pub struct Call {
+                    /// The name of the variable that was looked up
+                    pub name: _rt::String,
+                    /// The value the variable resolved to or the error the lookup failed with
+                    ///
+                    /// Values of variables which depend on secret application variables are
+                    /// replaced with "<redacted>".
+                    pub result: Result<_rt::String, Error>,
+                }
+                impl ::core::fmt::Debug for Call {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Call")
+                            .field("name", &self.name)
+                            .field("result", &self.result)
+                            .finish()
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_set_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                    arg2: *mut u8,
+                    arg3: usize,
+                ) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let len1 = arg3;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+                    T::set(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_unset_cabi<T: Guest>(arg0: *mut u8, arg1: usize) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    T::unset(_rt::string_lift(bytes0));
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_clear_cabi<T: Guest>() {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::clear();
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_calls_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::calls();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec10 = result0;
+                    let len10 = vec10.len();
+                    let layout10 =
+                        _rt::alloc::Layout::from_size_align_unchecked(vec10.len() * 24, 4);
+                    let result10 = if layout10.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout10).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout10);
+                        }
+                        ptr
+                    } else {
+                        {
+                            ::core::ptr::null_mut()
+                        }
+                    };
+                    for (i, e) in vec10.into_iter().enumerate() {
+                        let base = result10.add(i * 24);
+                        {
+                            let Call {
+                                name: name2,
+                                result: result2,
+                            } = e;
+                            let vec3 = (name2.into_bytes()).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *base.add(4).cast::<usize>() = len3;
+                            *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                            match result2 {
+                                Ok(e) => {
+                                    *base.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *base.add(16).cast::<usize>() = len4;
+                                    *base.add(12).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                                Err(e) => {
+                                    *base.add(8).cast::<u8>() = (1i32) as u8;
+                                    use super::super::super::super::exports::fermyon::spin::variables::Error as V9;
+                                    match e {
+                                        V9::InvalidName(e) => {
+                                            *base.add(12).cast::<u8>() = (0i32) as u8;
+                                            let vec5 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                                            let len5 = vec5.len();
+                                            ::core::mem::forget(vec5);
+                                            *base.add(20).cast::<usize>() = len5;
+                                            *base.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                        }
+                                        V9::Undefined(e) => {
+                                            *base.add(12).cast::<u8>() = (1i32) as u8;
+                                            let vec6 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                                            let len6 = vec6.len();
+                                            ::core::mem::forget(vec6);
+                                            *base.add(20).cast::<usize>() = len6;
+                                            *base.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                                        }
+                                        V9::Provider(e) => {
+                                            *base.add(12).cast::<u8>() = (2i32) as u8;
+                                            let vec7 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                                            let len7 = vec7.len();
+                                            ::core::mem::forget(vec7);
+                                            *base.add(20).cast::<usize>() = len7;
+                                            *base.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                        }
+                                        V9::Other(e) => {
+                                            *base.add(12).cast::<u8>() = (3i32) as u8;
+                                            let vec8 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr8 = vec8.as_ptr().cast::<u8>();
+                                            let len8 = vec8.len();
+                                            ::core::mem::forget(vec8);
+                                            *base.add(20).cast::<usize>() = len8;
+                                            *base.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                        }
+                                    }
+                                }
+                            };
+                        }
+                    }
+                    *ptr1.add(4).cast::<usize>() = len10;
+                    *ptr1.add(0).cast::<*mut u8>() = result10;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_calls<T: Guest>(arg0: *mut u8) {
+                    let l14 = *arg0.add(0).cast::<*mut u8>();
+                    let l15 = *arg0.add(4).cast::<usize>();
+                    let base16 = l14;
+                    let len16 = l15;
+                    for i in 0..len16 {
+                        let base = base16.add(i * 24);
+                        {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            _rt::cabi_dealloc(l0, l1, 1);
+                            let l2 = i32::from(*base.add(8).cast::<u8>());
+                            match l2 {
+                                0 => {
+                                    let l3 = *base.add(12).cast::<*mut u8>();
+                                    let l4 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l3, l4, 1);
+                                }
+                                _ => {
+                                    let l5 = i32::from(*base.add(12).cast::<u8>());
+                                    match l5 {
+                                        0 => {
+                                            let l6 = *base.add(16).cast::<*mut u8>();
+                                            let l7 = *base.add(20).cast::<usize>();
+                                            _rt::cabi_dealloc(l6, l7, 1);
+                                        }
+                                        1 => {
+                                            let l8 = *base.add(16).cast::<*mut u8>();
+                                            let l9 = *base.add(20).cast::<usize>();
+                                            _rt::cabi_dealloc(l8, l9, 1);
+                                        }
+                                        2 => {
+                                            let l10 = *base.add(16).cast::<*mut u8>();
+                                            let l11 = *base.add(20).cast::<usize>();
+                                            _rt::cabi_dealloc(l10, l11, 1);
+                                        }
+                                        _ => {
+                                            let l12 = *base.add(16).cast::<*mut u8>();
+                                            let l13 = *base.add(20).cast::<usize>();
+                                            _rt::cabi_dealloc(l12, l13, 1);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    _rt::cabi_dealloc(base16, len16 * 24, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_reset_calls_cabi<T: Guest>() {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::reset_calls();
+                }
+                pub trait Guest {
+                    /// Provide the value of an application variable
+                    ///
+                    /// Takes precedence over the values `spin-test` gathered from the runtime config, `.env`
+                    /// files, `SPIN_VARIABLE_*` environment variables and the command line.
+                    fn set(key: _rt::String, value: _rt::String);
+                    /// Remove a value previously provided with `set`
+                    ///
+                    /// The variable falls back to the value gathered by `spin-test` if there is one.
+                    fn unset(key: _rt::String);
+                    /// Remove all values previously provided with `set`
+                    fn clear();
+                    /// Get the recorded lookups of variables through the `fermyon:spin/variables` interface
+                    fn calls() -> _rt::Vec<Call>;
+                    /// Reset the state of the call tracking
+                    fn reset_calls();
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_fermyon_spin_test_virt_variables_cabi{
+  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+    #[export_name = "fermyon:spin-test-virt/variables#set"]
+    unsafe extern "C" fn export_set(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) {
+      $($path_to_types)*::_export_set_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[export_name = "fermyon:spin-test-virt/variables#unset"]
+    unsafe extern "C" fn export_unset(arg0: *mut u8,arg1: usize,) {
+      $($path_to_types)*::_export_unset_cabi::<$ty>(arg0, arg1)
+    }
+    #[export_name = "fermyon:spin-test-virt/variables#clear"]
+    unsafe extern "C" fn export_clear() {
+      $($path_to_types)*::_export_clear_cabi::<$ty>()
+    }
+    #[export_name = "fermyon:spin-test-virt/variables#calls"]
+    unsafe extern "C" fn export_calls() -> *mut u8 {
+      $($path_to_types)*::_export_calls_cabi::<$ty>()
+    }
+    #[export_name = "cabi_post_fermyon:spin-test-virt/variables#calls"]
+    unsafe extern "C" fn _post_return_calls(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_calls::<$ty>(arg0)
+    }
+    #[export_name = "fermyon:spin-test-virt/variables#reset-calls"]
+    unsafe extern "C" fn export_reset_calls() {
+      $($path_to_types)*::_export_reset_calls_cabi::<$ty>()
+    }
+  };);
+}
+                #[doc(hidden)]
+                pub(crate) use __export_fermyon_spin_test_virt_variables_cabi;
+                #[repr(align(4))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+            }
+
+            #[allow(dead_code, clippy::all)]
+            pub mod clock {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_advance_cabi<T: Guest>(arg0: i64) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::advance(arg0 as u64);
+                }
+                pub trait Guest {
+                    /// Move the monotonic and wall clocks forward by `nanoseconds`
+                    ///
+                    /// Time then passes as it does on the host from the new instant on. Anything based on the
+                    /// clocks, such as the expiry of Redis keys, sees the jump immediately.
+                    fn advance(nanoseconds: u64);
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_fermyon_spin_test_virt_clock_cabi{
+  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+    #[export_name = "fermyon:spin-test-virt/clock#advance"]
+    unsafe extern "C" fn export_advance(arg0: i64,) {
+      $($path_to_types)*::_export_advance_cabi::<$ty>(arg0)
+    }
+  };);
+}
+                #[doc(hidden)]
+                pub(crate) use __export_fermyon_spin_test_virt_clock_cabi;
+            }
+
+            #[allow(dead_code, clippy::all)]
+            pub mod inbound_redis {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                /// General purpose error.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, PartialEq)]
+                pub enum Error {
+                    Success,
+                    Error,
+                }
+                impl Error {
+                    pub fn name(&self) -> &'static str {
+                        match self {
+                            Error::Success => "success",
+                            Error::Error => "error",
+                        }
+                    }
+                    pub fn message(&self) -> &'static str {
+                        match self {
+                            Error::Success => "",
+                            Error::Error => "",
+                        }
+                    }
+                }
+                impl ::core::fmt::Debug for Error {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Error")
+                            .field("code", &(*self as i32))
+                            .field("name", &self.name())
+                            .field("message", &self.message())
+                            .finish()
+                    }
+                }
+                impl ::core::fmt::Display for Error {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        write!(f, "{} (error {})", self.name(), *self as i32)
+                    }
+                }
+
+                impl std::error::Error for Error {}
+
+                impl Error {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> Error {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+
+                        match val {
+                            0 => Error::Success,
+                            1 => Error::Error,
+
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+
+                /// The message payload.
+                pub type Payload = _rt::Vec<u8>;
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_handle_message_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let result1 =
+                        T::handle_message(_rt::Vec::from_raw_parts(arg0.cast(), len0, len0));
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(_) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr2.add(1).cast::<u8>() = (e.clone() as i32) as u8;
+                        }
+                    };
+                    ptr2
+                }
+                pub trait Guest {
+                    /// The entrypoint for a Redis handler.
+                    fn handle_message(message: Payload) -> Result<(), Error>;
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_fermyon_spin_test_virt_inbound_redis_cabi{
+  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+    #[export_name = "fermyon:spin-test-virt/inbound-redis#handle-message"]
+    unsafe extern "C" fn export_handle_message(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_handle_message_cabi::<$ty>(arg0, arg1)
+    }
+  };);
+}
+                #[doc(hidden)]
+                pub(crate) use __export_fermyon_spin_test_virt_inbound_redis_cabi;
+                #[repr(align(1))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 2]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 2]);
+            }
+        }
+        #[allow(dead_code)]
+        pub mod spin_wasi_virt {
+            #[allow(dead_code, clippy::all)]
+            pub mod http_handler {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type OutgoingResponse =
+                    super::super::super::super::exports::wasi::http::types::OutgoingResponse;
+                pub type OutgoingResponseBorrow<'a> =
+                    super::super::super::super::exports::wasi::http::types::OutgoingResponseBorrow<
+                        'a,
+                    >;
+                pub enum ResponseHandler {
+                    Echo,
+                    Response(OutgoingResponse),
+                }
+                impl ::core::fmt::Debug for ResponseHandler {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            ResponseHandler::Echo => {
+                                f.debug_tuple("ResponseHandler::Echo").finish()
+                            }
+                            ResponseHandler::Response(e) => {
+                                f.debug_tuple("ResponseHandler::Response").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                /// A request the app made to itself through local service chaining
+                #[derive(Clone)]
+                pub struct InternalRequest {
+                    pub method: _rt::String,
+                    pub url: _rt::String,
+                }
+                impl ::core::fmt::Debug for InternalRequest {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("InternalRequest")
+                            .field("method", &self.method)
+                            .field("url", &self.url)
+                            .finish()
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_set_response_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                    arg2: i32,
+                    arg3: i32,
+                ) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let v1 = match arg2 {
+                        0 => ResponseHandler::Echo,
+                        n => {
+                            debug_assert_eq!(n, 1, "invalid enum discriminant");
+                            let e1 = super::super::super::super::exports::wasi::http::types::OutgoingResponse::from_handle(arg3 as u32);
+                            ResponseHandler::Response(e1)
+                        }
+                    };
+                    T::set_response(_rt::string_lift(bytes0), v1);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_internal_requests_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::internal_requests();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec5 = result0;
+                    let len5 = vec5.len();
+                    let layout5 = _rt::alloc::Layout::from_size_align_unchecked(vec5.len() * 16, 4);
+                    let result5 = if layout5.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout5);
+                        }
+                        ptr
+                    } else {
+                        {
+                            ::core::ptr::null_mut()
+                        }
+                    };
+                    for (i, e) in vec5.into_iter().enumerate() {
+                        let base = result5.add(i * 16);
+                        {
+                            let InternalRequest {
+                                method: method2,
+                                url: url2,
+                            } = e;
+                            let vec3 = (method2.into_bytes()).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *base.add(4).cast::<usize>() = len3;
+                            *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                            let vec4 = (url2.into_bytes()).into_boxed_slice();
+                            let ptr4 = vec4.as_ptr().cast::<u8>();
+                            let len4 = vec4.len();
+                            ::core::mem::forget(vec4);
+                            *base.add(12).cast::<usize>() = len4;
+                            *base.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+                        }
+                    }
+                    *ptr1.add(4).cast::<usize>() = len5;
+                    *ptr1.add(0).cast::<*mut u8>() = result5;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_internal_requests<T: Guest>(arg0: *mut u8) {
+                    let l4 = *arg0.add(0).cast::<*mut u8>();
+                    let l5 = *arg0.add(4).cast::<usize>();
+                    let base6 = l4;
+                    let len6 = l5;
+                    for i in 0..len6 {
+                        let base = base6.add(i * 16);
+                        {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            _rt::cabi_dealloc(l0, l1, 1);
+                            let l2 = *base.add(8).cast::<*mut u8>();
+                            let l3 = *base.add(12).cast::<usize>();
+                            _rt::cabi_dealloc(l2, l3, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(base6, len6 * 16, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_reset_internal_requests_cabi<T: Guest>() {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::reset_internal_requests();
+                }
+                pub trait Guest {
+                    /// Set a response for a given url
+                    fn set_response(url: _rt::String, response: ResponseHandler);
+                    /// The requests the app made to `self` or `*.spin.internal` urls
+                    fn internal_requests() -> _rt::Vec<InternalRequest>;
+                    /// Forget the recorded internal requests
+                    fn reset_internal_requests();
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_fermyon_spin_wasi_virt_http_handler_cabi{
+  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+    #[export_name = "fermyon:spin-wasi-virt/http-handler#set-response"]
+    unsafe extern "C" fn export_set_response(arg0: *mut u8,arg1: usize,arg2: i32,arg3: i32,) {
+      $($path_to_types)*::_export_set_response_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[export_name = "fermyon:spin-wasi-virt/http-handler#internal-requests"]
+    unsafe extern "C" fn export_internal_requests() -> *mut u8 {
+      $($path_to_types)*::_export_internal_requests_cabi::<$ty>()
+    }
+    #[export_name = "cabi_post_fermyon:spin-wasi-virt/http-handler#internal-requests"]
+    unsafe extern "C" fn _post_return_internal_requests(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_internal_requests::<$ty>(arg0)
+    }
+    #[export_name = "fermyon:spin-wasi-virt/http-handler#reset-internal-requests"]
+    unsafe extern "C" fn export_reset_internal_requests() {
+      $($path_to_types)*::_export_reset_internal_requests_cabi::<$ty>()
+    }
+  };);
+}
+                #[doc(hidden)]
+                pub(crate) use __export_fermyon_spin_wasi_virt_http_handler_cabi;
+                #[repr(align(4))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+            }
+
+            #[allow(dead_code, clippy::all)]
+            pub mod http_helper {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type IncomingRequest =
+                    super::super::super::super::exports::wasi::http::types::IncomingRequest;
+                pub type IncomingRequestBorrow<'a> =
+                    super::super::super::super::exports::wasi::http::types::IncomingRequestBorrow<
+                        'a,
+                    >;
+                pub type IncomingResponse =
+                    super::super::super::super::exports::wasi::http::types::IncomingResponse;
+                pub type IncomingResponseBorrow<'a> =
+                    super::super::super::super::exports::wasi::http::types::IncomingResponseBorrow<
+                        'a,
+                    >;
+                pub type OutgoingResponseBorrow<'a> =
+                    super::super::super::super::exports::wasi::http::types::OutgoingResponseBorrow<
+                        'a,
+                    >;
+                pub type OutgoingRequest =
+                    super::super::super::super::exports::wasi::http::types::OutgoingRequest;
+                pub type OutgoingRequestBorrow<'a> =
+                    super::super::super::super::exports::wasi::http::types::OutgoingRequestBorrow<
+                        'a,
+                    >;
+                pub type ResponseOutparam =
+                    super::super::super::super::exports::wasi::http::types::ResponseOutparam;
+                pub type ResponseOutparamBorrow<'a> =
+                    super::super::super::super::exports::wasi::http::types::ResponseOutparamBorrow<
+                        'a,
+                    >;
+                pub type FutureIncomingResponse =
+                    super::super::super::super::exports::wasi::http::types::FutureIncomingResponse;
+                pub type FutureIncomingResponseBorrow<'a> = super::super::super::super::exports::wasi::http::types::FutureIncomingResponseBorrow<'a>;
+                pub type IncomingBody =
+                    super::super::super::super::exports::wasi::http::types::IncomingBody;
+                pub type IncomingBodyBorrow<'a> =
+                    super::super::super::super::exports::wasi::http::types::IncomingBodyBorrow<'a>;
+                /// A receiver of an `incoming-response`
+
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct ResponseReceiver {
+                    handle: _rt::Resource<ResponseReceiver>,
+                }
+
+                type _ResponseReceiverRep<T> = Option<T>;
+
+                impl ResponseReceiver {
+                    /// Creates a new resource from the specified representation.
+                    ///
+                    /// This function will create a new resource handle by moving `val` onto
+                    /// the heap and then passing that heap pointer to the component model to
+                    /// create a handle. The owned handle is then returned as `ResponseReceiver`.
+                    pub fn new<T: GuestResponseReceiver>(val: T) -> Self {
+                        Self::type_guard::<T>();
+                        let val: _ResponseReceiverRep<T> = Some(val);
+                        let ptr: *mut _ResponseReceiverRep<T> =
+                            _rt::Box::into_raw(_rt::Box::new(val));
+                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
+                    }
+
+                    /// Gets access to the underlying `T` which represents this resource.
+                    pub fn get<T: GuestResponseReceiver>(&self) -> &T {
+                        let ptr = unsafe { &*self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+
+                    /// Gets mutable access to the underlying `T` which represents this
+                    /// resource.
+                    pub fn get_mut<T: GuestResponseReceiver>(&mut self) -> &mut T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
                         ptr.as_mut().unwrap()
                     }
 
@@ -16102,6 +23833,153 @@ pub mod exports {
                     *ptr1.add(4).cast::<i32>() = (t2_1).take_handle() as i32;
                     ptr1
                 }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_set_client_addr_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    T::set_client_addr(
+                        IncomingRequestBorrow::lift(arg0 as u32 as usize),
+                        _rt::string_lift(bytes0),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_set_tls_cabi<T: Guest>(arg0: i32, arg1: i32) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::set_tls(
+                        IncomingRequestBorrow::lift(arg0 as u32 as usize),
+                        _rt::bool_lift(arg1 as u8),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_set_listen_addr_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    T::set_listen_addr(
+                        IncomingRequestBorrow::lift(arg0 as u32 as usize),
+                        _rt::string_lift(bytes0),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_new_response_future_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::new_response_future();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let (t2_0, t2_1) = result0;
+                    *ptr1.add(0).cast::<i32>() = (t2_0).take_handle() as i32;
+                    *ptr1.add(4).cast::<i32>() = (t2_1).take_handle() as i32;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_client_addr_cabi<T: Guest>(arg0: i32) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::client_addr(IncomingRequestBorrow::lift(arg0 as u32 as usize));
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Some(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec2 = (e.into_bytes()).into_boxed_slice();
+                            let ptr2 = vec2.as_ptr().cast::<u8>();
+                            let len2 = vec2.len();
+                            ::core::mem::forget(vec2);
+                            *ptr1.add(8).cast::<usize>() = len2;
+                            *ptr1.add(4).cast::<*mut u8>() = ptr2.cast_mut();
+                        }
+                        None => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_client_addr<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = *arg0.add(4).cast::<*mut u8>();
+                            let l2 = *arg0.add(8).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_tls_cabi<T: Guest>(arg0: i32) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::tls(IncomingRequestBorrow::lift(arg0 as u32 as usize));
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Some(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr1.add(1).cast::<u8>() = (match e {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                        }
+                        None => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_listen_addr_cabi<T: Guest>(arg0: i32) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::listen_addr(IncomingRequestBorrow::lift(arg0 as u32 as usize));
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Some(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec2 = (e.into_bytes()).into_boxed_slice();
+                            let ptr2 = vec2.as_ptr().cast::<u8>();
+                            let len2 = vec2.len();
+                            ::core::mem::forget(vec2);
+                            *ptr1.add(8).cast::<usize>() = len2;
+                            *ptr1.add(4).cast::<*mut u8>() = ptr2.cast_mut();
+                        }
+                        None => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_listen_addr<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = *arg0.add(4).cast::<*mut u8>();
+                            let l2 = *arg0.add(8).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                    }
+                }
                 pub trait Guest {
                     type ResponseReceiver: GuestResponseReceiver;
                     /// Create an `incoming-request` from an `outgoing-request`
@@ -16114,6 +23992,35 @@ pub mod exports {
                     ) -> IncomingRequest;
                     /// Get a pair of a `response-outparam` and a `response-receiver`
                     fn new_response() -> (ResponseOutparam, ResponseReceiver);
+                    /// Set the address (e.g., `203.0.113.7:51234`) of the client the request comes from
+                    ///
+                    /// This is reported to the app as `spin-client-addr`.
+                    fn set_client_addr(
+                        request: IncomingRequestBorrow<'_>,
+                        client_addr: _rt::String,
+                    );
+                    /// Set whether the request was received over TLS
+                    ///
+                    /// This decides the scheme of `spin-full-url`. When not set, the scheme of the request is used.
+                    fn set_tls(request: IncomingRequestBorrow<'_>, tls: bool);
+                    /// Set the address (e.g., `0.0.0.0:8080`) the app listens on
+                    ///
+                    /// This is used for `spin-full-url` when the request has no `Host` header. When not set, the
+                    /// authority of the request is used.
+                    fn set_listen_addr(
+                        request: IncomingRequestBorrow<'_>,
+                        listen_addr: _rt::String,
+                    );
+                    /// Get a pair of a `response-outparam` and a `future-incoming-response`
+                    ///
+                    /// The future resolves to the response set on the `response-outparam`.
+                    fn new_response_future() -> (ResponseOutparam, FutureIncomingResponse);
+                    /// The client address set with `set-client-addr`
+                    fn client_addr(request: IncomingRequestBorrow<'_>) -> Option<_rt::String>;
+                    /// Whether the request was received over TLS as set with `set-tls`
+                    fn tls(request: IncomingRequestBorrow<'_>) -> Option<bool>;
+                    /// The listen address set with `set-listen-addr`
+                    fn listen_addr(request: IncomingRequestBorrow<'_>) -> Option<_rt::String>;
                 }
                 pub trait GuestResponseReceiver: 'static {
                     #[doc(hidden)]
@@ -16183,6 +24090,42 @@ pub mod exports {
     unsafe extern "C" fn export_new_response() -> *mut u8 {
       $($path_to_types)*::_export_new_response_cabi::<$ty>()
     }
+    #[export_name = "fermyon:spin-wasi-virt/http-helper#set-client-addr"]
+    unsafe extern "C" fn export_set_client_addr(arg0: i32,arg1: *mut u8,arg2: usize,) {
+      $($path_to_types)*::_export_set_client_addr_cabi::<$ty>(arg0, arg1, arg2)
+    }
+    #[export_name = "fermyon:spin-wasi-virt/http-helper#set-tls"]
+    unsafe extern "C" fn export_set_tls(arg0: i32,arg1: i32,) {
+      $($path_to_types)*::_export_set_tls_cabi::<$ty>(arg0, arg1)
+    }
+    #[export_name = "fermyon:spin-wasi-virt/http-helper#set-listen-addr"]
+    unsafe extern "C" fn export_set_listen_addr(arg0: i32,arg1: *mut u8,arg2: usize,) {
+      $($path_to_types)*::_export_set_listen_addr_cabi::<$ty>(arg0, arg1, arg2)
+    }
+    #[export_name = "fermyon:spin-wasi-virt/http-helper#new-response-future"]
+    unsafe extern "C" fn export_new_response_future() -> *mut u8 {
+      $($path_to_types)*::_export_new_response_future_cabi::<$ty>()
+    }
+    #[export_name = "fermyon:spin-wasi-virt/http-helper#client-addr"]
+    unsafe extern "C" fn export_client_addr(arg0: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_client_addr_cabi::<$ty>(arg0)
+    }
+    #[export_name = "cabi_post_fermyon:spin-wasi-virt/http-helper#client-addr"]
+    unsafe extern "C" fn _post_return_client_addr(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_client_addr::<$ty>(arg0)
+    }
+    #[export_name = "fermyon:spin-wasi-virt/http-helper#tls"]
+    unsafe extern "C" fn export_tls(arg0: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_tls_cabi::<$ty>(arg0)
+    }
+    #[export_name = "fermyon:spin-wasi-virt/http-helper#listen-addr"]
+    unsafe extern "C" fn export_listen_addr(arg0: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_listen_addr_cabi::<$ty>(arg0)
+    }
+    #[export_name = "cabi_post_fermyon:spin-wasi-virt/http-helper#listen-addr"]
+    unsafe extern "C" fn _post_return_listen_addr(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_listen_addr::<$ty>(arg0)
+    }
 
     const _: () = {
       #[doc(hidden)]
@@ -16200,8 +24143,8 @@ pub mod exports {
                 #[doc(hidden)]
                 pub(crate) use __export_fermyon_spin_wasi_virt_http_helper_cabi;
                 #[repr(align(4))]
-                struct _RetArea([::core::mem::MaybeUninit<u8>; 8]);
-                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 12]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 12]);
             }
 
             #[allow(dead_code, clippy::all)]
@@ -16253,6 +24196,45 @@ pub mod exports {
     pub mod wasi {
         #[allow(dead_code)]
         pub mod cli {
+            #[allow(dead_code, clippy::all)]
+            pub mod run {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_run_cabi<T: Guest>() -> i32 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::run();
+                    let result1 = match result0 {
+                        Ok(_) => 0i32,
+                        Err(_) => 1i32,
+                    };
+                    result1
+                }
+                pub trait Guest {
+                    /// Run the program.
+                    fn run() -> Result<(), ()>;
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_wasi_cli_run_0_2_0_cabi{
+      ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+        #[export_name = "wasi:cli/run@0.2.0#run"]
+        unsafe extern "C" fn export_run() -> i32 {
+          $($path_to_types)*::_export_run_cabi::<$ty>()
+        }
+      };);
+    }
+                #[doc(hidden)]
+                pub(crate) use __export_wasi_cli_run_0_2_0_cabi;
+            }
+
             #[allow(dead_code, clippy::all)]
             pub mod stdout {
                 #[used]
@@ -16279,14 +24261,14 @@ pub mod exports {
                 #[doc(hidden)]
 
                 macro_rules! __export_wasi_cli_stdout_0_2_0_cabi{
-      ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+    ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
-        #[export_name = "wasi:cli/stdout@0.2.0#get-stdout"]
-        unsafe extern "C" fn export_get_stdout() -> i32 {
-          $($path_to_types)*::_export_get_stdout_cabi::<$ty>()
-        }
-      };);
-    }
+      #[export_name = "wasi:cli/stdout@0.2.0#get-stdout"]
+      unsafe extern "C" fn export_get_stdout() -> i32 {
+        $($path_to_types)*::_export_get_stdout_cabi::<$ty>()
+      }
+    };);
+  }
                 #[doc(hidden)]
                 pub(crate) use __export_wasi_cli_stdout_0_2_0_cabi;
             }
@@ -16317,14 +24299,14 @@ pub mod exports {
                 #[doc(hidden)]
 
                 macro_rules! __export_wasi_cli_stdin_0_2_0_cabi{
-    ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
-      #[export_name = "wasi:cli/stdin@0.2.0#get-stdin"]
-      unsafe extern "C" fn export_get_stdin() -> i32 {
-        $($path_to_types)*::_export_get_stdin_cabi::<$ty>()
-      }
-    };);
-  }
+    #[export_name = "wasi:cli/stdin@0.2.0#get-stdin"]
+    unsafe extern "C" fn export_get_stdin() -> i32 {
+      $($path_to_types)*::_export_get_stdin_cabi::<$ty>()
+    }
+  };);
+}
                 #[doc(hidden)]
                 pub(crate) use __export_wasi_cli_stdin_0_2_0_cabi;
             }
@@ -28029,6 +36011,61 @@ pub mod exports {
                 struct _RetArea([::core::mem::MaybeUninit<u8>; 40]);
                 static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 40]);
             }
+
+            #[allow(dead_code, clippy::all)]
+            pub mod incoming_handler {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type IncomingRequest =
+                    super::super::super::super::exports::wasi::http::types::IncomingRequest;
+                pub type IncomingRequestBorrow<'a> =
+                    super::super::super::super::exports::wasi::http::types::IncomingRequestBorrow<
+                        'a,
+                    >;
+                pub type ResponseOutparam =
+                    super::super::super::super::exports::wasi::http::types::ResponseOutparam;
+                pub type ResponseOutparamBorrow<'a> =
+                    super::super::super::super::exports::wasi::http::types::ResponseOutparamBorrow<
+                        'a,
+                    >;
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_handle_cabi<T: Guest>(arg0: i32, arg1: i32) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::handle(super::super::super::super::exports::wasi::http::types::IncomingRequest::from_handle(arg0 as u32), super::super::super::super::exports::wasi::http::types::ResponseOutparam::from_handle(arg1 as u32));
+                }
+                pub trait Guest {
+                    /// This function is invoked with an incoming HTTP Request, and a resource
+                    /// `response-outparam` which provides the capability to reply with an HTTP
+                    /// Response. The response is sent by calling the `response-outparam.set`
+                    /// method, which allows execution to continue after the response has been
+                    /// sent. This enables both streaming to the response body, and performing other
+                    /// work.
+                    ///
+                    /// The implementor of this function must write a response to the
+                    /// `response-outparam` before returning, or else the caller will respond
+                    /// with an error on its behalf.
+                    fn handle(request: IncomingRequest, response_out: ResponseOutparam);
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_wasi_http_incoming_handler_0_2_0_cabi{
+                                                                                                                    ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+                                                                                                                      #[export_name = "wasi:http/incoming-handler@0.2.0#handle"]
+                                                                                                                      unsafe extern "C" fn export_handle(arg0: i32,arg1: i32,) {
+                                                                                                                        $($path_to_types)*::_export_handle_cabi::<$ty>(arg0, arg1)
+                                                                                                                      }
+                                                                                                                    };);
+                                                                                                                  }
+                #[doc(hidden)]
+                pub(crate) use __export_wasi_http_incoming_handler_0_2_0_cabi;
+            }
         }
         #[allow(dead_code)]
         pub mod io {
@@ -28320,38 +36357,38 @@ pub mod exports {
                 #[doc(hidden)]
 
                 macro_rules! __export_wasi_io_poll_0_2_0_cabi{
-                                                                                                                ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+                                                                                                              ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
-                                                                                                                  #[export_name = "wasi:io/poll@0.2.0#[method]pollable.ready"]
-                                                                                                                  unsafe extern "C" fn export_method_pollable_ready(arg0: *mut u8,) -> i32 {
-                                                                                                                    $($path_to_types)*::_export_method_pollable_ready_cabi::<<$ty as $($path_to_types)*::Guest>::Pollable>(arg0)
-                                                                                                                  }
-                                                                                                                  #[export_name = "wasi:io/poll@0.2.0#[method]pollable.block"]
-                                                                                                                  unsafe extern "C" fn export_method_pollable_block(arg0: *mut u8,) {
-                                                                                                                    $($path_to_types)*::_export_method_pollable_block_cabi::<<$ty as $($path_to_types)*::Guest>::Pollable>(arg0)
-                                                                                                                  }
-                                                                                                                  #[export_name = "wasi:io/poll@0.2.0#poll"]
-                                                                                                                  unsafe extern "C" fn export_poll(arg0: *mut u8,arg1: usize,) -> *mut u8 {
-                                                                                                                    $($path_to_types)*::_export_poll_cabi::<$ty>(arg0, arg1)
-                                                                                                                  }
-                                                                                                                  #[export_name = "cabi_post_wasi:io/poll@0.2.0#poll"]
-                                                                                                                  unsafe extern "C" fn _post_return_poll(arg0: *mut u8,) {
-                                                                                                                    $($path_to_types)*::__post_return_poll::<$ty>(arg0)
-                                                                                                                  }
+                                                                                                                #[export_name = "wasi:io/poll@0.2.0#[method]pollable.ready"]
+                                                                                                                unsafe extern "C" fn export_method_pollable_ready(arg0: *mut u8,) -> i32 {
+                                                                                                                  $($path_to_types)*::_export_method_pollable_ready_cabi::<<$ty as $($path_to_types)*::Guest>::Pollable>(arg0)
+                                                                                                                }
+                                                                                                                #[export_name = "wasi:io/poll@0.2.0#[method]pollable.block"]
+                                                                                                                unsafe extern "C" fn export_method_pollable_block(arg0: *mut u8,) {
+                                                                                                                  $($path_to_types)*::_export_method_pollable_block_cabi::<<$ty as $($path_to_types)*::Guest>::Pollable>(arg0)
+                                                                                                                }
+                                                                                                                #[export_name = "wasi:io/poll@0.2.0#poll"]
+                                                                                                                unsafe extern "C" fn export_poll(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+                                                                                                                  $($path_to_types)*::_export_poll_cabi::<$ty>(arg0, arg1)
+                                                                                                                }
+                                                                                                                #[export_name = "cabi_post_wasi:io/poll@0.2.0#poll"]
+                                                                                                                unsafe extern "C" fn _post_return_poll(arg0: *mut u8,) {
+                                                                                                                  $($path_to_types)*::__post_return_poll::<$ty>(arg0)
+                                                                                                                }
 
-                                                                                                                  const _: () = {
-                                                                                                                    #[doc(hidden)]
-                                                                                                                    #[export_name = "wasi:io/poll@0.2.0#[dtor]pollable"]
-                                                                                                                    #[allow(non_snake_case)]
-                                                                                                                    unsafe extern "C" fn dtor(rep: *mut u8) {
-                                                                                                                      $($path_to_types)*::Pollable::dtor::<
-                                                                                                                      <$ty as $($path_to_types)*::Guest>::Pollable
-                                                                                                                      >(rep)
-                                                                                                                    }
-                                                                                                                  };
+                                                                                                                const _: () = {
+                                                                                                                  #[doc(hidden)]
+                                                                                                                  #[export_name = "wasi:io/poll@0.2.0#[dtor]pollable"]
+                                                                                                                  #[allow(non_snake_case)]
+                                                                                                                  unsafe extern "C" fn dtor(rep: *mut u8) {
+                                                                                                                    $($path_to_types)*::Pollable::dtor::<
+                                                                                                                    <$ty as $($path_to_types)*::Guest>::Pollable
+                                                                                                                    >(rep)
+                                                                                                                  }
+                                                                                                                };
 
-                                                                                                                };);
-                                                                                                              }
+                                                                                                              };);
+                                                                                                            }
                 #[doc(hidden)]
                 pub(crate) use __export_wasi_io_poll_0_2_0_cabi;
                 #[repr(align(4))]
@@ -28613,30 +36650,30 @@ pub mod exports {
                 #[doc(hidden)]
 
                 macro_rules! __export_wasi_io_error_0_2_0_cabi{
-                                                                                                              ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+                                                                                                            ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
-                                                                                                                #[export_name = "wasi:io/error@0.2.0#[method]error.to-debug-string"]
-                                                                                                                unsafe extern "C" fn export_method_error_to_debug_string(arg0: *mut u8,) -> *mut u8 {
-                                                                                                                  $($path_to_types)*::_export_method_error_to_debug_string_cabi::<<$ty as $($path_to_types)*::Guest>::Error>(arg0)
-                                                                                                                }
-                                                                                                                #[export_name = "cabi_post_wasi:io/error@0.2.0#[method]error.to-debug-string"]
-                                                                                                                unsafe extern "C" fn _post_return_method_error_to_debug_string(arg0: *mut u8,) {
-                                                                                                                  $($path_to_types)*::__post_return_method_error_to_debug_string::<<$ty as $($path_to_types)*::Guest>::Error>(arg0)
-                                                                                                                }
+                                                                                                              #[export_name = "wasi:io/error@0.2.0#[method]error.to-debug-string"]
+                                                                                                              unsafe extern "C" fn export_method_error_to_debug_string(arg0: *mut u8,) -> *mut u8 {
+                                                                                                                $($path_to_types)*::_export_method_error_to_debug_string_cabi::<<$ty as $($path_to_types)*::Guest>::Error>(arg0)
+                                                                                                              }
+                                                                                                              #[export_name = "cabi_post_wasi:io/error@0.2.0#[method]error.to-debug-string"]
+                                                                                                              unsafe extern "C" fn _post_return_method_error_to_debug_string(arg0: *mut u8,) {
+                                                                                                                $($path_to_types)*::__post_return_method_error_to_debug_string::<<$ty as $($path_to_types)*::Guest>::Error>(arg0)
+                                                                                                              }
 
-                                                                                                                const _: () = {
-                                                                                                                  #[doc(hidden)]
-                                                                                                                  #[export_name = "wasi:io/error@0.2.0#[dtor]error"]
-                                                                                                                  #[allow(non_snake_case)]
-                                                                                                                  unsafe extern "C" fn dtor(rep: *mut u8) {
-                                                                                                                    $($path_to_types)*::Error::dtor::<
-                                                                                                                    <$ty as $($path_to_types)*::Guest>::Error
-                                                                                                                    >(rep)
-                                                                                                                  }
-                                                                                                                };
+                                                                                                              const _: () = {
+                                                                                                                #[doc(hidden)]
+                                                                                                                #[export_name = "wasi:io/error@0.2.0#[dtor]error"]
+                                                                                                                #[allow(non_snake_case)]
+                                                                                                                unsafe extern "C" fn dtor(rep: *mut u8) {
+                                                                                                                  $($path_to_types)*::Error::dtor::<
+                                                                                                                  <$ty as $($path_to_types)*::Guest>::Error
+                                                                                                                  >(rep)
+                                                                                                                }
+                                                                                                              };
 
-                                                                                                              };);
-                                                                                                            }
+                                                                                                            };);
+                                                                                                          }
                 #[doc(hidden)]
                 pub(crate) use __export_wasi_io_error_0_2_0_cabi;
                 #[repr(align(4))]
@@ -29770,102 +37807,102 @@ pub mod exports {
                 #[doc(hidden)]
 
                 macro_rules! __export_wasi_io_streams_0_2_0_cabi{
-                                                                                ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
-
-                                                                                  #[export_name = "wasi:io/streams@0.2.0#[method]input-stream.read"]
-                                                                                  unsafe extern "C" fn export_method_input_stream_read(arg0: *mut u8,arg1: i64,) -> *mut u8 {
-                                                                                    $($path_to_types)*::_export_method_input_stream_read_cabi::<<$ty as $($path_to_types)*::Guest>::InputStream>(arg0, arg1)
-                                                                                  }
-                                                                                  #[export_name = "cabi_post_wasi:io/streams@0.2.0#[method]input-stream.read"]
-                                                                                  unsafe extern "C" fn _post_return_method_input_stream_read(arg0: *mut u8,) {
-                                                                                    $($path_to_types)*::__post_return_method_input_stream_read::<<$ty as $($path_to_types)*::Guest>::InputStream>(arg0)
-                                                                                  }
-                                                                                  #[export_name = "wasi:io/streams@0.2.0#[method]input-stream.blocking-read"]
-                                                                                  unsafe extern "C" fn export_method_input_stream_blocking_read(arg0: *mut u8,arg1: i64,) -> *mut u8 {
-                                                                                    $($path_to_types)*::_export_method_input_stream_blocking_read_cabi::<<$ty as $($path_to_types)*::Guest>::InputStream>(arg0, arg1)
-                                                                                  }
-                                                                                  #[export_name = "cabi_post_wasi:io/streams@0.2.0#[method]input-stream.blocking-read"]
-                                                                                  unsafe extern "C" fn _post_return_method_input_stream_blocking_read(arg0: *mut u8,) {
-                                                                                    $($path_to_types)*::__post_return_method_input_stream_blocking_read::<<$ty as $($path_to_types)*::Guest>::InputStream>(arg0)
-                                                                                  }
-                                                                                  #[export_name = "wasi:io/streams@0.2.0#[method]input-stream.skip"]
-                                                                                  unsafe extern "C" fn export_method_input_stream_skip(arg0: *mut u8,arg1: i64,) -> *mut u8 {
-                                                                                    $($path_to_types)*::_export_method_input_stream_skip_cabi::<<$ty as $($path_to_types)*::Guest>::InputStream>(arg0, arg1)
-                                                                                  }
-                                                                                  #[export_name = "wasi:io/streams@0.2.0#[method]input-stream.blocking-skip"]
-                                                                                  unsafe extern "C" fn export_method_input_stream_blocking_skip(arg0: *mut u8,arg1: i64,) -> *mut u8 {
-                                                                                    $($path_to_types)*::_export_method_input_stream_blocking_skip_cabi::<<$ty as $($path_to_types)*::Guest>::InputStream>(arg0, arg1)
+                                                                              ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+                                                                                #[export_name = "wasi:io/streams@0.2.0#[method]input-stream.read"]
+                                                                                unsafe extern "C" fn export_method_input_stream_read(arg0: *mut u8,arg1: i64,) -> *mut u8 {
+                                                                                  $($path_to_types)*::_export_method_input_stream_read_cabi::<<$ty as $($path_to_types)*::Guest>::InputStream>(arg0, arg1)
+                                                                                }
+                                                                                #[export_name = "cabi_post_wasi:io/streams@0.2.0#[method]input-stream.read"]
+                                                                                unsafe extern "C" fn _post_return_method_input_stream_read(arg0: *mut u8,) {
+                                                                                  $($path_to_types)*::__post_return_method_input_stream_read::<<$ty as $($path_to_types)*::Guest>::InputStream>(arg0)
+                                                                                }
+                                                                                #[export_name = "wasi:io/streams@0.2.0#[method]input-stream.blocking-read"]
+                                                                                unsafe extern "C" fn export_method_input_stream_blocking_read(arg0: *mut u8,arg1: i64,) -> *mut u8 {
+                                                                                  $($path_to_types)*::_export_method_input_stream_blocking_read_cabi::<<$ty as $($path_to_types)*::Guest>::InputStream>(arg0, arg1)
+                                                                                }
+                                                                                #[export_name = "cabi_post_wasi:io/streams@0.2.0#[method]input-stream.blocking-read"]
+                                                                                unsafe extern "C" fn _post_return_method_input_stream_blocking_read(arg0: *mut u8,) {
+                                                                                  $($path_to_types)*::__post_return_method_input_stream_blocking_read::<<$ty as $($path_to_types)*::Guest>::InputStream>(arg0)
+                                                                                }
+                                                                                #[export_name = "wasi:io/streams@0.2.0#[method]input-stream.skip"]
+                                                                                unsafe extern "C" fn export_method_input_stream_skip(arg0: *mut u8,arg1: i64,) -> *mut u8 {
+                                                                                  $($path_to_types)*::_export_method_input_stream_skip_cabi::<<$ty as $($path_to_types)*::Guest>::InputStream>(arg0, arg1)
+                                                                                }
+                                                                                #[export_name = "wasi:io/streams@0.2.0#[method]input-stream.blocking-skip"]
+                                                                                unsafe extern "C" fn export_method_input_stream_blocking_skip(arg0: *mut u8,arg1: i64,) -> *mut u8 {
+                                                                                  $($path_to_types)*::_export_method_input_stream_blocking_skip_cabi::<<$ty as $($path_to_types)*::Guest>::InputStream>(arg0, arg1)
+                                                                                }
+                                                                                #[export_name = "wasi:io/streams@0.2.0#[method]input-stream.subscribe"]
+                                                                                unsafe extern "C" fn export_method_input_stream_subscribe(arg0: *mut u8,) -> i32 {
+                                                                                  $($path_to_types)*::_export_method_input_stream_subscribe_cabi::<<$ty as $($path_to_types)*::Guest>::InputStream>(arg0)
+                                                                                }
+                                                                                #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.check-write"]
+                                                                                unsafe extern "C" fn export_method_output_stream_check_write(arg0: *mut u8,) -> *mut u8 {
+                                                                                  $($path_to_types)*::_export_method_output_stream_check_write_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0)
+                                                                                }
+                                                                                #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.write"]
+                                                                                unsafe extern "C" fn export_method_output_stream_write(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
+                                                                                  $($path_to_types)*::_export_method_output_stream_write_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0, arg1, arg2)
+                                                                                }
+                                                                                #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.blocking-write-and-flush"]
+                                                                                unsafe extern "C" fn export_method_output_stream_blocking_write_and_flush(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
+                                                                                  $($path_to_types)*::_export_method_output_stream_blocking_write_and_flush_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0, arg1, arg2)
+                                                                                }
+                                                                                #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.flush"]
+                                                                                unsafe extern "C" fn export_method_output_stream_flush(arg0: *mut u8,) -> *mut u8 {
+                                                                                  $($path_to_types)*::_export_method_output_stream_flush_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0)
+                                                                                }
+                                                                                #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.blocking-flush"]
+                                                                                unsafe extern "C" fn export_method_output_stream_blocking_flush(arg0: *mut u8,) -> *mut u8 {
+                                                                                  $($path_to_types)*::_export_method_output_stream_blocking_flush_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0)
+                                                                                }
+                                                                                #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.subscribe"]
+                                                                                unsafe extern "C" fn export_method_output_stream_subscribe(arg0: *mut u8,) -> i32 {
+                                                                                  $($path_to_types)*::_export_method_output_stream_subscribe_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0)
+                                                                                }
+                                                                                #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.write-zeroes"]
+                                                                                unsafe extern "C" fn export_method_output_stream_write_zeroes(arg0: *mut u8,arg1: i64,) -> *mut u8 {
+                                                                                  $($path_to_types)*::_export_method_output_stream_write_zeroes_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0, arg1)
+                                                                                }
+                                                                                #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.blocking-write-zeroes-and-flush"]
+                                                                                unsafe extern "C" fn export_method_output_stream_blocking_write_zeroes_and_flush(arg0: *mut u8,arg1: i64,) -> *mut u8 {
+                                                                                  $($path_to_types)*::_export_method_output_stream_blocking_write_zeroes_and_flush_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0, arg1)
+                                                                                }
+                                                                                #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.splice"]
+                                                                                unsafe extern "C" fn export_method_output_stream_splice(arg0: *mut u8,arg1: i32,arg2: i64,) -> *mut u8 {
+                                                                                  $($path_to_types)*::_export_method_output_stream_splice_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0, arg1, arg2)
+                                                                                }
+                                                                                #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.blocking-splice"]
+                                                                                unsafe extern "C" fn export_method_output_stream_blocking_splice(arg0: *mut u8,arg1: i32,arg2: i64,) -> *mut u8 {
+                                                                                  $($path_to_types)*::_export_method_output_stream_blocking_splice_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0, arg1, arg2)
+                                                                                }
+
+                                                                                const _: () = {
+                                                                                  #[doc(hidden)]
+                                                                                  #[export_name = "wasi:io/streams@0.2.0#[dtor]input-stream"]
+                                                                                  #[allow(non_snake_case)]
+                                                                                  unsafe extern "C" fn dtor(rep: *mut u8) {
+                                                                                    $($path_to_types)*::InputStream::dtor::<
+                                                                                    <$ty as $($path_to_types)*::Guest>::InputStream
+                                                                                    >(rep)
                                                                                   }
-                                                                                  #[export_name = "wasi:io/streams@0.2.0#[method]input-stream.subscribe"]
-                                                                                  unsafe extern "C" fn export_method_input_stream_subscribe(arg0: *mut u8,) -> i32 {
-                                                                                    $($path_to_types)*::_export_method_input_stream_subscribe_cabi::<<$ty as $($path_to_types)*::Guest>::InputStream>(arg0)
-                                                                                  }
-                                                                                  #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.check-write"]
-                                                                                  unsafe extern "C" fn export_method_output_stream_check_write(arg0: *mut u8,) -> *mut u8 {
-                                                                                    $($path_to_types)*::_export_method_output_stream_check_write_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0)
-                                                                                  }
-                                                                                  #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.write"]
-                                                                                  unsafe extern "C" fn export_method_output_stream_write(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
-                                                                                    $($path_to_types)*::_export_method_output_stream_write_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0, arg1, arg2)
-                                                                                  }
-                                                                                  #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.blocking-write-and-flush"]
-                                                                                  unsafe extern "C" fn export_method_output_stream_blocking_write_and_flush(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
-                                                                                    $($path_to_types)*::_export_method_output_stream_blocking_write_and_flush_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0, arg1, arg2)
-                                                                                  }
-                                                                                  #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.flush"]
-                                                                                  unsafe extern "C" fn export_method_output_stream_flush(arg0: *mut u8,) -> *mut u8 {
-                                                                                    $($path_to_types)*::_export_method_output_stream_flush_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0)
-                                                                                  }
-                                                                                  #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.blocking-flush"]
-                                                                                  unsafe extern "C" fn export_method_output_stream_blocking_flush(arg0: *mut u8,) -> *mut u8 {
-                                                                                    $($path_to_types)*::_export_method_output_stream_blocking_flush_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0)
-                                                                                  }
-                                                                                  #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.subscribe"]
-                                                                                  unsafe extern "C" fn export_method_output_stream_subscribe(arg0: *mut u8,) -> i32 {
-                                                                                    $($path_to_types)*::_export_method_output_stream_subscribe_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0)
-                                                                                  }
-                                                                                  #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.write-zeroes"]
-                                                                                  unsafe extern "C" fn export_method_output_stream_write_zeroes(arg0: *mut u8,arg1: i64,) -> *mut u8 {
-                                                                                    $($path_to_types)*::_export_method_output_stream_write_zeroes_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0, arg1)
-                                                                                  }
-                                                                                  #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.blocking-write-zeroes-and-flush"]
-                                                                                  unsafe extern "C" fn export_method_output_stream_blocking_write_zeroes_and_flush(arg0: *mut u8,arg1: i64,) -> *mut u8 {
-                                                                                    $($path_to_types)*::_export_method_output_stream_blocking_write_zeroes_and_flush_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0, arg1)
-                                                                                  }
-                                                                                  #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.splice"]
-                                                                                  unsafe extern "C" fn export_method_output_stream_splice(arg0: *mut u8,arg1: i32,arg2: i64,) -> *mut u8 {
-                                                                                    $($path_to_types)*::_export_method_output_stream_splice_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0, arg1, arg2)
-                                                                                  }
-                                                                                  #[export_name = "wasi:io/streams@0.2.0#[method]output-stream.blocking-splice"]
-                                                                                  unsafe extern "C" fn export_method_output_stream_blocking_splice(arg0: *mut u8,arg1: i32,arg2: i64,) -> *mut u8 {
-                                                                                    $($path_to_types)*::_export_method_output_stream_blocking_splice_cabi::<<$ty as $($path_to_types)*::Guest>::OutputStream>(arg0, arg1, arg2)
+                                                                                };
+
+
+                                                                                const _: () = {
+                                                                                  #[doc(hidden)]
+                                                                                  #[export_name = "wasi:io/streams@0.2.0#[dtor]output-stream"]
+                                                                                  #[allow(non_snake_case)]
+                                                                                  unsafe extern "C" fn dtor(rep: *mut u8) {
+                                                                                    $($path_to_types)*::OutputStream::dtor::<
+                                                                                    <$ty as $($path_to_types)*::Guest>::OutputStream
+                                                                                    >(rep)
                                                                                   }
+                                                                                };
 
-                                                                                  const _: () = {
-                                                                                    #[doc(hidden)]
-                                                                                    #[export_name = "wasi:io/streams@0.2.0#[dtor]input-stream"]
-                                                                                    #[allow(non_snake_case)]
-                                                                                    unsafe extern "C" fn dtor(rep: *mut u8) {
-                                                                                      $($path_to_types)*::InputStream::dtor::<
-                                                                                      <$ty as $($path_to_types)*::Guest>::InputStream
-                                                                                      >(rep)
-                                                                                    }
-                                                                                  };
-
-
-                                                                                  const _: () = {
-                                                                                    #[doc(hidden)]
-                                                                                    #[export_name = "wasi:io/streams@0.2.0#[dtor]output-stream"]
-                                                                                    #[allow(non_snake_case)]
-                                                                                    unsafe extern "C" fn dtor(rep: *mut u8) {
-                                                                                      $($path_to_types)*::OutputStream::dtor::<
-                                                                                      <$ty as $($path_to_types)*::Guest>::OutputStream
-                                                                                      >(rep)
-                                                                                    }
-                                                                                  };
-
-                                                                                };);
-                                                                              }
+                                                                              };);
+                                                                            }
                 #[doc(hidden)]
                 pub(crate) use __export_wasi_io_streams_0_2_0_cabi;
                 #[repr(align(8))]
@@ -29938,22 +37975,22 @@ pub mod exports {
                 #[doc(hidden)]
 
                 macro_rules! __export_wasi_random_random_0_2_0_cabi{
-                                                                            ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
-
-                                                                              #[export_name = "wasi:random/random@0.2.0#get-random-bytes"]
-                                                                              unsafe extern "C" fn export_get_random_bytes(arg0: i64,) -> *mut u8 {
-                                                                                $($path_to_types)*::_export_get_random_bytes_cabi::<$ty>(arg0)
-                                                                              }
-                                                                              #[export_name = "cabi_post_wasi:random/random@0.2.0#get-random-bytes"]
-                                                                              unsafe extern "C" fn _post_return_get_random_bytes(arg0: *mut u8,) {
-                                                                                $($path_to_types)*::__post_return_get_random_bytes::<$ty>(arg0)
-                                                                              }
-                                                                              #[export_name = "wasi:random/random@0.2.0#get-random-u64"]
-                                                                              unsafe extern "C" fn export_get_random_u64() -> i64 {
-                                                                                $($path_to_types)*::_export_get_random_u64_cabi::<$ty>()
-                                                                              }
-                                                                            };);
-                                                                          }
+                                                                          ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+                                                                            #[export_name = "wasi:random/random@0.2.0#get-random-bytes"]
+                                                                            unsafe extern "C" fn export_get_random_bytes(arg0: i64,) -> *mut u8 {
+                                                                              $($path_to_types)*::_export_get_random_bytes_cabi::<$ty>(arg0)
+                                                                            }
+                                                                            #[export_name = "cabi_post_wasi:random/random@0.2.0#get-random-bytes"]
+                                                                            unsafe extern "C" fn _post_return_get_random_bytes(arg0: *mut u8,) {
+                                                                              $($path_to_types)*::__post_return_get_random_bytes::<$ty>(arg0)
+                                                                            }
+                                                                            #[export_name = "wasi:random/random@0.2.0#get-random-u64"]
+                                                                            unsafe extern "C" fn export_get_random_u64() -> i64 {
+                                                                              $($path_to_types)*::_export_get_random_u64_cabi::<$ty>()
+                                                                            }
+                                                                          };);
+                                                                        }
                 #[doc(hidden)]
                 pub(crate) use __export_wasi_random_random_0_2_0_cabi;
                 #[repr(align(4))]
@@ -30022,22 +38059,22 @@ pub mod exports {
                 #[doc(hidden)]
 
                 macro_rules! __export_wasi_random_insecure_0_2_0_cabi{
-                                                                        ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
-
-                                                                          #[export_name = "wasi:random/insecure@0.2.0#get-insecure-random-bytes"]
-                                                                          unsafe extern "C" fn export_get_insecure_random_bytes(arg0: i64,) -> *mut u8 {
-                                                                            $($path_to_types)*::_export_get_insecure_random_bytes_cabi::<$ty>(arg0)
-                                                                          }
-                                                                          #[export_name = "cabi_post_wasi:random/insecure@0.2.0#get-insecure-random-bytes"]
-                                                                          unsafe extern "C" fn _post_return_get_insecure_random_bytes(arg0: *mut u8,) {
-                                                                            $($path_to_types)*::__post_return_get_insecure_random_bytes::<$ty>(arg0)
-                                                                          }
-                                                                          #[export_name = "wasi:random/insecure@0.2.0#get-insecure-random-u64"]
-                                                                          unsafe extern "C" fn export_get_insecure_random_u64() -> i64 {
-                                                                            $($path_to_types)*::_export_get_insecure_random_u64_cabi::<$ty>()
-                                                                          }
-                                                                        };);
-                                                                      }
+                                                                      ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+                                                                        #[export_name = "wasi:random/insecure@0.2.0#get-insecure-random-bytes"]
+                                                                        unsafe extern "C" fn export_get_insecure_random_bytes(arg0: i64,) -> *mut u8 {
+                                                                          $($path_to_types)*::_export_get_insecure_random_bytes_cabi::<$ty>(arg0)
+                                                                        }
+                                                                        #[export_name = "cabi_post_wasi:random/insecure@0.2.0#get-insecure-random-bytes"]
+                                                                        unsafe extern "C" fn _post_return_get_insecure_random_bytes(arg0: *mut u8,) {
+                                                                          $($path_to_types)*::__post_return_get_insecure_random_bytes::<$ty>(arg0)
+                                                                        }
+                                                                        #[export_name = "wasi:random/insecure@0.2.0#get-insecure-random-u64"]
+                                                                        unsafe extern "C" fn export_get_insecure_random_u64() -> i64 {
+                                                                          $($path_to_types)*::_export_get_insecure_random_u64_cabi::<$ty>()
+                                                                        }
+                                                                      };);
+                                                                    }
                 #[doc(hidden)]
                 pub(crate) use __export_wasi_random_insecure_0_2_0_cabi;
                 #[repr(align(4))]
@@ -30088,14 +38125,14 @@ pub mod exports {
                 #[doc(hidden)]
 
                 macro_rules! __export_wasi_random_insecure_seed_0_2_0_cabi{
-                                                                      ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+                                                                    ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
-                                                                        #[export_name = "wasi:random/insecure-seed@0.2.0#insecure-seed"]
-                                                                        unsafe extern "C" fn export_insecure_seed() -> *mut u8 {
-                                                                          $($path_to_types)*::_export_insecure_seed_cabi::<$ty>()
-                                                                        }
-                                                                      };);
-                                                                    }
+                                                                      #[export_name = "wasi:random/insecure-seed@0.2.0#insecure-seed"]
+                                                                      unsafe extern "C" fn export_insecure_seed() -> *mut u8 {
+                                                                        $($path_to_types)*::_export_insecure_seed_cabi::<$ty>()
+                                                                      }
+                                                                    };);
+                                                                  }
                 #[doc(hidden)]
                 pub(crate) use __export_wasi_random_insecure_seed_0_2_0_cabi;
                 #[repr(align(8))]
@@ -30360,45 +38397,45 @@ pub mod exports {
                     }
                     pub fn message(&self) -> &'static str {
                         match self {
-                                                                          ErrorCode::Unknown => "Unknown error",
-                                                                          ErrorCode::AccessDenied => "Access denied.
-
-                                                                          POSIX equivalent: EACCES, EPERM",
-                                                                          ErrorCode::NotSupported => "The operation is not supported.
-
-                                                                          POSIX equivalent: EOPNOTSUPP",
-                                                                          ErrorCode::InvalidArgument => "One of the arguments is invalid.
-
-                                                                          POSIX equivalent: EINVAL",
-                                                                          ErrorCode::OutOfMemory => "Not enough memory to complete the operation.
-
-                                                                          POSIX equivalent: ENOMEM, ENOBUFS, EAI_MEMORY",
-                                                                          ErrorCode::Timeout => "The operation timed out before it could finish completely.",
-                                                                          ErrorCode::ConcurrencyConflict => "This operation is incompatible with another asynchronous operation that is already in progress.
-
-                                                                          POSIX equivalent: EALREADY",
-                                                                          ErrorCode::NotInProgress => "Trying to finish an asynchronous operation that:
-                                                                          - has not been started yet, or:
-                                                                          - was already finished by a previous `finish-*` call.
-
-                                                                          Note: this is scheduled to be removed when `future`s are natively supported.",
-                                                                          ErrorCode::WouldBlock => "The operation has been aborted because it could not be completed immediately.
-
-                                                                          Note: this is scheduled to be removed when `future`s are natively supported.",
-                                                                          ErrorCode::InvalidState => "The operation is not valid in the socket's current state.",
-                                                                          ErrorCode::NewSocketLimit => "A new socket resource could not be created because of a system limit.",
-                                                                          ErrorCode::AddressNotBindable => "A bind operation failed because the provided address is not an address that the `network` can bind to.",
-                                                                          ErrorCode::AddressInUse => "A bind operation failed because the provided address is already in use or because there are no ephemeral ports available.",
-                                                                          ErrorCode::RemoteUnreachable => "The remote address is not reachable",
-                                                                          ErrorCode::ConnectionRefused => "The TCP connection was forcefully rejected",
-                                                                          ErrorCode::ConnectionReset => "The TCP connection was reset.",
-                                                                          ErrorCode::ConnectionAborted => "A TCP connection was aborted.",
-                                                                          ErrorCode::DatagramTooLarge => "The size of a datagram sent to a UDP socket exceeded the maximum
-                                                                          supported size.",
-                                                                          ErrorCode::NameUnresolvable => "Name does not exist or has no suitable associated IP addresses.",
-                                                                          ErrorCode::TemporaryResolverFailure => "A temporary failure in name resolution occurred.",
-                                                                          ErrorCode::PermanentResolverFailure => "A permanent failure in name resolution occurred.",
-                                                                        }
+                                                                        ErrorCode::Unknown => "Unknown error",
+                                                                        ErrorCode::AccessDenied => "Access denied.
+
+                                                                        POSIX equivalent: EACCES, EPERM",
+                                                                        ErrorCode::NotSupported => "The operation is not supported.
+
+                                                                        POSIX equivalent: EOPNOTSUPP",
+                                                                        ErrorCode::InvalidArgument => "One of the arguments is invalid.
+
+                                                                        POSIX equivalent: EINVAL",
+                                                                        ErrorCode::OutOfMemory => "Not enough memory to complete the operation.
+
+                                                                        POSIX equivalent: ENOMEM, ENOBUFS, EAI_MEMORY",
+                                                                        ErrorCode::Timeout => "The operation timed out before it could finish completely.",
+                                                                        ErrorCode::ConcurrencyConflict => "This operation is incompatible with another asynchronous operation that is already in progress.
+
+                                                                        POSIX equivalent: EALREADY",
+                                                                        ErrorCode::NotInProgress => "Trying to finish an asynchronous operation that:
+                                                                        - has not been started yet, or:
+                                                                        - was already finished by a previous `finish-*` call.
+
+                                                                        Note: this is scheduled to be removed when `future`s are natively supported.",
+                                                                        ErrorCode::WouldBlock => "The operation has been aborted because it could not be completed immediately.
+
+                                                                        Note: this is scheduled to be removed when `future`s are natively supported.",
+                                                                        ErrorCode::InvalidState => "The operation is not valid in the socket's current state.",
+                                                                        ErrorCode::NewSocketLimit => "A new socket resource could not be created because of a system limit.",
+                                                                        ErrorCode::AddressNotBindable => "A bind operation failed because the provided address is not an address that the `network` can bind to.",
+                                                                        ErrorCode::AddressInUse => "A bind operation failed because the provided address is already in use or because there are no ephemeral ports available.",
+                                                                        ErrorCode::RemoteUnreachable => "The remote address is not reachable",
+                                                                        ErrorCode::ConnectionRefused => "The TCP connection was forcefully rejected",
+                                                                        ErrorCode::ConnectionReset => "The TCP connection was reset.",
+                                                                        ErrorCode::ConnectionAborted => "A TCP connection was aborted.",
+                                                                        ErrorCode::DatagramTooLarge => "The size of a datagram sent to a UDP socket exceeded the maximum
+                                                                        supported size.",
+                                                                        ErrorCode::NameUnresolvable => "Name does not exist or has no suitable associated IP addresses.",
+                                                                        ErrorCode::TemporaryResolverFailure => "A temporary failure in name resolution occurred.",
+                                                                        ErrorCode::PermanentResolverFailure => "A permanent failure in name resolution occurred.",
+                                                                      }
                     }
                 }
                 impl ::core::fmt::Debug for ErrorCode {
@@ -30615,22 +38652,22 @@ pub mod exports {
                 #[doc(hidden)]
 
                 macro_rules! __export_wasi_sockets_network_0_2_0_cabi{
-                                                                      ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+                                                                    ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
 
-                                                                        const _: () = {
-                                                                          #[doc(hidden)]
-                                                                          #[export_name = "wasi:sockets/network@0.2.0#[dtor]network"]
-                                                                          #[allow(non_snake_case)]
-                                                                          unsafe extern "C" fn dtor(rep: *mut u8) {
-                                                                            $($path_to_types)*::Network::dtor::<
-                                                                            <$ty as $($path_to_types)*::Guest>::Network
-                                                                            >(rep)
-                                                                          }
-                                                                        };
+                                                                      const _: () = {
+                                                                        #[doc(hidden)]
+                                                                        #[export_name = "wasi:sockets/network@0.2.0#[dtor]network"]
+                                                                        #[allow(non_snake_case)]
+                                                                        unsafe extern "C" fn dtor(rep: *mut u8) {
+                                                                          $($path_to_types)*::Network::dtor::<
+                                                                          <$ty as $($path_to_types)*::Guest>::Network
+                                                                          >(rep)
+                                                                        }
+                                                                      };
 
-                                                                      };);
-                                                                    }
+                                                                    };);
+                                                                  }
                 #[doc(hidden)]
                 pub(crate) use __export_wasi_sockets_network_0_2_0_cabi;
             }
@@ -30662,14 +38699,14 @@ pub mod exports {
                 #[doc(hidden)]
 
                 macro_rules! __export_wasi_sockets_instance_network_0_2_0_cabi{
-                                                                    ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+                                                                  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
-                                                                      #[export_name = "wasi:sockets/instance-network@0.2.0#instance-network"]
-                                                                      unsafe extern "C" fn export_instance_network() -> i32 {
-                                                                        $($path_to_types)*::_export_instance_network_cabi::<$ty>()
-                                                                      }
-                                                                    };);
-                                                                  }
+                                                                    #[export_name = "wasi:sockets/instance-network@0.2.0#instance-network"]
+                                                                    unsafe extern "C" fn export_instance_network() -> i32 {
+                                                                      $($path_to_types)*::_export_instance_network_cabi::<$ty>()
+                                                                    }
+                                                                  };);
+                                                                }
                 #[doc(hidden)]
                 pub(crate) use __export_wasi_sockets_instance_network_0_2_0_cabi;
             }
@@ -31191,19 +39228,19 @@ pub mod exports {
                     let v0 = match arg2 {
                         0 => {
                             let e0 = super::super::super::super::exports::wasi::sockets::network::Ipv4SocketAddress{
-                                                                        port: arg3 as u16,
-                                                                        address: (arg4 as u8, arg5 as u8, arg6 as u8, arg7 as u8),
-                                                                      };
+                                                                      port: arg3 as u16,
+                                                                      address: (arg4 as u8, arg5 as u8, arg6 as u8, arg7 as u8),
+                                                                    };
                             V0::Ipv4(e0)
                         }
                         n => {
                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                             let e0 = super::super::super::super::exports::wasi::sockets::network::Ipv6SocketAddress{
-                                                                        port: arg3 as u16,
-                                                                        flow_info: arg4 as u32,
-                                                                        address: (arg5 as u16, arg6 as u16, arg7 as u16, arg8 as u16, arg9 as u16, arg10 as u16, arg11 as u16, arg12 as u16),
-                                                                        scope_id: arg13 as u32,
-                                                                      };
+                                                                      port: arg3 as u16,
+                                                                      flow_info: arg4 as u32,
+                                                                      address: (arg5 as u16, arg6 as u16, arg7 as u16, arg8 as u16, arg9 as u16, arg10 as u16, arg11 as u16, arg12 as u16),
+                                                                      scope_id: arg13 as u32,
+                                                                    };
                             V0::Ipv6(e0)
                         }
                     };
@@ -31274,19 +39311,19 @@ pub mod exports {
                                     let v0 = match arg2 {
                                         0 => {
                                             let e0 = super::super::super::super::exports::wasi::sockets::network::Ipv4SocketAddress{
-                                                                          port: arg3 as u16,
-                                                                          address: (arg4 as u8, arg5 as u8, arg6 as u8, arg7 as u8),
-                                                                        };
+                                                                        port: arg3 as u16,
+                                                                        address: (arg4 as u8, arg5 as u8, arg6 as u8, arg7 as u8),
+                                                                      };
                                             V0::Ipv4(e0)
                                         }
                                         n => {
                                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                                             let e0 = super::super::super::super::exports::wasi::sockets::network::Ipv6SocketAddress{
-                                                                          port: arg3 as u16,
-                                                                          flow_info: arg4 as u32,
-                                                                          address: (arg5 as u16, arg6 as u16, arg7 as u16, arg8 as u16, arg9 as u16, arg10 as u16, arg11 as u16, arg12 as u16),
-                                                                          scope_id: arg13 as u32,
-                                                                        };
+                                                                        port: arg3 as u16,
+                                                                        flow_info: arg4 as u32,
+                                                                        address: (arg5 as u16, arg6 as u16, arg7 as u16, arg8 as u16, arg9 as u16, arg10 as u16, arg11 as u16, arg12 as u16),
+                                                                        scope_id: arg13 as u32,
+                                                                      };
                                             V0::Ipv6(e0)
                                         }
                                     };
@@ -31793,9 +39830,9 @@ pub mod exports {
                                                             i32::from(*base.add(21).cast::<u8>());
 
                                                         super::super::super::super::exports::wasi::sockets::network::Ipv4SocketAddress{
-                                                      port: l5 as u16,
-                                                      address: (l6 as u8, l7 as u8, l8 as u8, l9 as u8),
-                                                    }
+                                                    port: l5 as u16,
+                                                    address: (l6 as u8, l7 as u8, l8 as u8, l9 as u8),
+                                                  }
                                                     };
                                                     V21::Ipv4(e21)
                                                 }
@@ -31827,11 +39864,11 @@ pub mod exports {
                                                         let l20 = *base.add(40).cast::<i32>();
 
                                                         super::super::super::super::exports::wasi::sockets::network::Ipv6SocketAddress{
-                                                      port: l10 as u16,
-                                                      flow_info: l11 as u32,
-                                                      address: (l12 as u16, l13 as u16, l14 as u16, l15 as u16, l16 as u16, l17 as u16, l18 as u16, l19 as u16),
-                                                      scope_id: l20 as u32,
-                                                    }
+                                                    port: l10 as u16,
+                                                    flow_info: l11 as u32,
+                                                    address: (l12 as u16, l13 as u16, l14 as u16, l15 as u16, l16 as u16, l17 as u16, l18 as u16, l19 as u16),
+                                                    scope_id: l20 as u32,
+                                                  }
                                                     };
                                                     V21::Ipv6(e21)
                                                 }
@@ -32245,122 +40282,122 @@ pub mod exports {
                 #[doc(hidden)]
 
                 macro_rules! __export_wasi_sockets_udp_0_2_0_cabi{
-                                ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+                              ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.start-bind"]
-                                  unsafe extern "C" fn export_method_udp_socket_start_bind(arg0: *mut u8,arg1: i32,arg2: i32,arg3: i32,arg4: i32,arg5: i32,arg6: i32,arg7: i32,arg8: i32,arg9: i32,arg10: i32,arg11: i32,arg12: i32,arg13: i32,) -> *mut u8 {
-                                    $($path_to_types)*::_export_method_udp_socket_start_bind_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.finish-bind"]
-                                  unsafe extern "C" fn export_method_udp_socket_finish_bind(arg0: *mut u8,) -> *mut u8 {
-                                    $($path_to_types)*::_export_method_udp_socket_finish_bind_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.stream"]
-                                  unsafe extern "C" fn export_method_udp_socket_stream(arg0: *mut u8,arg1: i32,arg2: i32,arg3: i32,arg4: i32,arg5: i32,arg6: i32,arg7: i32,arg8: i32,arg9: i32,arg10: i32,arg11: i32,arg12: i32,arg13: i32,) -> *mut u8 {
-                                    $($path_to_types)*::_export_method_udp_socket_stream_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.local-address"]
-                                  unsafe extern "C" fn export_method_udp_socket_local_address(arg0: *mut u8,) -> *mut u8 {
-                                    $($path_to_types)*::_export_method_udp_socket_local_address_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.remote-address"]
-                                  unsafe extern "C" fn export_method_udp_socket_remote_address(arg0: *mut u8,) -> *mut u8 {
-                                    $($path_to_types)*::_export_method_udp_socket_remote_address_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.address-family"]
-                                  unsafe extern "C" fn export_method_udp_socket_address_family(arg0: *mut u8,) -> i32 {
-                                    $($path_to_types)*::_export_method_udp_socket_address_family_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.unicast-hop-limit"]
-                                  unsafe extern "C" fn export_method_udp_socket_unicast_hop_limit(arg0: *mut u8,) -> *mut u8 {
-                                    $($path_to_types)*::_export_method_udp_socket_unicast_hop_limit_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.set-unicast-hop-limit"]
-                                  unsafe extern "C" fn export_method_udp_socket_set_unicast_hop_limit(arg0: *mut u8,arg1: i32,) -> *mut u8 {
-                                    $($path_to_types)*::_export_method_udp_socket_set_unicast_hop_limit_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0, arg1)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.receive-buffer-size"]
-                                  unsafe extern "C" fn export_method_udp_socket_receive_buffer_size(arg0: *mut u8,) -> *mut u8 {
-                                    $($path_to_types)*::_export_method_udp_socket_receive_buffer_size_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.set-receive-buffer-size"]
-                                  unsafe extern "C" fn export_method_udp_socket_set_receive_buffer_size(arg0: *mut u8,arg1: i64,) -> *mut u8 {
-                                    $($path_to_types)*::_export_method_udp_socket_set_receive_buffer_size_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0, arg1)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.send-buffer-size"]
-                                  unsafe extern "C" fn export_method_udp_socket_send_buffer_size(arg0: *mut u8,) -> *mut u8 {
-                                    $($path_to_types)*::_export_method_udp_socket_send_buffer_size_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.set-send-buffer-size"]
-                                  unsafe extern "C" fn export_method_udp_socket_set_send_buffer_size(arg0: *mut u8,arg1: i64,) -> *mut u8 {
-                                    $($path_to_types)*::_export_method_udp_socket_set_send_buffer_size_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0, arg1)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.subscribe"]
-                                  unsafe extern "C" fn export_method_udp_socket_subscribe(arg0: *mut u8,) -> i32 {
-                                    $($path_to_types)*::_export_method_udp_socket_subscribe_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]incoming-datagram-stream.receive"]
-                                  unsafe extern "C" fn export_method_incoming_datagram_stream_receive(arg0: *mut u8,arg1: i64,) -> *mut u8 {
-                                    $($path_to_types)*::_export_method_incoming_datagram_stream_receive_cabi::<<$ty as $($path_to_types)*::Guest>::IncomingDatagramStream>(arg0, arg1)
-                                  }
-                                  #[export_name = "cabi_post_wasi:sockets/udp@0.2.0#[method]incoming-datagram-stream.receive"]
-                                  unsafe extern "C" fn _post_return_method_incoming_datagram_stream_receive(arg0: *mut u8,) {
-                                    $($path_to_types)*::__post_return_method_incoming_datagram_stream_receive::<<$ty as $($path_to_types)*::Guest>::IncomingDatagramStream>(arg0)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]incoming-datagram-stream.subscribe"]
-                                  unsafe extern "C" fn export_method_incoming_datagram_stream_subscribe(arg0: *mut u8,) -> i32 {
-                                    $($path_to_types)*::_export_method_incoming_datagram_stream_subscribe_cabi::<<$ty as $($path_to_types)*::Guest>::IncomingDatagramStream>(arg0)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]outgoing-datagram-stream.check-send"]
-                                  unsafe extern "C" fn export_method_outgoing_datagram_stream_check_send(arg0: *mut u8,) -> *mut u8 {
-                                    $($path_to_types)*::_export_method_outgoing_datagram_stream_check_send_cabi::<<$ty as $($path_to_types)*::Guest>::OutgoingDatagramStream>(arg0)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]outgoing-datagram-stream.send"]
-                                  unsafe extern "C" fn export_method_outgoing_datagram_stream_send(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
-                                    $($path_to_types)*::_export_method_outgoing_datagram_stream_send_cabi::<<$ty as $($path_to_types)*::Guest>::OutgoingDatagramStream>(arg0, arg1, arg2)
-                                  }
-                                  #[export_name = "wasi:sockets/udp@0.2.0#[method]outgoing-datagram-stream.subscribe"]
-                                  unsafe extern "C" fn export_method_outgoing_datagram_stream_subscribe(arg0: *mut u8,) -> i32 {
-                                    $($path_to_types)*::_export_method_outgoing_datagram_stream_subscribe_cabi::<<$ty as $($path_to_types)*::Guest>::OutgoingDatagramStream>(arg0)
-                                  }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.start-bind"]
+                                unsafe extern "C" fn export_method_udp_socket_start_bind(arg0: *mut u8,arg1: i32,arg2: i32,arg3: i32,arg4: i32,arg5: i32,arg6: i32,arg7: i32,arg8: i32,arg9: i32,arg10: i32,arg11: i32,arg12: i32,arg13: i32,) -> *mut u8 {
+                                  $($path_to_types)*::_export_method_udp_socket_start_bind_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.finish-bind"]
+                                unsafe extern "C" fn export_method_udp_socket_finish_bind(arg0: *mut u8,) -> *mut u8 {
+                                  $($path_to_types)*::_export_method_udp_socket_finish_bind_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.stream"]
+                                unsafe extern "C" fn export_method_udp_socket_stream(arg0: *mut u8,arg1: i32,arg2: i32,arg3: i32,arg4: i32,arg5: i32,arg6: i32,arg7: i32,arg8: i32,arg9: i32,arg10: i32,arg11: i32,arg12: i32,arg13: i32,) -> *mut u8 {
+                                  $($path_to_types)*::_export_method_udp_socket_stream_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.local-address"]
+                                unsafe extern "C" fn export_method_udp_socket_local_address(arg0: *mut u8,) -> *mut u8 {
+                                  $($path_to_types)*::_export_method_udp_socket_local_address_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.remote-address"]
+                                unsafe extern "C" fn export_method_udp_socket_remote_address(arg0: *mut u8,) -> *mut u8 {
+                                  $($path_to_types)*::_export_method_udp_socket_remote_address_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.address-family"]
+                                unsafe extern "C" fn export_method_udp_socket_address_family(arg0: *mut u8,) -> i32 {
+                                  $($path_to_types)*::_export_method_udp_socket_address_family_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.unicast-hop-limit"]
+                                unsafe extern "C" fn export_method_udp_socket_unicast_hop_limit(arg0: *mut u8,) -> *mut u8 {
+                                  $($path_to_types)*::_export_method_udp_socket_unicast_hop_limit_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.set-unicast-hop-limit"]
+                                unsafe extern "C" fn export_method_udp_socket_set_unicast_hop_limit(arg0: *mut u8,arg1: i32,) -> *mut u8 {
+                                  $($path_to_types)*::_export_method_udp_socket_set_unicast_hop_limit_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0, arg1)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.receive-buffer-size"]
+                                unsafe extern "C" fn export_method_udp_socket_receive_buffer_size(arg0: *mut u8,) -> *mut u8 {
+                                  $($path_to_types)*::_export_method_udp_socket_receive_buffer_size_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.set-receive-buffer-size"]
+                                unsafe extern "C" fn export_method_udp_socket_set_receive_buffer_size(arg0: *mut u8,arg1: i64,) -> *mut u8 {
+                                  $($path_to_types)*::_export_method_udp_socket_set_receive_buffer_size_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0, arg1)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.send-buffer-size"]
+                                unsafe extern "C" fn export_method_udp_socket_send_buffer_size(arg0: *mut u8,) -> *mut u8 {
+                                  $($path_to_types)*::_export_method_udp_socket_send_buffer_size_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.set-send-buffer-size"]
+                                unsafe extern "C" fn export_method_udp_socket_set_send_buffer_size(arg0: *mut u8,arg1: i64,) -> *mut u8 {
+                                  $($path_to_types)*::_export_method_udp_socket_set_send_buffer_size_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0, arg1)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]udp-socket.subscribe"]
+                                unsafe extern "C" fn export_method_udp_socket_subscribe(arg0: *mut u8,) -> i32 {
+                                  $($path_to_types)*::_export_method_udp_socket_subscribe_cabi::<<$ty as $($path_to_types)*::Guest>::UdpSocket>(arg0)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]incoming-datagram-stream.receive"]
+                                unsafe extern "C" fn export_method_incoming_datagram_stream_receive(arg0: *mut u8,arg1: i64,) -> *mut u8 {
+                                  $($path_to_types)*::_export_method_incoming_datagram_stream_receive_cabi::<<$ty as $($path_to_types)*::Guest>::IncomingDatagramStream>(arg0, arg1)
+                                }
+                                #[export_name = "cabi_post_wasi:sockets/udp@0.2.0#[method]incoming-datagram-stream.receive"]
+                                unsafe extern "C" fn _post_return_method_incoming_datagram_stream_receive(arg0: *mut u8,) {
+                                  $($path_to_types)*::__post_return_method_incoming_datagram_stream_receive::<<$ty as $($path_to_types)*::Guest>::IncomingDatagramStream>(arg0)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]incoming-datagram-stream.subscribe"]
+                                unsafe extern "C" fn export_method_incoming_datagram_stream_subscribe(arg0: *mut u8,) -> i32 {
+                                  $($path_to_types)*::_export_method_incoming_datagram_stream_subscribe_cabi::<<$ty as $($path_to_types)*::Guest>::IncomingDatagramStream>(arg0)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]outgoing-datagram-stream.check-send"]
+                                unsafe extern "C" fn export_method_outgoing_datagram_stream_check_send(arg0: *mut u8,) -> *mut u8 {
+                                  $($path_to_types)*::_export_method_outgoing_datagram_stream_check_send_cabi::<<$ty as $($path_to_types)*::Guest>::OutgoingDatagramStream>(arg0)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]outgoing-datagram-stream.send"]
+                                unsafe extern "C" fn export_method_outgoing_datagram_stream_send(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
+                                  $($path_to_types)*::_export_method_outgoing_datagram_stream_send_cabi::<<$ty as $($path_to_types)*::Guest>::OutgoingDatagramStream>(arg0, arg1, arg2)
+                                }
+                                #[export_name = "wasi:sockets/udp@0.2.0#[method]outgoing-datagram-stream.subscribe"]
+                                unsafe extern "C" fn export_method_outgoing_datagram_stream_subscribe(arg0: *mut u8,) -> i32 {
+                                  $($path_to_types)*::_export_method_outgoing_datagram_stream_subscribe_cabi::<<$ty as $($path_to_types)*::Guest>::OutgoingDatagramStream>(arg0)
+                                }
 
-                                  const _: () = {
-                                    #[doc(hidden)]
-                                    #[export_name = "wasi:sockets/udp@0.2.0#[dtor]udp-socket"]
-                                    #[allow(non_snake_case)]
-                                    unsafe extern "C" fn dtor(rep: *mut u8) {
-                                      $($path_to_types)*::UdpSocket::dtor::<
-                                      <$ty as $($path_to_types)*::Guest>::UdpSocket
-                                      >(rep)
-                                    }
-                                  };
+                                const _: () = {
+                                  #[doc(hidden)]
+                                  #[export_name = "wasi:sockets/udp@0.2.0#[dtor]udp-socket"]
+                                  #[allow(non_snake_case)]
+                                  unsafe extern "C" fn dtor(rep: *mut u8) {
+                                    $($path_to_types)*::UdpSocket::dtor::<
+                                    <$ty as $($path_to_types)*::Guest>::UdpSocket
+                                    >(rep)
+                                  }
+                                };
 
 
-                                  const _: () = {
-                                    #[doc(hidden)]
-                                    #[export_name = "wasi:sockets/udp@0.2.0#[dtor]incoming-datagram-stream"]
-                                    #[allow(non_snake_case)]
-                                    unsafe extern "C" fn dtor(rep: *mut u8) {
-                                      $($path_to_types)*::IncomingDatagramStream::dtor::<
-                                      <$ty as $($path_to_types)*::Guest>::IncomingDatagramStream
-                                      >(rep)
-                                    }
-                                  };
+                                const _: () = {
+                                  #[doc(hidden)]
+                                  #[export_name = "wasi:sockets/udp@0.2.0#[dtor]incoming-datagram-stream"]
+                                  #[allow(non_snake_case)]
+                                  unsafe extern "C" fn dtor(rep: *mut u8) {
+                                    $($path_to_types)*::IncomingDatagramStream::dtor::<
+                                    <$ty as $($path_to_types)*::Guest>::IncomingDatagramStream
+                                    >(rep)
+                                  }
+                                };
 
 
-                                  const _: () = {
-                                    #[doc(hidden)]
-                                    #[export_name = "wasi:sockets/udp@0.2.0#[dtor]outgoing-datagram-stream"]
-                                    #[allow(non_snake_case)]
-                                    unsafe extern "C" fn dtor(rep: *mut u8) {
-                                      $($path_to_types)*::OutgoingDatagramStream::dtor::<
-                                      <$ty as $($path_to_types)*::Guest>::OutgoingDatagramStream
-                                      >(rep)
-                                    }
-                                  };
+                                const _: () = {
+                                  #[doc(hidden)]
+                                  #[export_name = "wasi:sockets/udp@0.2.0#[dtor]outgoing-datagram-stream"]
+                                  #[allow(non_snake_case)]
+                                  unsafe extern "C" fn dtor(rep: *mut u8) {
+                                    $($path_to_types)*::OutgoingDatagramStream::dtor::<
+                                    <$ty as $($path_to_types)*::Guest>::OutgoingDatagramStream
+                                    >(rep)
+                                  }
+                                };
 
-                                };);
-                              }
+                              };);
+                            }
                 #[doc(hidden)]
                 pub(crate) use __export_wasi_sockets_udp_0_2_0_cabi;
                 #[repr(align(8))]
@@ -32433,14 +40470,14 @@ pub mod exports {
                 #[doc(hidden)]
 
                 macro_rules! __export_wasi_sockets_udp_create_socket_0_2_0_cabi{
-                              ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+                            ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
-                                #[export_name = "wasi:sockets/udp-create-socket@0.2.0#create-udp-socket"]
-                                unsafe extern "C" fn export_create_udp_socket(arg0: i32,) -> *mut u8 {
-                                  $($path_to_types)*::_export_create_udp_socket_cabi::<$ty>(arg0)
-                                }
-                              };);
-                            }
+                              #[export_name = "wasi:sockets/udp-create-socket@0.2.0#create-udp-socket"]
+                              unsafe extern "C" fn export_create_udp_socket(arg0: i32,) -> *mut u8 {
+                                $($path_to_types)*::_export_create_udp_socket_cabi::<$ty>(arg0)
+                              }
+                            };);
+                          }
                 #[doc(hidden)]
                 pub(crate) use __export_wasi_sockets_udp_create_socket_0_2_0_cabi;
                 #[repr(align(4))]
@@ -32704,19 +40741,19 @@ pub mod exports {
                     let v0 = match arg2 {
                         0 => {
                             let e0 = super::super::super::super::exports::wasi::sockets::network::Ipv4SocketAddress{
-                                  port: arg3 as u16,
-                                  address: (arg4 as u8, arg5 as u8, arg6 as u8, arg7 as u8),
-                                };
+                                port: arg3 as u16,
+                                address: (arg4 as u8, arg5 as u8, arg6 as u8, arg7 as u8),
+                              };
                             V0::Ipv4(e0)
                         }
                         n => {
                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                             let e0 = super::super::super::super::exports::wasi::sockets::network::Ipv6SocketAddress{
-                                  port: arg3 as u16,
-                                  flow_info: arg4 as u32,
-                                  address: (arg5 as u16, arg6 as u16, arg7 as u16, arg8 as u16, arg9 as u16, arg10 as u16, arg11 as u16, arg12 as u16),
-                                  scope_id: arg13 as u32,
-                                };
+                                port: arg3 as u16,
+                                flow_info: arg4 as u32,
+                                address: (arg5 as u16, arg6 as u16, arg7 as u16, arg8 as u16, arg9 as u16, arg10 as u16, arg11 as u16, arg12 as u16),
+                                scope_id: arg13 as u32,
+                              };
                             V0::Ipv6(e0)
                         }
                     };
@@ -32781,19 +40818,19 @@ pub mod exports {
                     let v0 = match arg2 {
                         0 => {
                             let e0 = super::super::super::super::exports::wasi::sockets::network::Ipv4SocketAddress{
-                              port: arg3 as u16,
-                              address: (arg4 as u8, arg5 as u8, arg6 as u8, arg7 as u8),
-                            };
+                            port: arg3 as u16,
+                            address: (arg4 as u8, arg5 as u8, arg6 as u8, arg7 as u8),
+                          };
                             V0::Ipv4(e0)
                         }
                         n => {
                             debug_assert_eq!(n, 1, "invalid enum discriminant");
                             let e0 = super::super::super::super::exports::wasi::sockets::network::Ipv6SocketAddress{
-                              port: arg3 as u16,
-                              flow_info: arg4 as u32,
-                              address: (arg5 as u16, arg6 as u16, arg7 as u16, arg8 as u16, arg9 as u16, arg10 as u16, arg11 as u16, arg12 as u16),
-                              scope_id: arg13 as u32,
-                            };
+                            port: arg3 as u16,
+                            flow_info: arg4 as u32,
+                            address: (arg5 as u16, arg6 as u16, arg7 as u16, arg8 as u16, arg9 as u16, arg10 as u16, arg11 as u16, arg12 as u16),
+                            scope_id: arg13 as u32,
+                          };
                             V0::Ipv6(e0)
                         }
                     };
@@ -34719,9 +42756,19 @@ macro_rules! __export_env_impl {
   $($path_to_types_root)*::exports::fermyon::spin::mysql::__export_fermyon_spin_mysql_2_0_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin::mysql);
   $($path_to_types_root)*::exports::fermyon::spin::sqlite::__export_fermyon_spin_sqlite_2_0_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin::sqlite);
   $($path_to_types_root)*::exports::fermyon::spin::variables::__export_fermyon_spin_variables_2_0_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin::variables);
+  $($path_to_types_root)*::exports::fermyon::spin_test_virt::types::__export_fermyon_spin_test_virt_types_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin_test_virt::types);
   $($path_to_types_root)*::exports::fermyon::spin_test_virt::key_value::__export_fermyon_spin_test_virt_key_value_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin_test_virt::key_value);
+  $($path_to_types_root)*::exports::fermyon::spin_test_virt::llm::__export_fermyon_spin_test_virt_llm_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin_test_virt::llm);
   $($path_to_types_root)*::exports::fermyon::spin_test_virt::sqlite::__export_fermyon_spin_test_virt_sqlite_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin_test_virt::sqlite);
+  $($path_to_types_root)*::exports::fermyon::spin_test_virt::redis::__export_fermyon_spin_test_virt_redis_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin_test_virt::redis);
+  $($path_to_types_root)*::exports::fermyon::spin_test_virt::mqtt::__export_fermyon_spin_test_virt_mqtt_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin_test_virt::mqtt);
+  $($path_to_types_root)*::exports::fermyon::spin_test_virt::postgres::__export_fermyon_spin_test_virt_postgres_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin_test_virt::postgres);
+  $($path_to_types_root)*::exports::fermyon::spin_test_virt::mysql::__export_fermyon_spin_test_virt_mysql_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin_test_virt::mysql);
+  $($path_to_types_root)*::exports::fermyon::spin_test_virt::rdbms::__export_fermyon_spin_test_virt_rdbms_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin_test_virt::rdbms);
   $($path_to_types_root)*::exports::fermyon::spin_test_virt::variables::__export_fermyon_spin_test_virt_variables_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin_test_virt::variables);
+  $($path_to_types_root)*::exports::fermyon::spin_test_virt::clock::__export_fermyon_spin_test_virt_clock_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin_test_virt::clock);
+  $($path_to_types_root)*::exports::wasi::cli::run::__export_wasi_cli_run_0_2_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::wasi::cli::run);
+  $($path_to_types_root)*::exports::fermyon::spin_test_virt::inbound_redis::__export_fermyon_spin_test_virt_inbound_redis_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin_test_virt::inbound_redis);
   $($path_to_types_root)*::exports::wasi::io::poll::__export_wasi_io_poll_0_2_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::wasi::io::poll);
   $($path_to_types_root)*::exports::wasi::clocks::monotonic_clock::__export_wasi_clocks_monotonic_clock_0_2_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::wasi::clocks::monotonic_clock);
   $($path_to_types_root)*::exports::wasi::clocks::wall_clock::__export_wasi_clocks_wall_clock_0_2_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::wasi::clocks::wall_clock);
@@ -34751,6 +42798,7 @@ macro_rules! __export_env_impl {
   $($path_to_types_root)*::exports::wasi::sockets::ip_name_lookup::__export_wasi_sockets_ip_name_lookup_0_2_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::wasi::sockets::ip_name_lookup);
   $($path_to_types_root)*::exports::wasi::http::types::__export_wasi_http_types_0_2_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::wasi::http::types);
   $($path_to_types_root)*::exports::wasi::http::outgoing_handler::__export_wasi_http_outgoing_handler_0_2_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::wasi::http::outgoing_handler);
+  $($path_to_types_root)*::exports::wasi::http::incoming_handler::__export_wasi_http_incoming_handler_0_2_0_cabi!($ty with_types_in $($path_to_types_root)*::exports::wasi::http::incoming_handler);
   $($path_to_types_root)*::exports::fermyon::spin_wasi_virt::http_handler::__export_fermyon_spin_wasi_virt_http_handler_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin_wasi_virt::http_handler);
   $($path_to_types_root)*::exports::fermyon::spin_wasi_virt::http_helper::__export_fermyon_spin_wasi_virt_http_helper_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin_wasi_virt::http_helper);
   $($path_to_types_root)*::exports::fermyon::spin_wasi_virt::fs_handler::__export_fermyon_spin_wasi_virt_fs_handler_cabi!($ty with_types_in $($path_to_types_root)*::exports::fermyon::spin_wasi_virt::fs_handler);
@@ -34762,9 +42810,9 @@ pub(crate) use __export_env_impl as export;
 #[cfg(target_arch = "wasm32")]
 #[link_section = "component-type:wit-bindgen:0.25.0:env:encoded world"]
 #[doc(hidden)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 28152] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xfd\xda\x01\x01A\x02\
-\x01A\xa2\x01\x01B\x0a\x04\0\x08pollable\x03\x01\x01h\0\x01@\x01\x04self\x01\0\x7f\
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 32789] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x9a\xff\x01\x01A\x02\
+\x01A\xd6\x01\x01B\x0a\x04\0\x08pollable\x03\x01\x01h\0\x01@\x01\x04self\x01\0\x7f\
 \x04\0\x16[method]pollable.ready\x01\x02\x01@\x01\x04self\x01\x01\0\x04\0\x16[me\
 thod]pollable.block\x01\x03\x01p\x01\x01py\x01@\x01\x02in\x04\0\x05\x04\0\x04pol\
 l\x01\x06\x03\x01\x12wasi:io/poll@0.2.0\x05\0\x02\x03\0\0\x08pollable\x01B\x0f\x02\
@@ -34909,32 +42957,37 @@ lf\xf8\0\0\xc6\0\x04\0![method]outgoing-response.headers\x01{\x01@\x01\x04self\x
 $[method]future-incoming-response.get\x01\x89\x01\x01h\x07\x01k\x1b\x01@\x01\x03\
 err\x8a\x01\0\x8b\x01\x04\0\x0fhttp-error-code\x01\x8c\x01\x03\x01\x15wasi:http/\
 types@0.2.0\x05\x19\x01@\0\0s\x03\0\x0cget-manifest\x01\x1a\x01@\x01\x0ccomponen\
-t-ids\x01\0\x04\0\x10set-component-id\x01\x1b\x01B\x19\x04\0\x05store\x03\x01\x01\
-q\x04\x10store-table-full\0\0\x0dno-such-store\0\0\x0daccess-denied\0\0\x05other\
-\x01s\0\x04\0\x05error\x03\0\x01\x01i\0\x01j\x01\x03\x01\x02\x01@\x01\x05labels\0\
-\x04\x04\0\x12[static]store.open\x01\x05\x01h\0\x01p}\x01k\x07\x01j\x01\x08\x01\x02\
-\x01@\x02\x04self\x06\x03keys\0\x09\x04\0\x11[method]store.get\x01\x0a\x01j\0\x01\
-\x02\x01@\x03\x04self\x06\x03keys\x05value\x07\0\x0b\x04\0\x11[method]store.set\x01\
-\x0c\x01@\x02\x04self\x06\x03keys\0\x0b\x04\0\x14[method]store.delete\x01\x0d\x01\
-j\x01\x7f\x01\x02\x01@\x02\x04self\x06\x03keys\0\x0e\x04\0\x14[method]store.exis\
-ts\x01\x0f\x01ps\x01j\x01\x10\x01\x02\x01@\x01\x04self\x06\0\x11\x04\0\x16[metho\
-d]store.get-keys\x01\x12\x04\x01\x1cfermyon:spin/key-value@2.0.0\x05\x1c\x01B\x1a\
-\x01s\x04\0\x11inferencing-model\x03\0\0\x01r\x06\x0amax-tokensy\x0erepeat-penal\
-tyv!repeat-penalty-last-n-token-county\x0btemperaturev\x05top-ky\x05top-pv\x04\0\
-\x12inferencing-params\x03\0\x02\x01q\x03\x13model-not-supported\0\0\x0druntime-\
-error\x01s\0\x0dinvalid-input\x01s\0\x04\0\x05error\x03\0\x04\x01r\x02\x12prompt\
--token-county\x15generated-token-county\x04\0\x11inferencing-usage\x03\0\x06\x01\
+t-ids\x01\0\x04\0\x10set-component-id\x01\x1b\x01@\x02\x03keys\x05values\x01\0\x04\
+\0\x10provide-variable\x01\x1c\x01ps\x01o\x02ss\x01p\x1e\x01p}\x01@\x03\x09argum\
+ents\x1d\x0benvironment\x1f\x05stdin\x20\x01\0\x04\0\x0cprepare-wagi\x01!\x01@\0\
+\0\x20\x04\0\x0bfinish-wagi\x01\"\x01ks\x01@\0\0#\x04\0\x13take-stand-in-error\x01\
+$\x01@\x01\x03urls\0\x7f\x04\0\x13allows-outbound-url\x01%\x01@\x02\x06methods\x03\
+urls\x01\0\x04\0\x17record-internal-request\x01&\x01B\x19\x04\0\x05store\x03\x01\
+\x01q\x04\x10store-table-full\0\0\x0dno-such-store\0\0\x0daccess-denied\0\0\x05o\
+ther\x01s\0\x04\0\x05error\x03\0\x01\x01i\0\x01j\x01\x03\x01\x02\x01@\x01\x05lab\
+els\0\x04\x04\0\x12[static]store.open\x01\x05\x01h\0\x01p}\x01k\x07\x01j\x01\x08\
+\x01\x02\x01@\x02\x04self\x06\x03keys\0\x09\x04\0\x11[method]store.get\x01\x0a\x01\
+j\0\x01\x02\x01@\x03\x04self\x06\x03keys\x05value\x07\0\x0b\x04\0\x11[method]sto\
+re.set\x01\x0c\x01@\x02\x04self\x06\x03keys\0\x0b\x04\0\x14[method]store.delete\x01\
+\x0d\x01j\x01\x7f\x01\x02\x01@\x02\x04self\x06\x03keys\0\x0e\x04\0\x14[method]st\
+ore.exists\x01\x0f\x01ps\x01j\x01\x10\x01\x02\x01@\x01\x04self\x06\0\x11\x04\0\x16\
+[method]store.get-keys\x01\x12\x04\x01\x1cfermyon:spin/key-value@2.0.0\x05'\x01B\
+\x1a\x01s\x04\0\x11inferencing-model\x03\0\0\x01r\x06\x0amax-tokensy\x0erepeat-p\
+enaltyv!repeat-penalty-last-n-token-county\x0btemperaturev\x05top-ky\x05top-pv\x04\
+\0\x12inferencing-params\x03\0\x02\x01q\x03\x13model-not-supported\0\0\x0druntim\
+e-error\x01s\0\x0dinvalid-input\x01s\0\x04\0\x05error\x03\0\x04\x01r\x02\x12prom\
+pt-token-county\x15generated-token-county\x04\0\x11inferencing-usage\x03\0\x06\x01\
 r\x02\x04texts\x05usage\x07\x04\0\x12inferencing-result\x03\0\x08\x01s\x04\0\x0f\
 embedding-model\x03\0\x0a\x01r\x01\x12prompt-token-county\x04\0\x10embeddings-us\
 age\x03\0\x0c\x01pv\x01p\x0e\x01r\x02\x0aembeddings\x0f\x05usage\x0d\x04\0\x11em\
 beddings-result\x03\0\x10\x01k\x03\x01j\x01\x09\x01\x05\x01@\x03\x05model\x01\x06\
 prompts\x06params\x12\0\x13\x04\0\x05infer\x01\x14\x01ps\x01j\x01\x11\x01\x05\x01\
 @\x02\x05model\x0b\x04text\x15\0\x16\x04\0\x13generate-embeddings\x01\x17\x04\x01\
-\x16fermyon:spin/llm@2.0.0\x05\x1d\x01B)\x01q\x04\x0finvalid-address\0\0\x14too-\
-many-connections\0\0\x0atype-error\0\0\x05other\x01s\0\x04\0\x05error\x03\0\0\x04\
-\0\x0aconnection\x03\x01\x01p}\x04\0\x07payload\x03\0\x03\x01q\x02\x05int64\x01x\
-\0\x06binary\x01\x04\0\x04\0\x0fredis-parameter\x03\0\x05\x01q\x04\x03nil\0\0\x06\
-status\x01s\0\x05int64\x01x\0\x06binary\x01\x04\0\x04\0\x0credis-result\x03\0\x07\
+\x16fermyon:spin/llm@2.0.0\x05(\x01B)\x01q\x04\x0finvalid-address\0\0\x14too-man\
+y-connections\0\0\x0atype-error\0\0\x05other\x01s\0\x04\0\x05error\x03\0\0\x04\0\
+\x0aconnection\x03\x01\x01p}\x04\0\x07payload\x03\0\x03\x01q\x02\x05int64\x01x\0\
+\x06binary\x01\x04\0\x04\0\x0fredis-parameter\x03\0\x05\x01q\x04\x03nil\0\0\x06s\
+tatus\x01s\0\x05int64\x01x\0\x06binary\x01\x04\0\x04\0\x0credis-result\x03\0\x07\
 \x01i\x02\x01j\x01\x09\x01\x01\x01@\x01\x07addresss\0\x0a\x04\0\x17[static]conne\
 ction.open\x01\x0b\x01h\x02\x01j\0\x01\x01\x01@\x03\x04self\x0c\x07channels\x07p\
 ayload\x04\0\x0d\x04\0\x1a[method]connection.publish\x01\x0e\x01k\x04\x01j\x01\x0f\
@@ -34947,287 +43000,378 @@ ues\x15\0\x16\x04\0\x17[method]connection.sadd\x01\x18\x01j\x01\x15\x01\x01\x01@
 \x02\x04self\x0c\x03keys\0\x19\x04\0\x1b[method]connection.smembers\x01\x1a\x04\0\
 \x17[method]connection.srem\x01\x18\x01p\x06\x01p\x08\x01j\x01\x1c\x01\x01\x01@\x03\
 \x04self\x0c\x07commands\x09arguments\x1b\0\x1d\x04\0\x1a[method]connection.exec\
-ute\x01\x1e\x04\x01\x18fermyon:spin/redis@2.0.0\x05\x1e\x01B\x11\x01q\x05\x11con\
-nection-failed\x01s\0\x0dbad-parameter\x01s\0\x0cquery-failed\x01s\0\x17value-co\
-nversion-failed\x01s\0\x05other\x01s\0\x04\0\x05error\x03\0\0\x01m\x0e\x07boolea\
-n\x04int8\x05int16\x05int32\x05int64\x05uint8\x06uint16\x06uint32\x06uint64\x0af\
-loating32\x0afloating64\x03str\x06binary\x05other\x04\0\x0cdb-data-type\x03\0\x02\
-\x01p}\x01q\x0f\x07boolean\x01\x7f\0\x04int8\x01~\0\x05int16\x01|\0\x05int32\x01\
-z\0\x05int64\x01x\0\x05uint8\x01}\0\x06uint16\x01{\0\x06uint32\x01y\0\x06uint64\x01\
-w\0\x0afloating32\x01v\0\x0afloating64\x01u\0\x03str\x01s\0\x06binary\x01\x04\0\x07\
-db-null\0\0\x0bunsupported\0\0\x04\0\x08db-value\x03\0\x05\x01q\x0e\x07boolean\x01\
-\x7f\0\x04int8\x01~\0\x05int16\x01|\0\x05int32\x01z\0\x05int64\x01x\0\x05uint8\x01\
-}\0\x06uint16\x01{\0\x06uint32\x01y\0\x06uint64\x01w\0\x0afloating32\x01v\0\x0af\
-loating64\x01u\0\x03str\x01s\0\x06binary\x01\x04\0\x07db-null\0\0\x04\0\x0fparam\
-eter-value\x03\0\x07\x01r\x02\x04names\x09data-type\x03\x04\0\x06column\x03\0\x09\
-\x01p\x06\x04\0\x03row\x03\0\x0b\x01p\x0a\x01p\x0c\x01r\x02\x07columns\x0d\x04ro\
-ws\x0e\x04\0\x07row-set\x03\0\x0f\x04\x01\x1efermyon:spin/rdbms-types@2.0.0\x05\x1f\
-\x02\x03\0\x16\x0fparameter-value\x02\x03\0\x16\x07row-set\x02\x03\0\x16\x05erro\
-r\x01B\x13\x02\x03\x02\x01\x20\x04\0\x0fparameter-value\x03\0\0\x02\x03\x02\x01!\
-\x04\0\x07row-set\x03\0\x02\x02\x03\x02\x01\"\x04\0\x05error\x03\0\x04\x04\0\x0a\
-connection\x03\x01\x01i\x06\x01j\x01\x07\x01\x05\x01@\x01\x07addresss\0\x08\x04\0\
-\x17[static]connection.open\x01\x09\x01h\x06\x01p\x01\x01j\x01\x03\x01\x05\x01@\x03\
-\x04self\x0a\x09statements\x06params\x0b\0\x0c\x04\0\x18[method]connection.query\
-\x01\x0d\x01j\x01w\x01\x05\x01@\x03\x04self\x0a\x09statements\x06params\x0b\0\x0e\
-\x04\0\x1a[method]connection.execute\x01\x0f\x04\x01\x1bfermyon:spin/postgres@2.\
-0.0\x05#\x01B\x0f\x01q\x04\x0finvalid-address\0\0\x14too-many-connections\0\0\x11\
-connection-failed\x01s\0\x05other\x01s\0\x04\0\x05error\x03\0\0\x01m\x03\x0cat-m\
-ost-once\x0dat-least-once\x0cexactly-once\x04\0\x03qos\x03\0\x02\x04\0\x0aconnec\
-tion\x03\x01\x01p}\x04\0\x07payload\x03\0\x05\x01i\x04\x01j\x01\x07\x01\x01\x01@\
-\x04\x07addresss\x08usernames\x08passwords\x1bkeep-alive-interval-in-secsw\0\x08\
-\x04\0\x17[static]connection.open\x01\x09\x01h\x04\x01j\0\x01\x01\x01@\x04\x04se\
-lf\x0a\x05topics\x07payload\x06\x03qos\x03\0\x0b\x04\0\x1a[method]connection.pub\
-lish\x01\x0c\x04\x01\x17fermyon:spin/mqtt@2.0.0\x05$\x01B\x13\x02\x03\x02\x01\x20\
-\x04\0\x0fparameter-value\x03\0\0\x02\x03\x02\x01!\x04\0\x07row-set\x03\0\x02\x02\
-\x03\x02\x01\"\x04\0\x05error\x03\0\x04\x04\0\x0aconnection\x03\x01\x01i\x06\x01\
-j\x01\x07\x01\x05\x01@\x01\x07addresss\0\x08\x04\0\x17[static]connection.open\x01\
-\x09\x01h\x06\x01p\x01\x01j\x01\x03\x01\x05\x01@\x03\x04self\x0a\x09statements\x06\
-params\x0b\0\x0c\x04\0\x18[method]connection.query\x01\x0d\x01j\0\x01\x05\x01@\x03\
-\x04self\x0a\x09statements\x06params\x0b\0\x0e\x04\0\x1a[method]connection.execu\
-te\x01\x0f\x04\x01\x18fermyon:spin/mysql@2.0.0\x05%\x01B\x15\x04\0\x0aconnection\
-\x03\x01\x01q\x05\x10no-such-database\0\0\x0daccess-denied\0\0\x12invalid-connec\
-tion\0\0\x0ddatabase-full\0\0\x02io\x01s\0\x04\0\x05error\x03\0\x01\x01p}\x01q\x05\
-\x07integer\x01x\0\x04real\x01u\0\x04text\x01s\0\x04blob\x01\x03\0\x04null\0\0\x04\
-\0\x05value\x03\0\x04\x01p\x05\x01r\x01\x06values\x06\x04\0\x0arow-result\x03\0\x07\
-\x01ps\x01p\x08\x01r\x02\x07columns\x09\x04rows\x0a\x04\0\x0cquery-result\x03\0\x0b\
-\x01i\0\x01j\x01\x0d\x01\x02\x01@\x01\x08databases\0\x0e\x04\0\x17[static]connec\
-tion.open\x01\x0f\x01h\0\x01j\x01\x0c\x01\x02\x01@\x03\x04self\x10\x09statements\
-\x0aparameters\x06\0\x11\x04\0\x1a[method]connection.execute\x01\x12\x04\x01\x19\
-fermyon:spin/sqlite@2.0.0\x05&\x01B\x05\x01q\x04\x0cinvalid-name\x01s\0\x09undef\
-ined\x01s\0\x08provider\x01s\0\x05other\x01s\0\x04\0\x05error\x03\0\0\x01j\x01s\x01\
-\x01\x01@\x01\x04names\0\x02\x04\0\x03get\x01\x03\x04\x01\x1cfermyon:spin/variab\
-les@2.0.0\x05'\x01B\x19\x04\0\x05store\x03\x01\x01p}\x01o\x02s\x01\x01q\x05\x03g\
-et\x01s\0\x03set\x01\x02\0\x06delete\x01s\0\x06exists\x01s\0\x08get-keys\0\0\x04\
-\0\x04call\x03\0\x03\x01i\0\x01@\x01\x05labels\0\x05\x04\0\x12[static]store.open\
-\x01\x06\x01h\0\x01@\x01\x04self\x07\0s\x04\0\x13[method]store.label\x01\x08\x01\
-k\x01\x01@\x02\x04self\x07\x03keys\0\x09\x04\0\x11[method]store.get\x01\x0a\x01@\
-\x03\x04self\x07\x03keys\x05value\x01\x01\0\x04\0\x11[method]store.set\x01\x0b\x01\
-@\x02\x04self\x07\x03keys\x01\0\x04\0\x14[method]store.delete\x01\x0c\x01p\x04\x01\
-o\x02s\x0d\x01p\x0e\x01@\0\0\x0f\x04\0\x05calls\x01\x10\x01@\0\x01\0\x04\0\x0bre\
-set-calls\x01\x11\x04\x01\x20fermyon:spin-test-virt/key-value\x05(\x02\x03\0\x1a\
-\x05value\x02\x03\0\x1a\x0cquery-result\x02\x03\0\x1a\x05error\x01B\x10\x02\x03\x02\
-\x01)\x04\0\x05value\x03\0\0\x02\x03\x02\x01*\x04\0\x0cquery-result\x03\0\x02\x02\
-\x03\x02\x01+\x04\0\x05error\x03\0\x04\x04\0\x0aconnection\x03\x01\x01i\x06\x01j\
-\x01\x07\x01\x05\x01@\x01\x08databases\0\x08\x04\0\x17[static]connection.open\x01\
-\x09\x01h\x06\x01p\x01\x01j\x01\x03\x01\x05\x01@\x03\x04self\x0a\x09statements\x0a\
-parameters\x0b\0\x0c\x04\0\x1a[method]connection.execute\x01\x0d\x04\x01\x1dferm\
-yon:spin-test-virt/sqlite\x05,\x01B\x02\x01@\x02\x03keys\x05values\x01\0\x04\0\x03\
-set\x01\0\x04\x01\x20fermyon:spin-test-virt/variables\x05-\x01B\x0a\x04\0\x08pol\
-lable\x03\x01\x01h\0\x01@\x01\x04self\x01\0\x7f\x04\0\x16[method]pollable.ready\x01\
-\x02\x01@\x01\x04self\x01\x01\0\x04\0\x16[method]pollable.block\x01\x03\x01p\x01\
-\x01py\x01@\x01\x02in\x04\0\x05\x04\0\x04poll\x01\x06\x04\x01\x12wasi:io/poll@0.\
-2.0\x05.\x01B\x0f\x02\x03\x02\x01\x01\x04\0\x08pollable\x03\0\0\x01w\x04\0\x07in\
-stant\x03\0\x02\x01w\x04\0\x08duration\x03\0\x04\x01@\0\0\x03\x04\0\x03now\x01\x06\
-\x01@\0\0\x05\x04\0\x0aresolution\x01\x07\x01i\x01\x01@\x01\x04when\x03\0\x08\x04\
-\0\x11subscribe-instant\x01\x09\x01@\x01\x04when\x05\0\x08\x04\0\x12subscribe-du\
-ration\x01\x0a\x04\x01!wasi:clocks/monotonic-clock@0.2.0\x05/\x01B\x05\x01r\x02\x07\
-secondsw\x0bnanosecondsy\x04\0\x08datetime\x03\0\0\x01@\0\0\x01\x04\0\x03now\x01\
-\x02\x04\0\x0aresolution\x01\x02\x04\x01\x1cwasi:clocks/wall-clock@0.2.0\x050\x01\
-B\x05\x01p}\x01@\x01\x03lenw\0\0\x04\0\x10get-random-bytes\x01\x01\x01@\0\0w\x04\
-\0\x0eget-random-u64\x01\x02\x04\x01\x18wasi:random/random@0.2.0\x051\x01B\x05\x01\
-p}\x01@\x01\x03lenw\0\0\x04\0\x19get-insecure-random-bytes\x01\x01\x01@\0\0w\x04\
-\0\x17get-insecure-random-u64\x01\x02\x04\x01\x1awasi:random/insecure@0.2.0\x052\
-\x01B\x03\x01o\x02ww\x01@\0\0\0\x04\0\x0dinsecure-seed\x01\x01\x04\x01\x1fwasi:r\
-andom/insecure-seed@0.2.0\x053\x01B\x04\x04\0\x05error\x03\x01\x01h\0\x01@\x01\x04\
-self\x01\0s\x04\0\x1d[method]error.to-debug-string\x01\x02\x04\x01\x13wasi:io/er\
-ror@0.2.0\x054\x01B(\x02\x03\x02\x01\x08\x04\0\x05error\x03\0\0\x02\x03\x02\x01\x01\
-\x04\0\x08pollable\x03\0\x02\x01i\x01\x01q\x02\x15last-operation-failed\x01\x04\0\
-\x06closed\0\0\x04\0\x0cstream-error\x03\0\x05\x04\0\x0cinput-stream\x03\x01\x04\
-\0\x0doutput-stream\x03\x01\x01h\x07\x01p}\x01j\x01\x0a\x01\x06\x01@\x02\x04self\
-\x09\x03lenw\0\x0b\x04\0\x19[method]input-stream.read\x01\x0c\x04\0\"[method]inp\
-ut-stream.blocking-read\x01\x0c\x01j\x01w\x01\x06\x01@\x02\x04self\x09\x03lenw\0\
-\x0d\x04\0\x19[method]input-stream.skip\x01\x0e\x04\0\"[method]input-stream.bloc\
-king-skip\x01\x0e\x01i\x03\x01@\x01\x04self\x09\0\x0f\x04\0\x1e[method]input-str\
-eam.subscribe\x01\x10\x01h\x08\x01@\x01\x04self\x11\0\x0d\x04\0![method]output-s\
-tream.check-write\x01\x12\x01j\0\x01\x06\x01@\x02\x04self\x11\x08contents\x0a\0\x13\
-\x04\0\x1b[method]output-stream.write\x01\x14\x04\0.[method]output-stream.blocki\
-ng-write-and-flush\x01\x14\x01@\x01\x04self\x11\0\x13\x04\0\x1b[method]output-st\
-ream.flush\x01\x15\x04\0$[method]output-stream.blocking-flush\x01\x15\x01@\x01\x04\
-self\x11\0\x0f\x04\0\x1f[method]output-stream.subscribe\x01\x16\x01@\x02\x04self\
-\x11\x03lenw\0\x13\x04\0\"[method]output-stream.write-zeroes\x01\x17\x04\05[meth\
-od]output-stream.blocking-write-zeroes-and-flush\x01\x17\x01@\x03\x04self\x11\x03\
-src\x09\x03lenw\0\x0d\x04\0\x1c[method]output-stream.splice\x01\x18\x04\0%[metho\
-d]output-stream.blocking-splice\x01\x18\x04\x01\x15wasi:io/streams@0.2.0\x055\x02\
-\x03\0&\x05error\x02\x03\0!\x08datetime\x01Br\x02\x03\x02\x01\x0c\x04\0\x0cinput\
--stream\x03\0\0\x02\x03\x02\x01\x0a\x04\0\x0doutput-stream\x03\0\x02\x02\x03\x02\
-\x016\x04\0\x05error\x03\0\x04\x02\x03\x02\x017\x04\0\x08datetime\x03\0\x06\x01w\
-\x04\0\x08filesize\x03\0\x08\x01m\x08\x07unknown\x0cblock-device\x10character-de\
-vice\x09directory\x04fifo\x0dsymbolic-link\x0cregular-file\x06socket\x04\0\x0fde\
-scriptor-type\x03\0\x0a\x01n\x06\x04read\x05write\x13file-integrity-sync\x13data\
--integrity-sync\x14requested-write-sync\x10mutate-directory\x04\0\x10descriptor-\
-flags\x03\0\x0c\x01n\x01\x0esymlink-follow\x04\0\x0apath-flags\x03\0\x0e\x01n\x04\
-\x06create\x09directory\x09exclusive\x08truncate\x04\0\x0aopen-flags\x03\0\x10\x01\
-w\x04\0\x0alink-count\x03\0\x12\x01k\x07\x01r\x06\x04type\x0b\x0alink-count\x13\x04\
-size\x09\x15data-access-timestamp\x14\x1bdata-modification-timestamp\x14\x17stat\
-us-change-timestamp\x14\x04\0\x0fdescriptor-stat\x03\0\x15\x01q\x03\x09no-change\
-\0\0\x03now\0\0\x09timestamp\x01\x07\0\x04\0\x0dnew-timestamp\x03\0\x17\x01r\x02\
-\x04type\x0b\x04names\x04\0\x0fdirectory-entry\x03\0\x19\x01m%\x06access\x0bwoul\
-d-block\x07already\x0ebad-descriptor\x04busy\x08deadlock\x05quota\x05exist\x0efi\
-le-too-large\x15illegal-byte-sequence\x0bin-progress\x0binterrupted\x07invalid\x02\
-io\x0cis-directory\x04loop\x0etoo-many-links\x0cmessage-size\x0dname-too-long\x09\
-no-device\x08no-entry\x07no-lock\x13insufficient-memory\x12insufficient-space\x0d\
-not-directory\x09not-empty\x0fnot-recoverable\x0bunsupported\x06no-tty\x0eno-suc\
-h-device\x08overflow\x0dnot-permitted\x04pipe\x09read-only\x0cinvalid-seek\x0ete\
-xt-file-busy\x0ccross-device\x04\0\x0aerror-code\x03\0\x1b\x01m\x06\x06normal\x0a\
-sequential\x06random\x09will-need\x09dont-need\x08no-reuse\x04\0\x06advice\x03\0\
-\x1d\x01r\x02\x05lowerw\x05upperw\x04\0\x13metadata-hash-value\x03\0\x1f\x04\0\x0a\
-descriptor\x03\x01\x04\0\x16directory-entry-stream\x03\x01\x01h!\x01i\x01\x01j\x01\
-$\x01\x1c\x01@\x02\x04self#\x06offset\x09\0%\x04\0\"[method]descriptor.read-via-\
-stream\x01&\x01i\x03\x01j\x01'\x01\x1c\x01@\x02\x04self#\x06offset\x09\0(\x04\0#\
-[method]descriptor.write-via-stream\x01)\x01@\x01\x04self#\0(\x04\0$[method]desc\
-riptor.append-via-stream\x01*\x01j\0\x01\x1c\x01@\x04\x04self#\x06offset\x09\x06\
-length\x09\x06advice\x1e\0+\x04\0\x19[method]descriptor.advise\x01,\x01@\x01\x04\
-self#\0+\x04\0\x1c[method]descriptor.sync-data\x01-\x01j\x01\x0d\x01\x1c\x01@\x01\
-\x04self#\0.\x04\0\x1c[method]descriptor.get-flags\x01/\x01j\x01\x0b\x01\x1c\x01\
-@\x01\x04self#\00\x04\0\x1b[method]descriptor.get-type\x011\x01@\x02\x04self#\x04\
-size\x09\0+\x04\0\x1b[method]descriptor.set-size\x012\x01@\x03\x04self#\x15data-\
-access-timestamp\x18\x1bdata-modification-timestamp\x18\0+\x04\0\x1c[method]desc\
-riptor.set-times\x013\x01p}\x01o\x024\x7f\x01j\x015\x01\x1c\x01@\x03\x04self#\x06\
-length\x09\x06offset\x09\06\x04\0\x17[method]descriptor.read\x017\x01j\x01\x09\x01\
-\x1c\x01@\x03\x04self#\x06buffer4\x06offset\x09\08\x04\0\x18[method]descriptor.w\
-rite\x019\x01i\"\x01j\x01:\x01\x1c\x01@\x01\x04self#\0;\x04\0![method]descriptor\
-.read-directory\x01<\x04\0\x17[method]descriptor.sync\x01-\x01@\x02\x04self#\x04\
-paths\0+\x04\0&[method]descriptor.create-directory-at\x01=\x01j\x01\x16\x01\x1c\x01\
-@\x01\x04self#\0>\x04\0\x17[method]descriptor.stat\x01?\x01@\x03\x04self#\x0apat\
-h-flags\x0f\x04paths\0>\x04\0\x1a[method]descriptor.stat-at\x01@\x01@\x05\x04sel\
-f#\x0apath-flags\x0f\x04paths\x15data-access-timestamp\x18\x1bdata-modification-\
-timestamp\x18\0+\x04\0\x1f[method]descriptor.set-times-at\x01A\x01@\x05\x04self#\
-\x0eold-path-flags\x0f\x08old-paths\x0enew-descriptor#\x08new-paths\0+\x04\0\x1a\
-[method]descriptor.link-at\x01B\x01i!\x01j\x01\xc3\0\x01\x1c\x01@\x05\x04self#\x0a\
-path-flags\x0f\x04paths\x0aopen-flags\x11\x05flags\x0d\0\xc4\0\x04\0\x1a[method]\
-descriptor.open-at\x01E\x01j\x01s\x01\x1c\x01@\x02\x04self#\x04paths\0\xc6\0\x04\
-\0\x1e[method]descriptor.readlink-at\x01G\x04\0&[method]descriptor.remove-direct\
-ory-at\x01=\x01@\x04\x04self#\x08old-paths\x0enew-descriptor#\x08new-paths\0+\x04\
-\0\x1c[method]descriptor.rename-at\x01H\x01@\x03\x04self#\x08old-paths\x08new-pa\
-ths\0+\x04\0\x1d[method]descriptor.symlink-at\x01I\x04\0![method]descriptor.unli\
-nk-file-at\x01=\x01@\x02\x04self#\x05other#\0\x7f\x04\0![method]descriptor.is-sa\
-me-object\x01J\x01j\x01\x20\x01\x1c\x01@\x01\x04self#\0\xcb\0\x04\0\x20[method]d\
-escriptor.metadata-hash\x01L\x01@\x03\x04self#\x0apath-flags\x0f\x04paths\0\xcb\0\
-\x04\0#[method]descriptor.metadata-hash-at\x01M\x01h\"\x01k\x1a\x01j\x01\xcf\0\x01\
-\x1c\x01@\x01\x04self\xce\0\0\xd0\0\x04\03[method]directory-entry-stream.read-di\
-rectory-entry\x01Q\x01h\x05\x01k\x1c\x01@\x01\x03err\xd2\0\0\xd3\0\x04\0\x15file\
-system-error-code\x01T\x04\x01\x1bwasi:filesystem/types@0.2.0\x058\x02\x03\0'\x0a\
-descriptor\x01B\x07\x02\x03\x02\x019\x04\0\x0adescriptor\x03\0\0\x01i\x01\x01o\x02\
-\x02s\x01p\x03\x01@\0\0\x04\x04\0\x0fget-directories\x01\x05\x04\x01\x1ewasi:fil\
-esystem/preopens@0.2.0\x05:\x01B\x05\x02\x03\x02\x01\x0a\x04\0\x0doutput-stream\x03\
-\0\0\x01i\x01\x01@\0\0\x02\x04\0\x0aget-stdout\x01\x03\x04\x01\x15wasi:cli/stdou\
-t@0.2.0\x05;\x01B\x05\x02\x03\x02\x01\x0c\x04\0\x0cinput-stream\x03\0\0\x01i\x01\
-\x01@\0\0\x02\x04\0\x09get-stdin\x01\x03\x04\x01\x14wasi:cli/stdin@0.2.0\x05<\x01\
-B\x05\x02\x03\x02\x01\x0a\x04\0\x0doutput-stream\x03\0\0\x01i\x01\x01@\0\0\x02\x04\
-\0\x0aget-stderr\x01\x03\x04\x01\x15wasi:cli/stderr@0.2.0\x05=\x01B\x01\x04\0\x0e\
-terminal-input\x03\x01\x04\x01\x1dwasi:cli/terminal-input@0.2.0\x05>\x01B\x01\x04\
-\0\x0fterminal-output\x03\x01\x04\x01\x1ewasi:cli/terminal-output@0.2.0\x05?\x01\
-B\x06\x02\x03\x02\x01\x11\x04\0\x0eterminal-input\x03\0\0\x01i\x01\x01k\x02\x01@\
-\0\0\x03\x04\0\x12get-terminal-stdin\x01\x04\x04\x01\x1dwasi:cli/terminal-stdin@\
-0.2.0\x05@\x01B\x06\x02\x03\x02\x01\x13\x04\0\x0fterminal-output\x03\0\0\x01i\x01\
-\x01k\x02\x01@\0\0\x03\x04\0\x13get-terminal-stdout\x01\x04\x04\x01\x1ewasi:cli/\
-terminal-stdout@0.2.0\x05A\x01B\x06\x02\x03\x02\x01\x13\x04\0\x0fterminal-output\
-\x03\0\0\x01i\x01\x01k\x02\x01@\0\0\x03\x04\0\x13get-terminal-stderr\x01\x04\x04\
-\x01\x1ewasi:cli/terminal-stderr@0.2.0\x05B\x01B\x0a\x01o\x02ss\x01p\0\x01@\0\0\x01\
-\x04\0\x0fget-environment\x01\x02\x01ps\x01@\0\0\x03\x04\0\x0dget-arguments\x01\x04\
-\x01ks\x01@\0\0\x05\x04\0\x0binitial-cwd\x01\x06\x04\x01\x1awasi:cli/environment\
-@0.2.0\x05C\x01B\x03\x01j\0\0\x01@\x01\x06status\0\x01\0\x04\0\x04exit\x01\x01\x04\
-\x01\x13wasi:cli/exit@0.2.0\x05D\x01B\x11\x04\0\x07network\x03\x01\x01m\x15\x07u\
-nknown\x0daccess-denied\x0dnot-supported\x10invalid-argument\x0dout-of-memory\x07\
-timeout\x14concurrency-conflict\x0fnot-in-progress\x0bwould-block\x0dinvalid-sta\
-te\x10new-socket-limit\x14address-not-bindable\x0eaddress-in-use\x12remote-unrea\
-chable\x12connection-refused\x10connection-reset\x12connection-aborted\x12datagr\
-am-too-large\x11name-unresolvable\x1atemporary-resolver-failure\x1apermanent-res\
-olver-failure\x04\0\x0aerror-code\x03\0\x01\x01m\x02\x04ipv4\x04ipv6\x04\0\x11ip\
--address-family\x03\0\x03\x01o\x04}}}}\x04\0\x0cipv4-address\x03\0\x05\x01o\x08{\
-{{{{{{{\x04\0\x0cipv6-address\x03\0\x07\x01q\x02\x04ipv4\x01\x06\0\x04ipv6\x01\x08\
-\0\x04\0\x0aip-address\x03\0\x09\x01r\x02\x04port{\x07address\x06\x04\0\x13ipv4-\
-socket-address\x03\0\x0b\x01r\x04\x04port{\x09flow-infoy\x07address\x08\x08scope\
--idy\x04\0\x13ipv6-socket-address\x03\0\x0d\x01q\x02\x04ipv4\x01\x0c\0\x04ipv6\x01\
-\x0e\0\x04\0\x11ip-socket-address\x03\0\x0f\x04\x01\x1awasi:sockets/network@0.2.\
-0\x05E\x02\x03\03\x07network\x01B\x05\x02\x03\x02\x01F\x04\0\x07network\x03\0\0\x01\
-i\x01\x01@\0\0\x02\x04\0\x10instance-network\x01\x03\x04\x01#wasi:sockets/instan\
-ce-network@0.2.0\x05G\x02\x03\03\x0aerror-code\x02\x03\03\x11ip-socket-address\x02\
-\x03\03\x11ip-address-family\x01BD\x02\x03\x02\x01\x01\x04\0\x08pollable\x03\0\0\
-\x02\x03\x02\x01F\x04\0\x07network\x03\0\x02\x02\x03\x02\x01H\x04\0\x0aerror-cod\
-e\x03\0\x04\x02\x03\x02\x01I\x04\0\x11ip-socket-address\x03\0\x06\x02\x03\x02\x01\
-J\x04\0\x11ip-address-family\x03\0\x08\x01p}\x01r\x02\x04data\x0a\x0eremote-addr\
-ess\x07\x04\0\x11incoming-datagram\x03\0\x0b\x01k\x07\x01r\x02\x04data\x0a\x0ere\
-mote-address\x0d\x04\0\x11outgoing-datagram\x03\0\x0e\x04\0\x0audp-socket\x03\x01\
-\x04\0\x18incoming-datagram-stream\x03\x01\x04\0\x18outgoing-datagram-stream\x03\
-\x01\x01h\x10\x01h\x03\x01j\0\x01\x05\x01@\x03\x04self\x13\x07network\x14\x0dloc\
-al-address\x07\0\x15\x04\0\x1d[method]udp-socket.start-bind\x01\x16\x01@\x01\x04\
-self\x13\0\x15\x04\0\x1e[method]udp-socket.finish-bind\x01\x17\x01i\x11\x01i\x12\
-\x01o\x02\x18\x19\x01j\x01\x1a\x01\x05\x01@\x02\x04self\x13\x0eremote-address\x0d\
-\0\x1b\x04\0\x19[method]udp-socket.stream\x01\x1c\x01j\x01\x07\x01\x05\x01@\x01\x04\
-self\x13\0\x1d\x04\0\x20[method]udp-socket.local-address\x01\x1e\x04\0![method]u\
-dp-socket.remote-address\x01\x1e\x01@\x01\x04self\x13\0\x09\x04\0![method]udp-so\
-cket.address-family\x01\x1f\x01j\x01}\x01\x05\x01@\x01\x04self\x13\0\x20\x04\0$[\
-method]udp-socket.unicast-hop-limit\x01!\x01@\x02\x04self\x13\x05value}\0\x15\x04\
-\0([method]udp-socket.set-unicast-hop-limit\x01\"\x01j\x01w\x01\x05\x01@\x01\x04\
-self\x13\0#\x04\0&[method]udp-socket.receive-buffer-size\x01$\x01@\x02\x04self\x13\
-\x05valuew\0\x15\x04\0*[method]udp-socket.set-receive-buffer-size\x01%\x04\0#[me\
-thod]udp-socket.send-buffer-size\x01$\x04\0'[method]udp-socket.set-send-buffer-s\
-ize\x01%\x01i\x01\x01@\x01\x04self\x13\0&\x04\0\x1c[method]udp-socket.subscribe\x01\
-'\x01h\x11\x01p\x0c\x01j\x01)\x01\x05\x01@\x02\x04self(\x0bmax-resultsw\0*\x04\0\
-([method]incoming-datagram-stream.receive\x01+\x01@\x01\x04self(\0&\x04\0*[metho\
-d]incoming-datagram-stream.subscribe\x01,\x01h\x12\x01@\x01\x04self-\0#\x04\0+[m\
-ethod]outgoing-datagram-stream.check-send\x01.\x01p\x0f\x01@\x02\x04self-\x09dat\
-agrams/\0#\x04\0%[method]outgoing-datagram-stream.send\x010\x01@\x01\x04self-\0&\
-\x04\0*[method]outgoing-datagram-stream.subscribe\x011\x04\x01\x16wasi:sockets/u\
-dp@0.2.0\x05K\x02\x03\05\x0audp-socket\x01B\x0c\x02\x03\x02\x01F\x04\0\x07networ\
-k\x03\0\0\x02\x03\x02\x01H\x04\0\x0aerror-code\x03\0\x02\x02\x03\x02\x01J\x04\0\x11\
-ip-address-family\x03\0\x04\x02\x03\x02\x01L\x04\0\x0audp-socket\x03\0\x06\x01i\x07\
-\x01j\x01\x08\x01\x03\x01@\x01\x0eaddress-family\x05\0\x09\x04\0\x11create-udp-s\
-ocket\x01\x0a\x04\x01$wasi:sockets/udp-create-socket@0.2.0\x05M\x01BT\x02\x03\x02\
-\x01\x0c\x04\0\x0cinput-stream\x03\0\0\x02\x03\x02\x01\x0a\x04\0\x0doutput-strea\
-m\x03\0\x02\x02\x03\x02\x01\x01\x04\0\x08pollable\x03\0\x04\x02\x03\x02\x01\x18\x04\
-\0\x08duration\x03\0\x06\x02\x03\x02\x01F\x04\0\x07network\x03\0\x08\x02\x03\x02\
-\x01H\x04\0\x0aerror-code\x03\0\x0a\x02\x03\x02\x01I\x04\0\x11ip-socket-address\x03\
-\0\x0c\x02\x03\x02\x01J\x04\0\x11ip-address-family\x03\0\x0e\x01m\x03\x07receive\
-\x04send\x04both\x04\0\x0dshutdown-type\x03\0\x10\x04\0\x0atcp-socket\x03\x01\x01\
-h\x12\x01h\x09\x01j\0\x01\x0b\x01@\x03\x04self\x13\x07network\x14\x0dlocal-addre\
-ss\x0d\0\x15\x04\0\x1d[method]tcp-socket.start-bind\x01\x16\x01@\x01\x04self\x13\
-\0\x15\x04\0\x1e[method]tcp-socket.finish-bind\x01\x17\x01@\x03\x04self\x13\x07n\
-etwork\x14\x0eremote-address\x0d\0\x15\x04\0\x20[method]tcp-socket.start-connect\
-\x01\x18\x01i\x01\x01i\x03\x01o\x02\x19\x1a\x01j\x01\x1b\x01\x0b\x01@\x01\x04sel\
-f\x13\0\x1c\x04\0![method]tcp-socket.finish-connect\x01\x1d\x04\0\x1f[method]tcp\
--socket.start-listen\x01\x17\x04\0\x20[method]tcp-socket.finish-listen\x01\x17\x01\
-i\x12\x01o\x03\x1e\x19\x1a\x01j\x01\x1f\x01\x0b\x01@\x01\x04self\x13\0\x20\x04\0\
-\x19[method]tcp-socket.accept\x01!\x01j\x01\x0d\x01\x0b\x01@\x01\x04self\x13\0\"\
-\x04\0\x20[method]tcp-socket.local-address\x01#\x04\0![method]tcp-socket.remote-\
-address\x01#\x01@\x01\x04self\x13\0\x7f\x04\0\x1f[method]tcp-socket.is-listening\
-\x01$\x01@\x01\x04self\x13\0\x0f\x04\0![method]tcp-socket.address-family\x01%\x01\
-@\x02\x04self\x13\x05valuew\0\x15\x04\0*[method]tcp-socket.set-listen-backlog-si\
-ze\x01&\x01j\x01\x7f\x01\x0b\x01@\x01\x04self\x13\0'\x04\0%[method]tcp-socket.ke\
-ep-alive-enabled\x01(\x01@\x02\x04self\x13\x05value\x7f\0\x15\x04\0)[method]tcp-\
-socket.set-keep-alive-enabled\x01)\x01j\x01\x07\x01\x0b\x01@\x01\x04self\x13\0*\x04\
-\0'[method]tcp-socket.keep-alive-idle-time\x01+\x01@\x02\x04self\x13\x05value\x07\
-\0\x15\x04\0+[method]tcp-socket.set-keep-alive-idle-time\x01,\x04\0&[method]tcp-\
-socket.keep-alive-interval\x01+\x04\0*[method]tcp-socket.set-keep-alive-interval\
-\x01,\x01j\x01y\x01\x0b\x01@\x01\x04self\x13\0-\x04\0#[method]tcp-socket.keep-al\
-ive-count\x01.\x01@\x02\x04self\x13\x05valuey\0\x15\x04\0'[method]tcp-socket.set\
--keep-alive-count\x01/\x01j\x01}\x01\x0b\x01@\x01\x04self\x13\00\x04\0\x1c[metho\
-d]tcp-socket.hop-limit\x011\x01@\x02\x04self\x13\x05value}\0\x15\x04\0\x20[metho\
-d]tcp-socket.set-hop-limit\x012\x01j\x01w\x01\x0b\x01@\x01\x04self\x13\03\x04\0&\
-[method]tcp-socket.receive-buffer-size\x014\x04\0*[method]tcp-socket.set-receive\
--buffer-size\x01&\x04\0#[method]tcp-socket.send-buffer-size\x014\x04\0'[method]t\
-cp-socket.set-send-buffer-size\x01&\x01i\x05\x01@\x01\x04self\x13\05\x04\0\x1c[m\
-ethod]tcp-socket.subscribe\x016\x01@\x02\x04self\x13\x0dshutdown-type\x11\0\x15\x04\
-\0\x1b[method]tcp-socket.shutdown\x017\x04\x01\x16wasi:sockets/tcp@0.2.0\x05N\x02\
-\x03\07\x0atcp-socket\x01B\x0c\x02\x03\x02\x01F\x04\0\x07network\x03\0\0\x02\x03\
-\x02\x01H\x04\0\x0aerror-code\x03\0\x02\x02\x03\x02\x01J\x04\0\x11ip-address-fam\
-ily\x03\0\x04\x02\x03\x02\x01O\x04\0\x0atcp-socket\x03\0\x06\x01i\x07\x01j\x01\x08\
-\x01\x03\x01@\x01\x0eaddress-family\x05\0\x09\x04\0\x11create-tcp-socket\x01\x0a\
-\x04\x01$wasi:sockets/tcp-create-socket@0.2.0\x05P\x02\x03\03\x0aip-address\x01B\
-\x16\x02\x03\x02\x01\x01\x04\0\x08pollable\x03\0\0\x02\x03\x02\x01F\x04\0\x07net\
-work\x03\0\x02\x02\x03\x02\x01H\x04\0\x0aerror-code\x03\0\x04\x02\x03\x02\x01Q\x04\
-\0\x0aip-address\x03\0\x06\x04\0\x16resolve-address-stream\x03\x01\x01h\x08\x01k\
-\x07\x01j\x01\x0a\x01\x05\x01@\x01\x04self\x09\0\x0b\x04\03[method]resolve-addre\
-ss-stream.resolve-next-address\x01\x0c\x01i\x01\x01@\x01\x04self\x09\0\x0d\x04\0\
-([method]resolve-address-stream.subscribe\x01\x0e\x01h\x03\x01i\x08\x01j\x01\x10\
-\x01\x05\x01@\x02\x07network\x0f\x04names\0\x11\x04\0\x11resolve-addresses\x01\x12\
-\x04\x01!wasi:sockets/ip-name-lookup@0.2.0\x05R\x01B\xc0\x01\x02\x03\x02\x01\x18\
-\x04\0\x08duration\x03\0\0\x02\x03\x02\x01\x0c\x04\0\x0cinput-stream\x03\0\x02\x02\
-\x03\x02\x01\x0a\x04\0\x0doutput-stream\x03\0\x04\x02\x03\x02\x01\x08\x04\0\x08i\
-o-error\x03\0\x06\x02\x03\x02\x01\x01\x04\0\x08pollable\x03\0\x08\x01q\x0a\x03ge\
-t\0\0\x04head\0\0\x04post\0\0\x03put\0\0\x06delete\0\0\x07connect\0\0\x07options\
-\0\0\x05trace\0\0\x05patch\0\0\x05other\x01s\0\x04\0\x06method\x03\0\x0a\x01q\x03\
+ute\x01\x1e\x04\x01\x18fermyon:spin/redis@2.0.0\x05)\x01B\x11\x01q\x05\x11connec\
+tion-failed\x01s\0\x0dbad-parameter\x01s\0\x0cquery-failed\x01s\0\x17value-conve\
+rsion-failed\x01s\0\x05other\x01s\0\x04\0\x05error\x03\0\0\x01m\x0e\x07boolean\x04\
+int8\x05int16\x05int32\x05int64\x05uint8\x06uint16\x06uint32\x06uint64\x0afloati\
+ng32\x0afloating64\x03str\x06binary\x05other\x04\0\x0cdb-data-type\x03\0\x02\x01\
+p}\x01q\x0f\x07boolean\x01\x7f\0\x04int8\x01~\0\x05int16\x01|\0\x05int32\x01z\0\x05\
+int64\x01x\0\x05uint8\x01}\0\x06uint16\x01{\0\x06uint32\x01y\0\x06uint64\x01w\0\x0a\
+floating32\x01v\0\x0afloating64\x01u\0\x03str\x01s\0\x06binary\x01\x04\0\x07db-n\
+ull\0\0\x0bunsupported\0\0\x04\0\x08db-value\x03\0\x05\x01q\x0e\x07boolean\x01\x7f\
+\0\x04int8\x01~\0\x05int16\x01|\0\x05int32\x01z\0\x05int64\x01x\0\x05uint8\x01}\0\
+\x06uint16\x01{\0\x06uint32\x01y\0\x06uint64\x01w\0\x0afloating32\x01v\0\x0afloa\
+ting64\x01u\0\x03str\x01s\0\x06binary\x01\x04\0\x07db-null\0\0\x04\0\x0fparamete\
+r-value\x03\0\x07\x01r\x02\x04names\x09data-type\x03\x04\0\x06column\x03\0\x09\x01\
+p\x06\x04\0\x03row\x03\0\x0b\x01p\x0a\x01p\x0c\x01r\x02\x07columns\x0d\x04rows\x0e\
+\x04\0\x07row-set\x03\0\x0f\x04\x01\x1efermyon:spin/rdbms-types@2.0.0\x05*\x02\x03\
+\0\x16\x0fparameter-value\x02\x03\0\x16\x07row-set\x02\x03\0\x16\x05error\x01B\x13\
+\x02\x03\x02\x01+\x04\0\x0fparameter-value\x03\0\0\x02\x03\x02\x01,\x04\0\x07row\
+-set\x03\0\x02\x02\x03\x02\x01-\x04\0\x05error\x03\0\x04\x04\0\x0aconnection\x03\
+\x01\x01i\x06\x01j\x01\x07\x01\x05\x01@\x01\x07addresss\0\x08\x04\0\x17[static]c\
+onnection.open\x01\x09\x01h\x06\x01p\x01\x01j\x01\x03\x01\x05\x01@\x03\x04self\x0a\
+\x09statements\x06params\x0b\0\x0c\x04\0\x18[method]connection.query\x01\x0d\x01\
+j\x01w\x01\x05\x01@\x03\x04self\x0a\x09statements\x06params\x0b\0\x0e\x04\0\x1a[\
+method]connection.execute\x01\x0f\x04\x01\x1bfermyon:spin/postgres@2.0.0\x05.\x01\
+B\x0f\x01q\x04\x0finvalid-address\0\0\x14too-many-connections\0\0\x11connection-\
+failed\x01s\0\x05other\x01s\0\x04\0\x05error\x03\0\0\x01m\x03\x0cat-most-once\x0d\
+at-least-once\x0cexactly-once\x04\0\x03qos\x03\0\x02\x04\0\x0aconnection\x03\x01\
+\x01p}\x04\0\x07payload\x03\0\x05\x01i\x04\x01j\x01\x07\x01\x01\x01@\x04\x07addr\
+esss\x08usernames\x08passwords\x1bkeep-alive-interval-in-secsw\0\x08\x04\0\x17[s\
+tatic]connection.open\x01\x09\x01h\x04\x01j\0\x01\x01\x01@\x04\x04self\x0a\x05to\
+pics\x07payload\x06\x03qos\x03\0\x0b\x04\0\x1a[method]connection.publish\x01\x0c\
+\x04\x01\x17fermyon:spin/mqtt@2.0.0\x05/\x01B\x13\x02\x03\x02\x01+\x04\0\x0fpara\
+meter-value\x03\0\0\x02\x03\x02\x01,\x04\0\x07row-set\x03\0\x02\x02\x03\x02\x01-\
+\x04\0\x05error\x03\0\x04\x04\0\x0aconnection\x03\x01\x01i\x06\x01j\x01\x07\x01\x05\
+\x01@\x01\x07addresss\0\x08\x04\0\x17[static]connection.open\x01\x09\x01h\x06\x01\
+p\x01\x01j\x01\x03\x01\x05\x01@\x03\x04self\x0a\x09statements\x06params\x0b\0\x0c\
+\x04\0\x18[method]connection.query\x01\x0d\x01j\0\x01\x05\x01@\x03\x04self\x0a\x09\
+statements\x06params\x0b\0\x0e\x04\0\x1a[method]connection.execute\x01\x0f\x04\x01\
+\x18fermyon:spin/mysql@2.0.0\x050\x01B\x15\x04\0\x0aconnection\x03\x01\x01q\x05\x10\
+no-such-database\0\0\x0daccess-denied\0\0\x12invalid-connection\0\0\x0ddatabase-\
+full\0\0\x02io\x01s\0\x04\0\x05error\x03\0\x01\x01p}\x01q\x05\x07integer\x01x\0\x04\
+real\x01u\0\x04text\x01s\0\x04blob\x01\x03\0\x04null\0\0\x04\0\x05value\x03\0\x04\
+\x01p\x05\x01r\x01\x06values\x06\x04\0\x0arow-result\x03\0\x07\x01ps\x01p\x08\x01\
+r\x02\x07columns\x09\x04rows\x0a\x04\0\x0cquery-result\x03\0\x0b\x01i\0\x01j\x01\
+\x0d\x01\x02\x01@\x01\x08databases\0\x0e\x04\0\x17[static]connection.open\x01\x0f\
+\x01h\0\x01j\x01\x0c\x01\x02\x01@\x03\x04self\x10\x09statements\x0aparameters\x06\
+\0\x11\x04\0\x1a[method]connection.execute\x01\x12\x04\x01\x19fermyon:spin/sqlit\
+e@2.0.0\x051\x01B\x05\x01q\x04\x0cinvalid-name\x01s\0\x09undefined\x01s\0\x08pro\
+vider\x01s\0\x05other\x01s\0\x04\0\x05error\x03\0\0\x01j\x01s\x01\x01\x01@\x01\x04\
+names\0\x02\x04\0\x03get\x01\x03\x04\x01\x1cfermyon:spin/variables@2.0.0\x052\x01\
+B\x02\x01q\x03\x05exact\x01s\0\x06prefix\x01s\0\x05regex\x01s\0\x04\0\x07matcher\
+\x03\0\0\x04\x01\x1cfermyon:spin-test-virt/types\x053\x01B\x19\x04\0\x05store\x03\
+\x01\x01p}\x01o\x02s\x01\x01q\x05\x03get\x01s\0\x03set\x01\x02\0\x06delete\x01s\0\
+\x06exists\x01s\0\x08get-keys\0\0\x04\0\x04call\x03\0\x03\x01i\0\x01@\x01\x05lab\
+els\0\x05\x04\0\x12[static]store.open\x01\x06\x01h\0\x01@\x01\x04self\x07\0s\x04\
+\0\x13[method]store.label\x01\x08\x01k\x01\x01@\x02\x04self\x07\x03keys\0\x09\x04\
+\0\x11[method]store.get\x01\x0a\x01@\x03\x04self\x07\x03keys\x05value\x01\x01\0\x04\
+\0\x11[method]store.set\x01\x0b\x01@\x02\x04self\x07\x03keys\x01\0\x04\0\x14[met\
+hod]store.delete\x01\x0c\x01p\x04\x01o\x02s\x0d\x01p\x0e\x01@\0\0\x0f\x04\0\x05c\
+alls\x01\x10\x01@\0\x01\0\x04\0\x0breset-calls\x01\x11\x04\x01\x20fermyon:spin-t\
+est-virt/key-value\x054\x02\x03\0\x14\x12inferencing-params\x02\x03\0\x14\x12inf\
+erencing-result\x02\x03\0\x14\x11embeddings-result\x02\x03\0\x14\x05error\x02\x03\
+\0\x1c\x07matcher\x01B#\x02\x03\x02\x015\x04\0\x12inferencing-params\x03\0\0\x02\
+\x03\x02\x016\x04\0\x12inferencing-result\x03\0\x02\x02\x03\x02\x017\x04\0\x11em\
+beddings-result\x03\0\x04\x02\x03\x02\x018\x04\0\x05error\x03\0\x06\x02\x03\x02\x01\
+9\x04\0\x07matcher\x03\0\x08\x01q\x02\x05error\x01\x07\0\x06result\x01\x03\0\x04\
+\0\x0einfer-response\x03\0\x0a\x01q\x02\x05error\x01\x07\0\x06result\x01\x05\0\x04\
+\0\x13embeddings-response\x03\0\x0c\x01k\x01\x01r\x03\x05models\x06prompts\x06pa\
+rams\x0e\x04\0\x0ainfer-call\x03\0\x0f\x01ps\x01r\x02\x05models\x04text\x11\x04\0\
+\x0fembeddings-call\x03\0\x12\x01q\x02\x05infer\x01\x10\0\x13generate-embeddings\
+\x01\x13\0\x04\0\x04call\x03\0\x14\x01ks\x01k\x09\x01j\0\x01s\x01@\x03\x05model\x16\
+\x06prompt\x17\x08response\x0b\0\x18\x04\0\x0eadd-infer-rule\x01\x19\x01@\x02\x05\
+model\x16\x08response\x0d\x01\0\x04\0\x13add-embeddings-rule\x01\x1a\x01@\0\x01\0\
+\x04\0\x0bclear-rules\x01\x1b\x01p\x15\x01@\0\0\x1c\x04\0\x05calls\x01\x1d\x04\0\
+\x0breset-calls\x01\x1b\x04\x01\x1afermyon:spin-test-virt/llm\x05:\x02\x03\0\x1a\
+\x05value\x02\x03\0\x1a\x0cquery-result\x02\x03\0\x1a\x05error\x01B1\x02\x03\x02\
+\x01;\x04\0\x05value\x03\0\0\x02\x03\x02\x01<\x04\0\x0cquery-result\x03\0\x02\x02\
+\x03\x02\x01=\x04\0\x05error\x03\0\x04\x02\x03\x02\x019\x04\0\x07matcher\x03\0\x06\
+\x04\0\x0aconnection\x03\x01\x01p\x01\x01j\x01w\x01\x05\x01r\x03\x09statements\x0a\
+parameters\x09\x06result\x0a\x04\0\x04call\x03\0\x0b\x01p}\x01o\x02s\x0d\x01p\x0e\
+\x04\0\x08snapshot\x03\0\x0f\x01q\x02\x05error\x01\x05\0\x06result\x01\x03\0\x04\
+\0\x0drule-response\x03\0\x11\x01i\x08\x01j\x01\x13\x01\x05\x01@\x01\x08database\
+s\0\x14\x04\0\x17[static]connection.open\x01\x15\x01h\x08\x01j\x01\x03\x01\x05\x01\
+@\x03\x04self\x16\x09statements\x0aparameters\x09\0\x17\x04\0\x1a[method]connect\
+ion.execute\x01\x18\x01j\0\x01\x05\x01@\x02\x04self\x16\x0astatementss\0\x19\x04\
+\0\x20[method]connection.execute-batch\x01\x1a\x01p\x0c\x01o\x02s\x1b\x01p\x1c\x01\
+@\0\0\x1d\x04\0\x05calls\x01\x1e\x01@\0\x01\0\x04\0\x0breset-calls\x01\x1f\x01ks\
+\x01j\0\x01s\x01@\x03\x08database\x20\x07matcher\x07\x08response\x12\0!\x04\0\x08\
+add-rule\x01\"\x04\0\x0bclear-rules\x01\x1f\x01j\x01\x10\x01\x05\x01@\0\0#\x04\0\
+\x0dtake-snapshot\x01$\x01@\x01\x08snapshot\x10\0\x19\x04\0\x10restore-snapshot\x01\
+%\x01@\0\0\x19\x04\0\x05reset\x01&\x04\x01\x1dfermyon:spin-test-virt/sqlite\x05>\
+\x02\x03\0\x15\x07payload\x02\x03\0\x15\x0fredis-parameter\x02\x03\0\x15\x0credi\
+s-result\x02\x03\0\x15\x05error\x01B8\x02\x03\x02\x01?\x04\0\x07payload\x03\0\0\x02\
+\x03\x02\x01@\x04\0\x0fredis-parameter\x03\0\x02\x02\x03\x02\x01A\x04\0\x0credis\
+-result\x03\0\x04\x02\x03\x02\x01B\x04\0\x05error\x03\0\x06\x02\x03\x02\x019\x04\
+\0\x07matcher\x03\0\x08\x04\0\x0aconnection\x03\x01\x01o\x02s\x01\x01ps\x01o\x02\
+s\x0c\x01p\x03\x01o\x02s\x0e\x01q\x09\x07publish\x01\x0b\0\x03get\x01s\0\x03set\x01\
+\x0b\0\x04incr\x01s\0\x03del\x01\x0c\0\x04sadd\x01\x0d\0\x08smembers\x01s\0\x04s\
+rem\x01\x0d\0\x07execute\x01\x0f\0\x04\0\x04call\x03\0\x10\x01p\x05\x01q\x02\x05\
+error\x01\x07\0\x06result\x01\x12\0\x04\0\x10execute-response\x03\0\x13\x01i\x0a\
+\x01@\x01\x07addresss\0\x15\x04\0\x17[static]connection.open\x01\x16\x01h\x0a\x01\
+@\x01\x04self\x17\0s\x04\0\x1a[method]connection.address\x01\x18\x01k\x01\x01j\x01\
+\x19\x01\x07\x01@\x02\x04self\x17\x03keys\0\x1a\x04\0\x16[method]connection.get\x01\
+\x1b\x01@\x03\x04self\x17\x03keys\x05value\x01\x01\0\x04\0\x16[method]connection\
+.set\x01\x1c\x01j\x01\x0c\x01\x07\x01@\x02\x04self\x17\x03keys\0\x1d\x04\0\x1b[m\
+ethod]connection.smembers\x01\x1e\x01j\0\x01\x07\x01@\x03\x04self\x17\x03keys\x06\
+values\x0c\0\x1f\x04\0\x17[method]connection.sadd\x01\x20\x01@\x02\x04self\x17\x04\
+keys\x0c\x01\0\x04\0\x16[method]connection.del\x01!\x01@\x01\x04self\x17\0\x0c\x04\
+\0\x17[method]connection.keys\x01\"\x01p\x11\x01o\x02s#\x01p$\x01@\0\0%\x04\0\x05\
+calls\x01&\x01@\0\x01\0\x04\0\x0breset-calls\x01'\x01ks\x01k\x09\x01j\0\x01s\x01\
+@\x04\x07address(\x07commands\x09arguments)\x08response\x14\0*\x04\0\x10add-exec\
+ute-rule\x01+\x04\0\x13clear-execute-rules\x01'\x04\x01\x1cfermyon:spin-test-vir\
+t/redis\x05C\x02\x03\0\x18\x07payload\x02\x03\0\x18\x03qos\x02\x03\0\x18\x05erro\
+r\x01B\x16\x02\x03\x02\x01D\x04\0\x07payload\x03\0\0\x02\x03\x02\x01E\x04\0\x03q\
+os\x03\0\x02\x02\x03\x02\x01F\x04\0\x05error\x03\0\x04\x01r\x05\x05topics\x07pay\
+load\x01\x03qos\x03\x08usernames\x1bkeep-alive-interval-in-secsw\x04\0\x07publis\
+h\x03\0\x06\x01p\x07\x01o\x02s\x08\x01p\x09\x01@\0\0\x0a\x04\0\x09publishes\x01\x0b\
+\x01@\0\x01\0\x04\0\x0freset-publishes\x01\x0c\x01@\x03\x07addresss\x08usernames\
+\x08passwords\x01\0\x04\0\x0fadd-credentials\x01\x0d\x01k\x05\x01@\x02\x07addres\
+ss\x05error\x0e\x01\0\x04\0\x0eset-open-error\x01\x0f\x04\0\x11set-publish-error\
+\x01\x0f\x04\0\x0dreset-brokers\x01\x0c\x04\x01\x1bfermyon:spin-test-virt/mqtt\x05\
+G\x01B\x18\x02\x03\x02\x01+\x04\0\x0fparameter-value\x03\0\0\x02\x03\x02\x01,\x04\
+\0\x07row-set\x03\0\x02\x02\x03\x02\x01-\x04\0\x05error\x03\0\x04\x04\0\x0aconne\
+ction\x03\x01\x01i\x06\x01j\x01\x07\x01\x05\x01@\x01\x07addresss\0\x08\x04\0\x17\
+[static]connection.open\x01\x09\x01h\x06\x01@\x01\x04self\x0a\0s\x04\0\x1a[metho\
+d]connection.address\x01\x0b\x01p\x01\x01j\x01\x03\x01\x05\x01@\x03\x04self\x0a\x09\
+statements\x06params\x0c\0\x0d\x04\0\x18[method]connection.query\x01\x0e\x01j\x01\
+w\x01\x05\x01@\x03\x04self\x0a\x09statements\x06params\x0c\0\x0f\x04\0\x1a[metho\
+d]connection.execute\x01\x10\x01j\0\x01\x05\x01@\x02\x04self\x0a\x0astatementss\0\
+\x11\x04\0\x20[method]connection.execute-batch\x01\x12\x04\x01\x1ffermyon:spin-t\
+est-virt/postgres\x05H\x01B\x18\x02\x03\x02\x01+\x04\0\x0fparameter-value\x03\0\0\
+\x02\x03\x02\x01,\x04\0\x07row-set\x03\0\x02\x02\x03\x02\x01-\x04\0\x05error\x03\
+\0\x04\x04\0\x0aconnection\x03\x01\x01i\x06\x01j\x01\x07\x01\x05\x01@\x01\x07add\
+resss\0\x08\x04\0\x17[static]connection.open\x01\x09\x01h\x06\x01@\x01\x04self\x0a\
+\0s\x04\0\x1a[method]connection.address\x01\x0b\x01p\x01\x01j\x01\x03\x01\x05\x01\
+@\x03\x04self\x0a\x09statements\x06params\x0c\0\x0d\x04\0\x18[method]connection.\
+query\x01\x0e\x01j\x01w\x01\x05\x01@\x03\x04self\x0a\x09statements\x06params\x0c\
+\0\x0f\x04\0\x1a[method]connection.execute\x01\x10\x01j\0\x01\x05\x01@\x02\x04se\
+lf\x0a\x0astatementss\0\x11\x04\0\x20[method]connection.execute-batch\x01\x12\x04\
+\x01\x1cfermyon:spin-test-virt/mysql\x05I\x01B\x1b\x02\x03\x02\x01+\x04\0\x0fpar\
+ameter-value\x03\0\0\x02\x03\x02\x01,\x04\0\x07row-set\x03\0\x02\x02\x03\x02\x01\
+-\x04\0\x05error\x03\0\x04\x02\x03\x02\x019\x04\0\x07matcher\x03\0\x06\x01q\x03\x05\
+error\x01\x05\0\x04rows\x01\x03\0\x07changed\x01w\0\x04\0\x0drule-response\x03\0\
+\x08\x01p\x01\x01j\x01w\x01\x05\x01r\x03\x09statements\x0aparameters\x0a\x06resu\
+lt\x0b\x04\0\x04call\x03\0\x0c\x01ks\x01k\x0a\x01j\0\x01s\x01@\x04\x07address\x0e\
+\x07matcher\x07\x0aparameters\x0f\x08response\x09\0\x10\x04\0\x08add-rule\x01\x11\
+\x01@\0\x01\0\x04\0\x0bclear-rules\x01\x12\x01p\x0d\x01o\x02s\x13\x01p\x14\x01@\0\
+\0\x15\x04\0\x05calls\x01\x16\x04\0\x0breset-calls\x01\x12\x04\x01\x1cfermyon:sp\
+in-test-virt/rdbms\x05J\x02\x03\0\x1b\x05error\x01B\x0f\x02\x03\x02\x01K\x04\0\x05\
+error\x03\0\0\x01j\x01s\x01\x01\x01r\x02\x04names\x06result\x02\x04\0\x04call\x03\
+\0\x03\x01@\x02\x03keys\x05values\x01\0\x04\0\x03set\x01\x05\x01@\x01\x03keys\x01\
+\0\x04\0\x05unset\x01\x06\x01@\0\x01\0\x04\0\x05clear\x01\x07\x01p\x04\x01@\0\0\x08\
+\x04\0\x05calls\x01\x09\x04\0\x0breset-calls\x01\x07\x04\x01\x20fermyon:spin-tes\
+t-virt/variables\x05L\x01B\x02\x01@\x01\x0bnanosecondsw\x01\0\x04\0\x07advance\x01\
+\0\x04\x01\x1cfermyon:spin-test-virt/clock\x05M\x01B\x03\x01j\0\0\x01@\0\0\0\x04\
+\0\x03run\x01\x01\x04\x01\x12wasi:cli/run@0.2.0\x05N\x01B\x07\x01m\x02\x07succes\
+s\x05error\x04\0\x05error\x03\0\0\x01p}\x04\0\x07payload\x03\0\x02\x01j\0\x01\x01\
+\x01@\x01\x07message\x03\0\x04\x04\0\x0ehandle-message\x01\x05\x04\x01$fermyon:s\
+pin-test-virt/inbound-redis\x05O\x01B\x0a\x04\0\x08pollable\x03\x01\x01h\0\x01@\x01\
+\x04self\x01\0\x7f\x04\0\x16[method]pollable.ready\x01\x02\x01@\x01\x04self\x01\x01\
+\0\x04\0\x16[method]pollable.block\x01\x03\x01p\x01\x01py\x01@\x01\x02in\x04\0\x05\
+\x04\0\x04poll\x01\x06\x04\x01\x12wasi:io/poll@0.2.0\x05P\x01B\x0f\x02\x03\x02\x01\
+\x01\x04\0\x08pollable\x03\0\0\x01w\x04\0\x07instant\x03\0\x02\x01w\x04\0\x08dur\
+ation\x03\0\x04\x01@\0\0\x03\x04\0\x03now\x01\x06\x01@\0\0\x05\x04\0\x0aresoluti\
+on\x01\x07\x01i\x01\x01@\x01\x04when\x03\0\x08\x04\0\x11subscribe-instant\x01\x09\
+\x01@\x01\x04when\x05\0\x08\x04\0\x12subscribe-duration\x01\x0a\x04\x01!wasi:clo\
+cks/monotonic-clock@0.2.0\x05Q\x01B\x05\x01r\x02\x07secondsw\x0bnanosecondsy\x04\
+\0\x08datetime\x03\0\0\x01@\0\0\x01\x04\0\x03now\x01\x02\x04\0\x0aresolution\x01\
+\x02\x04\x01\x1cwasi:clocks/wall-clock@0.2.0\x05R\x01B\x05\x01p}\x01@\x01\x03len\
+w\0\0\x04\0\x10get-random-bytes\x01\x01\x01@\0\0w\x04\0\x0eget-random-u64\x01\x02\
+\x04\x01\x18wasi:random/random@0.2.0\x05S\x01B\x05\x01p}\x01@\x01\x03lenw\0\0\x04\
+\0\x19get-insecure-random-bytes\x01\x01\x01@\0\0w\x04\0\x17get-insecure-random-u\
+64\x01\x02\x04\x01\x1awasi:random/insecure@0.2.0\x05T\x01B\x03\x01o\x02ww\x01@\0\
+\0\0\x04\0\x0dinsecure-seed\x01\x01\x04\x01\x1fwasi:random/insecure-seed@0.2.0\x05\
+U\x01B\x04\x04\0\x05error\x03\x01\x01h\0\x01@\x01\x04self\x01\0s\x04\0\x1d[metho\
+d]error.to-debug-string\x01\x02\x04\x01\x13wasi:io/error@0.2.0\x05V\x01B(\x02\x03\
+\x02\x01\x08\x04\0\x05error\x03\0\0\x02\x03\x02\x01\x01\x04\0\x08pollable\x03\0\x02\
+\x01i\x01\x01q\x02\x15last-operation-failed\x01\x04\0\x06closed\0\0\x04\0\x0cstr\
+eam-error\x03\0\x05\x04\0\x0cinput-stream\x03\x01\x04\0\x0doutput-stream\x03\x01\
+\x01h\x07\x01p}\x01j\x01\x0a\x01\x06\x01@\x02\x04self\x09\x03lenw\0\x0b\x04\0\x19\
+[method]input-stream.read\x01\x0c\x04\0\"[method]input-stream.blocking-read\x01\x0c\
+\x01j\x01w\x01\x06\x01@\x02\x04self\x09\x03lenw\0\x0d\x04\0\x19[method]input-str\
+eam.skip\x01\x0e\x04\0\"[method]input-stream.blocking-skip\x01\x0e\x01i\x03\x01@\
+\x01\x04self\x09\0\x0f\x04\0\x1e[method]input-stream.subscribe\x01\x10\x01h\x08\x01\
+@\x01\x04self\x11\0\x0d\x04\0![method]output-stream.check-write\x01\x12\x01j\0\x01\
+\x06\x01@\x02\x04self\x11\x08contents\x0a\0\x13\x04\0\x1b[method]output-stream.w\
+rite\x01\x14\x04\0.[method]output-stream.blocking-write-and-flush\x01\x14\x01@\x01\
+\x04self\x11\0\x13\x04\0\x1b[method]output-stream.flush\x01\x15\x04\0$[method]ou\
+tput-stream.blocking-flush\x01\x15\x01@\x01\x04self\x11\0\x0f\x04\0\x1f[method]o\
+utput-stream.subscribe\x01\x16\x01@\x02\x04self\x11\x03lenw\0\x13\x04\0\"[method\
+]output-stream.write-zeroes\x01\x17\x04\05[method]output-stream.blocking-write-z\
+eroes-and-flush\x01\x17\x01@\x03\x04self\x11\x03src\x09\x03lenw\0\x0d\x04\0\x1c[\
+method]output-stream.splice\x01\x18\x04\0%[method]output-stream.blocking-splice\x01\
+\x18\x04\x01\x15wasi:io/streams@0.2.0\x05W\x02\x03\00\x05error\x02\x03\0+\x08dat\
+etime\x01Br\x02\x03\x02\x01\x0c\x04\0\x0cinput-stream\x03\0\0\x02\x03\x02\x01\x0a\
+\x04\0\x0doutput-stream\x03\0\x02\x02\x03\x02\x01X\x04\0\x05error\x03\0\x04\x02\x03\
+\x02\x01Y\x04\0\x08datetime\x03\0\x06\x01w\x04\0\x08filesize\x03\0\x08\x01m\x08\x07\
+unknown\x0cblock-device\x10character-device\x09directory\x04fifo\x0dsymbolic-lin\
+k\x0cregular-file\x06socket\x04\0\x0fdescriptor-type\x03\0\x0a\x01n\x06\x04read\x05\
+write\x13file-integrity-sync\x13data-integrity-sync\x14requested-write-sync\x10m\
+utate-directory\x04\0\x10descriptor-flags\x03\0\x0c\x01n\x01\x0esymlink-follow\x04\
+\0\x0apath-flags\x03\0\x0e\x01n\x04\x06create\x09directory\x09exclusive\x08trunc\
+ate\x04\0\x0aopen-flags\x03\0\x10\x01w\x04\0\x0alink-count\x03\0\x12\x01k\x07\x01\
+r\x06\x04type\x0b\x0alink-count\x13\x04size\x09\x15data-access-timestamp\x14\x1b\
+data-modification-timestamp\x14\x17status-change-timestamp\x14\x04\0\x0fdescript\
+or-stat\x03\0\x15\x01q\x03\x09no-change\0\0\x03now\0\0\x09timestamp\x01\x07\0\x04\
+\0\x0dnew-timestamp\x03\0\x17\x01r\x02\x04type\x0b\x04names\x04\0\x0fdirectory-e\
+ntry\x03\0\x19\x01m%\x06access\x0bwould-block\x07already\x0ebad-descriptor\x04bu\
+sy\x08deadlock\x05quota\x05exist\x0efile-too-large\x15illegal-byte-sequence\x0bi\
+n-progress\x0binterrupted\x07invalid\x02io\x0cis-directory\x04loop\x0etoo-many-l\
+inks\x0cmessage-size\x0dname-too-long\x09no-device\x08no-entry\x07no-lock\x13ins\
+ufficient-memory\x12insufficient-space\x0dnot-directory\x09not-empty\x0fnot-reco\
+verable\x0bunsupported\x06no-tty\x0eno-such-device\x08overflow\x0dnot-permitted\x04\
+pipe\x09read-only\x0cinvalid-seek\x0etext-file-busy\x0ccross-device\x04\0\x0aerr\
+or-code\x03\0\x1b\x01m\x06\x06normal\x0asequential\x06random\x09will-need\x09don\
+t-need\x08no-reuse\x04\0\x06advice\x03\0\x1d\x01r\x02\x05lowerw\x05upperw\x04\0\x13\
+metadata-hash-value\x03\0\x1f\x04\0\x0adescriptor\x03\x01\x04\0\x16directory-ent\
+ry-stream\x03\x01\x01h!\x01i\x01\x01j\x01$\x01\x1c\x01@\x02\x04self#\x06offset\x09\
+\0%\x04\0\"[method]descriptor.read-via-stream\x01&\x01i\x03\x01j\x01'\x01\x1c\x01\
+@\x02\x04self#\x06offset\x09\0(\x04\0#[method]descriptor.write-via-stream\x01)\x01\
+@\x01\x04self#\0(\x04\0$[method]descriptor.append-via-stream\x01*\x01j\0\x01\x1c\
+\x01@\x04\x04self#\x06offset\x09\x06length\x09\x06advice\x1e\0+\x04\0\x19[method\
+]descriptor.advise\x01,\x01@\x01\x04self#\0+\x04\0\x1c[method]descriptor.sync-da\
+ta\x01-\x01j\x01\x0d\x01\x1c\x01@\x01\x04self#\0.\x04\0\x1c[method]descriptor.ge\
+t-flags\x01/\x01j\x01\x0b\x01\x1c\x01@\x01\x04self#\00\x04\0\x1b[method]descript\
+or.get-type\x011\x01@\x02\x04self#\x04size\x09\0+\x04\0\x1b[method]descriptor.se\
+t-size\x012\x01@\x03\x04self#\x15data-access-timestamp\x18\x1bdata-modification-\
+timestamp\x18\0+\x04\0\x1c[method]descriptor.set-times\x013\x01p}\x01o\x024\x7f\x01\
+j\x015\x01\x1c\x01@\x03\x04self#\x06length\x09\x06offset\x09\06\x04\0\x17[method\
+]descriptor.read\x017\x01j\x01\x09\x01\x1c\x01@\x03\x04self#\x06buffer4\x06offse\
+t\x09\08\x04\0\x18[method]descriptor.write\x019\x01i\"\x01j\x01:\x01\x1c\x01@\x01\
+\x04self#\0;\x04\0![method]descriptor.read-directory\x01<\x04\0\x17[method]descr\
+iptor.sync\x01-\x01@\x02\x04self#\x04paths\0+\x04\0&[method]descriptor.create-di\
+rectory-at\x01=\x01j\x01\x16\x01\x1c\x01@\x01\x04self#\0>\x04\0\x17[method]descr\
+iptor.stat\x01?\x01@\x03\x04self#\x0apath-flags\x0f\x04paths\0>\x04\0\x1a[method\
+]descriptor.stat-at\x01@\x01@\x05\x04self#\x0apath-flags\x0f\x04paths\x15data-ac\
+cess-timestamp\x18\x1bdata-modification-timestamp\x18\0+\x04\0\x1f[method]descri\
+ptor.set-times-at\x01A\x01@\x05\x04self#\x0eold-path-flags\x0f\x08old-paths\x0en\
+ew-descriptor#\x08new-paths\0+\x04\0\x1a[method]descriptor.link-at\x01B\x01i!\x01\
+j\x01\xc3\0\x01\x1c\x01@\x05\x04self#\x0apath-flags\x0f\x04paths\x0aopen-flags\x11\
+\x05flags\x0d\0\xc4\0\x04\0\x1a[method]descriptor.open-at\x01E\x01j\x01s\x01\x1c\
+\x01@\x02\x04self#\x04paths\0\xc6\0\x04\0\x1e[method]descriptor.readlink-at\x01G\
+\x04\0&[method]descriptor.remove-directory-at\x01=\x01@\x04\x04self#\x08old-path\
+s\x0enew-descriptor#\x08new-paths\0+\x04\0\x1c[method]descriptor.rename-at\x01H\x01\
+@\x03\x04self#\x08old-paths\x08new-paths\0+\x04\0\x1d[method]descriptor.symlink-\
+at\x01I\x04\0![method]descriptor.unlink-file-at\x01=\x01@\x02\x04self#\x05other#\
+\0\x7f\x04\0![method]descriptor.is-same-object\x01J\x01j\x01\x20\x01\x1c\x01@\x01\
+\x04self#\0\xcb\0\x04\0\x20[method]descriptor.metadata-hash\x01L\x01@\x03\x04sel\
+f#\x0apath-flags\x0f\x04paths\0\xcb\0\x04\0#[method]descriptor.metadata-hash-at\x01\
+M\x01h\"\x01k\x1a\x01j\x01\xcf\0\x01\x1c\x01@\x01\x04self\xce\0\0\xd0\0\x04\03[m\
+ethod]directory-entry-stream.read-directory-entry\x01Q\x01h\x05\x01k\x1c\x01@\x01\
+\x03err\xd2\0\0\xd3\0\x04\0\x15filesystem-error-code\x01T\x04\x01\x1bwasi:filesy\
+stem/types@0.2.0\x05Z\x02\x03\01\x0adescriptor\x01B\x07\x02\x03\x02\x01[\x04\0\x0a\
+descriptor\x03\0\0\x01i\x01\x01o\x02\x02s\x01p\x03\x01@\0\0\x04\x04\0\x0fget-dir\
+ectories\x01\x05\x04\x01\x1ewasi:filesystem/preopens@0.2.0\x05\\\x01B\x05\x02\x03\
+\x02\x01\x0a\x04\0\x0doutput-stream\x03\0\0\x01i\x01\x01@\0\0\x02\x04\0\x0aget-s\
+tdout\x01\x03\x04\x01\x15wasi:cli/stdout@0.2.0\x05]\x01B\x05\x02\x03\x02\x01\x0c\
+\x04\0\x0cinput-stream\x03\0\0\x01i\x01\x01@\0\0\x02\x04\0\x09get-stdin\x01\x03\x04\
+\x01\x14wasi:cli/stdin@0.2.0\x05^\x01B\x05\x02\x03\x02\x01\x0a\x04\0\x0doutput-s\
+tream\x03\0\0\x01i\x01\x01@\0\0\x02\x04\0\x0aget-stderr\x01\x03\x04\x01\x15wasi:\
+cli/stderr@0.2.0\x05_\x01B\x01\x04\0\x0eterminal-input\x03\x01\x04\x01\x1dwasi:c\
+li/terminal-input@0.2.0\x05`\x01B\x01\x04\0\x0fterminal-output\x03\x01\x04\x01\x1e\
+wasi:cli/terminal-output@0.2.0\x05a\x01B\x06\x02\x03\x02\x01\x11\x04\0\x0etermin\
+al-input\x03\0\0\x01i\x01\x01k\x02\x01@\0\0\x03\x04\0\x12get-terminal-stdin\x01\x04\
+\x04\x01\x1dwasi:cli/terminal-stdin@0.2.0\x05b\x01B\x06\x02\x03\x02\x01\x13\x04\0\
+\x0fterminal-output\x03\0\0\x01i\x01\x01k\x02\x01@\0\0\x03\x04\0\x13get-terminal\
+-stdout\x01\x04\x04\x01\x1ewasi:cli/terminal-stdout@0.2.0\x05c\x01B\x06\x02\x03\x02\
+\x01\x13\x04\0\x0fterminal-output\x03\0\0\x01i\x01\x01k\x02\x01@\0\0\x03\x04\0\x13\
+get-terminal-stderr\x01\x04\x04\x01\x1ewasi:cli/terminal-stderr@0.2.0\x05d\x01B\x0a\
+\x01o\x02ss\x01p\0\x01@\0\0\x01\x04\0\x0fget-environment\x01\x02\x01ps\x01@\0\0\x03\
+\x04\0\x0dget-arguments\x01\x04\x01ks\x01@\0\0\x05\x04\0\x0binitial-cwd\x01\x06\x04\
+\x01\x1awasi:cli/environment@0.2.0\x05e\x01B\x03\x01j\0\0\x01@\x01\x06status\0\x01\
+\0\x04\0\x04exit\x01\x01\x04\x01\x13wasi:cli/exit@0.2.0\x05f\x01B\x11\x04\0\x07n\
+etwork\x03\x01\x01m\x15\x07unknown\x0daccess-denied\x0dnot-supported\x10invalid-\
+argument\x0dout-of-memory\x07timeout\x14concurrency-conflict\x0fnot-in-progress\x0b\
+would-block\x0dinvalid-state\x10new-socket-limit\x14address-not-bindable\x0eaddr\
+ess-in-use\x12remote-unreachable\x12connection-refused\x10connection-reset\x12co\
+nnection-aborted\x12datagram-too-large\x11name-unresolvable\x1atemporary-resolve\
+r-failure\x1apermanent-resolver-failure\x04\0\x0aerror-code\x03\0\x01\x01m\x02\x04\
+ipv4\x04ipv6\x04\0\x11ip-address-family\x03\0\x03\x01o\x04}}}}\x04\0\x0cipv4-add\
+ress\x03\0\x05\x01o\x08{{{{{{{{\x04\0\x0cipv6-address\x03\0\x07\x01q\x02\x04ipv4\
+\x01\x06\0\x04ipv6\x01\x08\0\x04\0\x0aip-address\x03\0\x09\x01r\x02\x04port{\x07\
+address\x06\x04\0\x13ipv4-socket-address\x03\0\x0b\x01r\x04\x04port{\x09flow-inf\
+oy\x07address\x08\x08scope-idy\x04\0\x13ipv6-socket-address\x03\0\x0d\x01q\x02\x04\
+ipv4\x01\x0c\0\x04ipv6\x01\x0e\0\x04\0\x11ip-socket-address\x03\0\x0f\x04\x01\x1a\
+wasi:sockets/network@0.2.0\x05g\x02\x03\0=\x07network\x01B\x05\x02\x03\x02\x01h\x04\
+\0\x07network\x03\0\0\x01i\x01\x01@\0\0\x02\x04\0\x10instance-network\x01\x03\x04\
+\x01#wasi:sockets/instance-network@0.2.0\x05i\x02\x03\0=\x0aerror-code\x02\x03\0\
+=\x11ip-socket-address\x02\x03\0=\x11ip-address-family\x01BD\x02\x03\x02\x01\x01\
+\x04\0\x08pollable\x03\0\0\x02\x03\x02\x01h\x04\0\x07network\x03\0\x02\x02\x03\x02\
+\x01j\x04\0\x0aerror-code\x03\0\x04\x02\x03\x02\x01k\x04\0\x11ip-socket-address\x03\
+\0\x06\x02\x03\x02\x01l\x04\0\x11ip-address-family\x03\0\x08\x01p}\x01r\x02\x04d\
+ata\x0a\x0eremote-address\x07\x04\0\x11incoming-datagram\x03\0\x0b\x01k\x07\x01r\
+\x02\x04data\x0a\x0eremote-address\x0d\x04\0\x11outgoing-datagram\x03\0\x0e\x04\0\
+\x0audp-socket\x03\x01\x04\0\x18incoming-datagram-stream\x03\x01\x04\0\x18outgoi\
+ng-datagram-stream\x03\x01\x01h\x10\x01h\x03\x01j\0\x01\x05\x01@\x03\x04self\x13\
+\x07network\x14\x0dlocal-address\x07\0\x15\x04\0\x1d[method]udp-socket.start-bin\
+d\x01\x16\x01@\x01\x04self\x13\0\x15\x04\0\x1e[method]udp-socket.finish-bind\x01\
+\x17\x01i\x11\x01i\x12\x01o\x02\x18\x19\x01j\x01\x1a\x01\x05\x01@\x02\x04self\x13\
+\x0eremote-address\x0d\0\x1b\x04\0\x19[method]udp-socket.stream\x01\x1c\x01j\x01\
+\x07\x01\x05\x01@\x01\x04self\x13\0\x1d\x04\0\x20[method]udp-socket.local-addres\
+s\x01\x1e\x04\0![method]udp-socket.remote-address\x01\x1e\x01@\x01\x04self\x13\0\
+\x09\x04\0![method]udp-socket.address-family\x01\x1f\x01j\x01}\x01\x05\x01@\x01\x04\
+self\x13\0\x20\x04\0$[method]udp-socket.unicast-hop-limit\x01!\x01@\x02\x04self\x13\
+\x05value}\0\x15\x04\0([method]udp-socket.set-unicast-hop-limit\x01\"\x01j\x01w\x01\
+\x05\x01@\x01\x04self\x13\0#\x04\0&[method]udp-socket.receive-buffer-size\x01$\x01\
+@\x02\x04self\x13\x05valuew\0\x15\x04\0*[method]udp-socket.set-receive-buffer-si\
+ze\x01%\x04\0#[method]udp-socket.send-buffer-size\x01$\x04\0'[method]udp-socket.\
+set-send-buffer-size\x01%\x01i\x01\x01@\x01\x04self\x13\0&\x04\0\x1c[method]udp-\
+socket.subscribe\x01'\x01h\x11\x01p\x0c\x01j\x01)\x01\x05\x01@\x02\x04self(\x0bm\
+ax-resultsw\0*\x04\0([method]incoming-datagram-stream.receive\x01+\x01@\x01\x04s\
+elf(\0&\x04\0*[method]incoming-datagram-stream.subscribe\x01,\x01h\x12\x01@\x01\x04\
+self-\0#\x04\0+[method]outgoing-datagram-stream.check-send\x01.\x01p\x0f\x01@\x02\
+\x04self-\x09datagrams/\0#\x04\0%[method]outgoing-datagram-stream.send\x010\x01@\
+\x01\x04self-\0&\x04\0*[method]outgoing-datagram-stream.subscribe\x011\x04\x01\x16\
+wasi:sockets/udp@0.2.0\x05m\x02\x03\0?\x0audp-socket\x01B\x0c\x02\x03\x02\x01h\x04\
+\0\x07network\x03\0\0\x02\x03\x02\x01j\x04\0\x0aerror-code\x03\0\x02\x02\x03\x02\
+\x01l\x04\0\x11ip-address-family\x03\0\x04\x02\x03\x02\x01n\x04\0\x0audp-socket\x03\
+\0\x06\x01i\x07\x01j\x01\x08\x01\x03\x01@\x01\x0eaddress-family\x05\0\x09\x04\0\x11\
+create-udp-socket\x01\x0a\x04\x01$wasi:sockets/udp-create-socket@0.2.0\x05o\x01B\
+T\x02\x03\x02\x01\x0c\x04\0\x0cinput-stream\x03\0\0\x02\x03\x02\x01\x0a\x04\0\x0d\
+output-stream\x03\0\x02\x02\x03\x02\x01\x01\x04\0\x08pollable\x03\0\x04\x02\x03\x02\
+\x01\x18\x04\0\x08duration\x03\0\x06\x02\x03\x02\x01h\x04\0\x07network\x03\0\x08\
+\x02\x03\x02\x01j\x04\0\x0aerror-code\x03\0\x0a\x02\x03\x02\x01k\x04\0\x11ip-soc\
+ket-address\x03\0\x0c\x02\x03\x02\x01l\x04\0\x11ip-address-family\x03\0\x0e\x01m\
+\x03\x07receive\x04send\x04both\x04\0\x0dshutdown-type\x03\0\x10\x04\0\x0atcp-so\
+cket\x03\x01\x01h\x12\x01h\x09\x01j\0\x01\x0b\x01@\x03\x04self\x13\x07network\x14\
+\x0dlocal-address\x0d\0\x15\x04\0\x1d[method]tcp-socket.start-bind\x01\x16\x01@\x01\
+\x04self\x13\0\x15\x04\0\x1e[method]tcp-socket.finish-bind\x01\x17\x01@\x03\x04s\
+elf\x13\x07network\x14\x0eremote-address\x0d\0\x15\x04\0\x20[method]tcp-socket.s\
+tart-connect\x01\x18\x01i\x01\x01i\x03\x01o\x02\x19\x1a\x01j\x01\x1b\x01\x0b\x01\
+@\x01\x04self\x13\0\x1c\x04\0![method]tcp-socket.finish-connect\x01\x1d\x04\0\x1f\
+[method]tcp-socket.start-listen\x01\x17\x04\0\x20[method]tcp-socket.finish-liste\
+n\x01\x17\x01i\x12\x01o\x03\x1e\x19\x1a\x01j\x01\x1f\x01\x0b\x01@\x01\x04self\x13\
+\0\x20\x04\0\x19[method]tcp-socket.accept\x01!\x01j\x01\x0d\x01\x0b\x01@\x01\x04\
+self\x13\0\"\x04\0\x20[method]tcp-socket.local-address\x01#\x04\0![method]tcp-so\
+cket.remote-address\x01#\x01@\x01\x04self\x13\0\x7f\x04\0\x1f[method]tcp-socket.\
+is-listening\x01$\x01@\x01\x04self\x13\0\x0f\x04\0![method]tcp-socket.address-fa\
+mily\x01%\x01@\x02\x04self\x13\x05valuew\0\x15\x04\0*[method]tcp-socket.set-list\
+en-backlog-size\x01&\x01j\x01\x7f\x01\x0b\x01@\x01\x04self\x13\0'\x04\0%[method]\
+tcp-socket.keep-alive-enabled\x01(\x01@\x02\x04self\x13\x05value\x7f\0\x15\x04\0\
+)[method]tcp-socket.set-keep-alive-enabled\x01)\x01j\x01\x07\x01\x0b\x01@\x01\x04\
+self\x13\0*\x04\0'[method]tcp-socket.keep-alive-idle-time\x01+\x01@\x02\x04self\x13\
+\x05value\x07\0\x15\x04\0+[method]tcp-socket.set-keep-alive-idle-time\x01,\x04\0\
+&[method]tcp-socket.keep-alive-interval\x01+\x04\0*[method]tcp-socket.set-keep-a\
+live-interval\x01,\x01j\x01y\x01\x0b\x01@\x01\x04self\x13\0-\x04\0#[method]tcp-s\
+ocket.keep-alive-count\x01.\x01@\x02\x04self\x13\x05valuey\0\x15\x04\0'[method]t\
+cp-socket.set-keep-alive-count\x01/\x01j\x01}\x01\x0b\x01@\x01\x04self\x13\00\x04\
+\0\x1c[method]tcp-socket.hop-limit\x011\x01@\x02\x04self\x13\x05value}\0\x15\x04\
+\0\x20[method]tcp-socket.set-hop-limit\x012\x01j\x01w\x01\x0b\x01@\x01\x04self\x13\
+\03\x04\0&[method]tcp-socket.receive-buffer-size\x014\x04\0*[method]tcp-socket.s\
+et-receive-buffer-size\x01&\x04\0#[method]tcp-socket.send-buffer-size\x014\x04\0\
+'[method]tcp-socket.set-send-buffer-size\x01&\x01i\x05\x01@\x01\x04self\x13\05\x04\
+\0\x1c[method]tcp-socket.subscribe\x016\x01@\x02\x04self\x13\x0dshutdown-type\x11\
+\0\x15\x04\0\x1b[method]tcp-socket.shutdown\x017\x04\x01\x16wasi:sockets/tcp@0.2\
+.0\x05p\x02\x03\0A\x0atcp-socket\x01B\x0c\x02\x03\x02\x01h\x04\0\x07network\x03\0\
+\0\x02\x03\x02\x01j\x04\0\x0aerror-code\x03\0\x02\x02\x03\x02\x01l\x04\0\x11ip-a\
+ddress-family\x03\0\x04\x02\x03\x02\x01q\x04\0\x0atcp-socket\x03\0\x06\x01i\x07\x01\
+j\x01\x08\x01\x03\x01@\x01\x0eaddress-family\x05\0\x09\x04\0\x11create-tcp-socke\
+t\x01\x0a\x04\x01$wasi:sockets/tcp-create-socket@0.2.0\x05r\x02\x03\0=\x0aip-add\
+ress\x01B\x16\x02\x03\x02\x01\x01\x04\0\x08pollable\x03\0\0\x02\x03\x02\x01h\x04\
+\0\x07network\x03\0\x02\x02\x03\x02\x01j\x04\0\x0aerror-code\x03\0\x04\x02\x03\x02\
+\x01s\x04\0\x0aip-address\x03\0\x06\x04\0\x16resolve-address-stream\x03\x01\x01h\
+\x08\x01k\x07\x01j\x01\x0a\x01\x05\x01@\x01\x04self\x09\0\x0b\x04\03[method]reso\
+lve-address-stream.resolve-next-address\x01\x0c\x01i\x01\x01@\x01\x04self\x09\0\x0d\
+\x04\0([method]resolve-address-stream.subscribe\x01\x0e\x01h\x03\x01i\x08\x01j\x01\
+\x10\x01\x05\x01@\x02\x07network\x0f\x04names\0\x11\x04\0\x11resolve-addresses\x01\
+\x12\x04\x01!wasi:sockets/ip-name-lookup@0.2.0\x05t\x01B\xc0\x01\x02\x03\x02\x01\
+\x18\x04\0\x08duration\x03\0\0\x02\x03\x02\x01\x0c\x04\0\x0cinput-stream\x03\0\x02\
+\x02\x03\x02\x01\x0a\x04\0\x0doutput-stream\x03\0\x04\x02\x03\x02\x01\x08\x04\0\x08\
+io-error\x03\0\x06\x02\x03\x02\x01\x01\x04\0\x08pollable\x03\0\x08\x01q\x0a\x03g\
+et\0\0\x04head\0\0\x04post\0\0\x03put\0\0\x06delete\0\0\x07connect\0\0\x07option\
+s\0\0\x05trace\0\0\x05patch\0\0\x05other\x01s\0\x04\0\x06method\x03\0\x0a\x01q\x03\
 \x04HTTP\0\0\x05HTTPS\0\0\x05other\x01s\0\x04\0\x06scheme\x03\0\x0c\x01ks\x01k{\x01\
 r\x02\x05rcode\x0e\x09info-code\x0f\x04\0\x11DNS-error-payload\x03\0\x10\x01k}\x01\
 r\x02\x08alert-id\x12\x0dalert-message\x0e\x04\0\x1aTLS-alert-received-payload\x03\
@@ -35310,30 +43454,41 @@ trailers\xf2\0\0\x81\x01\x04\0\x1c[static]outgoing-body.finish\x01\x82\x01\x01h0
 be\x01\x84\x01\x01i+\x01j\x01\x85\x01\x01\x1b\x01j\x01\x86\x01\0\x01k\x87\x01\x01\
 @\x01\x04self\x83\x01\0\x88\x01\x04\0$[method]future-incoming-response.get\x01\x89\
 \x01\x01h\x07\x01k\x1b\x01@\x01\x03err\x8a\x01\0\x8b\x01\x04\0\x0fhttp-error-cod\
-e\x01\x8c\x01\x04\x01\x15wasi:http/types@0.2.0\x05S\x02\x03\0:\x10outgoing-reque\
-st\x02\x03\0:\x0frequest-options\x02\x03\0:\x18future-incoming-response\x02\x03\0\
-:\x0aerror-code\x01B\x0f\x02\x03\x02\x01T\x04\0\x10outgoing-request\x03\0\0\x02\x03\
-\x02\x01U\x04\0\x0frequest-options\x03\0\x02\x02\x03\x02\x01V\x04\0\x18future-in\
-coming-response\x03\0\x04\x02\x03\x02\x01W\x04\0\x0aerror-code\x03\0\x06\x01i\x01\
+e\x01\x8c\x01\x04\x01\x15wasi:http/types@0.2.0\x05u\x02\x03\0D\x10outgoing-reque\
+st\x02\x03\0D\x0frequest-options\x02\x03\0D\x18future-incoming-response\x02\x03\0\
+D\x0aerror-code\x01B\x0f\x02\x03\x02\x01v\x04\0\x10outgoing-request\x03\0\0\x02\x03\
+\x02\x01w\x04\0\x0frequest-options\x03\0\x02\x02\x03\x02\x01x\x04\0\x18future-in\
+coming-response\x03\0\x04\x02\x03\x02\x01y\x04\0\x0aerror-code\x03\0\x06\x01i\x01\
 \x01i\x03\x01k\x09\x01i\x05\x01j\x01\x0b\x01\x07\x01@\x02\x07request\x08\x07opti\
 ons\x0a\0\x0c\x04\0\x06handle\x01\x0d\x04\x01\x20wasi:http/outgoing-handler@0.2.\
-0\x05X\x02\x03\0:\x11outgoing-response\x01B\x07\x02\x03\x02\x01Y\x04\0\x11outgoi\
-ng-response\x03\0\0\x01i\x01\x01q\x02\x04echo\0\0\x08response\x01\x02\0\x04\0\x10\
-response-handler\x03\0\x03\x01@\x02\x03urls\x08response\x04\x01\0\x04\0\x0cset-r\
-esponse\x01\x05\x04\x01#fermyon:spin-wasi-virt/http-handler\x05Z\x02\x03\0:\x10i\
-ncoming-request\x02\x03\0:\x11incoming-response\x02\x03\0:\x11response-outparam\x02\
-\x03\0:\x0dincoming-body\x01B\x1f\x02\x03\x02\x01[\x04\0\x10incoming-request\x03\
-\0\0\x02\x03\x02\x01\\\x04\0\x11incoming-response\x03\0\x02\x02\x03\x02\x01Y\x04\
-\0\x11outgoing-response\x03\0\x04\x02\x03\x02\x01T\x04\0\x10outgoing-request\x03\
-\0\x06\x02\x03\x02\x01]\x04\0\x11response-outparam\x03\0\x08\x02\x03\x02\x01V\x04\
-\0\x18future-incoming-response\x03\0\x0a\x02\x03\x02\x01^\x04\0\x0dincoming-body\
-\x03\0\x0c\x04\0\x11response-receiver\x03\x01\x01h\x0e\x01i\x03\x01k\x10\x01@\x01\
-\x04self\x0f\0\x11\x04\0\x1d[method]response-receiver.get\x01\x12\x01i\x07\x01i\x0d\
-\x01k\x14\x01i\x01\x01@\x02\x07request\x13\x0dincoming-body\x15\0\x16\x04\0\x0bn\
-ew-request\x01\x17\x01i\x09\x01i\x0e\x01o\x02\x18\x19\x01@\0\0\x1a\x04\0\x0cnew-\
-response\x01\x1b\x04\x01\"fermyon:spin-wasi-virt/http-helper\x05_\x01B\x03\x01p}\
-\x01@\x02\x04paths\x08contents\0\x01\0\x04\0\x08add-file\x01\x01\x04\x01!fermyon\
-:spin-wasi-virt/fs-handler\x05`\x04\x01\x1afermyon:spin-test-virt/env\x04\0\x0b\x09\
+0\x05z\x02\x03\0D\x10incoming-request\x02\x03\0D\x11response-outparam\x01B\x08\x02\
+\x03\x02\x01{\x04\0\x10incoming-request\x03\0\0\x02\x03\x02\x01|\x04\0\x11respon\
+se-outparam\x03\0\x02\x01i\x01\x01i\x03\x01@\x02\x07request\x04\x0cresponse-out\x05\
+\x01\0\x04\0\x06handle\x01\x06\x04\x01\x20wasi:http/incoming-handler@0.2.0\x05}\x02\
+\x03\0D\x11outgoing-response\x01B\x0e\x02\x03\x02\x01~\x04\0\x11outgoing-respons\
+e\x03\0\0\x01i\x01\x01q\x02\x04echo\0\0\x08response\x01\x02\0\x04\0\x10response-\
+handler\x03\0\x03\x01r\x02\x06methods\x03urls\x04\0\x10internal-request\x03\0\x05\
+\x01@\x02\x03urls\x08response\x04\x01\0\x04\0\x0cset-response\x01\x07\x01p\x06\x01\
+@\0\0\x08\x04\0\x11internal-requests\x01\x09\x01@\0\x01\0\x04\0\x17reset-interna\
+l-requests\x01\x0a\x04\x01#fermyon:spin-wasi-virt/http-handler\x05\x7f\x02\x03\0\
+D\x11incoming-response\x02\x03\0D\x0dincoming-body\x01B1\x02\x03\x02\x01{\x04\0\x10\
+incoming-request\x03\0\0\x02\x03\x02\x01\x80\x01\x04\0\x11incoming-response\x03\0\
+\x02\x02\x03\x02\x01~\x04\0\x11outgoing-response\x03\0\x04\x02\x03\x02\x01v\x04\0\
+\x10outgoing-request\x03\0\x06\x02\x03\x02\x01|\x04\0\x11response-outparam\x03\0\
+\x08\x02\x03\x02\x01x\x04\0\x18future-incoming-response\x03\0\x0a\x02\x03\x02\x01\
+\x81\x01\x04\0\x0dincoming-body\x03\0\x0c\x04\0\x11response-receiver\x03\x01\x01\
+h\x0e\x01i\x03\x01k\x10\x01@\x01\x04self\x0f\0\x11\x04\0\x1d[method]response-rec\
+eiver.get\x01\x12\x01i\x07\x01i\x0d\x01k\x14\x01i\x01\x01@\x02\x07request\x13\x0d\
+incoming-body\x15\0\x16\x04\0\x0bnew-request\x01\x17\x01i\x09\x01i\x0e\x01o\x02\x18\
+\x19\x01@\0\0\x1a\x04\0\x0cnew-response\x01\x1b\x01h\x01\x01@\x02\x07request\x1c\
+\x0bclient-addrs\x01\0\x04\0\x0fset-client-addr\x01\x1d\x01@\x02\x07request\x1c\x03\
+tls\x7f\x01\0\x04\0\x07set-tls\x01\x1e\x01@\x02\x07request\x1c\x0blisten-addrs\x01\
+\0\x04\0\x0fset-listen-addr\x01\x1f\x01i\x0b\x01o\x02\x18\x20\x01@\0\0!\x04\0\x13\
+new-response-future\x01\"\x01ks\x01@\x01\x07request\x1c\0#\x04\0\x0bclient-addr\x01\
+$\x01k\x7f\x01@\x01\x07request\x1c\0%\x04\0\x03tls\x01&\x04\0\x0blisten-addr\x01\
+$\x04\x01\"fermyon:spin-wasi-virt/http-helper\x05\x82\x01\x01B\x03\x01p}\x01@\x02\
+\x04paths\x08contents\0\x01\0\x04\0\x08add-file\x01\x01\x04\x01!fermyon:spin-was\
+i-virt/fs-handler\x05\x83\x01\x04\x01\x1afermyon:spin-test-virt/env\x04\0\x0b\x09\
 \x01\0\x03env\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x07\
 0.208.1\x10wit-bindgen-rust\x060.25.0";
 
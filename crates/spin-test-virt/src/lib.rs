@@ -2,18 +2,26 @@ mod helper;
 
 #[allow(warnings)]
 mod bindings;
+mod llm;
 mod manifest;
+mod mqtt;
+mod rdbms;
+mod redis;
+mod stand_in;
 mod wasi;
 
 use std::{
     cell::{LazyCell, RefCell},
-    collections::{HashMap, HashSet},
+    collections::HashMap,
     sync::{Arc, Mutex, OnceLock, RwLock},
 };
 
 use bindings::exports::fermyon::{
-    spin::{self, llm, mqtt, mysql, postgres, redis, sqlite, variables},
-    spin_test_virt::{self, key_value as virt_key_value},
+    spin::{self, sqlite, variables},
+    spin_test_virt::{
+        self, key_value as virt_key_value, sqlite as virt_sqlite, types as virt_types,
+        variables as virt_variables,
+    },
 };
 
 struct Component;
@@ -165,261 +173,64 @@ impl spin::key_value::GuestStore for KeyValueStore {
     }
 }
 
-impl llm::Guest for Component {
-    fn infer(
-        model: llm::InferencingModel,
-        prompt: String,
-        params: Option<llm::InferencingParams>,
-    ) -> Result<llm::InferencingResult, llm::Error> {
-        check_model(&model)?;
-        let _ = (model, prompt, params);
-        Ok(llm::InferencingResult {
-            text: "Hello, world!".to_string(),
-            usage: llm::InferencingUsage {
-                prompt_token_count: 0,
-                generated_token_count: 0,
-            },
-        })
-    }
-
-    fn generate_embeddings(
-        model: llm::EmbeddingModel,
-        text: Vec<String>,
-    ) -> Result<llm::EmbeddingsResult, llm::Error> {
-        check_model(&model)?;
-        let _ = (model, text);
-        Ok(llm::EmbeddingsResult {
-            embeddings: vec![],
-            usage: llm::EmbeddingsUsage {
-                prompt_token_count: 0,
-            },
-        })
-    }
+impl sqlite::Guest for Component {
+    type Connection = SqliteConnection;
 }
 
-fn check_model(model: &str) -> Result<(), llm::Error> {
-    let model_allowed = manifest::AppManifest::get_component()
-        .expect("internal error: component id not yet set")
-        .ai_models
-        .into_iter()
-        .any(|m| m.as_ref() == model);
-
-    if !model_allowed {
-        return Err(llm::Error::ModelNotSupported);
-    }
+type ConnectionPool = HashMap<String, Arc<Mutex<rusqlite::Connection>>>;
+static SQLITE_CONNECTION_POOL: std::sync::OnceLock<Mutex<ConnectionPool>> =
+    std::sync::OnceLock::new();
 
-    Ok(())
-}
+/// The calls made to the sqlite interface keyed by database name.
+static SQLITE_CALLS: std::sync::OnceLock<Mutex<HashMap<String, Vec<virt_sqlite::Call>>>> =
+    std::sync::OnceLock::new();
 
-impl redis::Guest for Component {
-    type Connection = RedisConnection;
-}
+/// The rules overriding the result of statements executed through the sqlite interface.
+static SQLITE_RULES: std::sync::OnceLock<Mutex<Vec<SqliteRule>>> = std::sync::OnceLock::new();
 
-struct RedisConnection {
-    /// The data stored in the Redis store.
-    data: SharedHashMap<String, RedisValue>,
+/// A rule which overrides the result of matching sqlite statements.
+struct SqliteRule {
+    /// The database the rule applies to or `None` if it applies to all databases.
+    database: Option<String>,
+    matcher: Matcher,
+    response: virt_sqlite::RuleResponse,
 }
 
-enum RedisValue {
-    Payload(redis::Payload),
-    Set(HashSet<String>),
+/// Matches text exactly, by prefix, or by a regular expression.
+enum Matcher {
+    Exact(String),
+    Prefix(String),
+    Regex(regex::Regex),
 }
 
-impl RedisConnection {
-    fn new() -> Self {
-        Self {
-            data: Default::default(),
-        }
-    }
-
-    /// Get the redis payload associated with a key.
-    ///
-    /// Returns `Ok(None)` if the key does not exist and `Err(redis::Error::TypeError)` if the key
-    /// exists but is not a payload.
-    fn get_payload(&self, key: String) -> Result<Option<redis::Payload>, redis::Error> {
-        match self.read_data().get(&key) {
-            Some(RedisValue::Payload(p)) => Ok(Some(p.clone())),
-            Some(RedisValue::Set(_)) => Err(redis::Error::TypeError),
-            None => Ok(None),
-        }
-    }
-
-    /// Get the set associated with the key.
-    fn get_set(&self, key: String) -> Result<HashSet<String>, redis::Error> {
-        match self.read_data().get(&key) {
-            Some(RedisValue::Set(s)) => Ok(s.clone()),
-            Some(RedisValue::Payload(_)) => Err(redis::Error::TypeError),
-            None => Ok(Default::default()),
-        }
-    }
-
-    /// Add the values to the set associated with the key.
-    fn add_to_set(&self, key: String, new: Vec<String>) -> Result<usize, redis::Error> {
-        match self.write_data().get_mut(&key) {
-            Some(RedisValue::Set(s)) => {
-                let original_len = s.len();
-                s.extend(new);
-                Ok(s.len() - original_len)
-            }
-            Some(RedisValue::Payload(_)) => Err(redis::Error::TypeError),
-            None => {
-                let set = new.into_iter().collect::<HashSet<_>>();
-                let len = set.len();
-                self.write_data().insert(key.clone(), RedisValue::Set(set));
-                Ok(len)
-            }
-        }
-    }
-
-    /// Remove the values from the set associated with the key.
-    fn remove_from_set(&self, key: String, values: Vec<String>) -> Result<usize, redis::Error> {
-        match self.write_data().get_mut(&key) {
-            Some(RedisValue::Set(s)) => {
-                let original_len = s.len();
-                s.retain(|v| !values.contains(v));
-                Ok(original_len - s.len())
-            }
-            Some(RedisValue::Payload(_)) => Err(redis::Error::TypeError),
-            None => Ok(0),
+impl Matcher {
+    /// Whether the matcher matches the given text.
+    fn matches(&self, text: &str) -> bool {
+        match self {
+            Matcher::Exact(e) => text == e,
+            Matcher::Prefix(p) => text.starts_with(p.as_str()),
+            Matcher::Regex(r) => r.is_match(text),
         }
     }
-
-    /// Set the value associated with a key.
-    fn set(&self, key: String, value: redis::Payload) {
-        self.write_data().insert(key, RedisValue::Payload(value));
-    }
-
-    /// Delete the values associated with the keys.
-    ///
-    /// Returns the number of keys that were deleted.
-    fn del(&self, keys: Vec<String>) -> usize {
-        let mut data = self.write_data();
-        let original_len = data.len();
-        data.retain(|k, _| !keys.contains(k));
-        let new_len = data.len();
-        original_len - new_len
-    }
-
-    fn write_data(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<String, RedisValue>> {
-        self.data.write().unwrap()
-    }
-
-    fn read_data(&self) -> std::sync::RwLockReadGuard<'_, HashMap<String, RedisValue>> {
-        self.data.read().unwrap()
-    }
 }
 
-impl redis::GuestConnection for RedisConnection {
-    fn open(address: String) -> Result<redis::Connection, redis::Error> {
-        let url_allowed = manifest::AppManifest::allows_url(&address, "redis")
-            .map_err(|_| redis::Error::InvalidAddress)?;
-        if !url_allowed {
-            return Err(redis::Error::InvalidAddress);
-        }
-        Ok(redis::Connection::new(RedisConnection::new()))
-    }
-
-    fn publish(&self, channel: String, payload: redis::Payload) -> Result<(), redis::Error> {
-        let _ = (channel, payload);
-        Ok(())
-    }
-
-    fn get(&self, key: String) -> Result<Option<redis::Payload>, redis::Error> {
-        self.get_payload(key)
-    }
-
-    fn set(&self, key: String, value: redis::Payload) -> Result<(), redis::Error> {
-        self.set(key, value);
-        Ok(())
-    }
-
-    fn incr(&self, key: String) -> Result<i64, redis::Error> {
-        let value = self
-            .get_payload(key)?
-            .map(String::from_utf8)
-            .transpose()
-            .map_err(|_| redis::Error::TypeError)?;
-        let result = value
-            .map(|v| v.parse::<i64>())
-            .transpose()
-            .map_err(|_| redis::Error::TypeError)?;
-        Ok(result.unwrap_or(0) + 1)
-    }
-
-    fn del(&self, keys: Vec<String>) -> Result<u32, redis::Error> {
-        Ok(self.del(keys) as u32)
-    }
-
-    fn sadd(&self, key: String, values: Vec<String>) -> Result<u32, redis::Error> {
-        self.add_to_set(key, values).map(|n| n as u32)
-    }
-
-    fn smembers(&self, key: String) -> Result<Vec<String>, redis::Error> {
-        self.get_set(key).map(|s| s.into_iter().collect())
-    }
-
-    fn srem(&self, key: String, values: Vec<String>) -> Result<u32, redis::Error> {
-        self.remove_from_set(key, values).map(|n| n as u32)
-    }
+impl TryFrom<virt_types::Matcher> for Matcher {
+    type Error = String;
 
-    fn execute(
-        &self,
-        command: String,
-        mut arguments: Vec<redis::RedisParameter>,
-    ) -> Result<Vec<redis::RedisResult>, redis::Error> {
-        let mut get_binary = || match arguments.pop().ok_or(redis::Error::TypeError)? {
-            redis::RedisParameter::Int64(_) => Err(redis::Error::TypeError),
-            redis::RedisParameter::Binary(b) => Ok(b),
-        };
-        match command.as_str() {
-            "incr" => {
-                let key = get_binary()?;
-                let key = String::from_utf8(key).map_err(|_| redis::Error::TypeError)?;
-                self.incr(key).map(|i| vec![redis::RedisResult::Int64(i)])
-            }
-            "set" => {
-                let value = get_binary()?;
-                let key = get_binary()?;
-                let key = String::from_utf8(key).map_err(|_| redis::Error::TypeError)?;
-                self.set(key, value);
-                Ok(vec![])
-            }
-            "append" => {
-                let value = get_binary()?;
-                let key = get_binary()?;
-                let key = String::from_utf8(key).map_err(|_| redis::Error::TypeError)?;
-                let mut current = self.get(key.clone())?.unwrap_or_default();
-                current.extend(value);
-                self.set(key, current);
-                Ok(vec![])
+    fn try_from(matcher: virt_types::Matcher) -> Result<Self, Self::Error> {
+        Ok(match matcher {
+            virt_types::Matcher::Exact(e) => Matcher::Exact(e),
+            virt_types::Matcher::Prefix(p) => Matcher::Prefix(p),
+            virt_types::Matcher::Regex(r) => {
+                Matcher::Regex(regex::Regex::new(&r).map_err(|e| e.to_string())?)
             }
-            "get" => {
-                let key = get_binary()?;
-                let key = String::from_utf8(key).map_err(|_| redis::Error::TypeError)?;
-                let value = self.get(key)?;
-                Ok(value
-                    .map(|v| vec![redis::RedisResult::Binary(v)])
-                    .unwrap_or_default())
-            }
-            _ => {
-                // TODO: implement this by getting input from user
-                Err(redis::Error::Other(format!(
-                    "not able to execute '{command}' command"
-                )))
-            }
-        }
+        })
     }
 }
 
-impl sqlite::Guest for Component {
-    type Connection = SqliteConnection;
-}
-
-type ConnectionPool = HashMap<String, Arc<Mutex<rusqlite::Connection>>>;
-static SQLITE_CONNECTION_POOL: std::sync::OnceLock<Mutex<ConnectionPool>> =
-    std::sync::OnceLock::new();
-
 struct SqliteConnection {
+    /// The name of the database the connection is for.
+    database: String,
     inner: Arc<Mutex<rusqlite::Connection>>,
 }
 
@@ -429,16 +240,53 @@ impl SqliteConnection {
             .get_or_init(Default::default)
             .lock()
             .unwrap()
-            .entry(database)
+            .entry(database.clone())
         {
             std::collections::hash_map::Entry::Occupied(c) => c.get().clone(),
-            std::collections::hash_map::Entry::Vacant(_) => {
-                let conn = rusqlite::Connection::open_in_memory()
-                    .map_err(|e| sqlite::Error::Io(e.to_string()))?;
-                Arc::new(Mutex::new(conn))
+            std::collections::hash_map::Entry::Vacant(v) => {
+                v.insert(Arc::new(Mutex::new(open_in_memory()?))).clone()
             }
         };
-        Ok(Self { inner: conn })
+        Ok(Self {
+            database,
+            inner: conn,
+        })
+    }
+
+    /// Get the response of the first rule matching the statement if there is one.
+    fn matching_rule(&self, statement: &str) -> Option<virt_sqlite::RuleResponse> {
+        SQLITE_RULES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.database.as_ref().is_none_or(|db| db == &self.database))
+            .find(|r| r.matcher.matches(statement))
+            .map(|r| r.response.clone())
+    }
+
+    /// Record a statement executed against the database.
+    fn push_call(
+        &self,
+        statement: String,
+        parameters: Vec<sqlite::Value>,
+        result: &Result<sqlite::QueryResult, sqlite::Error>,
+    ) {
+        let result = match result {
+            Ok(r) => Ok(r.rows.len() as u64),
+            Err(e) => Err(e.clone()),
+        };
+        SQLITE_CALLS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .entry(self.database.clone())
+            .or_default()
+            .push(virt_sqlite::Call {
+                statement,
+                parameters,
+                result,
+            });
     }
 
     fn execute(
@@ -492,6 +340,73 @@ impl SqliteConnection {
     }
 }
 
+/// Open a new, empty in-memory database.
+fn open_in_memory() -> Result<rusqlite::Connection, sqlite::Error> {
+    rusqlite::Connection::open_in_memory().map_err(|e| sqlite::Error::Io(e.to_string()))
+}
+
+/// Serialize a database into the contents of its database file.
+fn serialize_database(conn: &rusqlite::Connection) -> Result<Vec<u8>, sqlite::Error> {
+    let mut size: rusqlite::ffi::sqlite3_int64 = 0;
+    // SAFETY: the connection handle is valid while `conn` is borrowed and the buffer returned
+    // by `sqlite3_serialize` is owned by us until it is freed below.
+    unsafe {
+        let data = rusqlite::ffi::sqlite3_serialize(conn.handle(), c"main".as_ptr(), &mut size, 0);
+        if data.is_null() {
+            // A database without any pages serializes to nothing
+            if size == 0 {
+                return Ok(Vec::new());
+            }
+            return Err(sqlite::Error::Io("failed to serialize database".into()));
+        }
+        let bytes = std::slice::from_raw_parts(data, size as usize).to_vec();
+        rusqlite::ffi::sqlite3_free(data.cast());
+        Ok(bytes)
+    }
+}
+
+/// Deserialize the contents of a database file into a new in-memory database.
+fn deserialize_database(bytes: &[u8]) -> Result<rusqlite::Connection, sqlite::Error> {
+    let conn = open_in_memory()?;
+    if bytes.is_empty() {
+        return Ok(conn);
+    }
+    // SAFETY: the buffer is allocated with `sqlite3_malloc64` as required by
+    // `SQLITE_DESERIALIZE_FREEONCLOSE` and ownership of it is handed over to SQLite.
+    unsafe {
+        let data = rusqlite::ffi::sqlite3_malloc64(bytes.len() as u64).cast::<u8>();
+        if data.is_null() {
+            return Err(sqlite::Error::Io("out of memory".into()));
+        }
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), data, bytes.len());
+        let code = rusqlite::ffi::sqlite3_deserialize(
+            conn.handle(),
+            c"main".as_ptr(),
+            data,
+            bytes.len() as i64,
+            bytes.len() as i64,
+            (rusqlite::ffi::SQLITE_DESERIALIZE_FREEONCLOSE
+                | rusqlite::ffi::SQLITE_DESERIALIZE_RESIZEABLE) as u32,
+        );
+        if code != rusqlite::ffi::SQLITE_OK {
+            return Err(sqlite::Error::Io(format!(
+                "failed to deserialize database (error code {code})"
+            )));
+        }
+    }
+    Ok(conn)
+}
+
+/// Replace the contents of `dst` with the contents of `src` using SQLite's backup API.
+fn restore_database(
+    src: &rusqlite::Connection,
+    dst: &mut rusqlite::Connection,
+) -> Result<(), sqlite::Error> {
+    rusqlite::backup::Backup::new(src, dst)
+        .and_then(|b| b.run_to_completion(-1, std::time::Duration::ZERO, None))
+        .map_err(|e| sqlite::Error::Io(e.to_string()))
+}
+
 impl sqlite::GuestConnection for SqliteConnection {
     fn open(database: String) -> Result<sqlite::Connection, sqlite::Error> {
         let component =
@@ -511,12 +426,99 @@ impl sqlite::GuestConnection for SqliteConnection {
         statement: String,
         parameters: Vec<sqlite::Value>,
     ) -> Result<sqlite::QueryResult, sqlite::Error> {
-        self.execute(statement, parameters)
+        let result = match self.matching_rule(&statement) {
+            Some(virt_sqlite::RuleResponse::Error(e)) => Err(e),
+            Some(virt_sqlite::RuleResponse::Result(r)) => Ok(r),
+            None => self.execute(statement.clone(), parameters.clone()),
+        };
+        self.push_call(statement, parameters, &result);
+        result
     }
 }
 
 impl spin_test_virt::sqlite::Guest for Component {
     type Connection = SqliteConnection;
+
+    fn calls() -> Vec<(String, Vec<virt_sqlite::Call>)> {
+        SQLITE_CALLS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(database, calls)| (database.clone(), calls.clone()))
+            .collect()
+    }
+
+    fn reset_calls() {
+        SQLITE_CALLS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .clear();
+    }
+
+    fn add_rule(
+        database: Option<String>,
+        matcher: virt_types::Matcher,
+        response: virt_sqlite::RuleResponse,
+    ) -> Result<(), String> {
+        let matcher = matcher.try_into()?;
+        SQLITE_RULES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .push(SqliteRule {
+                database,
+                matcher,
+                response,
+            });
+        Ok(())
+    }
+
+    fn clear_rules() {
+        SQLITE_RULES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .clear();
+    }
+
+    fn take_snapshot() -> Result<virt_sqlite::Snapshot, virt_sqlite::Error> {
+        SQLITE_CONNECTION_POOL
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(database, conn)| {
+                let bytes = serialize_database(&conn.lock().unwrap())?;
+                Ok((database.clone(), bytes))
+            })
+            .collect()
+    }
+
+    fn restore_snapshot(snapshot: virt_sqlite::Snapshot) -> Result<(), virt_sqlite::Error> {
+        let mut pool = SQLITE_CONNECTION_POOL
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap();
+        for (database, _) in &snapshot {
+            if !pool.contains_key(database) {
+                pool.insert(database.clone(), Arc::new(Mutex::new(open_in_memory()?)));
+            }
+        }
+        for (database, conn) in pool.iter() {
+            let src = match snapshot.iter().find(|(db, _)| db == database) {
+                Some((_, bytes)) => deserialize_database(bytes)?,
+                None => open_in_memory()?,
+            };
+            restore_database(&src, &mut conn.lock().unwrap())?;
+        }
+        Ok(())
+    }
+
+    fn reset() -> Result<(), virt_sqlite::Error> {
+        <Component as virt_sqlite::Guest>::restore_snapshot(Vec::new())
+    }
 }
 
 impl spin_test_virt::sqlite::GuestConnection for SqliteConnection {
@@ -535,6 +537,14 @@ impl spin_test_virt::sqlite::GuestConnection for SqliteConnection {
     ) -> Result<spin_test_virt::sqlite::QueryResult, spin_test_virt::sqlite::Error> {
         self.execute(statement, parameters)
     }
+
+    fn execute_batch(&self, statements: String) -> Result<(), spin_test_virt::sqlite::Error> {
+        self.inner
+            .lock()
+            .unwrap()
+            .execute_batch(&statements)
+            .map_err(|e| sqlite::Error::Io(e.to_string()))
+    }
 }
 
 impl std::hash::Hash for sqlite::Value {
@@ -563,117 +573,58 @@ impl PartialEq for sqlite::Value {
 }
 impl Eq for sqlite::Value {}
 
-impl mysql::Guest for Component {
-    type Connection = MySqlConnection;
-}
-
-struct MySqlConnection;
-
-impl mysql::GuestConnection for MySqlConnection {
-    fn open(address: String) -> Result<mysql::Connection, mysql::Error> {
-        let _ = address;
-        Err(mysql::Error::Other("not yet implemented".into()))
-    }
-
-    fn query(
-        &self,
-        statement: String,
-        params: Vec<mysql::ParameterValue>,
-    ) -> Result<mysql::RowSet, mysql::Error> {
-        let _ = (statement, params);
-        Err(mysql::Error::Other("not yet implemented".into()))
-    }
-
-    fn execute(
-        &self,
-        statement: String,
-        params: Vec<mysql::ParameterValue>,
-    ) -> Result<(), mysql::Error> {
-        let _ = (statement, params);
-        Err(mysql::Error::Other("not yet implemented".into()))
-    }
-}
-
-impl postgres::Guest for Component {
-    type Connection = PostgresConnection;
-}
-
-struct PostgresConnection;
-
-impl postgres::GuestConnection for PostgresConnection {
-    fn open(address: String) -> Result<postgres::Connection, postgres::Error> {
-        let _ = address;
-        Err(postgres::Error::Other("not yet implemented".into()))
-    }
-
-    fn query(
-        &self,
-        statement: String,
-        params: Vec<postgres::ParameterValue>,
-    ) -> Result<postgres::RowSet, postgres::Error> {
-        let _ = (statement, params);
-        Err(postgres::Error::Other("not yet implemented".into()))
-    }
-
-    fn execute(
-        &self,
-        statement: String,
-        params: Vec<postgres::ParameterValue>,
-    ) -> Result<u64, postgres::Error> {
-        let _ = (statement, params);
-        Err(postgres::Error::Other("not yet implemented".into()))
-    }
-}
-
-impl mqtt::Guest for Component {
-    type Connection = MqttConnection;
-}
-
-struct MqttConnection;
-
-impl mqtt::GuestConnection for MqttConnection {
-    fn open(
-        address: String,
-        username: String,
-        password: String,
-        keep_alive_interval_in_secs: u64,
-    ) -> Result<mqtt::Connection, mqtt::Error> {
-        let url_allowed = manifest::AppManifest::allows_url(&address, "mqtt")
-            .map_err(|_| mqtt::Error::InvalidAddress)?;
-        if !url_allowed {
-            return Err(mqtt::Error::ConnectionFailed(format!(
-                "address {address} is not permitted"
-            )));
-        }
-        let _ = (username, password, keep_alive_interval_in_secs);
-        Ok(mqtt::Connection::new(MqttConnection))
-    }
-
-    fn publish(
-        &self,
-        topic: String,
-        payload: mqtt::Payload,
-        qos: mqtt::Qos,
-    ) -> Result<(), mqtt::Error> {
-        let _ = (topic, payload, qos);
-        Ok(())
-    }
-}
-
 impl variables::Guest for Component {
     fn get(name: String) -> Result<String, variables::Error> {
-        let key = spin_expressions::Key::new(&name)
-            .map_err(|_| variables::Error::InvalidName(name.clone()))?;
-        let component_id = manifest::AppManifest::get_component_id().expect("no component id set");
-        VARIABLE_RESOLVER.with(|resolver| {
-            let name = key.as_str().to_owned();
-            let fut = resolver
-                .as_ref()
-                .map_err(|e| variables::Error::Other(e.to_string()))?
-                .resolve(component_id.as_ref(), key);
-            futures::executor::block_on(fut).map_err(|_| variables::Error::Undefined(name))
+        let result = resolve_variable(&name);
+        let recorded = match &result {
+            Ok(_) if is_secret_variable(&name) => Ok("<redacted>".to_owned()),
+            r => r.clone(),
+        };
+        VARIABLE_CALLS.with(|calls| {
+            calls.borrow_mut().push(virt_variables::Call {
+                name,
+                result: recorded,
+            })
+        });
+        result
+    }
+}
+
+/// Resolve the value of a component variable.
+fn resolve_variable(name: &str) -> Result<String, variables::Error> {
+    let key = spin_expressions::Key::new(name)
+        .map_err(|_| variables::Error::InvalidName(name.to_owned()))?;
+    let component_id = manifest::AppManifest::get_component_id().expect("no component id set");
+    VARIABLE_RESOLVER.with(|resolver| {
+        let name = key.as_str().to_owned();
+        let fut = resolver
+            .as_ref()
+            .map_err(|e| variables::Error::Other(e.to_string()))?
+            .resolve(component_id.as_ref(), key);
+        futures::executor::block_on(fut).map_err(|_| variables::Error::Undefined(name))
+    })
+}
+
+/// Whether the value of a component variable depends on a secret application variable.
+fn is_secret_variable(name: &str) -> bool {
+    let manifest = manifest::AppManifest::get();
+    let Some(template) = manifest::AppManifest::get_component()
+        .expect("no component set")
+        .variables
+        .into_iter()
+        .find(|(k, _)| k.as_ref() == name)
+        .map(|(_, v)| v)
+    else {
+        return false;
+    };
+    manifest
+        .variables
+        .iter()
+        .filter(|(_, v)| v.secret)
+        .any(|(secret, _)| {
+            let pattern = format!(r"\{{\{{\s*{}\s*\}}\}}", regex::escape(secret.as_ref()));
+            regex::Regex::new(&pattern).is_ok_and(|r| r.is_match(&template))
         })
-    }
 }
 
 thread_local! {
@@ -701,6 +652,7 @@ thread_local! {
                     .map(|(k, v)| (k.to_string(), v)),
             )?;
         resolver.add_provider(Box::new(UserGivenProvider));
+        resolver.add_provider(Box::new(HostGivenProvider));
         Ok(resolver)
 
     });
@@ -711,6 +663,14 @@ thread_local! {
     static USER_DEFINED_VARIABLES: LazyCell<RefCell<HashMap<String, String>>> = LazyCell::new(|| {
         RefCell::new(HashMap::new())
     });
+
+    /// The variables provided by the host.
+    ///
+    /// These are the application variables `spin-test` gathered from the same sources as `spin up`.
+    static HOST_GIVEN_VARIABLES: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+
+    /// The lookups of variables made through the `fermyon:spin/variables` interface.
+    static VARIABLE_CALLS: RefCell<Vec<virt_variables::Call>> = const { RefCell::new(Vec::new()) };
 }
 
 /// A variable provider populated through the `fermyon:spin-test-virt/variable` interface.
@@ -724,12 +684,50 @@ impl spin_expressions::Provider for UserGivenProvider {
     }
 }
 
-impl spin_test_virt::variables::Guest for Component {
+/// A variable provider populated by the host through `provide-variable`.
+///
+/// Values given by the test through `UserGivenProvider` take precedence.
+#[derive(Debug)]
+struct HostGivenProvider;
+
+#[async_trait::async_trait]
+impl spin_expressions::Provider for HostGivenProvider {
+    async fn get(&self, key: &spin_expressions::Key) -> anyhow::Result<Option<String>> {
+        Ok(HOST_GIVEN_VARIABLES.with(|vars| vars.borrow().get(key.as_str()).cloned()))
+    }
+}
+
+/// Provide the value of an application variable from the host.
+fn provide_variable(key: String, value: String) {
+    HOST_GIVEN_VARIABLES.with(|vars| {
+        vars.borrow_mut().insert(key, value);
+    });
+}
+
+impl virt_variables::Guest for Component {
     fn set(key: String, value: String) {
         USER_DEFINED_VARIABLES.with(|vars| {
             vars.borrow_mut().insert(key, value);
         });
     }
+
+    fn unset(key: String) {
+        USER_DEFINED_VARIABLES.with(|vars| {
+            vars.borrow_mut().remove(&key);
+        });
+    }
+
+    fn clear() {
+        USER_DEFINED_VARIABLES.with(|vars| vars.borrow_mut().clear());
+    }
+
+    fn calls() -> Vec<virt_variables::Call> {
+        VARIABLE_CALLS.with(|calls| calls.borrow().clone())
+    }
+
+    fn reset_calls() {
+        VARIABLE_CALLS.with(|calls| calls.borrow_mut().clear());
+    }
 }
 
 impl virt_key_value::Guest for Component {
@@ -0,0 +1,168 @@
+use std::sync::{Mutex, OnceLock};
+
+use crate::bindings::exports::fermyon::{
+    spin::llm,
+    spin_test_virt::{llm as virt_llm, types as virt_types},
+};
+use crate::{manifest, Component, Matcher};
+
+/// The calls made to the llm interface.
+static CALLS: OnceLock<Mutex<Vec<virt_llm::Call>>> = OnceLock::new();
+
+/// The rules determining the result of inferencing requests.
+static INFER_RULES: OnceLock<Mutex<Vec<InferRule>>> = OnceLock::new();
+
+/// The rules determining the result of embeddings requests.
+static EMBEDDINGS_RULES: OnceLock<Mutex<Vec<EmbeddingsRule>>> = OnceLock::new();
+
+/// A rule which determines the result of matching inferencing requests.
+struct InferRule {
+    /// The model the rule applies to or `None` if it applies to all models.
+    model: Option<String>,
+    /// The matcher for the prompt or `None` if any prompt matches.
+    prompt: Option<Matcher>,
+    response: virt_llm::InferResponse,
+}
+
+/// A rule which determines the result of matching embeddings requests.
+struct EmbeddingsRule {
+    /// The model the rule applies to or `None` if it applies to all models.
+    model: Option<String>,
+    response: virt_llm::EmbeddingsResponse,
+}
+
+impl llm::Guest for Component {
+    fn infer(
+        model: llm::InferencingModel,
+        prompt: String,
+        params: Option<llm::InferencingParams>,
+    ) -> Result<llm::InferencingResult, llm::Error> {
+        push_call(virt_llm::Call::Infer(virt_llm::InferCall {
+            model: model.clone(),
+            prompt: prompt.clone(),
+            params,
+        }));
+        check_model(&model)?;
+        let response = INFER_RULES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.model.as_ref().is_none_or(|m| m == &model))
+            .find(|r| r.prompt.as_ref().is_none_or(|p| p.matches(&prompt)))
+            .map(|r| r.response.clone());
+        match response {
+            Some(virt_llm::InferResponse::Error(e)) => Err(e),
+            Some(virt_llm::InferResponse::Result(r)) => Ok(r),
+            None => Ok(llm::InferencingResult {
+                text: "Hello, world!".to_string(),
+                usage: llm::InferencingUsage {
+                    prompt_token_count: 0,
+                    generated_token_count: 0,
+                },
+            }),
+        }
+    }
+
+    fn generate_embeddings(
+        model: llm::EmbeddingModel,
+        text: Vec<String>,
+    ) -> Result<llm::EmbeddingsResult, llm::Error> {
+        push_call(virt_llm::Call::GenerateEmbeddings(
+            virt_llm::EmbeddingsCall {
+                model: model.clone(),
+                text,
+            },
+        ));
+        check_model(&model)?;
+        let response = EMBEDDINGS_RULES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|r| r.model.as_ref().is_none_or(|m| m == &model))
+            .map(|r| r.response.clone());
+        match response {
+            Some(virt_llm::EmbeddingsResponse::Error(e)) => Err(e),
+            Some(virt_llm::EmbeddingsResponse::Result(r)) => Ok(r),
+            None => Ok(llm::EmbeddingsResult {
+                embeddings: vec![],
+                usage: llm::EmbeddingsUsage {
+                    prompt_token_count: 0,
+                },
+            }),
+        }
+    }
+}
+
+fn check_model(model: &str) -> Result<(), llm::Error> {
+    let model_allowed = manifest::AppManifest::get_component()
+        .expect("internal error: component id not yet set")
+        .ai_models
+        .into_iter()
+        .any(|m| m.as_ref() == model);
+
+    if !model_allowed {
+        return Err(llm::Error::ModelNotSupported);
+    }
+
+    Ok(())
+}
+
+/// Record a call made to the llm interface.
+fn push_call(call: virt_llm::Call) {
+    CALLS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap()
+        .push(call);
+}
+
+impl virt_llm::Guest for Component {
+    fn add_infer_rule(
+        model: Option<String>,
+        prompt: Option<virt_types::Matcher>,
+        response: virt_llm::InferResponse,
+    ) -> Result<(), String> {
+        let prompt = prompt.map(Matcher::try_from).transpose()?;
+        INFER_RULES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .push(InferRule {
+                model,
+                prompt,
+                response,
+            });
+        Ok(())
+    }
+
+    fn add_embeddings_rule(model: Option<String>, response: virt_llm::EmbeddingsResponse) {
+        EMBEDDINGS_RULES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .push(EmbeddingsRule { model, response });
+    }
+
+    fn clear_rules() {
+        INFER_RULES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .clear();
+        EMBEDDINGS_RULES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .clear();
+    }
+
+    fn calls() -> Vec<virt_llm::Call> {
+        CALLS.get_or_init(Default::default).lock().unwrap().clone()
+    }
+
+    fn reset_calls() {
+        CALLS.get_or_init(Default::default).lock().unwrap().clear();
+    }
+}
@@ -85,4 +85,28 @@ impl crate::bindings::Guest for crate::Component {
     fn set_component_id(component_id: String) {
         *COMPONENT_ID.write().unwrap() = Some(component_id);
     }
+
+    fn provide_variable(key: String, value: String) {
+        crate::provide_variable(key, value)
+    }
+
+    fn prepare_wagi(arguments: Vec<String>, environment: Vec<(String, String)>, stdin: Vec<u8>) {
+        crate::wasi::wagi::prepare(arguments, environment, stdin)
+    }
+
+    fn finish_wagi() -> Vec<u8> {
+        crate::wasi::wagi::finish()
+    }
+
+    fn take_stand_in_error() -> Option<String> {
+        crate::stand_in::take_error()
+    }
+
+    fn allows_outbound_url(url: String) -> bool {
+        crate::wasi::http::allows_internal_url(&url)
+    }
+
+    fn record_internal_request(method: String, url: String) {
+        crate::wasi::http::record_internal_request(method, url)
+    }
 }
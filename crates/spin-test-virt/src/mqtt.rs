@@ -0,0 +1,148 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+use crate::bindings::exports::fermyon::{spin::mqtt, spin_test_virt::mqtt as virt_mqtt};
+use crate::{manifest, Component};
+
+/// The messages published through the mqtt interface keyed by broker address.
+static PUBLISHES: OnceLock<Mutex<HashMap<String, Vec<virt_mqtt::Publish>>>> = OnceLock::new();
+
+/// The configuration of the virtual brokers keyed by address.
+static BROKERS: OnceLock<Mutex<HashMap<String, Broker>>> = OnceLock::new();
+
+/// How a virtual broker behaves when the app connects to it.
+#[derive(Default)]
+struct Broker {
+    /// The accepted username and password pairs or empty if all credentials are accepted.
+    credentials: Vec<(String, String)>,
+    /// The error opening a connection fails with.
+    open_error: Option<mqtt::Error>,
+    /// The error publishing a message fails with.
+    publish_error: Option<mqtt::Error>,
+}
+
+impl Broker {
+    /// Run `f` with the configuration of the broker at `address` creating it if needed.
+    fn with<T>(address: &str, f: impl FnOnce(&mut Broker) -> T) -> T {
+        let mut brokers = BROKERS.get_or_init(Default::default).lock().unwrap();
+        f(brokers.entry(address.to_owned()).or_default())
+    }
+}
+
+impl mqtt::Guest for Component {
+    type Connection = MqttConnection;
+}
+
+/// A connection made by the app to an MQTT broker.
+pub struct MqttConnection {
+    address: String,
+    username: String,
+    keep_alive_interval_in_secs: u64,
+}
+
+impl mqtt::GuestConnection for MqttConnection {
+    fn open(
+        address: String,
+        username: String,
+        password: String,
+        keep_alive_interval_in_secs: u64,
+    ) -> Result<mqtt::Connection, mqtt::Error> {
+        let url_allowed = manifest::AppManifest::allows_url(&address, "mqtt")
+            .map_err(|_| mqtt::Error::InvalidAddress)?;
+        if !url_allowed {
+            return Err(mqtt::Error::ConnectionFailed(format!(
+                "address {address} is not permitted"
+            )));
+        }
+        Broker::with(&address, |broker| {
+            if let Some(error) = &broker.open_error {
+                return Err(error.clone());
+            }
+            let accepted = broker.credentials.is_empty()
+                || broker
+                    .credentials
+                    .iter()
+                    .any(|(u, p)| u == &username && p == &password);
+            if !accepted {
+                return Err(mqtt::Error::ConnectionFailed(format!(
+                    "broker at {address} rejected the credentials for user '{username}'"
+                )));
+            }
+            Ok(())
+        })?;
+        Ok(mqtt::Connection::new(MqttConnection {
+            address,
+            username,
+            keep_alive_interval_in_secs,
+        }))
+    }
+
+    fn publish(
+        &self,
+        topic: String,
+        payload: mqtt::Payload,
+        qos: mqtt::Qos,
+    ) -> Result<(), mqtt::Error> {
+        if let Some(error) = Broker::with(&self.address, |broker| broker.publish_error.clone()) {
+            return Err(error);
+        }
+        PUBLISHES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .entry(self.address.clone())
+            .or_default()
+            .push(virt_mqtt::Publish {
+                topic,
+                payload,
+                qos,
+                username: self.username.clone(),
+                keep_alive_interval_in_secs: self.keep_alive_interval_in_secs,
+            });
+        Ok(())
+    }
+}
+
+impl virt_mqtt::Guest for Component {
+    fn publishes() -> Vec<(String, Vec<virt_mqtt::Publish>)> {
+        PUBLISHES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(address, publishes)| (address.clone(), publishes.clone()))
+            .collect()
+    }
+
+    fn reset_publishes() {
+        PUBLISHES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .clear();
+    }
+
+    fn add_credentials(address: String, username: String, password: String) {
+        Broker::with(&address, |broker| {
+            broker.credentials.push((username, password))
+        })
+    }
+
+    fn set_open_error(address: String, error: Option<mqtt::Error>) {
+        Broker::with(&address, |broker| broker.open_error = error)
+    }
+
+    fn set_publish_error(address: String, error: Option<mqtt::Error>) {
+        Broker::with(&address, |broker| broker.publish_error = error)
+    }
+
+    fn reset_brokers() {
+        BROKERS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .clear();
+    }
+}
@@ -0,0 +1,437 @@
+//! Virtualized relational databases backed by an embedded sqlite engine
+
+mod mysql;
+mod postgres;
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock},
+};
+
+use crate::bindings::exports::fermyon::spin::rdbms_types;
+use crate::bindings::exports::fermyon::spin_test_virt::{rdbms as virt_rdbms, types as virt_types};
+use crate::{Component, Matcher};
+
+/// The statements run by the app keyed by address.
+static CALLS: OnceLock<Mutex<HashMap<String, Vec<virt_rdbms::Call>>>> = OnceLock::new();
+
+/// The rules overriding the result of statements run by the app.
+static RULES: OnceLock<Mutex<Vec<RdbmsRule>>> = OnceLock::new();
+
+/// A rule which overrides the result of matching statements.
+struct RdbmsRule {
+    /// The address the rule applies to or `None` if it applies to all databases.
+    address: Option<String>,
+    matcher: Matcher,
+    /// The parameters the statement must be run with or `None` if any parameters match.
+    parameters: Option<Vec<rdbms_types::ParameterValue>>,
+    response: virt_rdbms::RuleResponse,
+}
+
+impl RdbmsRule {
+    /// Whether the rule matches the statement run against the database at `address`.
+    fn matches(
+        &self,
+        address: &str,
+        statement: &str,
+        parameters: &[rdbms_types::ParameterValue],
+    ) -> bool {
+        self.address.as_ref().is_none_or(|a| a == address)
+            && self.matcher.matches(statement)
+            && self
+                .parameters
+                .as_ref()
+                .is_none_or(|p| p.as_slice() == parameters)
+    }
+}
+
+/// A relational database reachable at a specific address.
+///
+/// Statements are run against an in-memory sqlite database.
+#[derive(Clone)]
+struct RdbmsDatabase {
+    address: String,
+    inner: Arc<Mutex<rusqlite::Connection>>,
+}
+
+impl RdbmsDatabase {
+    /// Get the database at the given address creating it if it does not exist yet.
+    fn open(address: &str) -> Result<Self, rdbms_types::Error> {
+        static DATABASES: OnceLock<Mutex<HashMap<String, RdbmsDatabase>>> = OnceLock::new();
+        let mut databases = DATABASES.get_or_init(Default::default).lock().unwrap();
+        if let Some(database) = databases.get(address) {
+            return Ok(database.clone());
+        }
+        let conn = rusqlite::Connection::open_in_memory()
+            .map_err(|e| rdbms_types::Error::ConnectionFailed(e.to_string()))?;
+        let database = Self {
+            address: address.to_owned(),
+            inner: Arc::new(Mutex::new(conn)),
+        };
+        databases.insert(address.to_owned(), database.clone());
+        Ok(database)
+    }
+
+    /// Run a query made by the app.
+    ///
+    /// The first matching rule takes precedence over running `engine_statement` (the statement
+    /// translated to sqlite's dialect) and the statement is recorded.
+    fn app_query(
+        &self,
+        statement: String,
+        engine_statement: &str,
+        params: Vec<rdbms_types::ParameterValue>,
+    ) -> Result<rdbms_types::RowSet, rdbms_types::Error> {
+        let result = match self.matching_rule(&statement, &params) {
+            Some(virt_rdbms::RuleResponse::Error(e)) => Err(e),
+            Some(virt_rdbms::RuleResponse::Rows(r)) => Ok(r),
+            Some(virt_rdbms::RuleResponse::Changed(_)) => Ok(rdbms_types::RowSet {
+                columns: vec![],
+                rows: vec![],
+            }),
+            None => self.query(engine_statement, params.clone()),
+        };
+        let recorded = match &result {
+            Ok(r) => Ok(r.rows.len() as u64),
+            Err(e) => Err(e.clone()),
+        };
+        self.push_call(statement, params, recorded);
+        result
+    }
+
+    /// Execute a statement made by the app.
+    ///
+    /// The first matching rule takes precedence over running `engine_statement` (the statement
+    /// translated to sqlite's dialect) and the statement is recorded.
+    fn app_execute(
+        &self,
+        statement: String,
+        engine_statement: &str,
+        params: Vec<rdbms_types::ParameterValue>,
+    ) -> Result<u64, rdbms_types::Error> {
+        let result = match self.matching_rule(&statement, &params) {
+            Some(virt_rdbms::RuleResponse::Error(e)) => Err(e),
+            Some(virt_rdbms::RuleResponse::Rows(r)) => Ok(r.rows.len() as u64),
+            Some(virt_rdbms::RuleResponse::Changed(n)) => Ok(n),
+            None => self.execute(engine_statement, params.clone()),
+        };
+        self.push_call(statement, params, result.clone());
+        result
+    }
+
+    /// Get the response of the first rule matching the statement if there is one.
+    fn matching_rule(
+        &self,
+        statement: &str,
+        parameters: &[rdbms_types::ParameterValue],
+    ) -> Option<virt_rdbms::RuleResponse> {
+        RULES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|r| r.matches(&self.address, statement, parameters))
+            .map(|r| r.response.clone())
+    }
+
+    /// Record a statement run by the app.
+    fn push_call(
+        &self,
+        statement: String,
+        parameters: Vec<rdbms_types::ParameterValue>,
+        result: Result<u64, rdbms_types::Error>,
+    ) {
+        CALLS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .entry(self.address.clone())
+            .or_default()
+            .push(virt_rdbms::Call {
+                statement,
+                parameters,
+                result,
+            });
+    }
+
+    /// Run a query returning the rows it produced.
+    fn query(
+        &self,
+        statement: &str,
+        params: Vec<rdbms_types::ParameterValue>,
+    ) -> Result<rdbms_types::RowSet, rdbms_types::Error> {
+        let conn = self.inner.lock().unwrap();
+        let mut prepared = conn
+            .prepare(statement)
+            .map_err(|e| rdbms_types::Error::QueryFailed(e.to_string()))?;
+        let mut columns = prepared
+            .columns()
+            .into_iter()
+            .map(|c| rdbms_types::Column {
+                name: c.name().to_owned(),
+                data_type: c
+                    .decl_type()
+                    .map_or(rdbms_types::DbDataType::Other, data_type),
+            })
+            .collect::<Vec<_>>();
+        let params = params
+            .into_iter()
+            .map(to_sqlite_value)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut rows = prepared
+            .query(rusqlite::params_from_iter(params))
+            .map_err(|e| rdbms_types::Error::QueryFailed(e.to_string()))?;
+        let mut values = Vec::new();
+        while let Some(row) = rows
+            .next()
+            .map_err(|e| rdbms_types::Error::QueryFailed(e.to_string()))?
+        {
+            let row = (0..columns.len())
+                .map(|i| row.get::<_, rusqlite::types::Value>(i))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| rdbms_types::Error::QueryFailed(e.to_string()))?;
+            values.push(row);
+        }
+
+        // Columns without a declared type (e.g., expressions) take the type of their values
+        for (i, column) in columns.iter_mut().enumerate() {
+            if matches!(column.data_type, rdbms_types::DbDataType::Other) {
+                if let Some(value) = values.iter().map(|row| &row[i]).find(|v| !is_null(v)) {
+                    column.data_type = inferred_data_type(value);
+                }
+            }
+        }
+
+        let rows = values
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .zip(&columns)
+                    .map(|(value, column)| to_db_value(value, column.data_type))
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(rdbms_types::RowSet { columns, rows })
+    }
+
+    /// Execute a statement returning the number of rows it changed.
+    fn execute(
+        &self,
+        statement: &str,
+        params: Vec<rdbms_types::ParameterValue>,
+    ) -> Result<u64, rdbms_types::Error> {
+        let params = params
+            .into_iter()
+            .map(to_sqlite_value)
+            .collect::<Result<Vec<_>, _>>()?;
+        let conn = self.inner.lock().unwrap();
+        let mut prepared = conn
+            .prepare(statement)
+            .map_err(|e| rdbms_types::Error::QueryFailed(e.to_string()))?;
+        // Statements returning rows (e.g., `INSERT ... RETURNING`) cannot be run with `execute`
+        let changed = if prepared.column_count() > 0 {
+            let mut rows = prepared
+                .query(rusqlite::params_from_iter(params))
+                .map_err(|e| rdbms_types::Error::QueryFailed(e.to_string()))?;
+            while rows
+                .next()
+                .map_err(|e| rdbms_types::Error::QueryFailed(e.to_string()))?
+                .is_some()
+            {}
+            conn.changes()
+        } else {
+            prepared
+                .execute(rusqlite::params_from_iter(params))
+                .map_err(|e| rdbms_types::Error::QueryFailed(e.to_string()))?
+        };
+        Ok(changed as u64)
+    }
+
+    /// Execute a batch of semicolon separated statements.
+    fn execute_batch(&self, statements: &str) -> Result<(), rdbms_types::Error> {
+        self.inner
+            .lock()
+            .unwrap()
+            .execute_batch(statements)
+            .map_err(|e| rdbms_types::Error::QueryFailed(e.to_string()))
+    }
+}
+
+impl virt_rdbms::Guest for Component {
+    fn add_rule(
+        address: Option<String>,
+        matcher: virt_types::Matcher,
+        parameters: Option<Vec<rdbms_types::ParameterValue>>,
+        response: virt_rdbms::RuleResponse,
+    ) -> Result<(), String> {
+        let matcher = Matcher::try_from(matcher)?;
+        RULES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .push(RdbmsRule {
+                address,
+                matcher,
+                parameters,
+                response,
+            });
+        Ok(())
+    }
+
+    fn clear_rules() {
+        RULES.get_or_init(Default::default).lock().unwrap().clear();
+    }
+
+    fn calls() -> Vec<(String, Vec<virt_rdbms::Call>)> {
+        CALLS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(address, calls)| (address.clone(), calls.clone()))
+            .collect()
+    }
+
+    fn reset_calls() {
+        CALLS.get_or_init(Default::default).lock().unwrap().clear();
+    }
+}
+
+impl PartialEq for rdbms_types::ParameterValue {
+    fn eq(&self, other: &Self) -> bool {
+        use rdbms_types::ParameterValue::*;
+        match (self, other) {
+            (Boolean(a), Boolean(b)) => a == b,
+            (Int8(a), Int8(b)) => a == b,
+            (Int16(a), Int16(b)) => a == b,
+            (Int32(a), Int32(b)) => a == b,
+            (Int64(a), Int64(b)) => a == b,
+            (Uint8(a), Uint8(b)) => a == b,
+            (Uint16(a), Uint16(b)) => a == b,
+            (Uint32(a), Uint32(b)) => a == b,
+            (Uint64(a), Uint64(b)) => a == b,
+            (Floating32(a), Floating32(b)) => a == b,
+            (Floating64(a), Floating64(b)) => a == b,
+            (Str(a), Str(b)) => a == b,
+            (Binary(a), Binary(b)) => a == b,
+            (DbNull, DbNull) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Convert a parameter into a sqlite value.
+fn to_sqlite_value(
+    param: rdbms_types::ParameterValue,
+) -> Result<rusqlite::types::Value, rdbms_types::Error> {
+    use rdbms_types::ParameterValue as P;
+    use rusqlite::types::Value as V;
+    Ok(match param {
+        P::Boolean(b) => V::Integer(b as i64),
+        P::Int8(i) => V::Integer(i.into()),
+        P::Int16(i) => V::Integer(i.into()),
+        P::Int32(i) => V::Integer(i.into()),
+        P::Int64(i) => V::Integer(i),
+        P::Uint8(u) => V::Integer(u.into()),
+        P::Uint16(u) => V::Integer(u.into()),
+        P::Uint32(u) => V::Integer(u.into()),
+        P::Uint64(u) => V::Integer(i64::try_from(u).map_err(|_| {
+            rdbms_types::Error::BadParameter(format!("{u} is too large to be stored"))
+        })?),
+        P::Floating32(f) => V::Real(f.into()),
+        P::Floating64(f) => V::Real(f),
+        P::Str(s) => V::Text(s),
+        P::Binary(b) => V::Blob(b),
+        P::DbNull => V::Null,
+    })
+}
+
+/// Convert a sqlite value read from a column of the given type into a database value.
+///
+/// Since sqlite does not enforce column types, values which do not fit the column type are
+/// converted based on their sqlite storage class instead.
+fn to_db_value(
+    value: rusqlite::types::Value,
+    data_type: rdbms_types::DbDataType,
+) -> Result<rdbms_types::DbValue, rdbms_types::Error> {
+    use rdbms_types::{DbDataType as T, DbValue as D};
+    use rusqlite::types::Value as V;
+    fn narrow<I: TryFrom<i64>>(i: i64, data_type: T) -> Result<I, rdbms_types::Error> {
+        I::try_from(i).map_err(|_| {
+            rdbms_types::Error::ValueConversionFailed(format!(
+                "{i} does not fit into a column of type {data_type:?}"
+            ))
+        })
+    }
+    Ok(match (value, data_type) {
+        (V::Null, _) => D::DbNull,
+        (V::Integer(i), T::Boolean) => D::Boolean(i != 0),
+        (V::Integer(i), T::Int8) => D::Int8(narrow(i, data_type)?),
+        (V::Integer(i), T::Int16) => D::Int16(narrow(i, data_type)?),
+        (V::Integer(i), T::Int32) => D::Int32(narrow(i, data_type)?),
+        (V::Integer(i), T::Uint8) => D::Uint8(narrow(i, data_type)?),
+        (V::Integer(i), T::Uint16) => D::Uint16(narrow(i, data_type)?),
+        (V::Integer(i), T::Uint32) => D::Uint32(narrow(i, data_type)?),
+        (V::Integer(i), T::Uint64) => D::Uint64(narrow(i, data_type)?),
+        (V::Integer(i), T::Floating32) => D::Floating32(i as f32),
+        (V::Integer(i), T::Floating64) => D::Floating64(i as f64),
+        (V::Integer(i), _) => D::Int64(i),
+        (V::Real(f), T::Floating32) => D::Floating32(f as f32),
+        (V::Real(f), _) => D::Floating64(f),
+        (V::Text(s), T::Binary) => D::Binary(s.into_bytes()),
+        (V::Text(s), _) => D::Str(s),
+        (V::Blob(b), T::Str) => D::Str(
+            String::from_utf8(b)
+                .map_err(|e| rdbms_types::Error::ValueConversionFailed(e.to_string()))?,
+        ),
+        (V::Blob(b), _) => D::Binary(b),
+    })
+}
+
+/// Map the declared type of a column to a database data type.
+fn data_type(decl_type: &str) -> rdbms_types::DbDataType {
+    use rdbms_types::DbDataType as T;
+    let decl_type = decl_type.to_ascii_lowercase();
+    // Strip any size or precision such as `varchar(255)`
+    let base = decl_type
+        .split('(')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .trim_end_matches(" unsigned");
+    let unsigned = decl_type.contains("unsigned");
+    match base {
+        "bool" | "boolean" => T::Boolean,
+        "tinyint" if unsigned => T::Uint8,
+        "tinyint" => T::Int8,
+        "smallint" | "int2" | "smallserial" if unsigned => T::Uint16,
+        "smallint" | "int2" | "smallserial" => T::Int16,
+        "int" | "integer" | "int4" | "mediumint" | "serial" if unsigned => T::Uint32,
+        "int" | "integer" | "int4" | "mediumint" | "serial" => T::Int32,
+        "bigint" | "int8" | "bigserial" if unsigned => T::Uint64,
+        "bigint" | "int8" | "bigserial" => T::Int64,
+        "real" | "float4" | "float" => T::Floating32,
+        "double" | "double precision" | "float8" | "numeric" | "decimal" => T::Floating64,
+        "text" | "varchar" | "character varying" | "char" | "character" | "uuid" | "json"
+        | "jsonb" | "date" | "time" | "timestamp" | "timestamptz" | "datetime" => T::Str,
+        "bytea" | "blob" | "binary" | "varbinary" => T::Binary,
+        _ => T::Other,
+    }
+}
+
+/// The data type of a column without a declared type based on one of its values.
+fn inferred_data_type(value: &rusqlite::types::Value) -> rdbms_types::DbDataType {
+    use rdbms_types::DbDataType as T;
+    use rusqlite::types::Value as V;
+    match value {
+        V::Null => T::Other,
+        V::Integer(_) => T::Int64,
+        V::Real(_) => T::Floating64,
+        V::Text(_) => T::Str,
+        V::Blob(_) => T::Binary,
+    }
+}
+
+fn is_null(value: &rusqlite::types::Value) -> bool {
+    matches!(value, rusqlite::types::Value::Null)
+}
@@ -0,0 +1,90 @@
+use super::RdbmsDatabase;
+use crate::bindings::exports::fermyon::{spin::mysql, spin_test_virt::mysql as virt_mysql};
+use crate::{manifest, Component};
+
+impl mysql::Guest for Component {
+    type Connection = MySqlConnection;
+}
+
+/// A connection made by the app to a MySQL database.
+pub struct MySqlConnection {
+    database: RdbmsDatabase,
+}
+
+impl mysql::GuestConnection for MySqlConnection {
+    fn open(address: String) -> Result<mysql::Connection, mysql::Error> {
+        let url_allowed = manifest::AppManifest::allows_url(&address, "mysql")
+            .map_err(|e| mysql::Error::ConnectionFailed(e.to_string()))?;
+        if !url_allowed {
+            return Err(mysql::Error::ConnectionFailed(format!(
+                "address {address} is not permitted"
+            )));
+        }
+        Ok(mysql::Connection::new(MySqlConnection {
+            database: RdbmsDatabase::open(&address)?,
+        }))
+    }
+
+    fn query(
+        &self,
+        statement: String,
+        params: Vec<mysql::ParameterValue>,
+    ) -> Result<mysql::RowSet, mysql::Error> {
+        self.database
+            .app_query(statement.clone(), &statement, params)
+    }
+
+    fn execute(
+        &self,
+        statement: String,
+        params: Vec<mysql::ParameterValue>,
+    ) -> Result<(), mysql::Error> {
+        self.database
+            .app_execute(statement.clone(), &statement, params)
+            .map(|_| ())
+    }
+}
+
+impl virt_mysql::Guest for Component {
+    type Connection = VirtMySqlConnection;
+}
+
+/// A connection made by the test to a MySQL database.
+///
+/// Unlike `MySqlConnection`, statements run through this connection are not recorded and
+/// rules added through `fermyon:spin-test-virt/rdbms` do not apply.
+pub struct VirtMySqlConnection {
+    database: RdbmsDatabase,
+}
+
+impl virt_mysql::GuestConnection for VirtMySqlConnection {
+    fn open(address: String) -> Result<virt_mysql::Connection, virt_mysql::Error> {
+        Ok(virt_mysql::Connection::new(VirtMySqlConnection {
+            database: RdbmsDatabase::open(&address)?,
+        }))
+    }
+
+    fn address(&self) -> String {
+        self.database.address.clone()
+    }
+
+    fn query(
+        &self,
+        statement: String,
+        params: Vec<virt_mysql::ParameterValue>,
+    ) -> Result<virt_mysql::RowSet, virt_mysql::Error> {
+        self.database.query(&statement, params)
+    }
+
+    fn execute(
+        &self,
+        statement: String,
+        params: Vec<virt_mysql::ParameterValue>,
+    ) -> Result<u64, virt_mysql::Error> {
+        self.database.execute(&statement, params)
+    }
+
+    fn execute_batch(&self, statements: String) -> Result<(), virt_mysql::Error> {
+        self.database.execute_batch(&statements)
+    }
+}
@@ -0,0 +1,116 @@
+use super::RdbmsDatabase;
+use crate::bindings::exports::fermyon::{
+    spin::postgres, spin_test_virt::postgres as virt_postgres,
+};
+use crate::{manifest, Component};
+
+impl postgres::Guest for Component {
+    type Connection = PostgresConnection;
+}
+
+/// A connection made by the app to a Postgres database.
+pub struct PostgresConnection {
+    database: RdbmsDatabase,
+}
+
+impl postgres::GuestConnection for PostgresConnection {
+    fn open(address: String) -> Result<postgres::Connection, postgres::Error> {
+        let url_allowed = manifest::AppManifest::allows_url(&address, "postgres")
+            .map_err(|e| postgres::Error::ConnectionFailed(e.to_string()))?;
+        if !url_allowed {
+            return Err(postgres::Error::ConnectionFailed(format!(
+                "address {address} is not permitted"
+            )));
+        }
+        Ok(postgres::Connection::new(PostgresConnection {
+            database: RdbmsDatabase::open(&address)?,
+        }))
+    }
+
+    fn query(
+        &self,
+        statement: String,
+        params: Vec<postgres::ParameterValue>,
+    ) -> Result<postgres::RowSet, postgres::Error> {
+        let engine_statement = translate_placeholders(&statement);
+        self.database
+            .app_query(statement, &engine_statement, params)
+    }
+
+    fn execute(
+        &self,
+        statement: String,
+        params: Vec<postgres::ParameterValue>,
+    ) -> Result<u64, postgres::Error> {
+        let engine_statement = translate_placeholders(&statement);
+        self.database
+            .app_execute(statement, &engine_statement, params)
+    }
+}
+
+impl virt_postgres::Guest for Component {
+    type Connection = VirtPostgresConnection;
+}
+
+/// A connection made by the test to a Postgres database.
+pub struct VirtPostgresConnection {
+    address: String,
+    database: RdbmsDatabase,
+}
+
+impl virt_postgres::GuestConnection for VirtPostgresConnection {
+    fn open(address: String) -> Result<virt_postgres::Connection, virt_postgres::Error> {
+        Ok(virt_postgres::Connection::new(VirtPostgresConnection {
+            database: RdbmsDatabase::open(&address)?,
+            address,
+        }))
+    }
+
+    fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    fn query(
+        &self,
+        statement: String,
+        params: Vec<virt_postgres::ParameterValue>,
+    ) -> Result<virt_postgres::RowSet, virt_postgres::Error> {
+        self.database
+            .query(&translate_placeholders(&statement), params)
+    }
+
+    fn execute(
+        &self,
+        statement: String,
+        params: Vec<virt_postgres::ParameterValue>,
+    ) -> Result<u64, virt_postgres::Error> {
+        self.database
+            .execute(&translate_placeholders(&statement), params)
+    }
+
+    fn execute_batch(&self, statements: String) -> Result<(), virt_postgres::Error> {
+        self.database.execute_batch(&statements)
+    }
+}
+
+/// Translate Postgres' `$1` style placeholders to sqlite's `?1` style placeholders.
+///
+/// Placeholders inside of string literals and quoted identifiers are left untouched.
+fn translate_placeholders(statement: &str) -> String {
+    let mut translated = String::with_capacity(statement.len());
+    let mut quote = None;
+    let mut chars = statement.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, quote) {
+            ('\'' | '"', None) => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            ('$', None) if chars.peek().is_some_and(char::is_ascii_digit) => {
+                translated.push('?');
+                continue;
+            }
+            _ => {}
+        }
+        translated.push(c);
+    }
+    translated
+}
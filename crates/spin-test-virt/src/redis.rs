@@ -0,0 +1,493 @@
+mod commands;
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::{Arc, Mutex, OnceLock, RwLock},
+};
+
+use crate::bindings::exports::fermyon::{
+    spin::redis,
+    spin_test_virt::{inbound_redis, redis as virt_redis, types as virt_types},
+};
+use crate::{manifest, Component, Matcher, SharedHashMap};
+
+impl redis::Guest for Component {
+    type Connection = RedisConnection;
+}
+
+/// Stands in for the entrypoint of apps which don't handle Redis messages.
+impl inbound_redis::Guest for Component {
+    fn handle_message(_message: inbound_redis::Payload) -> Result<(), inbound_redis::Error> {
+        crate::stand_in::fail(
+            "the app does not export `fermyon:spin/inbound-redis` so it can't handle Redis messages",
+        );
+        Err(inbound_redis::Error::Error)
+    }
+}
+
+/// The error Redis returns when an operation is performed on a key holding the wrong type.
+const WRONG_TYPE: &str = "WRONGTYPE Operation against a key holding the wrong kind of value";
+/// The error Redis returns when a value is not a valid integer.
+const NOT_AN_INTEGER: &str = "ERR value is not an integer or out of range";
+
+/// The global collection of Redis databases.
+struct Databases;
+
+impl Databases {
+    /// Get the global collection of Redis databases.
+    ///
+    /// The keys are the addresses of the databases.
+    fn get() -> &'static RwLock<HashMap<String, RedisDatabase>> {
+        static DATABASES: OnceLock<RwLock<HashMap<String, RedisDatabase>>> = OnceLock::new();
+        DATABASES.get_or_init(Default::default)
+    }
+
+    /// Get the database at the given address creating it if it does not exist yet.
+    fn open(address: String) -> RedisDatabase {
+        Self::get()
+            .write()
+            .unwrap()
+            .entry(address.clone())
+            .or_insert_with(|| RedisDatabase::new(address))
+            .clone()
+    }
+}
+
+/// A Redis database reachable at a specific address.
+#[derive(Clone)]
+struct RedisDatabase {
+    address: String,
+    /// The data stored in the Redis database.
+    data: SharedHashMap<String, RedisValue>,
+    /// The instants (as measured by the monotonic clock) at which keys expire.
+    expirations: SharedHashMap<String, u64>,
+    /// The calls made to the Redis database.
+    calls: Arc<RwLock<Vec<virt_redis::Call>>>,
+}
+
+enum RedisValue {
+    Payload(redis::Payload),
+    Set(HashSet<String>),
+    Hash(HashMap<String, redis::Payload>),
+    List(VecDeque<redis::Payload>),
+}
+
+impl RedisDatabase {
+    fn new(address: String) -> Self {
+        Self {
+            address,
+            data: Default::default(),
+            expirations: Default::default(),
+            calls: Default::default(),
+        }
+    }
+
+    /// Get the redis payload associated with a key.
+    ///
+    /// Returns `Ok(None)` if the key does not exist and `Err(redis::Error::TypeError)` if the key
+    /// exists but is not a payload.
+    fn get_payload(&self, key: &str) -> Result<Option<redis::Payload>, redis::Error> {
+        match self.read_data().get(key) {
+            Some(RedisValue::Payload(p)) => Ok(Some(p.clone())),
+            Some(_) => Err(redis::Error::TypeError),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the set associated with the key.
+    fn get_set(&self, key: &str) -> Result<HashSet<String>, redis::Error> {
+        match self.read_data().get(key) {
+            Some(RedisValue::Set(s)) => Ok(s.clone()),
+            Some(_) => Err(redis::Error::TypeError),
+            None => Ok(Default::default()),
+        }
+    }
+
+    /// Add the values to the set associated with the key.
+    fn add_to_set(&self, key: String, new: Vec<String>) -> Result<usize, redis::Error> {
+        let mut data = self.write_data();
+        match data
+            .entry(key)
+            .or_insert_with(|| RedisValue::Set(Default::default()))
+        {
+            RedisValue::Set(s) => {
+                let original_len = s.len();
+                s.extend(new);
+                Ok(s.len() - original_len)
+            }
+            _ => Err(redis::Error::TypeError),
+        }
+    }
+
+    /// Remove the values from the set associated with the key.
+    ///
+    /// Like Redis, the key is removed once its set is empty.
+    fn remove_from_set(&self, key: &str, values: &[String]) -> Result<usize, redis::Error> {
+        let mut data = self.write_data();
+        let removed = match data.get_mut(key) {
+            Some(RedisValue::Set(s)) => {
+                let original_len = s.len();
+                s.retain(|v| !values.contains(v));
+                original_len - s.len()
+            }
+            Some(_) => return Err(redis::Error::TypeError),
+            None => 0,
+        };
+        if matches!(data.get(key), Some(RedisValue::Set(s)) if s.is_empty()) {
+            self.remove_key(&mut data, key);
+        }
+        Ok(removed)
+    }
+
+    /// Set the value associated with a key.
+    ///
+    /// Any expiration previously set on the key is removed.
+    fn set(&self, key: String, value: redis::Payload) {
+        self.expirations.write().unwrap().remove(&key);
+        self.write_data().insert(key, RedisValue::Payload(value));
+    }
+
+    /// Increment the integer stored at the key by `delta` and store the result.
+    fn incr(&self, key: &str, delta: i64) -> Result<i64, redis::Error> {
+        let mut data = self.write_data();
+        let current = match data.get(key) {
+            Some(RedisValue::Payload(p)) => std::str::from_utf8(p)
+                .ok()
+                .and_then(|s| s.parse::<i64>().ok())
+                .ok_or_else(|| other(NOT_AN_INTEGER))?,
+            Some(_) => return Err(other(WRONG_TYPE)),
+            None => 0,
+        };
+        let new = current
+            .checked_add(delta)
+            .ok_or_else(|| other("ERR increment or decrement would overflow"))?;
+        data.insert(
+            key.to_owned(),
+            RedisValue::Payload(new.to_string().into_bytes()),
+        );
+        Ok(new)
+    }
+
+    /// Delete the values associated with the keys.
+    ///
+    /// Returns the number of keys that were deleted.
+    fn del(&self, keys: &[String]) -> usize {
+        let mut data = self.write_data();
+        let original_len = data.len();
+        data.retain(|k, _| !keys.contains(k));
+        self.expirations
+            .write()
+            .unwrap()
+            .retain(|k, _| !keys.contains(k));
+        let new_len = data.len();
+        original_len - new_len
+    }
+
+    /// Remove the key along with its expiration.
+    fn remove_key(&self, data: &mut HashMap<String, RedisValue>, key: &str) {
+        data.remove(key);
+        self.expirations.write().unwrap().remove(key);
+    }
+
+    /// Get all the keys in the database.
+    fn keys(&self) -> Vec<String> {
+        self.read_data().keys().cloned().collect()
+    }
+
+    /// Expire the key after the given number of nanoseconds.
+    ///
+    /// Returns whether the key exists.
+    fn expire(&self, key: &str, nanoseconds: i64) -> bool {
+        if !self.read_data().contains_key(key) {
+            return false;
+        }
+        let deadline = now().saturating_add_signed(nanoseconds);
+        self.expirations
+            .write()
+            .unwrap()
+            .insert(key.to_owned(), deadline);
+        true
+    }
+
+    /// Get the number of nanoseconds until the key expires.
+    ///
+    /// Returns `None` if the key does not exist and `Some(None)` if the key does not expire.
+    fn time_to_live(&self, key: &str) -> Option<Option<u64>> {
+        if !self.read_data().contains_key(key) {
+            return None;
+        }
+        let deadline = self.expirations.read().unwrap().get(key).copied();
+        Some(deadline.map(|d| d.saturating_sub(now())))
+    }
+
+    fn push_call(&self, call: virt_redis::Call) {
+        self.calls.write().unwrap().push(call)
+    }
+
+    fn read_calls(&self) -> Vec<virt_redis::Call> {
+        self.calls.read().unwrap().clone()
+    }
+
+    /// Clear the recorded calls made to the Redis database.
+    fn clear_calls(&self) {
+        self.calls.write().unwrap().clear()
+    }
+
+    fn write_data(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<String, RedisValue>> {
+        let mut data = self.data.write().unwrap();
+        self.remove_expired(&mut data);
+        data
+    }
+
+    fn read_data(&self) -> std::sync::RwLockReadGuard<'_, HashMap<String, RedisValue>> {
+        self.remove_expired(&mut self.data.write().unwrap());
+        self.data.read().unwrap()
+    }
+
+    /// Remove all keys whose expiration has passed.
+    fn remove_expired(&self, data: &mut HashMap<String, RedisValue>) {
+        let now = now();
+        self.expirations.write().unwrap().retain(|key, deadline| {
+            let expired = *deadline <= now;
+            if expired {
+                data.remove(key);
+            }
+            !expired
+        });
+    }
+}
+
+/// The current instant of the virtualized monotonic clock.
+///
+/// Tests move it forward with `fermyon:spin-test-virt/clock#advance` to expire keys.
+fn now() -> u64 {
+    <Component as crate::bindings::exports::wasi::clocks::monotonic_clock::Guest>::now()
+}
+
+/// The rules scripting the response of commands run through `execute`.
+static EXECUTE_RULES: OnceLock<Mutex<Vec<ExecuteRule>>> = OnceLock::new();
+
+/// A rule which scripts the response of matching `execute` commands.
+struct ExecuteRule {
+    /// The address the rule applies to or `None` if it applies to all databases.
+    address: Option<String>,
+    command: String,
+    /// The matcher for the space separated arguments or `None` if any arguments match.
+    arguments: Option<Matcher>,
+    response: virt_redis::ExecuteResponse,
+}
+
+impl ExecuteRule {
+    /// Whether the rule matches the command run against the database at `address`.
+    fn matches(&self, address: &str, command: &str, arguments: &[redis::RedisParameter]) -> bool {
+        if self.address.as_ref().is_some_and(|a| a != address)
+            || !self.command.eq_ignore_ascii_case(command)
+        {
+            return false;
+        }
+        let Some(matcher) = &self.arguments else {
+            return true;
+        };
+        let arguments = arguments
+            .iter()
+            .map(|a| match a {
+                redis::RedisParameter::Int64(i) => i.to_string(),
+                redis::RedisParameter::Binary(b) => String::from_utf8_lossy(b).into_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        matcher.matches(&arguments)
+    }
+}
+
+/// Get the response of the first rule matching the command if there is one.
+fn matching_execute_rule(
+    address: &str,
+    command: &str,
+    arguments: &[redis::RedisParameter],
+) -> Option<virt_redis::ExecuteResponse> {
+    EXECUTE_RULES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|r| r.matches(address, command, arguments))
+        .map(|r| r.response.clone())
+}
+
+/// A connection made by the app to a Redis database.
+pub struct RedisConnection {
+    database: RedisDatabase,
+}
+
+impl redis::GuestConnection for RedisConnection {
+    fn open(address: String) -> Result<redis::Connection, redis::Error> {
+        let url_allowed = manifest::AppManifest::allows_url(&address, "redis")
+            .map_err(|_| redis::Error::InvalidAddress)?;
+        if !url_allowed {
+            return Err(redis::Error::InvalidAddress);
+        }
+        Ok(redis::Connection::new(RedisConnection {
+            database: Databases::open(address),
+        }))
+    }
+
+    fn publish(&self, channel: String, payload: redis::Payload) -> Result<(), redis::Error> {
+        self.database
+            .push_call(virt_redis::Call::Publish((channel, payload)));
+        Ok(())
+    }
+
+    fn get(&self, key: String) -> Result<Option<redis::Payload>, redis::Error> {
+        let result = self.database.get_payload(&key);
+        self.database.push_call(virt_redis::Call::Get(key));
+        result
+    }
+
+    fn set(&self, key: String, value: redis::Payload) -> Result<(), redis::Error> {
+        self.database.set(key.clone(), value.clone());
+        self.database.push_call(virt_redis::Call::Set((key, value)));
+        Ok(())
+    }
+
+    fn incr(&self, key: String) -> Result<i64, redis::Error> {
+        let result = self.database.incr(&key, 1);
+        self.database.push_call(virt_redis::Call::Incr(key));
+        result
+    }
+
+    fn del(&self, keys: Vec<String>) -> Result<u32, redis::Error> {
+        let result = self.database.del(&keys);
+        self.database.push_call(virt_redis::Call::Del(keys));
+        Ok(result as u32)
+    }
+
+    fn sadd(&self, key: String, values: Vec<String>) -> Result<u32, redis::Error> {
+        let result = self.database.add_to_set(key.clone(), values.clone());
+        self.database
+            .push_call(virt_redis::Call::Sadd((key, values)));
+        result.map(|n| n as u32)
+    }
+
+    fn smembers(&self, key: String) -> Result<Vec<String>, redis::Error> {
+        let result = self.database.get_set(&key);
+        self.database.push_call(virt_redis::Call::Smembers(key));
+        result.map(|s| s.into_iter().collect())
+    }
+
+    fn srem(&self, key: String, values: Vec<String>) -> Result<u32, redis::Error> {
+        let result = self.database.remove_from_set(&key, &values);
+        self.database
+            .push_call(virt_redis::Call::Srem((key, values)));
+        result.map(|n| n as u32)
+    }
+
+    fn execute(
+        &self,
+        command: String,
+        arguments: Vec<redis::RedisParameter>,
+    ) -> Result<Vec<redis::RedisResult>, redis::Error> {
+        let result = match matching_execute_rule(&self.database.address, &command, &arguments) {
+            Some(virt_redis::ExecuteResponse::Error(e)) => Err(e),
+            Some(virt_redis::ExecuteResponse::Result(r)) => Ok(r),
+            None => self.database.execute(&command, arguments.clone()),
+        };
+        self.database
+            .push_call(virt_redis::Call::Execute((command, arguments)));
+        result
+    }
+}
+
+impl virt_redis::Guest for Component {
+    type Connection = VirtRedisConnection;
+
+    fn calls() -> Vec<(String, Vec<virt_redis::Call>)> {
+        Databases::get()
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(address, database)| (address.clone(), database.read_calls()))
+            .collect()
+    }
+
+    fn reset_calls() {
+        for database in Databases::get().read().unwrap().values() {
+            database.clear_calls();
+        }
+    }
+
+    fn add_execute_rule(
+        address: Option<String>,
+        command: String,
+        arguments: Option<virt_types::Matcher>,
+        response: virt_redis::ExecuteResponse,
+    ) -> Result<(), String> {
+        let arguments = arguments.map(Matcher::try_from).transpose()?;
+        EXECUTE_RULES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .push(ExecuteRule {
+                address,
+                command,
+                arguments,
+                response,
+            });
+        Ok(())
+    }
+
+    fn clear_execute_rules() {
+        EXECUTE_RULES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .clear();
+    }
+}
+
+/// A connection made by the test to a Redis database.
+///
+/// Unlike `RedisConnection`, calls made through this connection are not recorded.
+pub struct VirtRedisConnection {
+    inner: RedisDatabase,
+}
+
+impl virt_redis::GuestConnection for VirtRedisConnection {
+    fn open(address: String) -> virt_redis::Connection {
+        virt_redis::Connection::new(VirtRedisConnection {
+            inner: Databases::open(address),
+        })
+    }
+
+    fn address(&self) -> String {
+        self.inner.address.clone()
+    }
+
+    fn get(&self, key: String) -> Result<Option<redis::Payload>, redis::Error> {
+        self.inner.get_payload(&key)
+    }
+
+    fn set(&self, key: String, value: redis::Payload) {
+        self.inner.set(key, value)
+    }
+
+    fn smembers(&self, key: String) -> Result<Vec<String>, redis::Error> {
+        self.inner.get_set(&key).map(|s| s.into_iter().collect())
+    }
+
+    fn sadd(&self, key: String, values: Vec<String>) -> Result<(), redis::Error> {
+        self.inner.add_to_set(key, values).map(|_| ())
+    }
+
+    fn del(&self, keys: Vec<String>) {
+        self.inner.del(&keys);
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.inner.keys()
+    }
+}
+
+fn other(message: impl Into<String>) -> redis::Error {
+    redis::Error::Other(message.into())
+}
@@ -0,0 +1,478 @@
+//! The Redis commands supported by `fermyon:spin/redis#connection.execute`
+
+use std::collections::{HashMap, VecDeque};
+
+use super::{other, redis, RedisDatabase, RedisValue, NOT_AN_INTEGER, WRONG_TYPE};
+
+/// The error Redis returns when a command has a syntax error.
+const SYNTAX_ERROR: &str = "ERR syntax error";
+
+impl RedisDatabase {
+    /// Execute an arbitrary Redis command.
+    ///
+    /// Results are returned the way Spin returns them: single replies are a list of one element
+    /// and array replies are flattened into the list.
+    pub(super) fn execute(
+        &self,
+        command: &str,
+        arguments: Vec<redis::RedisParameter>,
+    ) -> Result<Vec<redis::RedisResult>, redis::Error> {
+        let mut args = Arguments::new(command, arguments);
+        let result = match command.to_ascii_lowercase().as_str() {
+            "get" => {
+                let key = args.next_string()?;
+                args.finish()?;
+                vec![nil_or_binary(self.get_payload(&key).map_err(wrong_type)?)]
+            }
+            "set" => {
+                let key = args.next_string()?;
+                let value = args.next_bytes()?;
+                let mut expire_in = None;
+                // `Some(true)` if the key must exist (`XX`) and `Some(false)` if it must not (`NX`)
+                let mut must_exist = None;
+                while let Some(option) = args.next_option()? {
+                    match option.as_str() {
+                        "ex" => expire_in = Some(seconds_to_nanos(positive(args.next_int()?)?)?),
+                        "px" => expire_in = Some(millis_to_nanos(positive(args.next_int()?)?)?),
+                        "nx" | "xx" if must_exist.is_none() => must_exist = Some(option == "xx"),
+                        _ => return Err(other(SYNTAX_ERROR)),
+                    }
+                }
+                let exists = self.read_data().contains_key(&key);
+                if must_exist.is_some_and(|must_exist| must_exist != exists) {
+                    return Ok(vec![redis::RedisResult::Nil]);
+                }
+                self.set(key.clone(), value);
+                if let Some(expire_in) = expire_in {
+                    self.expire(&key, expire_in);
+                }
+                vec![ok()]
+            }
+            "setnx" => {
+                let key = args.next_string()?;
+                let value = args.next_bytes()?;
+                args.finish()?;
+                let exists = self.read_data().contains_key(&key);
+                if !exists {
+                    self.set(key, value);
+                }
+                vec![int(!exists as usize)]
+            }
+            "append" => {
+                let key = args.next_string()?;
+                let value = args.next_bytes()?;
+                args.finish()?;
+                let mut data = self.write_data();
+                match data
+                    .entry(key)
+                    .or_insert_with(|| RedisValue::Payload(Vec::new()))
+                {
+                    RedisValue::Payload(p) => {
+                        p.extend(value);
+                        vec![int(p.len())]
+                    }
+                    _ => return Err(other(WRONG_TYPE)),
+                }
+            }
+            "incr" | "decr" => {
+                let key = args.next_string()?;
+                args.finish()?;
+                let delta = if command.eq_ignore_ascii_case("incr") {
+                    1
+                } else {
+                    -1
+                };
+                vec![redis::RedisResult::Int64(self.incr(&key, delta)?)]
+            }
+            "incrby" | "decrby" => {
+                let key = args.next_string()?;
+                let delta = args.next_int()?;
+                args.finish()?;
+                let delta = if command.eq_ignore_ascii_case("incrby") {
+                    delta
+                } else {
+                    delta.checked_neg().ok_or_else(|| other(NOT_AN_INTEGER))?
+                };
+                vec![redis::RedisResult::Int64(self.incr(&key, delta)?)]
+            }
+            "del" => {
+                let keys = args.rest_strings(1)?;
+                vec![int(self.del(&keys))]
+            }
+            "exists" => {
+                let keys = args.rest_strings(1)?;
+                let data = self.read_data();
+                vec![int(keys.iter().filter(|k| data.contains_key(*k)).count())]
+            }
+            "mget" => {
+                let keys = args.rest_strings(1)?;
+                let data = self.read_data();
+                keys.iter()
+                    .map(|k| match data.get(k) {
+                        Some(RedisValue::Payload(p)) => redis::RedisResult::Binary(p.clone()),
+                        _ => redis::RedisResult::Nil,
+                    })
+                    .collect()
+            }
+            "keys" => {
+                let pattern = args.next_string()?;
+                args.finish()?;
+                let pattern = glob_to_regex(&pattern)?;
+                let mut keys = self.keys();
+                keys.retain(|k| pattern.is_match(k));
+                keys.sort();
+                keys.into_iter()
+                    .map(|k| redis::RedisResult::Binary(k.into_bytes()))
+                    .collect()
+            }
+            "expire" | "pexpire" => {
+                let key = args.next_string()?;
+                let amount = args.next_int()?;
+                args.finish()?;
+                let expire_in = if command.eq_ignore_ascii_case("expire") {
+                    seconds_to_nanos(amount)?
+                } else {
+                    millis_to_nanos(amount)?
+                };
+                vec![int(self.expire(&key, expire_in) as usize)]
+            }
+            "ttl" | "pttl" => {
+                let key = args.next_string()?;
+                args.finish()?;
+                let ttl = match self.time_to_live(&key) {
+                    None => -2,
+                    Some(None) => -1,
+                    Some(Some(nanos)) if command.eq_ignore_ascii_case("ttl") => {
+                        // Redis rounds the remaining time to the nearest second
+                        ((nanos + 500_000_000) / 1_000_000_000) as i64
+                    }
+                    Some(Some(nanos)) => ((nanos + 500_000) / 1_000_000) as i64,
+                };
+                vec![redis::RedisResult::Int64(ttl)]
+            }
+            "hset" => {
+                let key = args.next_string()?;
+                let mut fields = Vec::new();
+                loop {
+                    let field = args.next_string()?;
+                    let value = args.next_bytes()?;
+                    fields.push((field, value));
+                    if args.is_empty() {
+                        break;
+                    }
+                }
+                let mut data = self.write_data();
+                match data
+                    .entry(key)
+                    .or_insert_with(|| RedisValue::Hash(HashMap::new()))
+                {
+                    RedisValue::Hash(h) => {
+                        let mut added = 0;
+                        for (field, value) in fields {
+                            if h.insert(field, value).is_none() {
+                                added += 1;
+                            }
+                        }
+                        vec![int(added)]
+                    }
+                    _ => return Err(other(WRONG_TYPE)),
+                }
+            }
+            "hget" => {
+                let key = args.next_string()?;
+                let field = args.next_string()?;
+                args.finish()?;
+                match self.read_data().get(&key) {
+                    Some(RedisValue::Hash(h)) => vec![nil_or_binary(h.get(&field).cloned())],
+                    Some(_) => return Err(other(WRONG_TYPE)),
+                    None => vec![redis::RedisResult::Nil],
+                }
+            }
+            "hgetall" => {
+                let key = args.next_string()?;
+                args.finish()?;
+                match self.read_data().get(&key) {
+                    Some(RedisValue::Hash(h)) => {
+                        let mut fields = h.iter().collect::<Vec<_>>();
+                        fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+                        fields
+                            .into_iter()
+                            .flat_map(|(field, value)| {
+                                [
+                                    redis::RedisResult::Binary(field.clone().into_bytes()),
+                                    redis::RedisResult::Binary(value.clone()),
+                                ]
+                            })
+                            .collect()
+                    }
+                    Some(_) => return Err(other(WRONG_TYPE)),
+                    None => vec![],
+                }
+            }
+            "hdel" => {
+                let key = args.next_string()?;
+                let fields = args.rest_strings(1)?;
+                let mut data = self.write_data();
+                let removed = match data.get_mut(&key) {
+                    Some(RedisValue::Hash(h)) => {
+                        fields.iter().filter(|f| h.remove(*f).is_some()).count()
+                    }
+                    Some(_) => return Err(other(WRONG_TYPE)),
+                    None => 0,
+                };
+                // Redis removes keys holding empty hashes
+                if matches!(data.get(&key), Some(RedisValue::Hash(h)) if h.is_empty()) {
+                    self.remove_key(&mut data, &key);
+                }
+                vec![int(removed)]
+            }
+            "lpush" | "rpush" => {
+                let key = args.next_string()?;
+                let values = args.rest_bytes(1)?;
+                let mut data = self.write_data();
+                match data
+                    .entry(key)
+                    .or_insert_with(|| RedisValue::List(VecDeque::new()))
+                {
+                    RedisValue::List(l) => {
+                        for value in values {
+                            if command.eq_ignore_ascii_case("lpush") {
+                                l.push_front(value);
+                            } else {
+                                l.push_back(value);
+                            }
+                        }
+                        vec![int(l.len())]
+                    }
+                    _ => return Err(other(WRONG_TYPE)),
+                }
+            }
+            "lrange" => {
+                let key = args.next_string()?;
+                let start = args.next_int()?;
+                let stop = args.next_int()?;
+                args.finish()?;
+                match self.read_data().get(&key) {
+                    Some(RedisValue::List(l)) => list_range(l, start, stop)
+                        .map(|v| redis::RedisResult::Binary(v.clone()))
+                        .collect(),
+                    Some(_) => return Err(other(WRONG_TYPE)),
+                    None => vec![],
+                }
+            }
+            "llen" => {
+                let key = args.next_string()?;
+                args.finish()?;
+                match self.read_data().get(&key) {
+                    Some(RedisValue::List(l)) => vec![int(l.len())],
+                    Some(_) => return Err(other(WRONG_TYPE)),
+                    None => vec![int(0)],
+                }
+            }
+            "sadd" => {
+                let key = args.next_string()?;
+                let members = args.rest_strings(1)?;
+                vec![int(self.add_to_set(key, members).map_err(wrong_type)?)]
+            }
+            "srem" => {
+                let key = args.next_string()?;
+                let members = args.rest_strings(1)?;
+                vec![int(self
+                    .remove_from_set(&key, &members)
+                    .map_err(wrong_type)?)]
+            }
+            "smembers" => {
+                let key = args.next_string()?;
+                args.finish()?;
+                let mut members = self
+                    .get_set(&key)
+                    .map_err(wrong_type)?
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                members.sort();
+                members
+                    .into_iter()
+                    .map(|m| redis::RedisResult::Binary(m.into_bytes()))
+                    .collect()
+            }
+            _ => {
+                // Tests can script responses for other commands with `add-execute-rule`
+                return Err(redis::Error::Other(format!(
+                    "not able to execute '{command}' command"
+                )));
+            }
+        };
+        Ok(result)
+    }
+}
+
+/// The arguments passed to a command consumed in order.
+struct Arguments<'a> {
+    command: &'a str,
+    arguments: std::vec::IntoIter<redis::RedisParameter>,
+}
+
+impl<'a> Arguments<'a> {
+    fn new(command: &'a str, arguments: Vec<redis::RedisParameter>) -> Self {
+        Self {
+            command,
+            arguments: arguments.into_iter(),
+        }
+    }
+
+    /// Whether all arguments have been consumed.
+    fn is_empty(&self) -> bool {
+        self.arguments.as_slice().is_empty()
+    }
+
+    /// Ensure that all arguments have been consumed.
+    fn finish(&self) -> Result<(), redis::Error> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.wrong_arity())
+        }
+    }
+
+    fn next_bytes(&mut self) -> Result<Vec<u8>, redis::Error> {
+        match self.arguments.next().ok_or_else(|| self.wrong_arity())? {
+            redis::RedisParameter::Binary(b) => Ok(b),
+            redis::RedisParameter::Int64(i) => Ok(i.to_string().into_bytes()),
+        }
+    }
+
+    fn next_string(&mut self) -> Result<String, redis::Error> {
+        String::from_utf8(self.next_bytes()?).map_err(|_| redis::Error::TypeError)
+    }
+
+    fn next_int(&mut self) -> Result<i64, redis::Error> {
+        match self.arguments.next().ok_or_else(|| self.wrong_arity())? {
+            redis::RedisParameter::Int64(i) => Ok(i),
+            redis::RedisParameter::Binary(b) => std::str::from_utf8(&b)
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| other(NOT_AN_INTEGER)),
+        }
+    }
+
+    /// The next optional argument (e.g., `EX` in `SET`) lowercased, if any.
+    fn next_option(&mut self) -> Result<Option<String>, redis::Error> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(self.next_string()?.to_ascii_lowercase()))
+    }
+
+    /// All remaining arguments which must be at least `min` in number.
+    fn rest_bytes(&mut self, min: usize) -> Result<Vec<Vec<u8>>, redis::Error> {
+        if self.arguments.len() < min {
+            return Err(self.wrong_arity());
+        }
+        std::iter::from_fn(|| (!self.is_empty()).then(|| self.next_bytes())).collect()
+    }
+
+    /// All remaining arguments as strings which must be at least `min` in number.
+    fn rest_strings(&mut self, min: usize) -> Result<Vec<String>, redis::Error> {
+        self.rest_bytes(min)?
+            .into_iter()
+            .map(|b| String::from_utf8(b).map_err(|_| redis::Error::TypeError))
+            .collect()
+    }
+
+    fn wrong_arity(&self) -> redis::Error {
+        other(format!(
+            "ERR wrong number of arguments for '{}' command",
+            self.command.to_ascii_lowercase()
+        ))
+    }
+}
+
+/// Get the elements of a list between `start` and `stop` (inclusive).
+///
+/// Negative indices count from the end of the list like they do in Redis.
+fn list_range(
+    list: &VecDeque<redis::Payload>,
+    start: i64,
+    stop: i64,
+) -> impl Iterator<Item = &redis::Payload> {
+    let len = list.len() as i64;
+    let normalize = |i: i64| if i < 0 { len + i } else { i };
+    let start = normalize(start).max(0);
+    let stop = normalize(stop).min(len - 1);
+    let count = if start > stop { 0 } else { stop - start + 1 };
+    list.iter().skip(start as usize).take(count as usize)
+}
+
+/// Convert a Redis glob-style pattern (as used by `KEYS`) to a regular expression.
+fn glob_to_regex(pattern: &str) -> Result<regex::Regex, redis::Error> {
+    let mut regex = String::from("^");
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            '[' => {
+                regex.push('[');
+                for c in chars.by_ref() {
+                    match c {
+                        ']' => break,
+                        '^' => regex.push('^'),
+                        '\\' => regex.push_str("\\\\"),
+                        c => regex.push(c),
+                    }
+                }
+                regex.push(']');
+            }
+            '\\' => {
+                if let Some(c) = chars.next() {
+                    regex.push_str(&regex::escape(&c.to_string()));
+                }
+            }
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    regex::Regex::new(&regex).map_err(|e| other(format!("ERR invalid pattern: {e}")))
+}
+
+fn seconds_to_nanos(seconds: i64) -> Result<i64, redis::Error> {
+    seconds
+        .checked_mul(1_000_000_000)
+        .ok_or_else(|| other("ERR invalid expire time"))
+}
+
+fn millis_to_nanos(millis: i64) -> Result<i64, redis::Error> {
+    millis
+        .checked_mul(1_000_000)
+        .ok_or_else(|| other("ERR invalid expire time"))
+}
+
+/// Ensure an expire time passed to `SET` is positive.
+fn positive(amount: i64) -> Result<i64, redis::Error> {
+    if amount <= 0 {
+        return Err(other("ERR invalid expire time"));
+    }
+    Ok(amount)
+}
+
+fn nil_or_binary(payload: Option<redis::Payload>) -> redis::RedisResult {
+    payload
+        .map(redis::RedisResult::Binary)
+        .unwrap_or(redis::RedisResult::Nil)
+}
+
+fn int(n: usize) -> redis::RedisResult {
+    redis::RedisResult::Int64(n as i64)
+}
+
+fn ok() -> redis::RedisResult {
+    redis::RedisResult::Status("OK".into())
+}
+
+/// Map a type error from the typed helpers to the error Redis returns.
+fn wrong_type(error: redis::Error) -> redis::Error {
+    match error {
+        redis::Error::TypeError => other(WRONG_TYPE),
+        e => e,
+    }
+}
@@ -0,0 +1,21 @@
+//! The reasons the stand-ins for entrypoints the app doesn't export fail with.
+//!
+//! The stand-ins can only fail with the error types of the entrypoints they stand in for, so
+//! they record why they failed for the router to report.
+
+use std::cell::RefCell;
+
+thread_local! {
+    /// The reason the last stand-in failed with if it hasn't been taken yet.
+    static ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Record the reason a stand-in is failing with.
+pub fn fail(reason: impl Into<String>) {
+    ERROR.with(|error| *error.borrow_mut() = Some(reason.into()));
+}
+
+/// Take the reason the last stand-in failed with.
+pub fn take_error() -> Option<String> {
+    ERROR.with(|error| error.borrow_mut().take())
+}
@@ -6,12 +6,18 @@ pub mod http;
 pub mod http_helper;
 pub mod io;
 mod tcp;
+pub mod wagi;
 
-use crate::bindings::exports::wasi;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::bindings::exports::{fermyon::spin_test_virt, wasi};
 use crate::Component;
 
 impl wasi::cli::stdout::Guest for Component {
     fn get_stdout() -> io::exports::streams::OutputStream {
+        if let Some(stdout) = wagi::stdout() {
+            return io::exports::streams::OutputStream::new(io::OutputStream::Buffered(stdout));
+        }
         io::exports::streams::OutputStream::new(io::OutputStream::Host(
             crate::bindings::wasi::cli::stdout::get_stdout(),
         ))
@@ -20,7 +26,8 @@ impl wasi::cli::stdout::Guest for Component {
 
 impl wasi::cli::stdin::Guest for Component {
     fn get_stdin() -> io::exports::streams::InputStream {
-        io::exports::streams::InputStream::new(io::InputStream::Buffered(io::Buffer::empty()))
+        let stdin = wagi::stdin().unwrap_or_else(io::Buffer::empty);
+        io::exports::streams::InputStream::new(io::InputStream::Buffered(stdin))
     }
 }
 
@@ -92,12 +99,22 @@ impl wasi::random::insecure::Guest for Component {
     }
 }
 
+/// How far tests have moved the virtualized clocks ahead of the host clocks in nanoseconds.
+static CLOCK_OFFSET: AtomicU64 = AtomicU64::new(0);
+
+impl spin_test_virt::clock::Guest for Component {
+    fn advance(nanoseconds: u64) {
+        CLOCK_OFFSET.fetch_add(nanoseconds, Ordering::Relaxed);
+    }
+}
+
 impl wasi::clocks::wall_clock::Guest for Component {
     fn now() -> wasi::clocks::wall_clock::Datetime {
         let now = crate::bindings::wasi::clocks::wall_clock::now();
+        let nanoseconds = u64::from(now.nanoseconds) + CLOCK_OFFSET.load(Ordering::Relaxed);
         wasi::clocks::wall_clock::Datetime {
-            seconds: now.seconds,
-            nanoseconds: now.nanoseconds,
+            seconds: now.seconds + nanoseconds / 1_000_000_000,
+            nanoseconds: (nanoseconds % 1_000_000_000) as u32,
         }
     }
 
@@ -109,6 +126,7 @@ impl wasi::clocks::wall_clock::Guest for Component {
 impl wasi::clocks::monotonic_clock::Guest for Component {
     fn now() -> wasi::clocks::monotonic_clock::Instant {
         crate::bindings::wasi::clocks::monotonic_clock::now()
+            .saturating_add(CLOCK_OFFSET.load(Ordering::Relaxed))
     }
 
     fn resolution() -> wasi::clocks::monotonic_clock::Duration {
@@ -133,11 +151,15 @@ impl wasi::cli::environment::Guest for Component {
             return crate::bindings::wasi::cli::environment::get_environment();
         };
 
-        component.environment.into_iter().collect()
+        component
+            .environment
+            .into_iter()
+            .chain(wagi::environment())
+            .collect()
     }
 
     fn get_arguments() -> Vec<String> {
-        Vec::new()
+        wagi::arguments()
     }
 
     fn initial_cwd() -> Option<String> {
@@ -43,6 +43,12 @@ pub struct IncomingRequest {
     pub path_with_query: Option<String>,
     pub headers: Fields,
     pub body: Consumable<IncomingBody>,
+    /// The address of the client the request comes from if it was set by the test
+    pub client_addr: RefCell<Option<String>>,
+    /// Whether the request was received over TLS if it was set by the test
+    pub tls: Cell<Option<bool>>,
+    /// The address the app listens on if it was set by the test
+    pub listen_addr: RefCell<Option<String>>,
 }
 
 /// A value which keeps track of whether it's been "consumed" or not.
@@ -436,6 +442,23 @@ impl exports::types::GuestRequestOptions for RequestOptions {
     }
 }
 
+/// Stands in for the entrypoint of apps which don't handle HTTP requests.
+impl exports::incoming_handler::Guest for Component {
+    fn handle(
+        _request: exports::types::IncomingRequest,
+        response_out: exports::types::ResponseOutparam,
+    ) {
+        let message = "the app does not export `wasi:http/incoming-handler` so it can't handle \
+            requests with the Spin executor";
+        <ResponseOutparam as exports::types::GuestResponseOutparam>::set(
+            response_out,
+            Err(exports::types::ErrorCode::InternalError(Some(
+                message.to_owned(),
+            ))),
+        );
+    }
+}
+
 pub struct ResponseOutparam(
     pub Arc<Mutex<Option<Result<exports::types::OutgoingResponse, exports::types::ErrorCode>>>>,
 );
@@ -450,13 +473,19 @@ impl exports::types::GuestResponseOutparam for ResponseOutparam {
     }
 }
 
-pub struct FutureIncomingResponse(
-    RefCell<Option<Result<IncomingResponse, exports::types::ErrorCode>>>,
-);
+pub enum FutureIncomingResponse {
+    /// The response is already known
+    Ready(RefCell<Option<Result<IncomingResponse, exports::types::ErrorCode>>>),
+    /// The response is the one set on the paired `response-outparam`
+    Receiver {
+        receiver: super::http_helper::ResponseReceiver,
+        taken: Cell<bool>,
+    },
+}
 
 impl FutureIncomingResponse {
     pub fn new(response: Result<IncomingResponse, exports::types::ErrorCode>) -> Self {
-        Self(RefCell::new(Some(response)))
+        Self::Ready(RefCell::new(Some(response)))
     }
 }
 
@@ -469,13 +498,23 @@ impl exports::types::GuestFutureIncomingResponse for FutureIncomingResponse {
         &self,
     ) -> Option<Result<Result<exports::types::IncomingResponse, exports::types::ErrorCode>, ()>>
     {
-        Some(
-            self.0
-                .borrow_mut()
-                .take()
-                .map(|s| s.map(exports::types::IncomingResponse::new))
-                .ok_or(()),
-        )
+        match self {
+            FutureIncomingResponse::Ready(response) => Some(
+                response
+                    .borrow_mut()
+                    .take()
+                    .map(|s| s.map(exports::types::IncomingResponse::new))
+                    .ok_or(()),
+            ),
+            FutureIncomingResponse::Receiver { receiver, taken } => {
+                if taken.get() {
+                    return Some(Err(()));
+                }
+                let response = receiver.receive()?;
+                taken.set(true);
+                Some(Ok(Ok(exports::types::IncomingResponse::new(response))))
+            }
+        }
     }
 }
 
@@ -497,6 +536,34 @@ impl exports::types::GuestFutureTrailers for FutureTrailers {
 pub static RESPONSES: std::sync::OnceLock<Mutex<HashMap<String, http_handler::ResponseHandler>>> =
     std::sync::OnceLock::new();
 
+/// The requests the app made to itself through local service chaining
+static INTERNAL_REQUESTS: std::sync::OnceLock<Mutex<Vec<http_handler::InternalRequest>>> =
+    std::sync::OnceLock::new();
+
+/// Record a request the app made to itself through local service chaining
+pub fn record_internal_request(method: String, url: String) {
+    INTERNAL_REQUESTS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap()
+        .push(http_handler::InternalRequest { method, url });
+}
+
+/// Whether the current component may make a request to `self` or a `*.spin.internal` url
+///
+/// Requests to `self` are relative requests which Spin allows when `allowed_outbound_hosts`
+/// contains `self`.
+pub fn allows_internal_url(url: &str) -> bool {
+    let is_self = url
+        .split_once("://")
+        .is_some_and(|(_, rest)| rest == "self" || rest.starts_with("self/"));
+    if is_self {
+        return crate::manifest::AppManifest::allowed_hosts()
+            .is_ok_and(|hosts| hosts.allows_relative_url(&["http", "https"]));
+    }
+    crate::manifest::AppManifest::allows_url(url, "http").unwrap_or(false)
+}
+
 impl exports::outgoing_handler::Guest for Component {
     fn handle(
         request: exports::outgoing_handler::OutgoingRequest,
@@ -553,6 +620,19 @@ impl exports::outgoing_handler::Guest for Component {
                     })),
                 ))
             }
+            None if request
+                .authority
+                .borrow()
+                .as_deref()
+                .is_some_and(|a| a == "self" || a.contains(".spin.internal")) =>
+            {
+                Err(exports::outgoing_handler::ErrorCode::InternalError(Some(
+                    format!(
+                        "`spin-test` only supports local service chaining one request deep and \
+                        this request was made while handling a chained request: {url}"
+                    ),
+                )))
+            }
             None => Err(exports::outgoing_handler::ErrorCode::InternalError(Some(
                 format!("mocking error - unrecognized url: {url}"),
             ))),
@@ -568,4 +648,20 @@ impl http_handler::Guest for Component {
             .unwrap()
             .insert(url, response);
     }
+
+    fn internal_requests() -> Vec<http_handler::InternalRequest> {
+        INTERNAL_REQUESTS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .clone()
+    }
+
+    fn reset_internal_requests() {
+        INTERNAL_REQUESTS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .clear();
+    }
 }
@@ -1,5 +1,5 @@
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     sync::{Arc, Mutex},
 };
 
@@ -38,9 +38,42 @@ impl exports::Guest for Component {
             body: body
                 .map(Into::into)
                 .unwrap_or_else(|| request.body.unconsume().map(Into::into)),
+            client_addr: RefCell::new(None),
+            tls: Cell::new(None),
+            listen_addr: RefCell::new(None),
         })
     }
 
+    fn set_client_addr(request: exports::IncomingRequestBorrow<'_>, client_addr: String) {
+        let request: &IncomingRequest = request.get();
+        *request.client_addr.borrow_mut() = Some(client_addr);
+    }
+
+    fn client_addr(request: exports::IncomingRequestBorrow<'_>) -> Option<String> {
+        let request: &IncomingRequest = request.get();
+        request.client_addr.borrow().clone()
+    }
+
+    fn set_tls(request: exports::IncomingRequestBorrow<'_>, tls: bool) {
+        let request: &IncomingRequest = request.get();
+        request.tls.set(Some(tls));
+    }
+
+    fn tls(request: exports::IncomingRequestBorrow<'_>) -> Option<bool> {
+        let request: &IncomingRequest = request.get();
+        request.tls.get()
+    }
+
+    fn set_listen_addr(request: exports::IncomingRequestBorrow<'_>, listen_addr: String) {
+        let request: &IncomingRequest = request.get();
+        *request.listen_addr.borrow_mut() = Some(listen_addr);
+    }
+
+    fn listen_addr(request: exports::IncomingRequestBorrow<'_>) -> Option<String> {
+        let request: &IncomingRequest = request.get();
+        request.listen_addr.borrow().clone()
+    }
+
     fn new_response() -> (exports::ResponseOutparam, exports::ResponseReceiver) {
         let response = Arc::new(Mutex::new(None));
         (
@@ -48,6 +81,17 @@ impl exports::Guest for Component {
             exports::ResponseReceiver::new(ResponseReceiver(response)),
         )
     }
+
+    fn new_response_future() -> (exports::ResponseOutparam, exports::FutureIncomingResponse) {
+        let response = Arc::new(Mutex::new(None));
+        (
+            exports::ResponseOutparam::new(ResponseOutparam(response.clone())),
+            exports::FutureIncomingResponse::new(http::FutureIncomingResponse::Receiver {
+                receiver: ResponseReceiver(response),
+                taken: Default::default(),
+            }),
+        )
+    }
 }
 
 pub struct ResponseReceiver(
@@ -65,7 +109,14 @@ pub struct ResponseReceiver(
 
 impl exports::GuestResponseReceiver for ResponseReceiver {
     fn get(&self) -> Option<exports::IncomingResponse> {
-        let response = match &*self.0.lock().unwrap() {
+        self.receive().map(exports::IncomingResponse::new)
+    }
+}
+
+impl ResponseReceiver {
+    /// The response set on the paired `response-outparam` if it has been set
+    pub fn receive(&self) -> Option<IncomingResponse> {
+        match &*self.0.lock().unwrap() {
             Some(Ok(r)) => {
                 let outgoing = r.get::<OutgoingResponse>();
                 let body = outgoing.body.unconsume();
@@ -99,7 +150,6 @@ impl exports::GuestResponseReceiver for ResponseReceiver {
                 })
             }
             None => None,
-        };
-        response.map(exports::IncomingResponse::new)
+        }
     }
 }
@@ -294,6 +294,11 @@ impl Buffer {
     pub(crate) fn len(&self) -> usize {
         self.inner.borrow().len()
     }
+
+    /// Everything written to the buffer
+    pub(crate) fn contents(&self) -> Vec<u8> {
+        self.inner.borrow().clone()
+    }
 }
 
 impl From<Vec<u8>> for Buffer {
@@ -0,0 +1,79 @@
+//! The stdio, environment and arguments of a WAGI component while it handles a request.
+
+use std::cell::RefCell;
+
+use super::io::Buffer;
+use crate::bindings::exports::wasi::cli::run;
+use crate::Component;
+
+thread_local! {
+    /// The run of a WAGI component in progress if there is one.
+    static RUN: RefCell<Option<WagiRun>> = const { RefCell::new(None) };
+}
+
+/// The inputs and output of a WAGI component handling a request.
+struct WagiRun {
+    arguments: Vec<String>,
+    environment: Vec<(String, String)>,
+    stdin: Buffer,
+    stdout: Buffer,
+}
+
+/// Start a WAGI run with the given inputs.
+pub fn prepare(arguments: Vec<String>, environment: Vec<(String, String)>, stdin: Vec<u8>) {
+    RUN.with(|run| {
+        *run.borrow_mut() = Some(WagiRun {
+            arguments,
+            environment,
+            stdin: Buffer::new(stdin),
+            stdout: Buffer::empty(),
+        })
+    });
+}
+
+/// End the WAGI run returning what was written to stdout.
+pub fn finish() -> Vec<u8> {
+    RUN.with(|run| run.borrow_mut().take())
+        .map(|run| run.stdout.contents())
+        .unwrap_or_default()
+}
+
+/// The stdin of the WAGI run in progress.
+pub fn stdin() -> Option<Buffer> {
+    RUN.with(|run| run.borrow().as_ref().map(|r| r.stdin.clone()))
+}
+
+/// The stdout of the WAGI run in progress.
+pub fn stdout() -> Option<Buffer> {
+    RUN.with(|run| run.borrow().as_ref().map(|r| r.stdout.clone()))
+}
+
+/// The CGI environment variables of the WAGI run in progress.
+pub fn environment() -> Vec<(String, String)> {
+    RUN.with(|run| {
+        run.borrow()
+            .as_ref()
+            .map(|r| r.environment.clone())
+            .unwrap_or_default()
+    })
+}
+
+/// The arguments of the WAGI run in progress.
+pub fn arguments() -> Vec<String> {
+    RUN.with(|run| {
+        run.borrow()
+            .as_ref()
+            .map(|r| r.arguments.clone())
+            .unwrap_or_default()
+    })
+}
+
+/// Stands in for the entrypoint of apps which are not WAGI components.
+impl run::Guest for Component {
+    fn run() -> Result<(), ()> {
+        crate::stand_in::fail(
+            "the app does not export `wasi:cli/run` so it can't handle requests with the WAGI executor",
+        );
+        Err(())
+    }
+}
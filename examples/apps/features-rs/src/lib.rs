@@ -0,0 +1,233 @@
+use spin_sdk::http::{IntoResponse, Params, Request, Response, Router};
+use spin_sdk::{http_component, llm, mqtt, mysql, pg, redis, sqlite, variables};
+
+/// The address of the Redis database the app uses.
+const REDIS_ADDRESS: &str = "redis://localhost:6379";
+
+/// The address of the MQTT broker the app publishes to.
+const MQTT_ADDRESS: &str = "mqtt://localhost:1883?client_id=features";
+
+/// The address of the Postgres database the app uses.
+const POSTGRES_ADDRESS: &str = "postgres://localhost:5432/features";
+
+/// The address of the MySQL database the app uses.
+const MYSQL_ADDRESS: &str = "mysql://localhost:3306/features";
+
+/// Route each request to the handler exercising one of the Spin interfaces.
+#[http_component]
+fn handle(req: Request) -> Response {
+    let mut router = Router::new();
+    router.get("/users/:id", get_user);
+    router.post("/users", add_user);
+    router.get("/redis/:key", redis_get);
+    router.put("/redis/:key", redis_set);
+    router.post("/redis-execute", redis_execute);
+    router.post("/redis-incr/:key", redis_incr);
+    router.post("/redis-publish/:channel", redis_publish);
+    router.post("/mqtt-publish/:topic", mqtt_publish);
+    router.get("/pg/notes", pg_notes);
+    router.post("/pg/notes", pg_add_note);
+    router.get("/mysql/pets/:id", mysql_get_pet);
+    router.post("/mysql/pets", mysql_add_pet);
+    router.post("/llm/infer", llm_infer);
+    router.post("/llm/infer-code", llm_infer_code);
+    router.post("/llm/embeddings", llm_embeddings);
+    router.get("/variables/:name", get_variable);
+    router.handle(req)
+}
+
+/// Look up the name of a user in the default sqlite database.
+fn get_user(_req: Request, params: Params) -> anyhow::Result<impl IntoResponse> {
+    let id: i64 = params.get("id").unwrap_or_default().parse()?;
+    let db = sqlite::Connection::open_default()?;
+    let result = db.execute(
+        "SELECT name FROM users WHERE id = ?",
+        &[sqlite::Value::Integer(id)],
+    )?;
+    let Some(row) = result.rows().next() else {
+        return Ok(Response::new(404, ()));
+    };
+    let name = row.get::<&str>("name").unwrap_or_default().to_owned();
+    Ok(Response::new(200, name))
+}
+
+/// Add a user with the name in the request body to the default sqlite database.
+fn add_user(req: Request, _params: Params) -> anyhow::Result<impl IntoResponse> {
+    let name = String::from_utf8(req.body().to_vec())?;
+    let db = sqlite::Connection::open_default()?;
+    db.execute(
+        "INSERT INTO users (name) VALUES (?)",
+        &[sqlite::Value::Text(name)],
+    )?;
+    Ok(Response::new(201, ()))
+}
+
+/// Get the value of a key from Redis.
+fn redis_get(_req: Request, params: Params) -> anyhow::Result<impl IntoResponse> {
+    let redis = redis::Connection::open(REDIS_ADDRESS)?;
+    Ok(match redis.get(params.get("key").unwrap_or_default())? {
+        Some(value) => Response::new(200, value),
+        None => Response::new(404, ()),
+    })
+}
+
+/// Set a key in Redis to the request body.
+fn redis_set(req: Request, params: Params) -> anyhow::Result<impl IntoResponse> {
+    let redis = redis::Connection::open(REDIS_ADDRESS)?;
+    redis.set(params.get("key").unwrap_or_default(), &req.body().to_vec())?;
+    Ok(Response::new(204, ()))
+}
+
+/// Increment the counter stored at a key in Redis.
+fn redis_incr(_req: Request, params: Params) -> anyhow::Result<impl IntoResponse> {
+    let redis = redis::Connection::open(REDIS_ADDRESS)?;
+    let value = redis.incr(params.get("key").unwrap_or_default())?;
+    Ok(Response::new(200, value.to_string()))
+}
+
+/// Run the command in the request body, split on spaces, through Redis `execute`.
+///
+/// Responds with one line per result.
+fn redis_execute(req: Request, _params: Params) -> anyhow::Result<impl IntoResponse> {
+    let body = String::from_utf8(req.body().to_vec())?;
+    let mut words = body.split(' ');
+    let command = words.next().unwrap_or_default();
+    let arguments: Vec<_> = words
+        .map(|w| redis::RedisParameter::Binary(w.as_bytes().to_vec()))
+        .collect();
+    let redis = redis::Connection::open(REDIS_ADDRESS)?;
+    let results = redis.execute(command, &arguments)?;
+    let lines: Vec<_> = results
+        .into_iter()
+        .map(|r| match r {
+            redis::RedisResult::Nil => "nil".to_owned(),
+            redis::RedisResult::Status(s) => s,
+            redis::RedisResult::Int64(i) => i.to_string(),
+            redis::RedisResult::Binary(b) => String::from_utf8_lossy(&b).into_owned(),
+        })
+        .collect();
+    Ok(Response::new(200, lines.join("\n")))
+}
+
+/// Publish the request body to a Redis channel.
+fn redis_publish(req: Request, params: Params) -> anyhow::Result<impl IntoResponse> {
+    let redis = redis::Connection::open(REDIS_ADDRESS)?;
+    redis.publish(
+        params.get("channel").unwrap_or_default(),
+        &req.body().to_vec(),
+    )?;
+    Ok(Response::new(202, ()))
+}
+
+/// Publish the request body to an MQTT topic.
+fn mqtt_publish(req: Request, params: Params) -> anyhow::Result<impl IntoResponse> {
+    let mqtt = mqtt::Connection::open(MQTT_ADDRESS, "features", "secret", 30)?;
+    mqtt.publish(
+        params.get("topic").unwrap_or_default(),
+        &req.body().to_vec(),
+        mqtt::Qos::AtLeastOnce,
+    )?;
+    Ok(Response::new(202, ()))
+}
+
+/// List the notes stored in Postgres, one per line.
+fn pg_notes(_req: Request, _params: Params) -> anyhow::Result<impl IntoResponse> {
+    use pg::Decode;
+    let db = pg::Connection::open(POSTGRES_ADDRESS)?;
+    let notes = db.query("SELECT body FROM notes ORDER BY body", &[])?;
+    let lines = notes
+        .rows
+        .iter()
+        .map(|row| String::decode(&row[0]))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Response::new(200, lines.join("\n")))
+}
+
+/// Store the request body as a note in Postgres.
+fn pg_add_note(req: Request, _params: Params) -> anyhow::Result<impl IntoResponse> {
+    let body = String::from_utf8(req.body().to_vec())?;
+    let db = pg::Connection::open(POSTGRES_ADDRESS)?;
+    db.execute(
+        "INSERT INTO notes (body) VALUES ($1)",
+        &[pg::ParameterValue::Str(body)],
+    )?;
+    Ok(Response::new(201, ()))
+}
+
+/// Look up the name of a pet in MySQL.
+fn mysql_get_pet(_req: Request, params: Params) -> anyhow::Result<impl IntoResponse> {
+    use mysql::Decode;
+    let id: i64 = params.get("id").unwrap_or_default().parse()?;
+    let db = mysql::Connection::open(MYSQL_ADDRESS)?;
+    let pets = db.query(
+        "SELECT name FROM pets WHERE id = ?",
+        &[mysql::ParameterValue::Int64(id)],
+    )?;
+    let Some(row) = pets.rows.first() else {
+        return Ok(Response::new(404, ()));
+    };
+    Ok(Response::new(200, String::decode(&row[0])?))
+}
+
+/// Add a pet with the name in the request body to MySQL.
+fn mysql_add_pet(req: Request, _params: Params) -> anyhow::Result<impl IntoResponse> {
+    let name = String::from_utf8(req.body().to_vec())?;
+    let db = mysql::Connection::open(MYSQL_ADDRESS)?;
+    db.execute(
+        "INSERT INTO pets (name) VALUES (?)",
+        &[mysql::ParameterValue::Str(name)],
+    )?;
+    Ok(Response::new(201, ()))
+}
+
+/// Complete the prompt in the request body with the chat model.
+///
+/// Responds with the generated text followed by the number of generated tokens.
+fn llm_infer(req: Request, _params: Params) -> anyhow::Result<impl IntoResponse> {
+    let prompt = String::from_utf8(req.body().to_vec())?;
+    let result = llm::infer(llm::InferencingModel::Llama2Chat, &prompt)?;
+    Ok(Response::new(
+        200,
+        format!(
+            "{} ({} tokens)",
+            result.text, result.usage.generated_token_count
+        ),
+    ))
+}
+
+/// Complete the prompt in the request body with the code model, which the app may not use.
+fn llm_infer_code(req: Request, _params: Params) -> anyhow::Result<impl IntoResponse> {
+    let prompt = String::from_utf8(req.body().to_vec())?;
+    let result = llm::infer(llm::InferencingModel::CodellamaInstruct, &prompt)?;
+    Ok(Response::new(200, result.text))
+}
+
+/// Generate the embeddings of the text in the request body.
+///
+/// Responds with one line of comma separated values per embedding.
+fn llm_embeddings(req: Request, _params: Params) -> anyhow::Result<impl IntoResponse> {
+    let text = String::from_utf8(req.body().to_vec())?;
+    let result = llm::generate_embeddings(llm::EmbeddingModel::AllMiniLmL6V2, &[text])?;
+    let lines: Vec<_> = result
+        .embeddings
+        .iter()
+        .map(|e| {
+            e.iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect();
+    Ok(Response::new(200, lines.join("\n")))
+}
+
+/// Get the value of a component variable.
+fn get_variable(_req: Request, params: Params) -> anyhow::Result<impl IntoResponse> {
+    Ok(
+        match variables::get(params.get("name").unwrap_or_default()) {
+            Ok(value) => Response::new(200, value),
+            Err(variables::Error::Undefined(_)) => Response::new(404, ()),
+            Err(e) => return Err(e.into()),
+        },
+    )
+}
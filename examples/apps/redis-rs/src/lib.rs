@@ -0,0 +1,20 @@
+use bytes::Bytes;
+use spin_sdk::redis::Connection;
+use spin_sdk::redis_component;
+
+/// The address of the Redis instance the app subscribes to and keeps its state in.
+const REDIS_ADDRESS: &str = "redis://localhost:6379";
+
+/// Store each message in `last-message` and count them in `message-count`.
+///
+/// Messages reading `fail` are rejected.
+#[redis_component]
+fn on_message(message: Bytes) -> anyhow::Result<()> {
+    if message.as_ref() == b"fail" {
+        anyhow::bail!("the app was asked to fail");
+    }
+    let connection = Connection::open(REDIS_ADDRESS)?;
+    connection.set("last-message", &message.to_vec())?;
+    connection.incr("message-count")?;
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use spin_sdk::http::{Request, Response};
+use spin_sdk::http_component;
+
+/// The headers Spin adds to describe how a request was routed.
+const ROUTING_HEADERS: [&str; 7] = [
+    "spin-full-url",
+    "spin-path-info",
+    "spin-matched-route",
+    "spin-component-route",
+    "spin-raw-component-route",
+    "spin-base-path",
+    "spin-client-addr",
+];
+
+/// Respond with how the request was routed unless the path asks for something else.
+///
+/// Requests to `/chain/<authority>/<path>` are forwarded to `http://<authority>/<path>` and
+/// respond with the forwarded response's status and body.
+#[http_component]
+async fn handle(req: Request) -> anyhow::Result<Response> {
+    let path_info = header(&req, "spin-path-info");
+    if let Some(target) = path_info.strip_prefix("/chain/") {
+        let response: Response =
+            spin_sdk::http::send(Request::get(format!("http://{target}"))).await?;
+        return Ok(Response::new(*response.status(), response.into_body()));
+    }
+    match path_info.as_str() {
+        "/fail" => anyhow::bail!("the app was asked to fail"),
+        _ => Ok(Response::new(200, describe(&req))),
+    }
+}
+
+/// Describe the routing of the request with one `name: value` line per routing header.
+fn describe(req: &Request) -> String {
+    ROUTING_HEADERS
+        .iter()
+        .map(|name| format!("{name}: {}", header(req, name)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The value of a header or an empty string if it is missing.
+fn header(req: &Request, name: &str) -> String {
+    req.header(name)
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_owned()
+}
@@ -0,0 +1,34 @@
+//! Responds with the CGI environment variables, arguments and stdin it was run with.
+
+use std::io::Read as _;
+
+/// The CGI environment variables included in the response
+const CGI_VARIABLES: &[&str] = &[
+    "REQUEST_METHOD",
+    "SCRIPT_NAME",
+    "PATH_INFO",
+    "QUERY_STRING",
+    "CONTENT_LENGTH",
+    "CONTENT_TYPE",
+    "SERVER_NAME",
+    "SERVER_PORT",
+    "REMOTE_ADDR",
+    "X_MATCHED_ROUTE",
+    "HTTP_X_GREETING",
+];
+
+fn main() {
+    let mut body = String::new();
+    std::io::stdin()
+        .read_to_string(&mut body)
+        .expect("stdin should be readable");
+
+    println!("Content-Type: text/plain");
+    println!("X-Wagi: true");
+    println!();
+    for name in CGI_VARIABLES {
+        println!("{name}: {}", std::env::var(name).unwrap_or_default());
+    }
+    println!("args: {}", std::env::args().collect::<Vec<_>>().join(" "));
+    print!("body: {body}");
+}
@@ -0,0 +1,38 @@
+//! Tests for the Spin interfaces virtualized by `spin-test`.
+//!
+//! The tests run against the app in `examples/apps/features-rs`.
+
+mod llm;
+mod mqtt;
+mod mysql;
+mod postgres;
+mod redis;
+mod sqlite;
+mod variables;
+
+use spin_test_sdk::bindings::wasi::http;
+
+/// Make a GET request to the app.
+fn get(path: &str) -> http::types::IncomingResponse {
+    request(http::types::Method::Get, path, None)
+}
+
+/// Make a POST request with the given body to the app.
+fn post(path: &str, body: &[u8]) -> http::types::IncomingResponse {
+    request(http::types::Method::Post, path, Some(body))
+}
+
+/// Make a request to the app.
+fn request(
+    method: http::types::Method,
+    path: &str,
+    body: Option<&[u8]>,
+) -> http::types::IncomingResponse {
+    let request = http::types::OutgoingRequest::new(http::types::Headers::new());
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+    if let Some(body) = body {
+        request.body().unwrap().write_bytes(body);
+    }
+    spin_test_sdk::perform_request(request)
+}
@@ -0,0 +1,102 @@
+use spin_test_sdk::{
+    bindings::fermyon::{
+        spin::mqtt::{Error, Qos},
+        spin_test_virt::mqtt,
+    },
+    spin_test,
+};
+
+/// The address of the MQTT broker the app publishes to.
+const ADDRESS: &str = "mqtt://localhost:1883?client_id=features";
+
+#[spin_test]
+fn mqtt_publishes_are_recorded() {
+    assert_eq!(
+        crate::post("/mqtt-publish/events", b"created").status(),
+        202
+    );
+
+    assert_eq!(
+        mqtt::publishes(),
+        vec![(
+            ADDRESS.to_owned(),
+            vec![mqtt::Publish {
+                topic: "events".to_owned(),
+                payload: b"created".to_vec(),
+                qos: Qos::AtLeastOnce,
+                username: "features".to_owned(),
+                keep_alive_interval_in_secs: 30,
+            }]
+        )]
+    );
+}
+
+#[spin_test]
+fn mqtt_publishes_can_be_reset() {
+    crate::post("/mqtt-publish/events", b"created");
+    mqtt::reset_publishes();
+
+    assert!(mqtt::publishes().is_empty());
+}
+
+#[spin_test]
+fn mqtt_brokers_accept_added_credentials() {
+    mqtt::add_credentials(ADDRESS, "features", "secret");
+
+    assert_eq!(
+        crate::post("/mqtt-publish/events", b"created").status(),
+        202
+    );
+}
+
+#[spin_test]
+fn mqtt_brokers_reject_other_credentials() {
+    mqtt::add_credentials(ADDRESS, "features", "hunter2");
+
+    assert_eq!(
+        crate::post("/mqtt-publish/events", b"created").status(),
+        500
+    );
+    assert!(mqtt::publishes().is_empty());
+}
+
+#[spin_test]
+fn mqtt_open_errors_are_injected() {
+    mqtt::set_open_error(ADDRESS, Some(&Error::TooManyConnections));
+    assert_eq!(
+        crate::post("/mqtt-publish/events", b"created").status(),
+        500
+    );
+
+    mqtt::set_open_error(ADDRESS, None);
+    assert_eq!(
+        crate::post("/mqtt-publish/events", b"created").status(),
+        202
+    );
+}
+
+#[spin_test]
+fn mqtt_publish_errors_are_injected() {
+    mqtt::set_publish_error(
+        ADDRESS,
+        Some(&Error::ConnectionFailed("broker is down".to_owned())),
+    );
+
+    assert_eq!(
+        crate::post("/mqtt-publish/events", b"created").status(),
+        500
+    );
+    assert!(mqtt::publishes().is_empty());
+}
+
+#[spin_test]
+fn mqtt_brokers_can_be_reset() {
+    mqtt::add_credentials(ADDRESS, "someone", "else");
+    mqtt::set_publish_error(ADDRESS, Some(&Error::Other("full".to_owned())));
+    mqtt::reset_brokers();
+
+    assert_eq!(
+        crate::post("/mqtt-publish/events", b"created").status(),
+        202
+    );
+}
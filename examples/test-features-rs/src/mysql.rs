@@ -0,0 +1,106 @@
+use spin_test_sdk::{
+    bindings::fermyon::{
+        spin::rdbms_types::{Column, DbDataType, DbValue, ParameterValue, RowSet},
+        spin_test_virt::{mysql, rdbms, types::Matcher},
+    },
+    spin_test,
+};
+
+/// The address of the MySQL database the app uses.
+const ADDRESS: &str = "mysql://localhost:3306/features";
+
+/// Create the pets table with a single pet.
+fn seed() -> mysql::Connection {
+    let db = mysql::Connection::open(ADDRESS).unwrap();
+    db.execute_batch(
+        "CREATE TABLE pets (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+         INSERT INTO pets (name) VALUES ('Rex');",
+    )
+    .unwrap();
+    db
+}
+
+#[spin_test]
+fn mysql_data_seeded_by_the_test_is_seen_by_the_app() {
+    seed();
+
+    let response = crate::get("/mysql/pets/1");
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.body_as_string().unwrap(), "Rex");
+    assert_eq!(crate::get("/mysql/pets/2").status(), 404);
+}
+
+#[spin_test]
+fn mysql_data_written_by_the_app_is_seen_by_the_test() {
+    let db = seed();
+    assert_eq!(crate::post("/mysql/pets", b"Tom").status(), 201);
+
+    let pets = db
+        .query(
+            "SELECT name FROM pets WHERE id = ?",
+            &[ParameterValue::Int64(2)],
+        )
+        .unwrap();
+    assert!(
+        matches!(&pets.rows[..], [row] if matches!(&row[..], [DbValue::Str(name)] if name == "Tom"))
+    );
+}
+
+#[spin_test]
+fn mysql_statements_are_recorded() {
+    seed();
+    crate::get("/mysql/pets/1");
+
+    let calls = rdbms::calls();
+    let (address, calls) = &calls[0];
+    assert_eq!(address, ADDRESS);
+    // Statements run by the test are not recorded
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].statement, "SELECT name FROM pets WHERE id = ?");
+    assert_eq!(calls[0].parameters, vec![ParameterValue::Int64(1)]);
+    assert_eq!(calls[0].result, Ok(1));
+
+    rdbms::reset_calls();
+    assert!(rdbms::calls().is_empty());
+}
+
+#[spin_test]
+fn mysql_rules_match_statement_parameters() {
+    seed();
+    rdbms::add_rule(
+        None,
+        &Matcher::Exact("SELECT name FROM pets WHERE id = ?".to_owned()),
+        Some(&[ParameterValue::Int64(42)]),
+        &rdbms::RuleResponse::Rows(RowSet {
+            columns: vec![Column {
+                name: "name".to_owned(),
+                data_type: DbDataType::Str,
+            }],
+            rows: vec![vec![DbValue::Str("Scripted".to_owned())]],
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        crate::get("/mysql/pets/42").body_as_string().unwrap(),
+        "Scripted"
+    );
+    // Other parameters run against the database
+    assert_eq!(crate::get("/mysql/pets/1").body_as_string().unwrap(), "Rex");
+}
+
+#[spin_test]
+fn mysql_rules_report_changed_rows_without_running_the_statement() {
+    let db = seed();
+    rdbms::add_rule(
+        None,
+        &Matcher::Prefix("INSERT INTO pets".to_owned()),
+        None,
+        &rdbms::RuleResponse::Changed(1),
+    )
+    .unwrap();
+
+    assert_eq!(crate::post("/mysql/pets", b"Tom").status(), 201);
+    let pets = db.query("SELECT name FROM pets", &[]).unwrap();
+    assert_eq!(pets.rows.len(), 1);
+}
@@ -0,0 +1,161 @@
+use spin_test_sdk::{
+    bindings::fermyon::{
+        spin::rdbms_types::{Column, DbDataType, DbValue, Error, ParameterValue, RowSet},
+        spin_test_virt::{postgres, rdbms, types::Matcher},
+    },
+    spin_test,
+};
+
+/// The address of the Postgres database the app uses.
+const ADDRESS: &str = "postgres://localhost:5432/features";
+
+/// Create the notes table with a single note.
+fn seed() -> postgres::Connection {
+    let db = postgres::Connection::open(ADDRESS).unwrap();
+    db.execute_batch(
+        "CREATE TABLE notes (body TEXT NOT NULL);
+         INSERT INTO notes (body) VALUES ('buy milk');",
+    )
+    .unwrap();
+    db
+}
+
+#[spin_test]
+fn postgres_data_seeded_by_the_test_is_seen_by_the_app() {
+    seed();
+
+    let response = crate::get("/pg/notes");
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.body_as_string().unwrap(), "buy milk");
+}
+
+#[spin_test]
+fn postgres_data_written_by_the_app_is_seen_by_the_test() {
+    let db = seed();
+    assert_eq!(crate::post("/pg/notes", b"add tests").status(), 201);
+
+    // The test's statements use Postgres' placeholders too
+    let notes = db
+        .query(
+            "SELECT body FROM notes WHERE body <> $1",
+            &[ParameterValue::Str("buy milk".to_owned())],
+        )
+        .unwrap();
+    assert!(
+        matches!(&notes.rows[..], [row] if matches!(&row[..], [DbValue::Str(body)] if body == "add tests"))
+    );
+}
+
+#[spin_test]
+fn postgres_statements_fail_without_a_seeded_table() {
+    assert_eq!(crate::get("/pg/notes").status(), 500);
+}
+
+#[spin_test]
+fn postgres_statements_are_recorded() {
+    seed();
+    assert_eq!(crate::post("/pg/notes", b"add tests").status(), 201);
+
+    let calls = rdbms::calls();
+    let (address, calls) = &calls[0];
+    assert_eq!(address, ADDRESS);
+    // Statements run by the test are not recorded
+    assert_eq!(calls.len(), 1);
+    // The statement is recorded as the app wrote it, before placeholders are translated
+    assert_eq!(calls[0].statement, "INSERT INTO notes (body) VALUES ($1)");
+    assert_eq!(
+        calls[0].parameters,
+        vec![ParameterValue::Str("add tests".to_owned())]
+    );
+    assert_eq!(calls[0].result, Ok(1));
+}
+
+#[spin_test]
+fn postgres_failing_statements_are_recorded() {
+    assert_eq!(crate::get("/pg/notes").status(), 500);
+
+    let calls = rdbms::calls();
+    assert!(calls[0].1[0].result.is_err());
+}
+
+#[spin_test]
+fn postgres_rules_script_rows_without_a_table() {
+    rdbms::add_rule(
+        Some(ADDRESS),
+        &Matcher::Regex("^SELECT body FROM notes".to_owned()),
+        None,
+        &rdbms::RuleResponse::Rows(RowSet {
+            columns: vec![Column {
+                name: "body".to_owned(),
+                data_type: DbDataType::Str,
+            }],
+            rows: vec![vec![DbValue::Str("scripted".to_owned())]],
+        }),
+    )
+    .unwrap();
+
+    let response = crate::get("/pg/notes");
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.body_as_string().unwrap(), "scripted");
+    assert_eq!(rdbms::calls()[0].1[0].result, Ok(1));
+}
+
+#[spin_test]
+fn postgres_rules_inject_errors() {
+    seed();
+    rdbms::add_rule(
+        None,
+        &Matcher::Prefix("INSERT INTO notes".to_owned()),
+        None,
+        &rdbms::RuleResponse::Error(Error::QueryFailed("disk full".to_owned())),
+    )
+    .unwrap();
+
+    assert_eq!(crate::post("/pg/notes", b"add tests").status(), 500);
+    let calls = rdbms::calls();
+    assert_eq!(
+        calls[0].1[0].result,
+        Err(Error::QueryFailed("disk full".to_owned()))
+    );
+}
+
+#[spin_test]
+fn postgres_rules_only_apply_to_their_address() {
+    seed();
+    rdbms::add_rule(
+        Some("postgres://elsewhere:5432/features"),
+        &Matcher::Prefix("INSERT".to_owned()),
+        None,
+        &rdbms::RuleResponse::Error(Error::Other("unreachable".to_owned())),
+    )
+    .unwrap();
+
+    assert_eq!(crate::post("/pg/notes", b"add tests").status(), 201);
+}
+
+#[spin_test]
+fn postgres_rules_can_be_cleared() {
+    seed();
+    rdbms::add_rule(
+        None,
+        &Matcher::Prefix("INSERT".to_owned()),
+        None,
+        &rdbms::RuleResponse::Error(Error::Other("read only".to_owned())),
+    )
+    .unwrap();
+    assert_eq!(crate::post("/pg/notes", b"add tests").status(), 500);
+
+    rdbms::clear_rules();
+    assert_eq!(crate::post("/pg/notes", b"add tests").status(), 201);
+}
+
+#[spin_test]
+fn postgres_rules_reject_invalid_regexes() {
+    let result = rdbms::add_rule(
+        None,
+        &Matcher::Regex("(".to_owned()),
+        None,
+        &rdbms::RuleResponse::Changed(0),
+    );
+    assert!(result.is_err());
+}
@@ -0,0 +1,232 @@
+use spin_test_sdk::{
+    bindings::fermyon::spin_test_virt::{clock, redis, types::Matcher},
+    spin_test,
+};
+
+/// The address of the Redis database the app uses.
+const ADDRESS: &str = "redis://localhost:6379";
+
+#[spin_test]
+fn redis_data_seeded_by_the_test_is_seen_by_the_app() {
+    let db = redis::Connection::open(ADDRESS);
+    db.set("greeting", &b"hello".to_vec());
+
+    let response = crate::get("/redis/greeting");
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.body_as_string().unwrap(), "hello");
+    assert_eq!(db.calls(), vec![redis::Call::Get("greeting".to_owned())]);
+}
+
+#[spin_test]
+fn redis_data_written_by_the_app_is_seen_by_the_test() {
+    let response = crate::request(
+        spin_test_sdk::bindings::wasi::http::types::Method::Put,
+        "/redis/greeting",
+        Some(b"hi"),
+    );
+    assert_eq!(response.status(), 204);
+
+    let db = redis::Connection::open(ADDRESS);
+    assert_eq!(db.get("greeting").unwrap().as_deref(), Some(&b"hi"[..]));
+    assert_eq!(db.keys(), vec!["greeting".to_owned()]);
+}
+
+#[spin_test]
+fn redis_data_is_kept_per_address() {
+    redis::Connection::open("redis://elsewhere:6379").set("greeting", &b"hello".to_vec());
+
+    assert_eq!(crate::get("/redis/greeting").status(), 404);
+}
+
+#[spin_test]
+fn redis_calls_can_be_reset() {
+    crate::get("/redis/greeting");
+    redis::reset_calls();
+
+    assert!(redis::Connection::open(ADDRESS).calls().is_empty());
+}
+
+/// Run a command through the app's Redis `execute` route and return the response lines.
+fn execute(command: &str) -> String {
+    let response = crate::post("/redis-execute", command.as_bytes());
+    assert_eq!(response.status(), 200);
+    response.body_as_string().unwrap()
+}
+
+#[spin_test]
+fn redis_execute_supports_hashes_and_lists() {
+    assert_eq!(execute("HSET user name Till"), "1");
+    assert_eq!(execute("HGET user name"), "Till");
+    assert_eq!(execute("RPUSH queue a b"), "2");
+    assert_eq!(execute("LPUSH queue z"), "3");
+    assert_eq!(execute("LRANGE queue 0 -1"), "z\na\nb");
+    assert_eq!(execute("HDEL user name"), "1");
+
+    let db = redis::Connection::open(ADDRESS);
+    assert_eq!(db.keys(), vec!["queue".to_owned()]);
+}
+
+#[spin_test]
+fn redis_keys_expire_when_the_clock_advances() {
+    assert_eq!(execute("SET session abc"), "OK");
+    assert_eq!(execute("EXPIRE session 10"), "1");
+    assert_eq!(execute("TTL session"), "10");
+
+    clock::advance(5_000_000_000);
+    assert_eq!(execute("TTL session"), "5");
+
+    clock::advance(5_000_000_000);
+    assert_eq!(execute("TTL session"), "-2");
+    assert!(redis::Connection::open(ADDRESS).keys().is_empty());
+}
+
+#[spin_test]
+fn redis_srem_removes_emptied_sets() {
+    let db = redis::Connection::open(ADDRESS);
+    db.sadd("tags", &["rust".to_owned()]).unwrap();
+
+    assert_eq!(execute("SREM tags rust"), "1");
+    assert!(db.keys().is_empty());
+    assert_eq!(execute("EXISTS tags"), "0");
+}
+
+#[spin_test]
+fn redis_execute_rules_script_results() {
+    redis::add_execute_rule(
+        None,
+        "get",
+        Some(&Matcher::Exact("answer".to_owned())),
+        &redis::ExecuteResponse::Result(vec![redis::RedisResult::Binary(b"42".to_vec())]),
+    )
+    .unwrap();
+
+    assert_eq!(execute("GET answer"), "42");
+    // Commands not matching the rule run against the database
+    assert_eq!(execute("GET question"), "nil");
+    assert_eq!(
+        redis::Connection::open(ADDRESS).calls()[0],
+        redis::Call::Execute((
+            "GET".to_owned(),
+            vec![redis::RedisParameter::Binary(b"answer".to_vec())]
+        ))
+    );
+}
+
+#[spin_test]
+fn redis_execute_rules_inject_errors() {
+    redis::add_execute_rule(
+        Some(ADDRESS),
+        "INCR",
+        None,
+        &redis::ExecuteResponse::Error(redis::Error::Other("ERR out of memory".to_owned())),
+    )
+    .unwrap();
+
+    assert_eq!(crate::post("/redis-execute", b"INCR hits").status(), 500);
+    assert!(redis::Connection::open(ADDRESS).keys().is_empty());
+}
+
+#[spin_test]
+fn redis_execute_rules_only_apply_to_their_address() {
+    redis::add_execute_rule(
+        Some("redis://elsewhere:6379"),
+        "SET",
+        Some(&Matcher::Prefix("greeting ".to_owned())),
+        &redis::ExecuteResponse::Error(redis::Error::TypeError),
+    )
+    .unwrap();
+
+    assert_eq!(execute("SET greeting hello"), "OK");
+}
+
+#[spin_test]
+fn redis_execute_rules_can_be_cleared() {
+    redis::add_execute_rule(
+        None,
+        "GET",
+        Some(&Matcher::Regex("^gr.*$".to_owned())),
+        &redis::ExecuteResponse::Result(vec![redis::RedisResult::Nil]),
+    )
+    .unwrap();
+    redis::Connection::open(ADDRESS).set("greeting", &b"hello".to_vec());
+    assert_eq!(execute("GET greeting"), "nil");
+
+    redis::clear_execute_rules();
+    assert_eq!(execute("GET greeting"), "hello");
+}
+
+#[spin_test]
+fn redis_execute_rules_reject_invalid_regexes() {
+    let result = redis::add_execute_rule(
+        None,
+        "GET",
+        Some(&Matcher::Regex("(".to_owned())),
+        &redis::ExecuteResponse::Result(vec![]),
+    );
+    assert!(result.is_err());
+}
+
+#[spin_test]
+fn redis_incr_persists_the_counter() {
+    assert_eq!(
+        crate::post("/redis-incr/hits", b"")
+            .body_as_string()
+            .unwrap(),
+        "1"
+    );
+    assert_eq!(
+        crate::post("/redis-incr/hits", b"")
+            .body_as_string()
+            .unwrap(),
+        "2"
+    );
+
+    let db = redis::Connection::open(ADDRESS);
+    assert_eq!(db.get("hits").unwrap().as_deref(), Some(&b"2"[..]));
+    assert_eq!(
+        db.calls(),
+        vec![
+            redis::Call::Incr("hits".to_owned()),
+            redis::Call::Incr("hits".to_owned())
+        ]
+    );
+}
+
+#[spin_test]
+fn redis_incr_shares_the_counter_with_execute() {
+    redis::Connection::open(ADDRESS).set("hits", &b"10".to_vec());
+
+    assert_eq!(execute("INCRBY hits 5"), "15");
+    assert_eq!(execute("DECR hits"), "14");
+    assert_eq!(execute("DECRBY hits 4"), "10");
+    assert_eq!(
+        crate::post("/redis-incr/hits", b"")
+            .body_as_string()
+            .unwrap(),
+        "11"
+    );
+    assert_eq!(execute("GET hits"), "11");
+}
+
+#[spin_test]
+fn redis_incr_rejects_values_that_are_not_integers() {
+    redis::Connection::open(ADDRESS).set("greeting", &b"hello".to_vec());
+
+    assert_eq!(crate::post("/redis-incr/greeting", b"").status(), 500);
+}
+
+#[spin_test]
+fn redis_publishes_are_recorded() {
+    assert_eq!(
+        crate::post("/redis-publish/events", b"created").status(),
+        202
+    );
+
+    assert_eq!(
+        redis::Connection::open(ADDRESS).calls(),
+        vec![redis::Call::Publish((
+            "events".to_owned(),
+            b"created".to_vec()
+        ))]
+    );
+}
@@ -0,0 +1,169 @@
+use spin_test_sdk::{
+    bindings::fermyon::{
+        spin::sqlite::RowResult,
+        spin_test_virt::{sqlite, types},
+    },
+    spin_test,
+};
+
+#[spin_test]
+fn sqlite_migrations_are_applied() {
+    // `migrations/seed.sql` adds the first user
+    let response = crate::get("/users/1");
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.body_as_string().unwrap(), "Till");
+
+    // Statements run by migrations are not recorded as calls made by the app
+    let calls = sqlite::calls();
+    let (_, calls) = calls.iter().find(|(db, _)| db == "default").unwrap();
+    assert_eq!(calls.len(), 1);
+}
+
+#[spin_test]
+fn sqlite_statements_are_recorded() {
+    assert_eq!(crate::post("/users", b"Ryan").status(), 201);
+    let response = crate::get("/users/2");
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.body_as_string().unwrap(), "Ryan");
+
+    assert_eq!(
+        sqlite::calls(),
+        vec![(
+            "default".to_owned(),
+            vec![
+                sqlite::Call {
+                    statement: "INSERT INTO users (name) VALUES (?)".to_owned(),
+                    parameters: vec![sqlite::Value::Text("Ryan".to_owned())],
+                    result: Ok(0),
+                },
+                sqlite::Call {
+                    statement: "SELECT name FROM users WHERE id = ?".to_owned(),
+                    parameters: vec![sqlite::Value::Integer(2)],
+                    result: Ok(1),
+                },
+            ]
+        )]
+    );
+}
+
+#[spin_test]
+fn sqlite_calls_can_be_reset() {
+    crate::get("/users/1");
+    sqlite::reset_calls();
+    crate::get("/users/3");
+
+    let calls = sqlite::calls();
+    let (_, calls) = calls.iter().find(|(db, _)| db == "default").unwrap();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].parameters, vec![sqlite::Value::Integer(3)]);
+    assert_eq!(calls[0].result, Ok(0));
+}
+
+#[spin_test]
+fn sqlite_rules_inject_errors() {
+    sqlite::add_rule(
+        None,
+        &types::Matcher::Prefix("INSERT INTO users".to_owned()),
+        &sqlite::RuleResponse::Error(sqlite::Error::DatabaseFull),
+    )
+    .unwrap();
+
+    assert_eq!(crate::post("/users", b"Ryan").status(), 500);
+
+    let calls = sqlite::calls();
+    let (_, calls) = calls.iter().find(|(db, _)| db == "default").unwrap();
+    assert_eq!(calls[0].result, Err(sqlite::Error::DatabaseFull));
+}
+
+#[spin_test]
+fn sqlite_rules_script_query_results() {
+    // There is no user 42 so the result must come from the rule
+    sqlite::add_rule(
+        Some("default"),
+        &types::Matcher::Regex(r"^SELECT name FROM users WHERE id = \?$".to_owned()),
+        &sqlite::RuleResponse::Result(sqlite::QueryResult {
+            columns: vec!["name".to_owned()],
+            rows: vec![RowResult {
+                values: vec![sqlite::Value::Text("Scripted".to_owned())],
+            }],
+        }),
+    )
+    .unwrap();
+
+    let response = crate::get("/users/42");
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.body_as_string().unwrap(), "Scripted");
+}
+
+#[spin_test]
+fn sqlite_rules_only_apply_to_their_database() {
+    sqlite::add_rule(
+        Some("other"),
+        &types::Matcher::Exact("SELECT name FROM users WHERE id = ?".to_owned()),
+        &sqlite::RuleResponse::Error(sqlite::Error::AccessDenied),
+    )
+    .unwrap();
+
+    assert_eq!(crate::get("/users/1").status(), 200);
+}
+
+#[spin_test]
+fn sqlite_rules_can_be_cleared() {
+    sqlite::add_rule(
+        None,
+        &types::Matcher::Prefix("SELECT".to_owned()),
+        &sqlite::RuleResponse::Error(sqlite::Error::Io("disk on fire".to_owned())),
+    )
+    .unwrap();
+    assert_eq!(crate::get("/users/1").status(), 500);
+
+    sqlite::clear_rules();
+    assert_eq!(crate::get("/users/1").status(), 200);
+}
+
+#[spin_test]
+fn sqlite_rules_reject_invalid_regexes() {
+    let result = sqlite::add_rule(
+        None,
+        &types::Matcher::Regex("(".to_owned()),
+        &sqlite::RuleResponse::Error(sqlite::Error::DatabaseFull),
+    );
+    assert!(result.is_err());
+}
+
+#[spin_test]
+fn sqlite_databases_are_isolated_per_test() {
+    assert_user_added_after_seeded_user("Isolated");
+}
+
+#[spin_test]
+fn sqlite_databases_are_isolated_from_other_tests() {
+    assert_user_added_after_seeded_user("Separate");
+}
+
+/// Add a user and check it directly follows the user added by the migrations
+///
+/// Whichever of the tests calling this runs second would see its user get id 3 if the tests shared
+/// a database.
+fn assert_user_added_after_seeded_user(name: &str) {
+    assert_eq!(crate::post("/users", name.as_bytes()).status(), 201);
+    assert_eq!(crate::get("/users/2").body_as_string().unwrap(), name);
+}
+
+#[spin_test]
+fn sqlite_snapshots_can_be_restored() {
+    let snapshot = sqlite::take_snapshot().unwrap();
+    assert_eq!(crate::post("/users", b"Ryan").status(), 201);
+    assert_eq!(crate::get("/users/2").status(), 200);
+
+    sqlite::restore_snapshot(&snapshot).unwrap();
+    assert_eq!(crate::get("/users/2").status(), 404);
+    assert_eq!(crate::get("/users/1").status(), 200);
+}
+
+#[spin_test]
+fn sqlite_databases_can_be_reset() {
+    sqlite::reset().unwrap();
+    // The `users` table created by the migrations is gone
+    assert_eq!(crate::get("/users/1").status(), 500);
+}
@@ -0,0 +1,103 @@
+use spin_test_sdk::{
+    bindings::fermyon::{spin::variables::Error, spin_test_virt::variables},
+    spin_test,
+};
+
+#[spin_test]
+fn variables_resolve_to_their_defaults() {
+    let response = crate::get("/variables/greeting");
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.body_as_string().unwrap(), "hello");
+}
+
+#[spin_test]
+fn variables_set_by_the_test_take_precedence() {
+    variables::set("greeting", "hi");
+
+    assert_eq!(
+        crate::get("/variables/greeting").body_as_string().unwrap(),
+        "hi"
+    );
+}
+
+#[spin_test]
+fn variables_can_be_unset() {
+    variables::set("api_key", "s3cr3t");
+    assert_eq!(crate::get("/variables/api_key").status(), 200);
+
+    variables::unset("api_key");
+    assert_eq!(crate::get("/variables/api_key").status(), 404);
+}
+
+#[spin_test]
+fn variables_can_be_cleared() {
+    variables::set("greeting", "hi");
+    variables::set("api_key", "s3cr3t");
+    variables::clear();
+
+    assert_eq!(
+        crate::get("/variables/greeting").body_as_string().unwrap(),
+        "hello"
+    );
+    assert_eq!(crate::get("/variables/api_key").status(), 404);
+}
+
+#[spin_test]
+fn variable_lookups_are_recorded_with_secrets_redacted() {
+    variables::set("api_key", "s3cr3t");
+    crate::get("/variables/greeting");
+    crate::get("/variables/api_key");
+    crate::get("/variables/missing");
+
+    assert_eq!(
+        variables::calls(),
+        vec![
+            variables::Call {
+                name: "greeting".to_owned(),
+                result: Ok("hello".to_owned()),
+            },
+            variables::Call {
+                name: "api_key".to_owned(),
+                result: Ok("<redacted>".to_owned()),
+            },
+            variables::Call {
+                name: "missing".to_owned(),
+                result: Err(Error::Undefined("missing".to_owned())),
+            },
+        ]
+    );
+
+    variables::reset_calls();
+    assert!(variables::calls().is_empty());
+}
+
+#[spin_test]
+fn variables_are_provided_from_the_dotenv_file() {
+    // `SPIN_VARIABLE_REGION` is set in the `.env` file next to the app's `spin.toml`
+    assert_eq!(
+        crate::get("/variables/region").body_as_string().unwrap(),
+        "eu-west"
+    );
+}
+
+#[spin_test]
+fn variables_provided_to_spin_test_survive_clearing_overrides() {
+    variables::set("region", "us-east");
+    assert_eq!(
+        crate::get("/variables/region").body_as_string().unwrap(),
+        "us-east"
+    );
+
+    variables::unset("region");
+    assert_eq!(
+        crate::get("/variables/region").body_as_string().unwrap(),
+        "eu-west"
+    );
+
+    variables::set("region", "us-east");
+    variables::clear();
+    assert_eq!(
+        crate::get("/variables/region").body_as_string().unwrap(),
+        "eu-west"
+    );
+}
@@ -0,0 +1,17 @@
+//! A test which fails on purpose so that `spin-test --keep-state` has state to keep.
+//!
+//! The test runs against the app in `examples/apps/features-rs`.
+
+use spin_test_sdk::{bindings::wasi::http, spin_test};
+
+#[spin_test]
+fn failing_test_keeps_its_sqlite_state() {
+    let request = http::types::OutgoingRequest::new(http::types::Headers::new());
+    request.set_method(&http::types::Method::Post).unwrap();
+    request.set_path_with_query(Some("/users")).unwrap();
+    request.body().unwrap().write_bytes(b"Kept");
+    let response = spin_test_sdk::perform_request(request);
+    assert_eq!(response.status(), 201);
+
+    panic!("this test fails on purpose so that the user it added is kept");
+}
@@ -0,0 +1,54 @@
+//! Tests for how `spin-test` delivers Redis messages to an app.
+//!
+//! The tests run against the app in `examples/apps/redis-rs`.
+
+mod trigger_exports;
+
+use spin_test_sdk::{
+    bindings::{
+        fermyon::{spin_test::redis_trigger, spin_test_virt::redis},
+        wasi::http,
+    },
+    spin_test,
+};
+
+/// The address of the Redis instance the app subscribes to.
+const ADDRESS: &str = "redis://localhost:6379";
+
+#[spin_test]
+fn messages_are_delivered_to_the_app() {
+    redis_trigger::publish(None, "messages", b"hello").unwrap();
+    redis_trigger::publish(Some(ADDRESS), "messages", b"again").unwrap();
+
+    let db = redis::Connection::open(ADDRESS);
+    assert_eq!(
+        db.get("last-message").unwrap().as_deref(),
+        Some(&b"again"[..])
+    );
+    assert_eq!(db.get("message-count").unwrap().as_deref(), Some(&b"2"[..]));
+}
+
+#[spin_test]
+fn messages_on_other_channels_are_rejected() {
+    let error = redis_trigger::publish(None, "elsewhere", b"hello").unwrap_err();
+    assert!(error.contains("channel 'elsewhere'"), "{error}");
+    assert!(redis::Connection::open(ADDRESS).keys().is_empty());
+}
+
+#[spin_test]
+fn messages_on_other_addresses_are_rejected() {
+    assert!(redis_trigger::publish(Some("redis://elsewhere:6379"), "messages", b"hello").is_err());
+}
+
+#[spin_test]
+fn handler_errors_are_reported() {
+    let error = redis_trigger::publish(None, "messages", b"fail").unwrap_err();
+    assert!(error.contains("failed to handle the message"), "{error}");
+}
+
+#[spin_test]
+fn http_requests_to_redis_apps_respond_with_server_error() {
+    let request = http::types::OutgoingRequest::new(http::types::Headers::new());
+    request.set_path_with_query(Some("/")).unwrap();
+    assert_eq!(spin_test_sdk::perform_request(request).status(), 500);
+}
@@ -0,0 +1,47 @@
+//! Calling the app's Redis handler directly through `spin-test.trigger-exports`, the way tests
+//! invoke triggers `spin-test` has no built-in support for.
+
+use spin_test_sdk::{bindings::fermyon::spin_test_virt::redis, spin_test};
+
+mod bindings {
+    spin_test_sdk::wit_bindgen::generate!({
+        inline: r#"
+            package fermyon:spin;
+
+            interface inbound-redis {
+                enum error {
+                    success,
+                    error,
+                }
+                type payload = list<u8>;
+                handle-message: func(message: payload) -> result<_, error>;
+            }
+
+            world trigger {
+                import inbound-redis;
+            }
+        "#,
+        runtime_path: "::spin_test_sdk::wit_bindgen::rt",
+    });
+}
+
+use bindings::fermyon::spin::inbound_redis;
+
+#[spin_test]
+fn trigger_exports_can_be_called_directly() {
+    inbound_redis::handle_message(&b"direct".to_vec()).unwrap();
+
+    let db = redis::Connection::open(crate::ADDRESS);
+    assert_eq!(
+        db.get("last-message").unwrap().as_deref(),
+        Some(&b"direct"[..])
+    );
+}
+
+#[spin_test]
+fn trigger_export_errors_are_returned() {
+    assert_eq!(
+        inbound_redis::handle_message(&b"fail".to_vec()),
+        Err(inbound_redis::Error::Error)
+    );
+}
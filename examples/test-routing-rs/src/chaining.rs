@@ -0,0 +1,51 @@
+use spin_test_sdk::{bindings::fermyon::spin_wasi_virt::http_handler, spin_test};
+
+/// The method and url of the requests the app made to itself.
+fn internal_requests() -> Vec<(String, String)> {
+    http_handler::internal_requests()
+        .into_iter()
+        .map(|r| (r.method, r.url))
+        .collect()
+}
+
+#[spin_test]
+fn requests_to_self_are_routed_back_into_the_app() {
+    let response = crate::get("/hello/chain/self/hello/world");
+    assert_eq!(response.status(), 200);
+    let body = response.body_as_string().unwrap();
+    assert_eq!(crate::routing_header(&body, "spin-path-info"), "/world");
+    assert_eq!(
+        internal_requests(),
+        vec![("GET".to_owned(), "http://self/hello/world".to_owned())]
+    );
+}
+
+#[spin_test]
+fn requests_to_spin_internal_reach_the_component() {
+    // The component is reached by its id whatever the path, as for its private route
+    let response = crate::get("/hello/chain/routing.spin.internal/anything");
+    assert_eq!(response.status(), 200);
+    let body = response.body_as_string().unwrap();
+    assert_eq!(crate::routing_header(&body, "spin-path-info"), "/anything");
+    assert_eq!(
+        internal_requests(),
+        vec![(
+            "GET".to_owned(),
+            "http://routing.spin.internal/anything".to_owned()
+        )]
+    );
+}
+
+#[spin_test]
+fn chaining_is_only_one_level_deep() {
+    // The chained request's own request to `self` isn't routed back into the app
+    let response = crate::get("/hello/chain/self/hello/chain/self/hello/world");
+    assert_eq!(response.status(), 500);
+    assert_eq!(
+        internal_requests(),
+        vec![(
+            "GET".to_owned(),
+            "http://self/hello/chain/self/hello/world".to_owned()
+        )]
+    );
+}
@@ -0,0 +1,44 @@
+use spin_test_sdk::{bindings::fermyon::spin_test::redis_trigger, spin_test};
+
+#[spin_test]
+fn unknown_paths_are_not_found() {
+    let response = crate::get("/goodbye");
+    assert_eq!(response.status(), 404);
+    assert_eq!(response.body_as_string().unwrap(), "");
+}
+
+#[spin_test]
+fn matching_paths_reach_the_app() {
+    let response = crate::get("/hello/world");
+    assert_eq!(response.status(), 200);
+    let body = response.body_as_string().unwrap();
+    assert_eq!(crate::routing_header(&body, "spin-path-info"), "/world");
+    assert_eq!(
+        crate::routing_header(&body, "spin-matched-route"),
+        "/hello/..."
+    );
+}
+
+#[spin_test]
+fn app_errors_respond_with_server_error() {
+    let response = crate::get("/hello/fail");
+    assert_eq!(response.status(), 500);
+}
+
+#[spin_test]
+fn wagi_routes_to_non_wagi_apps_respond_with_server_error() {
+    let response = crate::get("/wagi/anything");
+    assert_eq!(response.status(), 500);
+    assert_eq!(response.body_as_string().unwrap(), "");
+}
+
+#[spin_test]
+fn redis_messages_to_non_redis_apps_fail() {
+    let error =
+        redis_trigger::publish(Some("redis://localhost:6379"), "messages", b"hello").unwrap_err();
+    assert!(error.contains("failed to handle the message"), "{error}");
+    assert!(
+        error.contains("does not export `fermyon:spin/inbound-redis`"),
+        "{error}"
+    );
+}
@@ -0,0 +1,24 @@
+//! Tests for how `spin-test` routes requests into an app.
+//!
+//! The tests run against the app in `examples/apps/routing-rs`.
+
+mod chaining;
+mod errors;
+mod origin;
+mod well_known;
+
+use spin_test_sdk::bindings::wasi::http;
+
+/// Make a GET request to the app.
+fn get(path: &str) -> http::types::IncomingResponse {
+    let request = http::types::OutgoingRequest::new(http::types::Headers::new());
+    request.set_path_with_query(Some(path)).unwrap();
+    spin_test_sdk::perform_request(request)
+}
+
+/// The value of a routing header the app reported in the body of its response.
+fn routing_header<'a>(body: &'a str, name: &str) -> &'a str {
+    body.lines()
+        .find_map(|line| line.strip_prefix(name)?.strip_prefix(": "))
+        .unwrap_or_else(|| panic!("the app did not report header '{name}'"))
+}
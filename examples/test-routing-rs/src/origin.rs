@@ -0,0 +1,73 @@
+use spin_test_sdk::{bindings::wasi::http, spin_test, RequestOptions};
+
+/// Make a GET request to `/hello/world` with the given headers and options.
+fn get_with(headers: &[(&str, &str)], options: RequestOptions<'_>) -> String {
+    let fields = http::types::Headers::new();
+    for (name, value) in headers {
+        fields
+            .append(&(*name).to_owned(), &value.as_bytes().to_vec())
+            .unwrap();
+    }
+    let request = http::types::OutgoingRequest::new(fields);
+    request.set_path_with_query(Some("/hello/world")).unwrap();
+    let response = spin_test_sdk::perform_request_with(request, options);
+    assert_eq!(response.status(), 200);
+    response.body_as_string().unwrap()
+}
+
+#[spin_test]
+fn requests_come_from_the_local_machine_by_default() {
+    let body = crate::get("/hello/world").body_as_string().unwrap();
+    assert_eq!(
+        crate::routing_header(&body, "spin-client-addr"),
+        "127.0.0.1:0"
+    );
+    assert_eq!(
+        crate::routing_header(&body, "spin-full-url"),
+        "http://localhost:3000/hello/world"
+    );
+}
+
+#[spin_test]
+fn client_addr_can_be_set_per_request() {
+    let request = http::types::OutgoingRequest::new(http::types::Headers::new());
+    request.set_path_with_query(Some("/hello/world")).unwrap();
+    let response = spin_test_sdk::perform_request_from(request, "203.0.113.7:51234");
+    let body = response.body_as_string().unwrap();
+    assert_eq!(
+        crate::routing_header(&body, "spin-client-addr"),
+        "203.0.113.7:51234"
+    );
+}
+
+#[spin_test]
+fn tls_and_listen_addr_make_up_the_full_url() {
+    let body = get_with(
+        &[],
+        RequestOptions {
+            tls: Some(true),
+            listen_addr: Some("0.0.0.0:8443"),
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        crate::routing_header(&body, "spin-full-url"),
+        "https://0.0.0.0:8443/hello/world"
+    );
+}
+
+#[spin_test]
+fn host_header_takes_precedence_over_listen_addr() {
+    let body = get_with(
+        &[("host", "example.com")],
+        RequestOptions {
+            tls: Some(false),
+            listen_addr: Some("0.0.0.0:8080"),
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        crate::routing_header(&body, "spin-full-url"),
+        "http://example.com/hello/world"
+    );
+}
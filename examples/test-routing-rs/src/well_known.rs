@@ -0,0 +1,37 @@
+use spin_test_sdk::spin_test;
+
+#[spin_test]
+fn health_endpoint_is_served_by_spin() {
+    let response = crate::get("/.well-known/spin/health");
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.body_as_string().unwrap(), "OK");
+}
+
+#[spin_test]
+fn info_endpoint_describes_the_app() {
+    let response = crate::get("/.well-known/spin/info");
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response.headers().get(&"content-type".to_owned()),
+        vec![b"application/json".to_vec()]
+    );
+    let body = response.body_as_string().unwrap();
+    assert!(body.contains(r#""name": "routing""#), "{body}");
+    assert!(body.contains(r#""version": "0.1.0""#), "{body}");
+}
+
+#[spin_test]
+fn unknown_well_known_endpoints_are_not_found() {
+    assert_eq!(crate::get("/.well-known/spin/unknown").status(), 404);
+}
+
+#[spin_test]
+fn other_well_known_paths_reach_the_app() {
+    let response = crate::get("/.well-known/security.txt");
+    assert_eq!(response.status(), 200);
+    let body = response.body_as_string().unwrap();
+    assert_eq!(
+        crate::routing_header(&body, "spin-matched-route"),
+        "/.well-known/..."
+    );
+}
@@ -0,0 +1,115 @@
+//! Tests for how `spin-test` runs WAGI components.
+//!
+//! The tests run against the app in `examples/apps/wagi-rs`.
+
+use spin_test_sdk::{bindings::wasi::http, spin_test, RequestOptions};
+
+/// Make a request to the app.
+fn request(
+    method: http::types::Method,
+    path: &str,
+    headers: &[(&str, &str)],
+    body: &[u8],
+) -> http::types::IncomingResponse {
+    request_with(method, path, headers, body, RequestOptions::default())
+}
+
+/// Make a request to the app with the given options.
+fn request_with(
+    method: http::types::Method,
+    path: &str,
+    headers: &[(&str, &str)],
+    body: &[u8],
+    options: RequestOptions<'_>,
+) -> http::types::IncomingResponse {
+    let fields = http::types::Headers::new();
+    for (name, value) in headers {
+        fields
+            .append(&(*name).to_owned(), &value.as_bytes().to_vec())
+            .unwrap();
+    }
+    let request = http::types::OutgoingRequest::new(fields);
+    request.set_method(&method).unwrap();
+    request.set_path_with_query(Some(path)).unwrap();
+    request.body().unwrap().write_bytes(body);
+    spin_test_sdk::perform_request_with(request, options)
+}
+
+/// The value the app reported for a line of its response body.
+fn reported<'a>(body: &'a str, name: &str) -> &'a str {
+    body.lines()
+        .find_map(|line| line.strip_prefix(name)?.strip_prefix(": "))
+        .unwrap_or_else(|| panic!("the app did not report '{name}'"))
+}
+
+#[spin_test]
+fn wagi_request_is_passed_through_cgi() {
+    let response = request(
+        http::types::Method::Post,
+        "/echo/path?a=1&b=2",
+        &[("content-type", "text/plain"), ("x-greeting", "hi")],
+        b"hello wagi",
+    );
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response.headers().get(&"x-wagi".to_owned()),
+        vec![b"true".to_vec()]
+    );
+    let body = response.body_as_string().unwrap();
+    assert_eq!(reported(&body, "REQUEST_METHOD"), "POST");
+    assert_eq!(reported(&body, "SCRIPT_NAME"), "/echo");
+    assert_eq!(reported(&body, "PATH_INFO"), "/path");
+    assert_eq!(reported(&body, "QUERY_STRING"), "a=1&b=2");
+    assert_eq!(reported(&body, "CONTENT_LENGTH"), "10");
+    assert_eq!(reported(&body, "CONTENT_TYPE"), "text/plain");
+    assert_eq!(reported(&body, "X_MATCHED_ROUTE"), "/echo/...");
+    assert_eq!(reported(&body, "HTTP_X_GREETING"), "hi");
+    assert_eq!(reported(&body, "args"), "/echo a=1 b=2");
+    assert_eq!(reported(&body, "body"), "hello wagi");
+}
+
+#[spin_test]
+fn wagi_component_handles_several_requests_per_test() {
+    let first = request(http::types::Method::Post, "/echo/first", &[], b"one");
+    assert_eq!(first.status(), 200);
+    let body = first.body_as_string().unwrap();
+    assert_eq!(reported(&body, "PATH_INFO"), "/first");
+    assert_eq!(reported(&body, "body"), "one");
+
+    let second = request(http::types::Method::Post, "/echo/second", &[], b"two");
+    assert_eq!(second.status(), 200);
+    let body = second.body_as_string().unwrap();
+    assert_eq!(reported(&body, "PATH_INFO"), "/second");
+    assert_eq!(reported(&body, "body"), "two");
+}
+
+#[spin_test]
+fn wagi_requests_beyond_the_composed_instances_fail() {
+    for _ in 0..8 {
+        let response = request(http::types::Method::Get, "/echo", &[], b"");
+        assert_eq!(response.status(), 200);
+    }
+
+    let response = request(http::types::Method::Get, "/echo", &[], b"");
+    assert_eq!(response.status(), 500);
+}
+
+#[spin_test]
+fn wagi_server_is_the_listen_addr() {
+    let response = request_with(
+        http::types::Method::Get,
+        "/echo",
+        &[],
+        b"",
+        RequestOptions {
+            client_addr: Some("203.0.113.7:51234"),
+            tls: Some(true),
+            listen_addr: Some("example.com"),
+        },
+    );
+    assert_eq!(response.status(), 200);
+    let body = response.body_as_string().unwrap();
+    assert_eq!(reported(&body, "SERVER_NAME"), "example.com");
+    assert_eq!(reported(&body, "SERVER_PORT"), "443");
+    assert_eq!(reported(&body, "REMOTE_ADDR"), "203.0.113.7");
+}
@@ -1,12 +1,14 @@
 mod composition;
 mod manifest;
 pub mod runtime;
+mod variables;
 
 use std::{collections::HashSet, path::PathBuf};
 
 use anyhow::Context;
 pub use composition::Composition;
 pub use manifest::ManifestInformation;
+pub use variables::Variables;
 
 /// The built `spin-test-virt` component
 const SPIN_TEST_VIRT: &[u8] = include_bytes!(concat!(
@@ -19,6 +21,21 @@ const ROUTER: &[u8] = include_bytes!(concat!(
     "/wasm32-unknown-unknown/release/router.wasm"
 ));
 
+/// The imports through which the `router` component runs WAGI requests
+///
+/// A WASI command can only be started once per instance, so each import is satisfied by a fresh
+/// instance of the app. These match the `wagi-instance*` imports of the router's world.
+const WAGI_INSTANCES: [&str; 8] = [
+    "wagi-instance0",
+    "wagi-instance1",
+    "wagi-instance2",
+    "wagi-instance3",
+    "wagi-instance4",
+    "wagi-instance5",
+    "wagi-instance6",
+    "wagi-instance7",
+];
+
 /// A Wasm component
 pub struct Component {
     bytes: Vec<u8>,
@@ -40,24 +57,32 @@ impl Component {
 }
 
 /// Encode a composition of an app component and a test component
+///
+/// The `trigger_exports` of the app component are passed to the test component so that tests
+/// can invoke triggers `spin-test` has no built-in support for.
 pub fn perform_composition(
     app_component: Component,
     test_component: Component,
     test_target: &TestTarget,
+    trigger_exports: &[String],
 ) -> anyhow::Result<Vec<u8>> {
     let composition = Composition::new();
 
     // Instantiate the `virt` component
     let virt = instantiate_virt(&composition)?;
 
-    // Instantiate the `app` component with various exports from the virt instance
-    let app = instantiate_app(&composition, app_component, &virt)?;
-
-    // Instantiate the `router` component
-    let router = instantiate_router(&composition, &virt, app)?;
+    // Instantiate the `app` and `router` components with various exports from the virt instance
+    let (app, router) = instantiate_app_and_router(&composition, app_component, &virt)?;
 
     // Instantiate the `test` component
-    let test = instantiate_test(&composition, test_component, router, virt)?;
+    let test = instantiate_test(
+        &composition,
+        test_component,
+        router,
+        virt,
+        &app,
+        trigger_exports,
+    )?;
 
     match test_target {
         TestTarget::AdHoc { exports } => {
@@ -109,11 +134,8 @@ pub fn virtualize_app(app_component: Component) -> anyhow::Result<Vec<u8>> {
     export("wasi:io/error@0.2.0");
     export("wasi:io/poll@0.2.0");
 
-    // Instantiate the `app` component with various exports from the virt instance
-    let app = instantiate_app(&composition, app_component, &virt)?;
-
-    // Instantiate the `router` component
-    let router = instantiate_router(&composition, &virt, app)?;
+    // Instantiate the `app` and `router` components with various exports from the virt instance
+    let (_, router) = instantiate_app_and_router(&composition, app_component, &virt)?;
 
     let export = router
         .export("wasi:http/incoming-handler@0.2.0")
@@ -134,20 +156,41 @@ fn instantiate_test(
     test_component: Component,
     router: composition::Instance,
     virt: composition::Instance,
+    app: &composition::Instance,
+    trigger_exports: &[String],
 ) -> Result<composition::Instance, anyhow::Error> {
     // Get args from `router` and `virt` instances
-    let router_args = [Ok((
-        "wasi:http/incoming-handler@0.2.0",
-        export_item(&router, "wasi:http/incoming-handler@0.2.0")?,
-    ))]
+    let router_args = [
+        Ok((
+            "wasi:http/incoming-handler@0.2.0",
+            export_item(&router, "wasi:http/incoming-handler@0.2.0")?,
+        )),
+        Ok((
+            "fermyon:spin-test/redis-trigger",
+            export_item(&router, "fermyon:router/redis-trigger")?,
+        )),
+    ]
     .into_iter();
     let virt_args = [
         "fermyon:spin-wasi-virt/http-handler",
         "fermyon:spin/sqlite@2.0.0",
+        "fermyon:spin-test-virt/types",
         "fermyon:spin-test-virt/sqlite",
+        "fermyon:spin-test-virt/redis",
+        "fermyon:spin-test-virt/mqtt",
+        "fermyon:spin-test-virt/postgres",
+        "fermyon:spin-test-virt/mysql",
+        "fermyon:spin-test-virt/rdbms",
         "fermyon:spin-test-virt/key-value",
+        "fermyon:spin-test-virt/llm",
         "fermyon:spin-test-virt/variables",
+        "fermyon:spin-test-virt/clock",
         "fermyon:spin/key-value@2.0.0",
+        "fermyon:spin/redis@2.0.0",
+        "fermyon:spin/mqtt@2.0.0",
+        "fermyon:spin/rdbms-types@2.0.0",
+        "fermyon:spin/llm@2.0.0",
+        "fermyon:spin/variables@2.0.0",
         "wasi:io/error@0.2.0",
         "wasi:io/streams@0.2.0",
         "wasi:io/poll@0.2.0",
@@ -168,9 +211,23 @@ fn instantiate_test(
         export_item(&virt, "fermyon:spin-wasi-virt/http-helper")?,
     ))]);
 
+    // Get the trigger entrypoints the test calls directly from the `app` instance
+    let app_args = trigger_exports.iter().map(|name| {
+        let export = app
+            .export(name)
+            .with_context(|| format!("failed to export '{name}' from app"))?
+            .with_context(|| {
+                format!(
+                    "app component does not export '{name}' listed in `spin-test.trigger-exports`"
+                )
+            })?;
+        Ok((name.as_str(), export))
+    });
+
     // Collect args and instantiate the `test` component
     let test_args = router_args
         .chain(virt_args)
+        .chain(app_args)
         .collect::<anyhow::Result<Vec<_>>>()?;
     let test_args = test_args
         .iter()
@@ -185,47 +242,150 @@ fn instantiate_test(
         })
 }
 
-fn instantiate_router(
+/// Instantiate the `app` component behind the `router` component
+///
+/// The app is instantiated twice: the outer instance handles the requests coming into the app
+/// while the inner instance handles the requests the outer instance makes to `self` or
+/// `*.spin.internal` urls. The inner instance's own requests go straight to the virt, so local
+/// service chaining is only one level deep. Each router also gets fresh instances of the app to
+/// run WAGI requests in. The outer app instance is returned along with the router instance which
+/// routes requests to it.
+fn instantiate_app_and_router(
     composition: &Composition,
+    app_component: Component,
+    virt: &composition::Instance,
+) -> anyhow::Result<(composition::Instance, composition::Instance)> {
+    let app = composition
+        .register_package("app", &app_component.bytes)
+        .context("failed to register Spin app")?;
+    let router = composition
+        .register_package("router", ROUTER)
+        .context("failed to register router")?;
+    let virt_outgoing_handler = export_item(virt, "wasi:http/outgoing-handler@0.2.0")?;
+
+    let inner_app = instantiate_app(&app, virt, &virt_outgoing_handler)?;
+    let inner_wagi = wagi_entrypoints(&app, &inner_app, virt, &virt_outgoing_handler)?;
+    let chaining_router =
+        instantiate_router(&router, virt, inner_app, inner_wagi, &virt_outgoing_handler)?;
+    let chaining_outgoing_handler =
+        export_item(&chaining_router, "wasi:http/outgoing-handler@0.2.0")?;
+
+    let outer_app = instantiate_app(&app, virt, &chaining_outgoing_handler)?;
+    let outer_wagi = wagi_entrypoints(&app, &outer_app, virt, &chaining_outgoing_handler)?;
+    let router = instantiate_router(
+        &router,
+        virt,
+        outer_app.clone(),
+        outer_wagi,
+        &virt_outgoing_handler,
+    )?;
+    Ok((outer_app, router))
+}
+
+/// Get the `wasi:cli/run` entrypoints the router runs WAGI requests through
+///
+/// A WASI command can only be started once per instance, so each entrypoint is from a fresh
+/// instance of the app. Apps which aren't WAGI components get a stand-in from the virt which
+/// fails explaining so.
+fn wagi_entrypoints(
+    app: &composition::Package,
+    app_instance: &composition::Instance,
+    virt: &composition::Instance,
+    outgoing_handler: &composition::InstanceExport,
+) -> anyhow::Result<Vec<composition::InstanceExport>> {
+    let is_wagi = app_instance.export("wasi:cli/run@0.2.0")?.is_some();
+    WAGI_INSTANCES
+        .iter()
+        .map(|_| {
+            if is_wagi {
+                let instance = instantiate_app(app, virt, outgoing_handler)?;
+                export_item(&instance, "wasi:cli/run@0.2.0")
+            } else {
+                export_item(virt, "wasi:cli/run@0.2.0")
+            }
+        })
+        .collect()
+}
+
+fn instantiate_router(
+    router: &composition::Package,
     virt: &composition::Instance,
     app: composition::Instance,
+    wagi_entrypoints: Vec<composition::InstanceExport>,
+    outgoing_handler: &composition::InstanceExport,
 ) -> anyhow::Result<composition::Instance> {
     // Get access to the `http/types` and `http-helper` exports
     let http_types = export_instance(virt, "wasi:http/types@0.2.0")?;
     let http_helper = export_instance(virt, "fermyon:spin-wasi-virt/http-helper")?;
 
-    let router_args = [
+    let mut router_args = [
         ("wasi:http/types@0.2.0", virt),
         ("wasi:io/error@0.2.0", virt),
         ("wasi:io/streams@0.2.0", virt),
         ("wasi:io/poll@0.2.0", virt),
         ("wasi:cli/stdout@0.2.0", virt),
         ("set-component-id", virt),
-        ("wasi:http/incoming-handler@0.2.0", &app),
+        ("prepare-wagi", virt),
+        ("finish-wagi", virt),
+        ("take-stand-in-error", virt),
+        ("allows-outbound-url", virt),
+        ("record-internal-request", virt),
         ("outgoing-request", &http_types),
         ("incoming-request", &http_types),
         ("incoming-body", &http_types),
+        ("response-outparam", &http_types),
+        ("future-incoming-response", &http_types),
         ("new-request", &http_helper),
+        ("new-response-future", &http_helper),
+        ("client-addr", &http_helper),
+        ("tls", &http_helper),
+        ("listen-addr", &http_helper),
     ]
     .into_iter()
     .map(|(k, v)| Ok((k, export_item(v, k)?)))
     .collect::<anyhow::Result<Vec<_>>>()?;
 
+    router_args.push(("wasi:http/outgoing-handler@0.2.0", outgoing_handler.clone()));
+
+    // The app is invoked through `wasi:http/incoming-handler` by the Spin executor and through
+    // `fermyon:spin/inbound-redis` by Redis triggers. Whichever the app doesn't export is satisfied
+    // by a stand-in from the virt which fails explaining that the app doesn't handle the trigger.
+    for (import, export, fallback) in [
+        (
+            "wasi:http/incoming-handler@0.2.0",
+            "wasi:http/incoming-handler@0.2.0",
+            "wasi:http/incoming-handler@0.2.0",
+        ),
+        (
+            "fermyon:router/inbound-redis",
+            "fermyon:spin/inbound-redis",
+            "fermyon:spin-test-virt/inbound-redis",
+        ),
+    ] {
+        let export = match app.export(export)? {
+            Some(export) => export,
+            None => export_item(virt, fallback)?,
+        };
+        router_args.push((import, export));
+    }
+    // The WAGI executor runs each request through the next of the `wasi:cli/run` entrypoints
+    router_args.extend(WAGI_INSTANCES.into_iter().zip(wagi_entrypoints));
+
     let router_args = router_args
         .iter()
         .map(|(k, v)| (*k, v as &dyn composition::InstantiationArg));
-    let router = composition
-        .instantiate("router", ROUTER, router_args)
+    let router = router
+        .instantiate(router_args)
         .context("failed to instantiate router")?;
     Ok(router)
 }
 
 fn instantiate_app(
-    composition: &Composition,
-    app_component: Component,
+    app: &composition::Package,
     virt: &composition::Instance,
+    outgoing_handler: &composition::InstanceExport,
 ) -> anyhow::Result<composition::Instance, anyhow::Error> {
-    let app_args = [
+    let mut app_args = [
         "fermyon:spin/key-value@2.0.0",
         "fermyon:spin/llm@2.0.0",
         "fermyon:spin/redis@2.0.0",
@@ -264,23 +424,24 @@ fn instantiate_app(
         "wasi:sockets/tcp@0.2.0",
         "wasi:sockets/tcp-create-socket@0.2.0",
         "wasi:sockets/ip-name-lookup@0.2.0",
-        "wasi:http/outgoing-handler@0.2.0",
         "wasi:http/types@0.2.0",
     ]
     .into_iter()
     .map(|k| Ok((k, export_item(virt, k)?)))
     .collect::<anyhow::Result<Vec<_>>>()?;
+    app_args.push(("wasi:http/outgoing-handler@0.2.0", outgoing_handler.clone()));
 
     let app_args = app_args
         .iter()
         .map(|(k, v)| (*k, v as &dyn composition::InstantiationArg));
-    let app = composition
-        .instantiate("app", &app_component.bytes, app_args)
+    let app = app
+        .instantiate(app_args)
         .context("failed to instantiate Spin app")?;
     Ok(app)
 }
 
-/// Instantiate the `virt` component and export the `fs-handler` instance
+/// Instantiate the `virt` component and export the `fs-handler` and `sqlite` instances and the
+/// `provide-variable` and `set-component-id` functions
 fn instantiate_virt(composition: &Composition) -> anyhow::Result<composition::Instance> {
     let virt = composition
         .instantiate("virt", SPIN_TEST_VIRT, Vec::new())
@@ -289,6 +450,18 @@ fn instantiate_virt(composition: &Composition) -> anyhow::Result<composition::In
     composition
         .export(fs_handler, "fermyon:spin-wasi-virt/fs-handler")
         .context("fatal error: could not export fs-handler from spin-test-virt")?;
+    let sqlite = export_item(&virt, "fermyon:spin-test-virt/sqlite")?;
+    composition
+        .export(sqlite, "fermyon:spin-test-virt/sqlite")
+        .context("fatal error: could not export sqlite from spin-test-virt")?;
+    let provide_variable = export_item(&virt, "provide-variable")?;
+    composition
+        .export(provide_variable, "provide-variable")
+        .context("fatal error: could not export provide-variable from spin-test-virt")?;
+    let set_component_id = export_item(&virt, "set-component-id")?;
+    composition
+        .export(set_component_id, "set-component-id")
+        .context("fatal error: could not export set-component-id from spin-test-virt")?;
     Ok(virt)
 }
 
@@ -3,7 +3,7 @@ use std::path::PathBuf;
 use anyhow::Context as _;
 use clap::Parser;
 use owo_colors::OwoColorize as _;
-use spin_test::{runtime::TestInvocation, Component, ManifestInformation, TestTarget};
+use spin_test::{runtime::TestInvocation, Component, ManifestInformation, TestTarget, Variables};
 
 #[derive(clap::Parser)]
 #[command(version, about)]
@@ -63,11 +63,34 @@ struct Run {
         default_value = spin_common::paths::DEFAULT_MANIFEST_FILE,
     )]
     pub app_source: PathBuf,
+
+    /// Write the sqlite databases of failing tests to this directory.
+    ///
+    /// Each database is written to `<DIR>/<TEST>/<DATABASE>.db`.
+    #[clap(long = "keep-state", value_name = "DIR")]
+    pub keep_state: Option<PathBuf>,
+
+    /// Configuration file for the variables providers, as used by `spin up`.
+    #[clap(long = "runtime-config-file", value_name = "FILE")]
+    pub runtime_config_file: Option<PathBuf>,
+
+    /// Set an application variable (can be specified multiple times).
+    ///
+    /// Takes precedence over `SPIN_VARIABLE_*` environment variables and the runtime config.
+    #[clap(long = "variable", value_name = "KEY=VALUE", value_parser = parse_key_value)]
+    pub variables: Vec<(String, String)>,
 }
 
 impl Run {
     fn exec(self) -> anyhow::Result<()> {
         let manifest = ManifestInformation::resolve(self.app_source)?;
+        let variables = Variables::resolve(
+            &manifest,
+            self.runtime_config_file.as_deref(),
+            std::env::vars(),
+            self.variables,
+        )
+        .context("failed to resolve application variables")?;
         if let Some(build) = manifest.build_info()? {
             build.exec()?;
         }
@@ -85,15 +108,28 @@ impl Run {
                 test_path.display()
             )
         })?;
-        let encoded =
-            spin_test::perform_composition(app_component, test_component, &test_target)
-                .context("failed to compose Spin app, test, and virtualized Spin environment")?;
+        let trigger_exports = manifest.trigger_exports()?;
+        let encoded = spin_test::perform_composition(
+            app_component,
+            test_component,
+            &test_target,
+            &trigger_exports,
+        )
+        .context("failed to compose Spin app, test, and virtualized Spin environment")?;
 
-        let tests = run_tests(test_target, encoded, manifest)?;
+        let tests = run_tests(test_target, encoded, manifest, variables, self.keep_state)?;
         libtest_mimic::run(&libtest_mimic::Arguments::default(), tests).exit();
     }
 }
 
+/// Parse a `KEY=VALUE` pair given on the command line
+fn parse_key_value(s: &str) -> anyhow::Result<(String, String)> {
+    let (key, value) = s
+        .split_once('=')
+        .context("variables must be given in the form KEY=VALUE")?;
+    Ok((key.trim().to_owned(), value.to_owned()))
+}
+
 fn print_error_chain(err: anyhow::Error) {
     if let Some(cause) = err.source() {
         let is_multiple = cause.source().is_some();
@@ -112,8 +148,18 @@ fn run_tests(
     test_target: spin_test::TestTarget,
     encoded: Vec<u8>,
     manifest: ManifestInformation,
+    variables: Variables,
+    keep_state: Option<PathBuf>,
 ) -> anyhow::Result<Vec<libtest_mimic::Trial>> {
     let encoded = std::sync::Arc::new(encoded);
+    let sqlite_snapshot = if manifest.sqlite_snapshot()? {
+        let snapshot = spin_test::runtime::Runtime::instantiate(manifest.clone(), &encoded)?
+            .take_sqlite_snapshot()
+            .context("failed to prepare the sqlite databases")?;
+        Some(snapshot)
+    } else {
+        None
+    };
 
     let tests: Vec<_> = match test_target {
         spin_test::TestTarget::AdHoc { exports } => exports
@@ -136,11 +182,23 @@ fn run_tests(
         .map(|(test_name, test)| {
             let manifest = manifest.clone();
             let encoded = encoded.clone();
+            let sqlite_snapshot = sqlite_snapshot.clone();
+            let variables = variables.clone();
+            let keep_state = keep_state.clone();
 
-            libtest_mimic::Trial::test(test_name, move || {
-                let mut runtime = spin_test::runtime::Runtime::instantiate(manifest, &encoded)?;
+            libtest_mimic::Trial::test(test_name.clone(), move || {
+                let mut runtime = spin_test::runtime::Runtime::instantiate(manifest, &encoded)?
+                    .with_sqlite_snapshot(sqlite_snapshot)
+                    .with_keep_sqlite_state(keep_state.is_some())
+                    .with_variables(variables);
 
-                Ok(runtime.run(test).map_err(FullError::from)?)
+                let result = runtime.run(test);
+                if let (Err(_), Some(dir)) = (&result, keep_state) {
+                    if let Err(e) = runtime.keep_sqlite_state(&dir, &test_name) {
+                        eprintln!("failed to keep sqlite state of test '{test_name}': {e:#}");
+                    }
+                }
+                Ok(result.map_err(FullError::from)?)
             })
         })
         .collect();
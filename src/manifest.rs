@@ -9,6 +9,8 @@ pub struct ManifestInformation {
     raw: String,
     /// Absolute path to the manifest file
     path: PathBuf,
+    /// The id of the component under test
+    component_id: String,
     /// The parsed manifest's config for the component under test
     component: spin_manifest::schema::v2::Component,
 }
@@ -26,25 +28,36 @@ impl ManifestInformation {
                 manifest_path.display()
             )
         })?;
-        let manifest = spin_manifest::manifest_from_str(&raw_manifest).with_context(|| {
+        let mut manifest = spin_manifest::manifest_from_str(&raw_manifest).with_context(|| {
             format!(
                 "failed to deserialize spin.toml manifest at {}",
                 manifest_path.display()
             )
         })?;
+        // Give inline trigger components (e.g., `[trigger.http.component]`) an id and move them
+        // into `[component]` so they are handled like any other component
+        spin_manifest::normalize::normalize_manifest(&mut manifest);
+        if manifest
+            .triggers
+            .values()
+            .all(|triggers| triggers.is_empty())
+        {
+            anyhow::bail!("spin.toml does not contain any triggers so there is nothing to test")
+        }
+        check_component_references(&manifest)?;
         if manifest.components.len() > 1 {
             anyhow::bail!("Spin applications with more than one component are not yet supported by `spin-test`")
         }
-        let component = manifest
+        let (component_id, component) = manifest
             .components
-            .values()
+            .iter()
             .next()
-            .context("spin.toml did not contain any components")?
-            .clone();
+            .context("spin.toml did not contain any components")?;
         Ok(Self {
             raw: raw_manifest,
             path: manifest_path,
-            component,
+            component_id: component_id.to_string(),
+            component: component.clone(),
         })
     }
 
@@ -56,6 +69,11 @@ impl ManifestInformation {
         &self.component
     }
 
+    /// Get the id of the component under test
+    pub fn component_id(&self) -> &str {
+        &self.component_id
+    }
+
     /// Get the source of the component under test
     pub fn app_source(&self) -> anyhow::Result<&str> {
         match &self.component.source {
@@ -96,6 +114,66 @@ impl ManifestInformation {
         Ok(std::path::Path::new(test_source))
     }
 
+    /// Get the SQL migration files to apply to each sqlite database before a test runs
+    ///
+    /// The files are returned as absolute paths keyed by the label of the database.
+    pub fn sqlite_migrations(&self) -> anyhow::Result<Vec<(String, Vec<PathBuf>)>> {
+        let Some(sqlite_config) = self.test_config()?.get("sqlite") else {
+            return Ok(Vec::new());
+        };
+        let sqlite_config = sqlite_config
+            .as_table()
+            .context("component `spin-test.sqlite` configuration was not a table")?;
+        let mut migrations = Vec::new();
+        for (database, files) in sqlite_config {
+            if !self
+                .component
+                .sqlite_databases
+                .iter()
+                .any(|db| db == database)
+            {
+                anyhow::bail!(
+                    "`spin-test.sqlite` configures database '{database}' which the component does not have access to"
+                );
+            }
+            let files = string_or_list(files).with_context(|| {
+                format!(
+                    "component `spin-test.sqlite.{database}` was not a string or a list of strings"
+                )
+            })?;
+            let files = files.into_iter().map(|f| self.absolute_from(f)).collect();
+            migrations.push((database.clone(), files));
+        }
+        Ok(migrations)
+    }
+
+    /// Whether the sqlite databases should be prepared once and shared between tests
+    ///
+    /// When enabled, migrations are applied once and every test starts from a snapshot of
+    /// the resulting databases instead of re-applying the migrations.
+    pub fn sqlite_snapshot(&self) -> anyhow::Result<bool> {
+        let Some(snapshot) = self.test_config()?.get("sqlite-snapshot") else {
+            return Ok(false);
+        };
+        snapshot
+            .as_bool()
+            .context("component `spin-test.sqlite-snapshot` was not a boolean")
+    }
+
+    /// Get the names of the exports of the app component which tests can call directly
+    ///
+    /// These are the entrypoints of triggers `spin-test` doesn't know about (e.g.,
+    /// `fermyon:spin/inbound-cron`).
+    pub fn trigger_exports(&self) -> anyhow::Result<Vec<String>> {
+        let Some(exports) = self.test_config()?.get("trigger-exports") else {
+            return Ok(Vec::new());
+        };
+        let exports = string_or_list(exports).context(
+            "component `spin-test.trigger-exports` was not a string or a list of strings",
+        )?;
+        Ok(exports.into_iter().map(ToOwned::to_owned).collect())
+    }
+
     /// Resolve a relative path from the manifest file to an absolute path
     pub fn absolute_from(&self, path: impl AsRef<std::path::Path>) -> PathBuf {
         self.path.parent().unwrap().join(path)
@@ -122,6 +200,15 @@ impl ManifestInformation {
     }
 }
 
+/// Read a toml value that is either a single string or a list of strings
+fn string_or_list(value: &toml::Value) -> Option<Vec<&str>> {
+    match value {
+        toml::Value::String(s) => Some(vec![s.as_str()]),
+        toml::Value::Array(a) => a.iter().map(|v| v.as_str()).collect(),
+        _ => None,
+    }
+}
+
 /// Information about how to build the test
 pub struct BuildInfo {
     /// The command to run to build the test
@@ -149,3 +236,24 @@ impl BuildInfo {
         Err(anyhow!("failed to build test component. {}", status))
     }
 }
+
+/// Ensure every trigger references a component the manifest defines
+fn check_component_references(
+    manifest: &spin_manifest::schema::v2::AppManifest,
+) -> anyhow::Result<()> {
+    for (trigger_type, triggers) in &manifest.triggers {
+        for trigger in triggers {
+            let Some(spin_manifest::schema::v2::ComponentSpec::Reference(component)) =
+                &trigger.component
+            else {
+                continue;
+            };
+            if !manifest.components.contains_key(component) {
+                anyhow::bail!(
+                    "a `{trigger_type}` trigger references component '{component}' which is not defined in spin.toml"
+                );
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anyhow::Context as _;
 
 use crate::manifest::ManifestInformation;
+use crate::Variables;
 
 mod non_dynamic {
     wasmtime::component::bindgen!({
@@ -16,12 +17,23 @@ mod dynamic {
     });
 }
 
+/// A serialized copy of the virtualized sqlite databases keyed by database name
+pub type SqliteSnapshot = std::sync::Arc<Vec<(String, Vec<u8>)>>;
+
 /// The `spin-test` runtime
 pub struct Runtime {
     store: wasmtime::Store<Data>,
     linker: wasmtime::component::Linker<Data>,
     component: wasmtime::component::Component,
     manifest: ManifestInformation,
+    /// The state the sqlite databases start in instead of applying migrations
+    sqlite_snapshot: Option<SqliteSnapshot>,
+    /// Whether to take a snapshot of the sqlite databases when a test fails
+    keep_sqlite_state: bool,
+    /// The sqlite databases as they were when the most recent test failed
+    failed_sqlite_state: Option<anyhow::Result<Vec<(String, Vec<u8>)>>>,
+    /// The application variables provided to every test
+    variables: Variables,
 }
 
 impl Runtime {
@@ -51,43 +63,175 @@ impl Runtime {
             linker,
             component,
             manifest,
+            sqlite_snapshot: None,
+            keep_sqlite_state: false,
+            failed_sqlite_state: None,
+            variables: Variables::default(),
         })
     }
 
+    /// Start every test from the given sqlite snapshot instead of applying migrations
+    pub fn with_sqlite_snapshot(mut self, snapshot: Option<SqliteSnapshot>) -> Self {
+        self.sqlite_snapshot = snapshot;
+        self
+    }
+
+    /// Take a snapshot of the sqlite databases of failing tests so they can be written to disk with
+    /// [`Runtime::keep_sqlite_state`]
+    pub fn with_keep_sqlite_state(mut self, keep: bool) -> Self {
+        self.keep_sqlite_state = keep;
+        self
+    }
+
+    /// Provide the given application variables to every test
+    pub fn with_variables(mut self, variables: Variables) -> Self {
+        self.variables = variables;
+        self
+    }
+
+    /// Apply the configured migrations and take a snapshot of the resulting sqlite databases
+    pub fn take_sqlite_snapshot(&mut self) -> anyhow::Result<SqliteSnapshot> {
+        let instance = self
+            .linker
+            .instantiate(&mut self.store, &self.component)
+            .context("failed to instantiate spin-test composition")?;
+        let runner = dynamic::DynamicRunner::new(&mut self.store, &instance)?;
+        self.apply_sqlite_migrations(&runner)?;
+        let snapshot = runner
+            .fermyon_spin_test_virt_sqlite()
+            .call_take_snapshot(&mut self.store)?
+            .context("failed to take a snapshot of the sqlite databases")?;
+        Ok(std::sync::Arc::new(snapshot))
+    }
+
     /// Run the test component
     pub fn run(&mut self, test: TestInvocation) -> anyhow::Result<()> {
-        match test {
+        let (runner, result) = match test {
             TestInvocation::Export(test_name) => {
                 let test_instance = self
                     .linker
                     .instantiate(&mut self.store, &self.component)
                     .context("failed to instantiate spin-test composition")?;
                 let runner = dynamic::DynamicRunner::new(&mut self.store, &test_instance)?;
-                self.add_files(runner)?;
+                self.prepare_sqlite(&runner)?;
+                self.provide_variables(&runner)?;
+                self.add_files(&runner)?;
+                runner.call_set_component_id(&mut self.store, self.manifest.component_id())?;
 
                 let test_func = test_instance
                     .get_typed_func::<(), ()>(&mut self.store, &test_name)
                     .with_context(|| format!("failed to get test function '{test_name}'"))?;
 
-                test_func
+                let result = test_func
                     .call(&mut self.store, ())
-                    .context(format!("test '{test_name}' failed "))
+                    .context(format!("test '{test_name}' failed "));
+                (runner, result)
             }
             TestInvocation::RunArgument(test_name) => {
-                let (runner, _) = non_dynamic::Runner::instantiate(
+                let (runner, test_instance) = non_dynamic::Runner::instantiate(
                     &mut self.store,
                     &self.component,
                     &self.linker,
                 )
                 .context("failed to instantiate spin-test composition as test runner world")?;
+                let dynamic_runner = dynamic::DynamicRunner::new(&mut self.store, &test_instance)?;
+                self.prepare_sqlite(&dynamic_runner)?;
+                self.provide_variables(&dynamic_runner)?;
+                dynamic_runner
+                    .call_set_component_id(&mut self.store, self.manifest.component_id())?;
 
-                runner.call_run(&mut self.store, &test_name)
+                let result = runner.call_run(&mut self.store, &test_name);
+                (dynamic_runner, result)
             }
+        };
+        if result.is_err() && self.keep_sqlite_state {
+            // Take the snapshot right away through the failed test's own runner. The virt can't
+            // be entered again if the test trapped while inside it, so report that when it happens.
+            let snapshot = runner
+                .fermyon_spin_test_virt_sqlite()
+                .call_take_snapshot(&mut self.store)
+                .context("the virtualized sqlite databases can't be read after the test trapped")
+                .and_then(|snapshot| snapshot.context("failed to serialize the sqlite databases"));
+            self.failed_sqlite_state = Some(snapshot);
         }
+        result
+    }
+
+    /// Write the sqlite databases of the most recent failing test to disk
+    ///
+    /// Each database is written to `<dir>/<test_name>/<database>.db` so that it can be
+    /// inspected with the `sqlite3` CLI.
+    pub fn keep_sqlite_state(
+        &mut self,
+        dir: &std::path::Path,
+        test_name: &str,
+    ) -> anyhow::Result<()> {
+        let databases = self
+            .failed_sqlite_state
+            .take()
+            .context("no test has failed so there is no sqlite state to keep")??;
+        let dir = dir.join(sanitize_file_name(test_name));
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("failed to create directory '{}'", dir.display()))?;
+        for (database, contents) in databases {
+            let path = dir.join(format!("{}.db", sanitize_file_name(&database)));
+            std::fs::write(&path, contents).with_context(|| {
+                format!("failed to write sqlite database to '{}'", path.display())
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Bring the virtualized sqlite databases into their initial state
+    ///
+    /// This either restores the sqlite snapshot if there is one or applies the migrations.
+    fn prepare_sqlite(&mut self, runner: &dynamic::DynamicRunner) -> anyhow::Result<()> {
+        let Some(snapshot) = &self.sqlite_snapshot else {
+            return self.apply_sqlite_migrations(runner);
+        };
+        runner
+            .fermyon_spin_test_virt_sqlite()
+            .call_restore_snapshot(&mut self.store, snapshot)?
+            .context("failed to restore the sqlite databases from snapshot")
+    }
+
+    /// Apply the configured SQL migrations to the virtualized sqlite databases
+    fn apply_sqlite_migrations(&mut self, runner: &dynamic::DynamicRunner) -> anyhow::Result<()> {
+        let sqlite = runner.fermyon_spin_test_virt_sqlite().connection();
+        for (database, files) in self.manifest.sqlite_migrations()? {
+            let connection = sqlite
+                .call_open(&mut self.store, &database)?
+                .with_context(|| format!("failed to open sqlite database '{database}'"))?;
+            for file in files {
+                let statements = std::fs::read_to_string(&file).with_context(|| {
+                    format!("failed to read sqlite migration '{}'", file.display())
+                })?;
+                sqlite
+                    .call_execute_batch(&mut self.store, connection, &statements)?
+                    .with_context(|| {
+                        format!(
+                            "failed to apply migration '{}' to sqlite database '{database}'",
+                            file.display()
+                        )
+                    })?;
+            }
+            connection.resource_drop(&mut self.store)?;
+        }
+        Ok(())
+    }
+
+    /// Provide the application variables to the virtualized variables interface
+    ///
+    /// These sit below the values tests set so that tests can override but not remove them.
+    fn provide_variables(&mut self, runner: &dynamic::DynamicRunner) -> anyhow::Result<()> {
+        for (key, value) in self.variables.iter() {
+            runner.call_provide_variable(&mut self.store, key, value)?;
+        }
+        Ok(())
     }
 
     /// Make all mounted files visible to the WASI virtual filesystem
-    fn add_files(&mut self, runner: dynamic::DynamicRunner) -> anyhow::Result<()> {
+    fn add_files(&mut self, runner: &dynamic::DynamicRunner) -> anyhow::Result<()> {
         //TODO(rylev): handle component.exclude_files
         /// Make a file visible to the WASI virtual filesystem
         fn add_file<T>(
@@ -123,7 +267,7 @@ impl Runtime {
 
                         // Guest path is the path relative to the manifest
                         let guest_path = self.manifest.relative_from(&host_path);
-                        add_file(&mut self.store, &runner, &host_path, &guest_path)?;
+                        add_file(&mut self.store, runner, &host_path, &guest_path)?;
                     }
                 }
                 spin_manifest::schema::v2::WasiFilesMount::Placement {
@@ -153,13 +297,13 @@ impl Runtime {
                             let guest_path = std::path::Path::new(&destination)
                                 .join(self.manifest.relative_from(&host_path));
 
-                            add_file(&mut self.store, &runner, &host_path, &guest_path)?;
+                            add_file(&mut self.store, runner, &host_path, &guest_path)?;
                         }
                     } else {
                         // Guest path is the path relative to the manifest appended to the destination
                         let guest_path = std::path::Path::new(&destination)
                             .join(self.manifest.relative_from(&host_path));
-                        add_file(&mut self.store, &runner, &host_path, &guest_path)?
+                        add_file(&mut self.store, runner, &host_path, &guest_path)?
                     }
                 }
             }
@@ -168,6 +312,16 @@ impl Runtime {
     }
 }
 
+/// Replace any characters that are not safe to use in a file name
+fn sanitize_file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
 /// How a test is expected to be invoked
 pub enum TestInvocation {
     /// As a named export
@@ -0,0 +1,150 @@
+use std::path::Path;
+
+use anyhow::Context as _;
+
+use crate::ManifestInformation;
+
+/// The prefix of environment variables which provide application variables
+const ENV_PREFIX: &str = "SPIN_VARIABLE_";
+
+/// Application variables provided to a test run the way `spin up` provides them
+#[derive(Clone, Default)]
+pub struct Variables {
+    values: Vec<(String, String)>,
+}
+
+impl Variables {
+    /// Gather the application variables
+    ///
+    /// Later sources take precedence over earlier ones:
+    /// * providers configured in the runtime config file
+    /// * `SPIN_VARIABLE_*` entries of the `.env` file next to the manifest
+    /// * `SPIN_VARIABLE_*` environment variables
+    /// * variables given on the command line
+    pub fn resolve(
+        manifest: &ManifestInformation,
+        runtime_config_file: Option<&Path>,
+        env: impl IntoIterator<Item = (String, String)>,
+        cli: Vec<(String, String)>,
+    ) -> anyhow::Result<Self> {
+        let env: Vec<_> = env.into_iter().collect();
+        let mut values = Vec::new();
+        if let Some(file) = runtime_config_file {
+            values.extend(from_runtime_config(file, &env)?);
+        }
+        let dotenv = manifest.absolute_from(".env");
+        if dotenv.is_file() {
+            values.extend(with_prefix(read_dotenv(&dotenv)?, ENV_PREFIX));
+        }
+        values.extend(with_prefix(env.iter().cloned(), ENV_PREFIX));
+        values.extend(cli.into_iter().map(|(k, v)| (k.to_lowercase(), v)));
+        Ok(Self { values })
+    }
+
+    /// Iterate over the variables in order of increasing precedence
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+/// Read the variables provided by the providers configured in a runtime config file
+///
+/// Only the `static` and `env` providers are supported since other providers require access
+/// to external services. The `env` providers read from `env`.
+fn from_runtime_config(
+    file: &Path,
+    env: &[(String, String)],
+) -> anyhow::Result<Vec<(String, String)>> {
+    let contents = std::fs::read_to_string(file)
+        .with_context(|| format!("failed to read runtime config file '{}'", file.display()))?;
+    let config: toml::Table = toml::from_str(&contents)
+        .with_context(|| format!("failed to parse runtime config file '{}'", file.display()))?;
+    let base_dir = file.parent().unwrap_or(Path::new("."));
+    let mut values = Vec::new();
+    // Spin 2 calls these `config_provider` while later versions call them `variables_provider`
+    for table in ["config_provider", "variables_provider"] {
+        let Some(providers) = config.get(table) else {
+            continue;
+        };
+        let providers = providers
+            .as_array()
+            .with_context(|| format!("runtime config `{table}` was not an array of tables"))?;
+        for provider in providers {
+            let kind = provider
+                .get("type")
+                .and_then(|t| t.as_str())
+                .with_context(|| format!("runtime config `{table}` entry had no `type`"))?;
+            match kind {
+                "static" => {
+                    let Some(static_values) = provider.get("values") else {
+                        continue;
+                    };
+                    let static_values = static_values
+                        .as_table()
+                        .context("static variables provider `values` was not a table")?;
+                    for (key, value) in static_values {
+                        let value = value.as_str().with_context(|| {
+                            format!("static variables provider value for '{key}' was not a string")
+                        })?;
+                        values.push((key.to_lowercase(), value.to_owned()));
+                    }
+                }
+                "env" => {
+                    let prefix = provider
+                        .get("prefix")
+                        .and_then(|p| p.as_str())
+                        .unwrap_or("SPIN_VARIABLE");
+                    let prefix = format!("{}_", prefix.trim_end_matches('_'));
+                    if let Some(path) = provider.get("dotenv_path").and_then(|p| p.as_str()) {
+                        let path = base_dir.join(path);
+                        values.extend(with_prefix(read_dotenv(&path)?, &prefix));
+                    }
+                    values.extend(with_prefix(env.iter().cloned(), &prefix));
+                }
+                kind => anyhow::bail!(
+                    "variables provider of type '{kind}' in runtime config file '{}' is not supported by `spin-test`",
+                    file.display()
+                ),
+            }
+        }
+    }
+    Ok(values)
+}
+
+/// Keep the variables starting with `prefix` and turn them into application variable names
+fn with_prefix(
+    vars: impl IntoIterator<Item = (String, String)>,
+    prefix: &str,
+) -> Vec<(String, String)> {
+    vars.into_iter()
+        .filter_map(|(k, v)| {
+            let name = k.strip_prefix(prefix)?;
+            Some((name.to_lowercase(), v))
+        })
+        .collect()
+}
+
+/// Read the `KEY=VALUE` entries of a `.env` file
+fn read_dotenv(path: &Path) -> anyhow::Result<Vec<(String, String)>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read .env file '{}'", path.display()))?;
+    let mut entries = Vec::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let Some((key, value)) = line.split_once('=') else {
+            anyhow::bail!("invalid line in .env file '{}': {line}", path.display());
+        };
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+            .unwrap_or(value);
+        entries.push((key.trim().to_owned(), value.to_owned()));
+    }
+    Ok(entries)
+}
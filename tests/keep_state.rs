@@ -0,0 +1,44 @@
+//! Checks that `spin-test --keep-state` writes the sqlite databases of failing tests to disk.
+//!
+//! This runs the test in `examples/test-keep-state-rs`, which fails on purpose after adding a user
+//! to the app in `examples/apps/features-rs`.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn keep_state_writes_the_sqlite_databases_of_failing_tests() {
+    let examples = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    let status = Command::new("cargo")
+        .args(["build", "--target", "wasm32-wasi", "--release"])
+        .current_dir(examples.join("apps/features-rs"))
+        .status()
+        .expect("failed to run cargo");
+    assert!(status.success(), "failed to build the features app");
+
+    let dir = temp_dir::TempDir::new().unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_spin-test"))
+        .arg("run")
+        .arg("--keep-state")
+        .arg(dir.path())
+        .current_dir(examples.join("test-keep-state-rs"))
+        .output()
+        .expect("failed to run spin-test");
+    assert!(
+        !output.status.success(),
+        "the test was expected to fail: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let database = dir
+        .path()
+        .join("failing-test-keeps-its-sqlite-state")
+        .join("default.db");
+    let contents = std::fs::read(&database)
+        .unwrap_or_else(|e| panic!("failed to read '{}': {e}", database.display()));
+    assert!(contents.starts_with(b"SQLite format 3\0"));
+    assert!(
+        contents.windows(4).any(|w| w == b"Kept"),
+        "the database does not contain the user the test added"
+    );
+}